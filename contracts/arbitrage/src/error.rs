@@ -0,0 +1,67 @@
+use soroban_sdk::{self, contracterror};
+use soroswap_library::SoroswapLibraryError;
+
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapArbitrageError {
+    /// SoroswapArbitrage: not yet initialized
+    NotInitialized = 1001,
+
+    /// SoroswapArbitrage: already initialized
+    InitializeAlreadyInitialized = 1002,
+
+    /// SoroswapArbitrage: negative amounts are not allowed
+    NegativeNotAllowed = 1003,
+
+    /// SoroswapArbitrage: deadline expired
+    DeadlineExpired = 1004,
+
+    /// SoroswapArbitrage: path must be a cycle of at least two pairs, starting and ending with the same token
+    InvalidPath = 1005,
+
+    /// SoroswapArbitrage: pair does not exist
+    PairDoesNotExist = 1006,
+
+    /// SoroswapArbitrage: the cycle is not profitable at the current reserves
+    NotProfitable = 1007,
+
+    /// SoroswapArbitrage: the cycle returned less than the input plus the minimum profit
+    InsufficientProfit = 1008,
+
+    /// SoroswapLibrary: insufficient amount
+    LibraryInsufficientAmount = 1010,
+
+    /// SoroswapLibrary: insufficient liquidity
+    LibraryInsufficientLiquidity = 1011,
+
+    /// SoroswapLibrary: insufficient input amount
+    LibraryInsufficientInputAmount = 1012,
+
+    /// SoroswapLibrary: insufficient output amount
+    LibraryInsufficientOutputAmount = 1013,
+
+    /// SoroswapLibrary: invalid path
+    LibraryInvalidPath = 1014,
+
+    /// SoroswapLibrary: token_a and token_b have identical addresses
+    LibrarySortIdenticalTokens = 1015,
+
+    /// SoroswapLibrary: arithmetic overflow
+    LibraryOverflow = 1016,
+}
+
+impl From<SoroswapLibraryError> for SoroswapArbitrageError {
+    fn from(err: SoroswapLibraryError) -> Self {
+        match err {
+            SoroswapLibraryError::InsufficientAmount => SoroswapArbitrageError::LibraryInsufficientAmount,
+            SoroswapLibraryError::InsufficientLiquidity => SoroswapArbitrageError::LibraryInsufficientLiquidity,
+            SoroswapLibraryError::InsufficientInputAmount => SoroswapArbitrageError::LibraryInsufficientInputAmount,
+            SoroswapLibraryError::InsufficientOutputAmount => SoroswapArbitrageError::LibraryInsufficientOutputAmount,
+            SoroswapLibraryError::InvalidPath => SoroswapArbitrageError::LibraryInvalidPath,
+            SoroswapLibraryError::SortIdenticalTokens => SoroswapArbitrageError::LibrarySortIdenticalTokens,
+            SoroswapLibraryError::Overflow => SoroswapArbitrageError::LibraryOverflow,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address, Vec};
+
+// INITIALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub factory: Address
+}
+
+/// Publishes an `InitializedEvent` when the contract is initialized.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `factory` - The Soroswap factory whose pairs are traded.
+pub(crate) fn initialized(e: &Env, factory: Address) {
+    let event = InitializedEvent {
+        factory,
+    };
+    e.events().publish(("SoroswapArbitrage", symbol_short!("init")), event);
+}
+
+// ARBITRAGE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrageEvent {
+    pub trader: Address,
+    pub path: Vec<Address>,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub profit: i128
+}
+
+/// Publishes an `ArbitrageEvent` when a cycle is traded.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The details of the trade.
+pub(crate) fn arbitrage(e: &Env, event: ArbitrageEvent) {
+    e.events().publish(("SoroswapArbitrage", symbol_short!("arbitrage")), event);
+}
@@ -0,0 +1,4 @@
+soroban_sdk::contractimport!(
+    file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.optimized.wasm"
+);
+pub type SoroswapFactoryClient<'a> = Client<'a>;
\ No newline at end of file
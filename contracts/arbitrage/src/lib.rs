@@ -0,0 +1,227 @@
+#![no_std]
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+mod pair;
+mod factory;
+mod test;
+mod event;
+mod storage;
+mod error;
+
+use factory::SoroswapFactoryClient;
+use pair::SoroswapPairClient;
+use storage::{
+    put_factory,
+    has_factory,
+    get_factory,
+    extend_instance_ttl,
+};
+pub use error::SoroswapArbitrageError;
+pub use event::ArbitrageEvent;
+
+fn check_nonnegative_amount(amount: i128) -> Result<(), SoroswapArbitrageError> {
+    if amount < 0 {
+        Err(SoroswapArbitrageError::NegativeNotAllowed)
+    } else {
+        Ok(())
+    }
+}
+
+fn ensure_deadline(e: &Env, timestamp: u64) -> Result<(), SoroswapArbitrageError> {
+    let ledger_timestamp = e.ledger().timestamp();
+    if ledger_timestamp >= timestamp {
+        Err(SoroswapArbitrageError::DeadlineExpired)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_initialized(e: &Env) -> Result<(), SoroswapArbitrageError> {
+    if has_factory(e) {
+        Ok(())
+    } else {
+        Err(SoroswapArbitrageError::NotInitialized)
+    }
+}
+
+/// Checks that `path` is a cycle of at least two pairs and that all of its pairs exist.
+fn check_cycle(e: &Env, factory: &Address, path: &Vec<Address>) -> Result<(), SoroswapArbitrageError> {
+    if path.len() < 3 || path.first() != path.last() {
+        return Err(SoroswapArbitrageError::InvalidPath);
+    }
+    let factory_client = SoroswapFactoryClient::new(e, factory);
+    for i in 0..path.len() - 1 {
+        if !factory_client.pair_exists(&path.get(i).unwrap(), &path.get(i + 1).unwrap()) {
+            return Err(SoroswapArbitrageError::PairDoesNotExist);
+        }
+    }
+    Ok(())
+}
+
+pub trait SoroswapArbitrageTrait {
+
+    /// Initializes the contract with the Soroswap factory whose pairs are traded.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `factory` - The address of the Soroswap factory.
+    fn initialize(e: Env, factory: Address) -> Result<(), SoroswapArbitrageError>;
+
+    /// Trades a cyclic path, such as XLM -> USDC -> EURC -> XLM, in a single transaction. The input is
+    /// `soroswap_library::get_cycle_arbitrage_amount_in`, capped at `amount_in_max`. The swaps are executed
+    /// directly against the pairs and the final amount is sent back to `trader`. The transaction is
+    /// reverted unless it exceeds the input by at least `min_profit`.
+    ///
+    /// # Arguments
+    /// * `trader` - The account paying the input and receiving the output. Must authorize the call.
+    /// * `path` - The cycle, starting and ending with the same token.
+    /// * `amount_in_max` - The maximum input amount.
+    /// * `min_profit` - The minimum profit, in the first token of the path.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The input amount and the profit.
+    fn arbitrage(
+        e: Env,
+        trader: Address,
+        path: Vec<Address>,
+        amount_in_max: i128,
+        min_profit: i128,
+        deadline: u64,
+    ) -> Result<(i128, i128), SoroswapArbitrageError>;
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the factory whose pairs are traded.
+    fn get_factory(e: Env) -> Result<Address, SoroswapArbitrageError>;
+
+    /// Returns the input amount that maximizes the profit of the cyclic `path` at the current reserves,
+    /// zero if it is not profitable.
+    fn get_arbitrage_amount_in(e: Env, path: Vec<Address>) -> Result<i128, SoroswapArbitrageError>;
+}
+
+#[contract]
+struct SoroswapArbitrage;
+
+#[contractimpl]
+impl SoroswapArbitrageTrait for SoroswapArbitrage {
+
+    /// Initializes the contract with the Soroswap factory whose pairs are traded.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `factory` - The address of the Soroswap factory.
+    fn initialize(e: Env, factory: Address) -> Result<(), SoroswapArbitrageError> {
+        if !has_factory(&e) {
+            put_factory(&e, &factory);
+            event::initialized(&e, factory);
+            extend_instance_ttl(&e);
+            Ok(())
+        } else {
+            Err(SoroswapArbitrageError::InitializeAlreadyInitialized)
+        }
+    }
+
+    /// Trades a cyclic path, such as XLM -> USDC -> EURC -> XLM, in a single transaction. The input is
+    /// `soroswap_library::get_cycle_arbitrage_amount_in`, capped at `amount_in_max`. The swaps are executed
+    /// directly against the pairs and the final amount is sent back to `trader`. The transaction is
+    /// reverted unless it exceeds the input by at least `min_profit`.
+    ///
+    /// # Arguments
+    /// * `trader` - The account paying the input and receiving the output. Must authorize the call.
+    /// * `path` - The cycle, starting and ending with the same token.
+    /// * `amount_in_max` - The maximum input amount.
+    /// * `min_profit` - The minimum profit, in the first token of the path.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The input amount and the profit.
+    fn arbitrage(
+        e: Env,
+        trader: Address,
+        path: Vec<Address>,
+        amount_in_max: i128,
+        min_profit: i128,
+        deadline: u64,
+    ) -> Result<(i128, i128), SoroswapArbitrageError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_in_max)?;
+        check_nonnegative_amount(min_profit)?;
+        extend_instance_ttl(&e);
+        trader.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let factory = get_factory(&e);
+        check_cycle(&e, &factory, &path)?;
+
+        let optimal_amount_in = soroswap_library::get_cycle_arbitrage_amount_in(e.clone(), factory.clone(), path.clone())?;
+        let amount_in = optimal_amount_in.min(amount_in_max);
+        if amount_in == 0 {
+            return Err(SoroswapArbitrageError::NotProfitable);
+        }
+        let amounts = soroswap_library::get_amounts_out(e.clone(), factory.clone(), amount_in, path.clone())?;
+
+        let arbitrage = e.current_contract_address();
+        let token = TokenClient::new(&e, &path.get(0).unwrap());
+        let initial_balance = token.balance(&arbitrage);
+
+        // Pay the first pair, then each pair pays the next one and the last one pays this contract
+        let first_pair = soroswap_library::pair_for(e.clone(), factory.clone(), path.get(0).unwrap(), path.get(1).unwrap())?;
+        token.transfer(&trader, &first_pair, &amount_in);
+        for i in 0..path.len() - 1 {
+            let (input, output) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
+            let (token_0, _) = soroswap_library::sort_tokens(input.clone(), output.clone())?;
+            let amount_out = amounts.get(i + 1).unwrap();
+            let (amount_0_out, amount_1_out) = if input == token_0 {
+                (0, amount_out)
+            } else {
+                (amount_out, 0)
+            };
+            let to = if i < path.len() - 2 {
+                soroswap_library::pair_for(e.clone(), factory.clone(), output.clone(), path.get(i + 2).unwrap())?
+            } else {
+                arbitrage.clone()
+            };
+            SoroswapPairClient::new(&e, &soroswap_library::pair_for(e.clone(), factory.clone(), input, output)?)
+                .swap(&amount_0_out, &amount_1_out, &to);
+        }
+
+        // Returning an error reverts the swaps and the transfer of the input
+        let amount_out = token.balance(&arbitrage).checked_sub(initial_balance).unwrap();
+        let profit = amount_out.checked_sub(amount_in).unwrap();
+        if profit <= 0 || profit < min_profit {
+            return Err(SoroswapArbitrageError::InsufficientProfit);
+        }
+        token.transfer(&arbitrage, &trader, &amount_out);
+
+        event::arbitrage(&e, ArbitrageEvent {
+            trader,
+            path,
+            amount_in,
+            amount_out,
+            profit,
+        });
+
+        Ok((amount_in, profit))
+    }
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the factory whose pairs are traded.
+    fn get_factory(e: Env) -> Result<Address, SoroswapArbitrageError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_factory(&e))
+    }
+
+    /// Returns the input amount that maximizes the profit of the cyclic `path` at the current reserves,
+    /// zero if it is not profitable.
+    fn get_arbitrage_amount_in(e: Env, path: Vec<Address>) -> Result<i128, SoroswapArbitrageError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        check_cycle(&e, &factory, &path)?;
+        Ok(soroswap_library::get_cycle_arbitrage_amount_in(e, factory, path)?)
+    }
+}
@@ -0,0 +1,125 @@
+#![cfg(test)]
+extern crate std;
+use crate::{SoroswapArbitrage, SoroswapArbitrageClient};
+use soroban_sdk::{
+    Env, 
+    BytesN, 
+    Address, 
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(&e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+// Pair Contract
+use crate::pair::SoroswapPairClient;
+
+fn pair_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// SoroswapFactory Contract
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryClient<'a> {
+    let pair_hash = pair_contract_wasm(&e);  
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address); 
+    factory.initialize(&setter, &pair_hash);
+    factory
+}
+
+// SoroswapArbitrage Contract
+fn create_soroswap_arbitrage<'a>(e: &Env) -> SoroswapArbitrageClient<'a> {
+    SoroswapArbitrageClient::new(e, &e.register_contract(None, SoroswapArbitrage {}))
+}
+
+// SoroswapArbitrage TEST
+
+pub struct SoroswapArbitrageTest<'a> {
+    env: Env,
+    contract: SoroswapArbitrageClient<'a>,
+    factory: SoroswapFactoryClient<'a>,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    token_2: TokenClient<'a>,
+    admin: Address,
+    trader: Address,
+}
+
+impl<'a> SoroswapArbitrageTest<'a> {
+    /// Three pairs where token_0 -> token_1 -> token_2 -> token_0 returns 1.2 times the input before
+    /// fees and price impact: token_0/token_1 and token_1/token_2 trade at 1, token_2/token_0 at 1.2.
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = 100;
+        });
+        let contract = create_soroswap_arbitrage(&env);
+
+        let admin = Address::generate(&env);
+        let trader = Address::generate(&env);
+
+        let token_0 = create_token_contract(&env, &admin);
+        let token_1 = create_token_contract(&env, &admin);
+        let token_2 = create_token_contract(&env, &admin);
+        token_0.mint(&admin, &10_000_000_000_000_000_000);
+        token_1.mint(&admin, &10_000_000_000_000_000_000);
+        token_2.mint(&admin, &10_000_000_000_000_000_000);
+        token_0.mint(&trader, &1_000_000_000);
+
+        let factory = create_soroswap_factory(&env, &admin);
+        let test = SoroswapArbitrageTest {
+            env,
+            contract,
+            factory,
+            token_0,
+            token_1,
+            token_2,
+            admin,
+            trader,
+        };
+        test.add_liquidity(&test.token_0, &test.token_1, 1_000_000_000, 1_000_000_000);
+        test.add_liquidity(&test.token_1, &test.token_2, 1_000_000_000, 1_000_000_000);
+        test.add_liquidity(&test.token_2, &test.token_0, 1_000_000_000, 1_200_000_000);
+        test.env.budget().reset_unlimited();
+        test
+    }
+
+    fn setup_initialized() -> Self {
+        let test = Self::setup();
+        test.contract.initialize(&test.factory.address);
+        test
+    }
+
+    /// Creates the pair of `token_a` and `token_b` and deposits the given amounts from the admin.
+    fn add_liquidity(&self, token_a: &TokenClient, token_b: &TokenClient, amount_a: i128, amount_b: i128) {
+        self.factory.create_pair(&token_a.address, &token_b.address);
+        let pair = SoroswapPairClient::new(&self.env, &self.factory.get_pair(&token_a.address, &token_b.address));
+        token_a.transfer(&self.admin, &pair.address, &amount_a);
+        token_b.transfer(&self.admin, &pair.address, &amount_b);
+        pair.deposit(&self.admin);
+    }
+}
+
+pub mod initialize;
+pub mod arbitrage;
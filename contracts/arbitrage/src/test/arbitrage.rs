@@ -0,0 +1,126 @@
+use soroban_sdk::{testutils::Events, vec, Address, IntoVal, Vec, symbol_short};
+
+use crate::test::{SoroswapArbitrageTest, create_token_contract};
+use crate::error::SoroswapArbitrageError;
+use crate::event::ArbitrageEvent;
+
+fn cycle(test: &SoroswapArbitrageTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), test.token_2.address.clone(), test.token_0.address.clone()]
+}
+
+#[test]
+fn arbitrage_optimal_amount() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+    let path = cycle(&test);
+
+    // The virtual pair of the cycle has reserves (334335335, 398798799):
+    // (sqrt(997000 * 334335335 * 398798799) - 1000 * 334335335) / 997 = 30354869
+    assert_eq!(test.contract.get_arbitrage_amount_in(&path), 30_354_869);
+
+    // 30354869 token_0 -> 29374810 token_1 -> 28453379 token_2 -> 33102567 token_0
+    let result = test.contract.arbitrage(&test.trader, &path, &1_000_000_000, &2_700_000, &1000);
+    assert_eq!(result, (30_354_869, 2_747_698));
+    assert_eq!(test.token_0.balance(&test.trader), 1_000_000_000 + 2_747_698);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+
+    let arbitrage_event = test.env.events().all().last().unwrap();
+    let expected_arbitrage_event: ArbitrageEvent = ArbitrageEvent {
+        trader: test.trader.clone(),
+        path: path.clone(),
+        amount_in: 30_354_869,
+        amount_out: 33_102_567,
+        profit: 2_747_698,
+    };
+    assert_eq!(
+        vec![&test.env, arbitrage_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapArbitrage", symbol_short!("arbitrage")).into_val(&test.env),
+                (expected_arbitrage_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The trade closed the price difference, a second one is not profitable
+    assert_eq!(test.contract.get_arbitrage_amount_in(&path), 0);
+    let result = test.contract.try_arbitrage(&test.trader, &path, &1_000_000_000, &0, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::NotProfitable)));
+}
+
+#[test]
+fn arbitrage_capped_amount() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+
+    // 10000000 token_0 -> 9871580 token_1 -> 9746044 token_2 -> 11547956 token_0
+    let result = test.contract.arbitrage(&test.trader, &cycle(&test), &10_000_000, &0, &1000);
+    assert_eq!(result, (10_000_000, 1_547_956));
+    assert_eq!(test.token_0.balance(&test.trader), 1_000_000_000 + 1_547_956);
+}
+
+#[test]
+fn arbitrage_insufficient_profit() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+    let path = cycle(&test);
+
+    let result = test.contract.try_arbitrage(&test.trader, &path, &1_000_000_000, &2_747_699, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::InsufficientProfit)));
+
+    // Nothing was traded
+    assert_eq!(test.token_0.balance(&test.trader), 1_000_000_000);
+    assert_eq!(test.contract.get_arbitrage_amount_in(&path), 30_354_869);
+}
+
+#[test]
+fn arbitrage_not_profitable() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+
+    // The opposite direction buys token_0 at 1.2 and sells it at 1
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_2.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+    assert_eq!(test.contract.get_arbitrage_amount_in(&path), 0);
+    let result = test.contract.try_arbitrage(&test.trader, &path, &1_000_000_000, &0, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::NotProfitable)));
+}
+
+#[test]
+fn arbitrage_invalid_path() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+
+    // Not a cycle
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), test.token_2.address.clone()];
+    let result = test.contract.try_arbitrage(&test.trader, &path, &1_000_000_000, &0, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::InvalidPath)));
+
+    // A single pair
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_0.address.clone()];
+    let result = test.contract.try_arbitrage(&test.trader, &path, &1_000_000_000, &0, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::InvalidPath)));
+    let result = test.contract.try_get_arbitrage_amount_in(&path);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::InvalidPath)));
+}
+
+#[test]
+fn arbitrage_pair_does_not_exist() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+    let token_3 = create_token_contract(&test.env, &test.admin);
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_3.address.clone(), test.token_0.address.clone()];
+    let result = test.contract.try_arbitrage(&test.trader, &path, &1_000_000_000, &0, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::PairDoesNotExist)));
+}
+
+#[test]
+fn arbitrage_negative_amounts() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+    let result = test.contract.try_arbitrage(&test.trader, &cycle(&test), &-1, &0, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::NegativeNotAllowed)));
+    let result = test.contract.try_arbitrage(&test.trader, &cycle(&test), &1_000_000_000, &-1, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::NegativeNotAllowed)));
+}
+
+#[test]
+fn arbitrage_deadline_expired() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+    let result = test.contract.try_arbitrage(&test.trader, &cycle(&test), &1_000_000_000, &0, &100);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::DeadlineExpired)));
+}
@@ -0,0 +1,48 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, IntoVal, symbol_short};
+
+use crate::test::SoroswapArbitrageTest;
+use crate::error::SoroswapArbitrageError;
+use crate::event::InitializedEvent;
+
+#[test]
+fn initialize_and_get_factory() {
+    let test = SoroswapArbitrageTest::setup();
+    test.contract.initialize(&test.factory.address);
+    assert_eq!(test.contract.get_factory(), test.factory.address);
+
+    let initialized_event = test.env.events().all().last().unwrap();
+    let expected_initialized_event: InitializedEvent = InitializedEvent {
+        factory: test.factory.address.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, initialized_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapArbitrage", symbol_short!("init")).into_val(&test.env),
+                (expected_initialized_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn not_yet_initialized() {
+    let test = SoroswapArbitrageTest::setup();
+    assert_eq!(test.contract.try_get_factory(), Err(Ok(SoroswapArbitrageError::NotInitialized)));
+
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), test.token_2.address.clone(), test.token_0.address.clone()];
+    let result = test.contract.try_get_arbitrage_amount_in(&path);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::NotInitialized)));
+
+    let result = test.contract.try_arbitrage(&test.trader, &path, &1_000_000_000, &0, &1000);
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::NotInitialized)));
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapArbitrageTest::setup_initialized();
+    let result = test.contract.try_initialize(&Address::generate(&test.env));
+    assert_eq!(result, Err(Ok(SoroswapArbitrageError::InitializeAlreadyInitialized)));
+}
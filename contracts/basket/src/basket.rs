@@ -0,0 +1,95 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::error::SoroswapBasketError;
+
+/// Maximum number of components, so that minting and redeeming, which trade every component, fit in
+/// one transaction.
+pub const MAX_COMPONENTS: u32 = 8;
+
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Shares of the first mint kept by the basket itself, so its shares can never all be redeemed and the
+/// price of a share reset by a tiny mint.
+pub const MINIMUM_SHARES: i128 = 1000;
+
+/// Checks that `components` are between 1 and `MAX_COMPONENTS` different tokens.
+pub fn check_components(components: &Vec<Address>) -> Result<(), SoroswapBasketError> {
+    if components.is_empty() || components.len() > MAX_COMPONENTS {
+        return Err(SoroswapBasketError::InvalidComponents);
+    }
+    for (i, component) in components.iter().enumerate() {
+        for other in components.iter().skip(i + 1) {
+            if component == other {
+                return Err(SoroswapBasketError::InvalidComponents);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that there is one positive weight per component, and that the weights add up to `BPS_DENOMINATOR`.
+pub fn check_weights(components: &Vec<Address>, weights: &Vec<u32>) -> Result<(), SoroswapBasketError> {
+    if weights.len() != components.len() {
+        return Err(SoroswapBasketError::InvalidWeights);
+    }
+    let mut total: u32 = 0;
+    for weight in weights.iter() {
+        if weight == 0 {
+            return Err(SoroswapBasketError::InvalidWeights);
+        }
+        total = total.checked_add(weight).ok_or(SoroswapBasketError::InvalidWeights)?;
+    }
+    if total != BPS_DENOMINATOR {
+        return Err(SoroswapBasketError::InvalidWeights);
+    }
+    Ok(())
+}
+
+/// Splits `amount` in proportion to `parts`, rounding down. What the rounding leaves goes to the last
+/// positive part, so the whole amount is split unless every part is zero.
+pub fn split(e: &Env, amount: i128, parts: &Vec<i128>) -> Vec<i128> {
+    let mut total: i128 = 0;
+    let mut last_positive = None;
+    for (i, part) in parts.iter().enumerate() {
+        total = total.checked_add(part).unwrap();
+        if part > 0 {
+            last_positive = Some(i as u32);
+        }
+    }
+
+    let mut amounts: Vec<i128> = Vec::new(e);
+    let mut allocated: i128 = 0;
+    for part in parts.iter() {
+        let amount_i = if part > 0 {
+            amount.checked_mul(part).unwrap().checked_div(total).unwrap()
+        } else {
+            0
+        };
+        allocated = allocated.checked_add(amount_i).unwrap();
+        amounts.push_back(amount_i);
+    }
+    if let Some(i) = last_positive {
+        let remainder = amount.checked_sub(allocated).unwrap();
+        amounts.set(i, amounts.get(i).unwrap().checked_add(remainder).unwrap());
+    }
+    amounts
+}
+
+/// Returns the shares minted for adding `amounts` to `holdings`: the smallest fraction added to a
+/// component held by the basket, applied to `total_shares` and rounded down. What the other components
+/// received above that fraction stays in the basket, to the benefit of every holder.
+pub fn shares_for(amounts: &Vec<i128>, holdings: &Vec<i128>, total_shares: i128) -> i128 {
+    let mut shares: Option<i128> = None;
+    for (amount, holding) in amounts.iter().zip(holdings.iter()) {
+        if holding <= 0 {
+            continue;
+        }
+        let shares_i = amount.checked_mul(total_shares).unwrap().checked_div(holding).unwrap();
+        shares = Some(shares.map_or(shares_i, |shares| shares.min(shares_i)));
+    }
+    shares.unwrap_or(0)
+}
+
+/// Returns the part of `holding` owned by `shares` out of `total_shares`, rounded down.
+pub fn pro_rata(holding: i128, shares: i128, total_shares: i128) -> i128 {
+    holding.checked_mul(shares).unwrap().checked_div(total_shares).unwrap()
+}
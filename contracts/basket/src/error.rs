@@ -0,0 +1,82 @@
+use soroban_sdk::{self, contracterror};
+use soroswap_library::SoroswapLibraryError;
+
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapBasketError {
+    /// SoroswapBasket: not yet initialized
+    NotInitialized = 1201,
+
+    /// SoroswapBasket: already initialized
+    InitializeAlreadyInitialized = 1202,
+
+    /// SoroswapBasket: components must be between 1 and MAX_COMPONENTS different tokens
+    InvalidComponents = 1203,
+
+    /// SoroswapBasket: there must be one positive weight per component, adding up to 10000 basis points
+    InvalidWeights = 1204,
+
+    /// SoroswapBasket: maximum slippage can not be above 10000 basis points
+    InvalidMaxSlippage = 1205,
+
+    /// SoroswapBasket: amounts must be positive and minimums can not be negative
+    InvalidAmount = 1206,
+
+    /// SoroswapBasket: token is not a component of the basket
+    NotAComponent = 1207,
+
+    /// SoroswapBasket: pair does not exist
+    PairDoesNotExist = 1208,
+
+    /// SoroswapBasket: less shares minted than the minimum
+    InsufficientSharesMinted = 1209,
+
+    /// SoroswapBasket: insufficient shares
+    InsufficientShares = 1210,
+
+    /// SoroswapBasket: less output than the minimum
+    InsufficientOutputAmount = 1211,
+
+    /// SoroswapBasket: the basket holds less of the component than the amount to rebalance
+    InsufficientHoldings = 1212,
+
+    /// SoroswapBasket: the price impact of the rebalance is above the maximum slippage
+    SlippageTooHigh = 1213,
+
+    /// SoroswapLibrary: insufficient amount
+    LibraryInsufficientAmount = 1214,
+
+    /// SoroswapLibrary: insufficient liquidity
+    LibraryInsufficientLiquidity = 1215,
+
+    /// SoroswapLibrary: insufficient input amount
+    LibraryInsufficientInputAmount = 1216,
+
+    /// SoroswapLibrary: insufficient output amount
+    LibraryInsufficientOutputAmount = 1217,
+
+    /// SoroswapLibrary: invalid path
+    LibraryInvalidPath = 1218,
+
+    /// SoroswapLibrary: token_a and token_b have identical addresses
+    LibrarySortIdenticalTokens = 1219,
+
+    /// SoroswapLibrary: arithmetic overflow
+    LibraryOverflow = 1220,
+}
+
+impl From<SoroswapLibraryError> for SoroswapBasketError {
+    fn from(err: SoroswapLibraryError) -> Self {
+        match err {
+            SoroswapLibraryError::InsufficientAmount => SoroswapBasketError::LibraryInsufficientAmount,
+            SoroswapLibraryError::InsufficientLiquidity => SoroswapBasketError::LibraryInsufficientLiquidity,
+            SoroswapLibraryError::InsufficientInputAmount => SoroswapBasketError::LibraryInsufficientInputAmount,
+            SoroswapLibraryError::InsufficientOutputAmount => SoroswapBasketError::LibraryInsufficientOutputAmount,
+            SoroswapLibraryError::InvalidPath => SoroswapBasketError::LibraryInvalidPath,
+            SoroswapLibraryError::SortIdenticalTokens => SoroswapBasketError::LibrarySortIdenticalTokens,
+            SoroswapLibraryError::Overflow => SoroswapBasketError::LibraryOverflow,
+        }
+    }
+}
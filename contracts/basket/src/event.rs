@@ -0,0 +1,155 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address, Vec};
+
+// INITIALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub router: Address,
+    pub factory: Address,
+    pub components: Vec<Address>,
+    pub weights: Vec<u32>,
+    pub max_slippage_bps: u32
+}
+
+/// Publishes an `InitializedEvent` when the contract is initialized.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The admin, the router every trade goes through, its factory, the components and
+///   their weights, and the maximum slippage of a rebalance.
+pub(crate) fn initialized(e: &Env, event: InitializedEvent) {
+    e.events().publish(("SoroswapBasket", symbol_short!("init")), event);
+}
+
+// MINT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintEvent {
+    pub to: Address,
+    pub token_in: Address,
+    pub amount_in: i128,
+    pub amounts: Vec<i128>,
+    pub shares: i128
+}
+
+/// Publishes a `MintEvent` when shares are minted with a single token.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The holder, the token and amount paid, the amount of every component it bought and
+///   the shares minted.
+pub(crate) fn mint(e: &Env, event: MintEvent) {
+    e.events().publish(("SoroswapBasket", symbol_short!("mint")), event);
+}
+
+// REDEEM EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedeemEvent {
+    pub from: Address,
+    pub token_out: Address,
+    pub shares: i128,
+    pub amounts: Vec<i128>,
+    pub amount_out: i128
+}
+
+/// Publishes a `RedeemEvent` when shares are burnt for a single token.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The holder, the token received, the shares burnt, the amount of every component they
+///   owned and the amount received.
+pub(crate) fn redeem(e: &Env, event: RedeemEvent) {
+    e.events().publish(("SoroswapBasket", symbol_short!("redeem")), event);
+}
+
+// REBALANCE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RebalanceEvent {
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128
+}
+
+/// Publishes a `RebalanceEvent` when the admin trades a component for another.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The component sold and its amount, and the component bought and its amount.
+pub(crate) fn rebalance(e: &Env, event: RebalanceEvent) {
+    e.events().publish(("SoroswapBasket", symbol_short!("rebalance")), event);
+}
+
+// NEW WEIGHTS EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewWeightsEvent {
+    pub weights: Vec<u32>
+}
+
+/// Publishes a `NewWeightsEvent` when the admin sets the target weights.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `weights` - The new weight of every component, in basis points.
+pub(crate) fn new_weights(e: &Env, weights: Vec<u32>) {
+    let event = NewWeightsEvent {
+        weights,
+    };
+    e.events().publish(("SoroswapBasket", symbol_short!("weights")), event);
+}
+
+// NEW MAX SLIPPAGE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewMaxSlippageEvent {
+    pub max_slippage_bps: u32
+}
+
+/// Publishes a `NewMaxSlippageEvent` when the admin sets the maximum slippage of a rebalance.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `max_slippage_bps` - The new maximum price impact of a rebalance, in basis points.
+pub(crate) fn new_max_slippage(e: &Env, max_slippage_bps: u32) {
+    let event = NewMaxSlippageEvent {
+        max_slippage_bps,
+    };
+    e.events().publish(("SoroswapBasket", symbol_short!("slippage")), event);
+}
+
+// TRANSFER EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferEvent {
+    pub from: Address,
+    pub to: Address,
+    pub shares: i128
+}
+
+/// Publishes a `TransferEvent` when a holder transfers shares.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `from` - The holder sending the shares.
+/// * `to` - The address receiving them.
+/// * `shares` - The shares transferred.
+pub(crate) fn transfer(e: &Env, from: Address, to: Address, shares: i128) {
+    let event = TransferEvent {
+        from,
+        to,
+        shares,
+    };
+    e.events().publish(("SoroswapBasket", symbol_short!("transfer")), event);
+}
@@ -0,0 +1,614 @@
+#![no_std]
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol, Vec};
+
+mod router;
+mod factory;
+mod test;
+mod event;
+mod storage;
+mod error;
+mod basket;
+
+use router::SoroswapRouterClient;
+use factory::SoroswapFactoryClient;
+use storage::{
+    put_router,
+    has_router,
+    get_router,
+    get_factory,
+    put_admin,
+    get_admin,
+    put_components,
+    get_components,
+    put_weights,
+    get_weights,
+    put_holdings,
+    get_holdings,
+    put_max_slippage_bps,
+    get_max_slippage_bps,
+    put_total_shares,
+    get_total_shares,
+    put_shares,
+    get_shares,
+    extend_instance_ttl,
+};
+use basket::{check_components, check_weights, split, shares_for, pro_rata, BPS_DENOMINATOR};
+pub use error::SoroswapBasketError;
+pub use basket::{MAX_COMPONENTS, MINIMUM_SHARES};
+
+fn check_initialized(e: &Env) -> Result<(), SoroswapBasketError> {
+    if has_router(e) {
+        Ok(())
+    } else {
+        Err(SoroswapBasketError::NotInitialized)
+    }
+}
+
+fn check_max_slippage(max_slippage_bps: u32) -> Result<(), SoroswapBasketError> {
+    if max_slippage_bps > BPS_DENOMINATOR {
+        Err(SoroswapBasketError::InvalidMaxSlippage)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_pair_exists(e: &Env, token_a: &Address, token_b: &Address) -> Result<(), SoroswapBasketError> {
+    if SoroswapFactoryClient::new(e, &get_factory(e)).pair_exists(token_a, token_b) {
+        Ok(())
+    } else {
+        Err(SoroswapBasketError::PairDoesNotExist)
+    }
+}
+
+/// Returns what `amount` of `token` is worth in `token_in`, at the spot price of their pair.
+fn spot_value(e: &Env, token: &Address, token_in: &Address, amount: i128) -> Result<i128, SoroswapBasketError> {
+    if token == token_in || amount == 0 {
+        return Ok(amount);
+    }
+    check_pair_exists(e, token, token_in)?;
+    let (reserve_token, reserve_in) = soroswap_library::get_reserves(e.clone(), get_factory(e), token.clone(), token_in.clone())?;
+    Ok(soroswap_library::quote(amount, reserve_token, reserve_in)?)
+}
+
+/// Swaps `amount_in` of `token_in` held by the basket for `token_out` through the router, requiring at
+/// least `amount_out_min`, and sends the output to `to`. Returns the output.
+fn swap(
+    e: &Env,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    amount_out_min: i128,
+    to: &Address,
+) -> Result<i128, SoroswapBasketError> {
+    let basket = e.current_contract_address();
+    let pair = soroswap_library::pair_for(e.clone(), get_factory(e), token_in.clone(), token_out.clone())?;
+
+    // The router pays the pair with the tokens of this contract, which has to authorize that transfer
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token_in.clone(),
+                fn_name: Symbol::new(e, "transfer"),
+                args: (basket.clone(), pair, amount_in).into_val(e),
+            },
+            sub_invocations: Vec::new(e),
+        }),
+    ]);
+
+    let amounts = SoroswapRouterClient::new(e, &get_router(e)).swap_exact_tokens_from(
+        &basket,
+        &amount_in,
+        &amount_out_min,
+        &vec![e, token_in.clone(), token_out.clone()],
+        to,
+        &(e.ledger().timestamp() + 1),
+    );
+    Ok(amounts.get(amounts.len() - 1).unwrap())
+}
+
+pub trait SoroswapBasketTrait {
+
+    /// Initializes the basket with its components and their target weights.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `admin` - The address allowed to set the weights and rebalance the basket.
+    /// * `router` - The address of the Soroswap router every trade goes through. Its factory is read from it.
+    /// * `components` - The tokens held by the basket, between 1 and `MAX_COMPONENTS` different tokens.
+    /// * `weights` - The target weight of every component, in basis points. They must be positive and add up to 10000.
+    /// * `max_slippage_bps` - The maximum price impact of a rebalance, in basis points, including the 0.3% fee.
+    fn initialize(
+        e: Env,
+        admin: Address,
+        router: Address,
+        components: Vec<Address>,
+        weights: Vec<u32>,
+        max_slippage_bps: u32,
+    ) -> Result<(), SoroswapBasketError>;
+
+    /// Mints shares paying with a single token. `amount` of `token_in` is split between the components in
+    /// proportion to their value in the basket at the spot prices, or to their weights for the first mint,
+    /// and every part is swapped for its component through the router. The shares minted are the smallest
+    /// fraction the purchase added to a component, so minting never dilutes the holders.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `to` - The address paying `token_in` and receiving the shares. Must authorize the call.
+    /// * `token_in` - The token paid. It needs a pair with every component it is not.
+    /// * `amount` - The amount of `token_in` paid.
+    /// * `min_shares` - The minimum amount of shares to mint.
+    ///
+    /// # Returns
+    /// The shares minted. The first mint mints `amount` shares, `MINIMUM_SHARES` of which are kept by the basket.
+    fn mint_with(e: Env, to: Address, token_in: Address, amount: i128, min_shares: i128) -> Result<i128, SoroswapBasketError>;
+
+    /// Burns shares for a single token: the part of every component the shares own is swapped for
+    /// `token_out` through the router.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `from` - The holder burning the shares and receiving `token_out`. Must authorize the call.
+    /// * `token_out` - The token received. It needs a pair with every component it is not.
+    /// * `shares` - The shares to burn.
+    /// * `min_out` - The minimum amount of `token_out` to receive.
+    ///
+    /// # Returns
+    /// The amount of `token_out` received.
+    fn redeem_to(e: Env, from: Address, token_out: Address, shares: i128, min_out: i128) -> Result<i128, SoroswapBasketError>;
+
+    /// Transfers shares between two addresses.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `from` - The holder sending the shares. Must authorize the call.
+    /// * `to` - The address receiving them.
+    /// * `shares` - The shares to transfer.
+    fn transfer(e: Env, from: Address, to: Address, shares: i128) -> Result<(), SoroswapBasketError>;
+
+    /*  *** Governance functions: *** */
+
+    /// Sells `amount_in` of a component for another through the router. The trade fails if its price impact
+    /// on their pair is above the maximum slippage, or if it buys less than `amount_out_min`.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing. The admin must authorize the call.
+    /// * `token_in` - The component sold.
+    /// * `token_out` - The component bought.
+    /// * `amount_in` - The amount of `token_in` sold.
+    /// * `amount_out_min` - The minimum amount of `token_out` to buy.
+    ///
+    /// # Returns
+    /// The amount of `token_out` bought.
+    fn rebalance(e: Env, token_in: Address, token_out: Address, amount_in: i128, amount_out_min: i128) -> Result<i128, SoroswapBasketError>;
+
+    /// Sets the target weights of the components, which the admin rebalances the basket towards.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing. The admin must authorize the call.
+    /// * `weights` - The weight of every component, in basis points. They must be positive and add up to 10000.
+    fn set_weights(e: Env, weights: Vec<u32>) -> Result<(), SoroswapBasketError>;
+
+    /// Sets the maximum price impact of a rebalance.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing. The admin must authorize the call.
+    /// * `max_slippage_bps` - The maximum price impact, in basis points, including the 0.3% fee.
+    fn set_max_slippage(e: Env, max_slippage_bps: u32) -> Result<(), SoroswapBasketError>;
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the router every trade goes through.
+    fn get_router(e: Env) -> Result<Address, SoroswapBasketError>;
+
+    /// Returns the admin of the basket.
+    fn get_admin(e: Env) -> Result<Address, SoroswapBasketError>;
+
+    /// Returns the components of the basket.
+    fn get_components(e: Env) -> Result<Vec<Address>, SoroswapBasketError>;
+
+    /// Returns the target weight of every component, in basis points.
+    fn get_weights(e: Env) -> Result<Vec<u32>, SoroswapBasketError>;
+
+    /// Returns the maximum price impact of a rebalance, in basis points.
+    fn get_max_slippage(e: Env) -> Result<u32, SoroswapBasketError>;
+
+    /// Returns the amount of every component owned by the shares. Tokens sent to the basket outside of
+    /// a mint are not counted.
+    fn get_holdings(e: Env) -> Result<Vec<i128>, SoroswapBasketError>;
+
+    /// Returns the total shares of the basket, including the minimum shares it keeps.
+    fn total_shares(e: Env) -> Result<i128, SoroswapBasketError>;
+
+    /// Returns the shares of `owner`.
+    fn shares(e: Env, owner: Address) -> Result<i128, SoroswapBasketError>;
+}
+
+#[contract]
+struct SoroswapBasket;
+
+#[contractimpl]
+impl SoroswapBasketTrait for SoroswapBasket {
+
+    /// Initializes the basket with its components and their target weights.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `admin` - The address allowed to set the weights and rebalance the basket.
+    /// * `router` - The address of the Soroswap router every trade goes through. Its factory is read from it.
+    /// * `components` - The tokens held by the basket, between 1 and `MAX_COMPONENTS` different tokens.
+    /// * `weights` - The target weight of every component, in basis points. They must be positive and add up to 10000.
+    /// * `max_slippage_bps` - The maximum price impact of a rebalance, in basis points, including the 0.3% fee.
+    fn initialize(
+        e: Env,
+        admin: Address,
+        router: Address,
+        components: Vec<Address>,
+        weights: Vec<u32>,
+        max_slippage_bps: u32,
+    ) -> Result<(), SoroswapBasketError> {
+        if has_router(&e) {
+            return Err(SoroswapBasketError::InitializeAlreadyInitialized);
+        }
+        check_components(&components)?;
+        check_weights(&components, &weights)?;
+        check_max_slippage(max_slippage_bps)?;
+
+        let factory = SoroswapRouterClient::new(&e, &router).get_factory();
+        put_router(&e, &router, &factory);
+        put_admin(&e, &admin);
+        put_components(&e, &components);
+        put_weights(&e, &weights);
+        let mut holdings: Vec<i128> = Vec::new(&e);
+        for _ in components.iter() {
+            holdings.push_back(0);
+        }
+        put_holdings(&e, &holdings);
+        put_max_slippage_bps(&e, max_slippage_bps);
+
+        event::initialized(&e, event::InitializedEvent {
+            admin,
+            router,
+            factory,
+            components,
+            weights,
+            max_slippage_bps,
+        });
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Mints shares paying with a single token. `amount` of `token_in` is split between the components in
+    /// proportion to their value in the basket at the spot prices, or to their weights for the first mint,
+    /// and every part is swapped for its component through the router. The shares minted are the smallest
+    /// fraction the purchase added to a component, so minting never dilutes the holders.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `to` - The address paying `token_in` and receiving the shares. Must authorize the call.
+    /// * `token_in` - The token paid. It needs a pair with every component it is not.
+    /// * `amount` - The amount of `token_in` paid.
+    /// * `min_shares` - The minimum amount of shares to mint.
+    ///
+    /// # Returns
+    /// The shares minted. The first mint mints `amount` shares, `MINIMUM_SHARES` of which are kept by the basket.
+    fn mint_with(e: Env, to: Address, token_in: Address, amount: i128, min_shares: i128) -> Result<i128, SoroswapBasketError> {
+        check_initialized(&e)?;
+        if amount <= 0 || min_shares < 0 {
+            return Err(SoroswapBasketError::InvalidAmount);
+        }
+        extend_instance_ttl(&e);
+        to.require_auth();
+
+        let components = get_components(&e);
+        let holdings = get_holdings(&e);
+        let total_shares = get_total_shares(&e);
+
+        let parts = if total_shares == 0 {
+            let mut weights: Vec<i128> = Vec::new(&e);
+            for weight in get_weights(&e).iter() {
+                weights.push_back(weight as i128);
+            }
+            split(&e, amount, &weights)
+        } else {
+            let mut values: Vec<i128> = Vec::new(&e);
+            for (component, holding) in components.iter().zip(holdings.iter()) {
+                values.push_back(spot_value(&e, &component, &token_in, holding)?);
+            }
+            split(&e, amount, &values)
+        };
+
+        TokenClient::new(&e, &token_in).transfer(&to, &e.current_contract_address(), &amount);
+
+        let mut amounts: Vec<i128> = Vec::new(&e);
+        for (component, part) in components.iter().zip(parts.iter()) {
+            let amount_i = if part == 0 || component == token_in {
+                part
+            } else {
+                check_pair_exists(&e, &token_in, &component)?;
+                swap(&e, &token_in, &component, part, 0, &e.current_contract_address())?
+            };
+            amounts.push_back(amount_i);
+        }
+
+        let (shares, total_shares) = if total_shares == 0 {
+            // The first mint has to buy every component, or the basket could never hold it
+            if amounts.iter().any(|amount_i| amount_i == 0) {
+                return Err(SoroswapBasketError::InsufficientSharesMinted);
+            }
+            // The minimum shares are locked by the basket itself
+            put_shares(&e, &e.current_contract_address(), MINIMUM_SHARES);
+            (amount.checked_sub(MINIMUM_SHARES).unwrap(), MINIMUM_SHARES)
+        } else {
+            (shares_for(&amounts, &holdings, total_shares), total_shares)
+        };
+        if shares <= 0 || shares < min_shares {
+            return Err(SoroswapBasketError::InsufficientSharesMinted);
+        }
+
+        let mut new_holdings: Vec<i128> = Vec::new(&e);
+        for (holding, amount_i) in holdings.iter().zip(amounts.iter()) {
+            new_holdings.push_back(holding.checked_add(amount_i).unwrap());
+        }
+        put_holdings(&e, &new_holdings);
+        put_total_shares(&e, total_shares.checked_add(shares).unwrap());
+        put_shares(&e, &to, get_shares(&e, &to).checked_add(shares).unwrap());
+
+        event::mint(&e, event::MintEvent {
+            to,
+            token_in,
+            amount_in: amount,
+            amounts,
+            shares,
+        });
+        Ok(shares)
+    }
+
+    /// Burns shares for a single token: the part of every component the shares own is swapped for
+    /// `token_out` through the router.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `from` - The holder burning the shares and receiving `token_out`. Must authorize the call.
+    /// * `token_out` - The token received. It needs a pair with every component it is not.
+    /// * `shares` - The shares to burn.
+    /// * `min_out` - The minimum amount of `token_out` to receive.
+    ///
+    /// # Returns
+    /// The amount of `token_out` received.
+    fn redeem_to(e: Env, from: Address, token_out: Address, shares: i128, min_out: i128) -> Result<i128, SoroswapBasketError> {
+        check_initialized(&e)?;
+        if shares <= 0 || min_out < 0 {
+            return Err(SoroswapBasketError::InvalidAmount);
+        }
+        extend_instance_ttl(&e);
+        from.require_auth();
+
+        let from_shares = get_shares(&e, &from);
+        if from_shares < shares {
+            return Err(SoroswapBasketError::InsufficientShares);
+        }
+
+        let components = get_components(&e);
+        let holdings = get_holdings(&e);
+        let total_shares = get_total_shares(&e);
+        let mut amounts: Vec<i128> = Vec::new(&e);
+        let mut new_holdings: Vec<i128> = Vec::new(&e);
+        for holding in holdings.iter() {
+            let amount_i = pro_rata(holding, shares, total_shares);
+            amounts.push_back(amount_i);
+            new_holdings.push_back(holding.checked_sub(amount_i).unwrap());
+        }
+
+        put_holdings(&e, &new_holdings);
+        put_shares(&e, &from, from_shares.checked_sub(shares).unwrap());
+        put_total_shares(&e, total_shares.checked_sub(shares).unwrap());
+
+        let mut amount_out: i128 = 0;
+        for (component, amount_i) in components.iter().zip(amounts.iter()) {
+            if amount_i == 0 {
+                continue;
+            }
+            let amount_out_i = if component == token_out {
+                TokenClient::new(&e, &component).transfer(&e.current_contract_address(), &from, &amount_i);
+                amount_i
+            } else {
+                check_pair_exists(&e, &component, &token_out)?;
+                swap(&e, &component, &token_out, amount_i, 0, &from)?
+            };
+            amount_out = amount_out.checked_add(amount_out_i).unwrap();
+        }
+        if amount_out < min_out {
+            return Err(SoroswapBasketError::InsufficientOutputAmount);
+        }
+
+        event::redeem(&e, event::RedeemEvent {
+            from,
+            token_out,
+            shares,
+            amounts,
+            amount_out,
+        });
+        Ok(amount_out)
+    }
+
+    /// Transfers shares between two addresses.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `from` - The holder sending the shares. Must authorize the call.
+    /// * `to` - The address receiving them.
+    /// * `shares` - The shares to transfer.
+    fn transfer(e: Env, from: Address, to: Address, shares: i128) -> Result<(), SoroswapBasketError> {
+        check_initialized(&e)?;
+        if shares <= 0 {
+            return Err(SoroswapBasketError::InvalidAmount);
+        }
+        extend_instance_ttl(&e);
+        from.require_auth();
+
+        let from_shares = get_shares(&e, &from);
+        if from_shares < shares {
+            return Err(SoroswapBasketError::InsufficientShares);
+        }
+        put_shares(&e, &from, from_shares.checked_sub(shares).unwrap());
+        put_shares(&e, &to, get_shares(&e, &to).checked_add(shares).unwrap());
+
+        event::transfer(&e, from, to, shares);
+        Ok(())
+    }
+
+    /*  *** Governance functions: *** */
+
+    /// Sells `amount_in` of a component for another through the router. The trade fails if its price impact
+    /// on their pair is above the maximum slippage, or if it buys less than `amount_out_min`.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing. The admin must authorize the call.
+    /// * `token_in` - The component sold.
+    /// * `token_out` - The component bought.
+    /// * `amount_in` - The amount of `token_in` sold.
+    /// * `amount_out_min` - The minimum amount of `token_out` to buy.
+    ///
+    /// # Returns
+    /// The amount of `token_out` bought.
+    fn rebalance(e: Env, token_in: Address, token_out: Address, amount_in: i128, amount_out_min: i128) -> Result<i128, SoroswapBasketError> {
+        check_initialized(&e)?;
+        if amount_in <= 0 || amount_out_min < 0 {
+            return Err(SoroswapBasketError::InvalidAmount);
+        }
+        extend_instance_ttl(&e);
+        get_admin(&e).require_auth();
+
+        let components = get_components(&e);
+        if !components.contains(&token_in) || !components.contains(&token_out) {
+            return Err(SoroswapBasketError::NotAComponent);
+        }
+        soroswap_library::sort_tokens(token_in.clone(), token_out.clone())?;
+        let mut holdings = get_holdings(&e);
+        let index_in = components.first_index_of(&token_in).unwrap();
+        let index_out = components.first_index_of(&token_out).unwrap();
+        let holding_in = holdings.get(index_in).unwrap();
+        if holding_in < amount_in {
+            return Err(SoroswapBasketError::InsufficientHoldings);
+        }
+
+        // The router would give the same output, so the price impact is known before the trade
+        check_pair_exists(&e, &token_in, &token_out)?;
+        let (reserve_in, reserve_out) = soroswap_library::get_reserves(e.clone(), get_factory(&e), token_in.clone(), token_out.clone())?;
+        let expected_amount_out = soroswap_library::get_amount_out(amount_in, reserve_in, reserve_out)?;
+        let price_impact_bps = soroswap_library::get_price_impact_bps(
+            soroswap_library::get_spot_price(reserve_in, reserve_out)?,
+            soroswap_library::get_execution_price(amount_in, expected_amount_out)?,
+        )?;
+        if price_impact_bps > get_max_slippage_bps(&e) as i128 {
+            return Err(SoroswapBasketError::SlippageTooHigh);
+        }
+
+        let amount_out = swap(&e, &token_in, &token_out, amount_in, amount_out_min, &e.current_contract_address())?;
+        holdings.set(index_in, holding_in.checked_sub(amount_in).unwrap());
+        holdings.set(index_out, holdings.get(index_out).unwrap().checked_add(amount_out).unwrap());
+        put_holdings(&e, &holdings);
+
+        event::rebalance(&e, event::RebalanceEvent {
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+        });
+        Ok(amount_out)
+    }
+
+    /// Sets the target weights of the components, which the admin rebalances the basket towards.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing. The admin must authorize the call.
+    /// * `weights` - The weight of every component, in basis points. They must be positive and add up to 10000.
+    fn set_weights(e: Env, weights: Vec<u32>) -> Result<(), SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        get_admin(&e).require_auth();
+        check_weights(&get_components(&e), &weights)?;
+
+        put_weights(&e, &weights);
+        event::new_weights(&e, weights);
+        Ok(())
+    }
+
+    /// Sets the maximum price impact of a rebalance.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing. The admin must authorize the call.
+    /// * `max_slippage_bps` - The maximum price impact, in basis points, including the 0.3% fee.
+    fn set_max_slippage(e: Env, max_slippage_bps: u32) -> Result<(), SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        get_admin(&e).require_auth();
+        check_max_slippage(max_slippage_bps)?;
+
+        put_max_slippage_bps(&e, max_slippage_bps);
+        event::new_max_slippage(&e, max_slippage_bps);
+        Ok(())
+    }
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the router every trade goes through.
+    fn get_router(e: Env) -> Result<Address, SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_router(&e))
+    }
+
+    /// Returns the admin of the basket.
+    fn get_admin(e: Env) -> Result<Address, SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_admin(&e))
+    }
+
+    /// Returns the components of the basket.
+    fn get_components(e: Env) -> Result<Vec<Address>, SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_components(&e))
+    }
+
+    /// Returns the target weight of every component, in basis points.
+    fn get_weights(e: Env) -> Result<Vec<u32>, SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_weights(&e))
+    }
+
+    /// Returns the maximum price impact of a rebalance, in basis points.
+    fn get_max_slippage(e: Env) -> Result<u32, SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_max_slippage_bps(&e))
+    }
+
+    /// Returns the amount of every component owned by the shares. Tokens sent to the basket outside of
+    /// a mint are not counted.
+    fn get_holdings(e: Env) -> Result<Vec<i128>, SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_holdings(&e))
+    }
+
+    /// Returns the total shares of the basket, including the minimum shares it keeps.
+    fn total_shares(e: Env) -> Result<i128, SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_total_shares(&e))
+    }
+
+    /// Returns the shares of `owner`.
+    fn shares(e: Env, owner: Address) -> Result<i128, SoroswapBasketError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_shares(&e, &owner))
+    }
+}
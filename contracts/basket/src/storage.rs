@@ -0,0 +1,117 @@
+use soroban_sdk::{contracttype, Env, Address, Vec};
+
+#[derive(Clone)]
+#[contracttype]
+
+enum DataKey {
+    Router, // Address of the Soroswap Router every trade goes through. Instance Data Type
+    Factory, // Address of the Soroswap Factory of the router. Instance Data Type
+    Admin, // Address allowed to set the weights and rebalance. Instance Data Type
+    Components, // Tokens held by the basket. Instance Data Type
+    Weights, // Target weight of every component, in basis points. Instance Data Type
+    Holdings, // Amount of every component owned by the shares. Instance Data Type
+    MaxSlippageBps, // Maximum price impact of a rebalance, in basis points. Instance Data Type
+    TotalShares, // Instance Data Type
+    Shares(Address), // Shares of a holder. Persistent Data Type
+}
+
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+pub fn put_router(e: &Env, router: &Address, factory: &Address) {
+    e.storage().instance().set(&DataKey::Router, router);
+    e.storage().instance().set(&DataKey::Factory, factory);
+}
+
+pub fn has_router(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Router)
+}
+
+pub fn get_router(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Router).unwrap()
+}
+
+pub fn get_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Factory).unwrap()
+}
+
+pub fn put_admin(e: &Env, admin: &Address) {
+    e.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_admin(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+pub fn put_components(e: &Env, components: &Vec<Address>) {
+    e.storage().instance().set(&DataKey::Components, components);
+}
+
+pub fn get_components(e: &Env) -> Vec<Address> {
+    e.storage().instance().get(&DataKey::Components).unwrap()
+}
+
+pub fn put_weights(e: &Env, weights: &Vec<u32>) {
+    e.storage().instance().set(&DataKey::Weights, weights);
+}
+
+pub fn get_weights(e: &Env) -> Vec<u32> {
+    e.storage().instance().get(&DataKey::Weights).unwrap()
+}
+
+pub fn put_holdings(e: &Env, holdings: &Vec<i128>) {
+    e.storage().instance().set(&DataKey::Holdings, holdings);
+}
+
+pub fn get_holdings(e: &Env) -> Vec<i128> {
+    e.storage().instance().get(&DataKey::Holdings).unwrap()
+}
+
+pub fn put_max_slippage_bps(e: &Env, max_slippage_bps: u32) {
+    e.storage().instance().set(&DataKey::MaxSlippageBps, &max_slippage_bps);
+}
+
+pub fn get_max_slippage_bps(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::MaxSlippageBps).unwrap()
+}
+
+pub fn put_total_shares(e: &Env, total_shares: i128) {
+    e.storage().instance().set(&DataKey::TotalShares, &total_shares);
+}
+
+pub fn get_total_shares(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::TotalShares).unwrap_or(0)
+}
+
+pub fn put_shares(e: &Env, owner: &Address, shares: i128) {
+    let key = DataKey::Shares(owner.clone());
+    if shares == 0 {
+        e.storage().persistent().remove(&key);
+        return;
+    }
+    e.storage().persistent().set(&key, &shares);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_shares(e: &Env, owner: &Address) -> i128 {
+    let key = DataKey::Shares(owner.clone());
+    if let Some(shares) = e.storage().persistent().get::<DataKey, i128>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        shares
+    } else {
+        0
+    }
+}
@@ -0,0 +1,172 @@
+#![cfg(test)]
+extern crate std;
+use crate::{SoroswapBasket, SoroswapBasketClient};
+use soroban_sdk::{
+    Env,
+    vec,
+    Vec,
+    BytesN,
+    Address,
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(&e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+fn pair_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// SoroswapFactory Contract
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryClient<'a> {
+    let pair_hash = pair_contract_wasm(&e);
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address);
+    factory.initialize(&setter, &pair_hash);
+    factory
+}
+
+// SoroswapRouter Contract
+mod router {
+    soroban_sdk::contractimport!(file = "../router/target/wasm32-unknown-unknown/release/soroswap_router.wasm");
+    pub type SoroswapRouterClient<'a> = Client<'a>;
+}
+use router::SoroswapRouterClient;
+
+fn create_soroswap_router<'a>(e: & Env, factory: & Address) -> SoroswapRouterClient<'a> {
+    let router = SoroswapRouterClient::new(e, &e.register_contract_wasm(None, router::WASM));
+    router.initialize(factory);
+    router
+}
+
+// SoroswapBasket Contract
+fn create_soroswap_basket<'a>(e: &Env) -> SoroswapBasketClient<'a> {
+    SoroswapBasketClient::new(e, &e.register_contract(None, SoroswapBasket {}))
+}
+
+// SoroswapBasket TEST
+
+pub struct SoroswapBasketTest<'a> {
+    env: Env,
+    contract: SoroswapBasketClient<'a>,
+    router: SoroswapRouterClient<'a>,
+    factory: SoroswapFactoryClient<'a>,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    token_2: TokenClient<'a>,
+    admin: Address,
+    user: Address,
+}
+
+impl<'a> SoroswapBasketTest<'a> {
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = 100;
+        });
+        let contract = create_soroswap_basket(&env);
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+
+        let token_0 = create_token_contract(&env, &admin);
+        let token_1 = create_token_contract(&env, &admin);
+        let token_2 = create_token_contract(&env, &admin);
+        for token in [&token_0, &token_1, &token_2] {
+            token.mint(&admin, &10_000_000_000_000_000_000);
+            token.mint(&user, &1_000_000_000);
+        }
+
+        let factory = create_soroswap_factory(&env, &admin);
+        let router = create_soroswap_router(&env, &factory.address);
+        env.budget().reset_unlimited();
+
+        // 1 token_0 = 2 token_1 = 4 token_2
+        for (token_a, token_b, amount_a, amount_b) in [
+            (&token_0, &token_1, 1_000_000_000, 2_000_000_000),
+            (&token_0, &token_2, 1_000_000_000, 4_000_000_000),
+            (&token_1, &token_2, 2_000_000_000, 4_000_000_000),
+        ] {
+            router.add_liquidity(
+                &token_a.address,
+                &token_b.address,
+                &amount_a,
+                &amount_b,
+                &0,
+                &0,
+                &admin,
+                &1000,
+            );
+        }
+
+        SoroswapBasketTest {
+            env,
+            contract,
+            router,
+            factory,
+            token_0,
+            token_1,
+            token_2,
+            admin,
+            user,
+        }
+    }
+
+    fn components(&self) -> Vec<Address> {
+        vec![&self.env, self.token_0.address.clone(), self.token_1.address.clone(), self.token_2.address.clone()]
+    }
+
+    /// Initializes a basket of 40% token_0, 30% token_1 and 30% token_2, with a maximum slippage of 1%.
+    fn setup_initialized() -> Self {
+        let test = Self::setup();
+        test.contract.initialize(
+            &test.admin,
+            &test.router.address,
+            &test.components(),
+            &vec![&test.env, 4000, 3000, 3000],
+            &100,
+        );
+        test
+    }
+
+    /// Initializes the basket and makes the first mint: the user pays 100_000_000 token_0.
+    fn setup_minted() -> Self {
+        let test = Self::setup_initialized();
+        test.contract.mint_with(&test.user, &test.token_0.address, &100_000_000, &0);
+        test
+    }
+
+    /// Returns the amount of `token_out` the router gives for `amount_in` of `token_in`.
+    fn quote(&self, token_in: &Address, token_out: &Address, amount_in: i128) -> i128 {
+        let path = vec![&self.env, token_in.clone(), token_out.clone()];
+        self.router.router_get_amounts_out(&amount_in, &path).get(1).unwrap()
+    }
+}
+
+// Test mods:
+pub mod initialize;
+pub mod mint;
+pub mod redeem;
+pub mod rebalance;
@@ -0,0 +1,109 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, IntoVal, symbol_short};
+
+use crate::test::SoroswapBasketTest;
+use crate::error::SoroswapBasketError;
+use crate::event::InitializedEvent;
+
+#[test]
+fn initialize_and_getters() {
+    let test = SoroswapBasketTest::setup();
+    let weights = vec![&test.env, 4000, 3000, 3000];
+    test.contract.initialize(&test.admin, &test.router.address, &test.components(), &weights, &100);
+
+    assert_eq!(test.contract.get_router(), test.router.address);
+    assert_eq!(test.contract.get_admin(), test.admin);
+    assert_eq!(test.contract.get_components(), test.components());
+    assert_eq!(test.contract.get_weights(), weights);
+    assert_eq!(test.contract.get_max_slippage(), 100);
+    assert_eq!(test.contract.get_holdings(), vec![&test.env, 0, 0, 0]);
+    assert_eq!(test.contract.total_shares(), 0);
+    assert_eq!(test.contract.shares(&test.user), 0);
+
+    // The factory is read from the router
+    let initialized_event = test.env.events().all().last().unwrap();
+    let expected_initialized_event: InitializedEvent = InitializedEvent {
+        admin: test.admin.clone(),
+        router: test.router.address.clone(),
+        factory: test.factory.address.clone(),
+        components: test.components(),
+        weights,
+        max_slippage_bps: 100,
+    };
+    assert_eq!(
+        vec![&test.env, initialized_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapBasket", symbol_short!("init")).into_val(&test.env),
+                (expected_initialized_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn initialize_invalid_components() {
+    let test = SoroswapBasketTest::setup();
+
+    let result = test.contract.try_initialize(&test.admin, &test.router.address, &vec![&test.env], &vec![&test.env], &100);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidComponents)));
+
+    let duplicated = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+    let result = test.contract.try_initialize(&test.admin, &test.router.address, &duplicated, &vec![&test.env, 4000, 3000, 3000], &100);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidComponents)));
+
+    let mut too_many = vec![&test.env];
+    let mut weights = vec![&test.env];
+    for _ in 0..9 {
+        too_many.push_back(Address::generate(&test.env));
+        weights.push_back(1000);
+    }
+    weights.set(0, 2000);
+    let result = test.contract.try_initialize(&test.admin, &test.router.address, &too_many, &weights, &100);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidComponents)));
+}
+
+#[test]
+fn initialize_invalid_weights() {
+    let test = SoroswapBasketTest::setup();
+    for weights in [
+        vec![&test.env, 5000, 5000],
+        vec![&test.env, 4000, 3000, 2000],
+        vec![&test.env, 5000, 5000, 0],
+        vec![&test.env, 4000, 3000, 3000, 0],
+    ] {
+        let result = test.contract.try_initialize(&test.admin, &test.router.address, &test.components(), &weights, &100);
+        assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidWeights)));
+    }
+}
+
+#[test]
+fn initialize_invalid_max_slippage() {
+    let test = SoroswapBasketTest::setup();
+    let result = test.contract.try_initialize(&test.admin, &test.router.address, &test.components(), &vec![&test.env, 4000, 3000, 3000], &10_001);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidMaxSlippage)));
+}
+
+#[test]
+fn not_yet_initialized() {
+    let test = SoroswapBasketTest::setup();
+    assert_eq!(test.contract.try_get_router(), Err(Ok(SoroswapBasketError::NotInitialized)));
+    assert_eq!(test.contract.try_get_holdings(), Err(Ok(SoroswapBasketError::NotInitialized)));
+
+    let result = test.contract.try_mint_with(&test.user, &test.token_0.address, &100_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::NotInitialized)));
+
+    let result = test.contract.try_redeem_to(&test.user, &test.token_0.address, &1, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::NotInitialized)));
+
+    let result = test.contract.try_rebalance(&test.token_0.address, &test.token_1.address, &1, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::NotInitialized)));
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapBasketTest::setup_initialized();
+    let result = test.contract.try_initialize(&test.admin, &test.router.address, &test.components(), &vec![&test.env, 4000, 3000, 3000], &100);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InitializeAlreadyInitialized)));
+}
@@ -0,0 +1,133 @@
+use soroban_sdk::{testutils::Events, vec, IntoVal, symbol_short};
+
+use crate::test::{SoroswapBasketTest, create_token_contract};
+use crate::error::SoroswapBasketError;
+use crate::event::MintEvent;
+use crate::MINIMUM_SHARES;
+
+#[test]
+fn first_mint_by_weights() {
+    let test = SoroswapBasketTest::setup_initialized();
+
+    // 40_000_000 token_0 are kept, 30_000_000 are swapped for token_1 and 30_000_000 for token_2
+    let amount_1 = test.quote(&test.token_0.address, &test.token_1.address, 30_000_000);
+    let amount_2 = test.quote(&test.token_0.address, &test.token_2.address, 30_000_000);
+    let shares = test.contract.mint_with(&test.user, &test.token_0.address, &100_000_000, &(100_000_000 - MINIMUM_SHARES));
+
+    // The minimum shares are kept by the basket
+    assert_eq!(shares, 100_000_000 - MINIMUM_SHARES);
+    assert_eq!(test.contract.shares(&test.user), 100_000_000 - MINIMUM_SHARES);
+    assert_eq!(test.contract.shares(&test.contract.address), MINIMUM_SHARES);
+    assert_eq!(test.contract.total_shares(), 100_000_000);
+    assert_eq!(test.contract.get_holdings(), vec![&test.env, 40_000_000, amount_1, amount_2]);
+    assert_eq!(test.token_0.balance(&test.user), 1_000_000_000 - 100_000_000);
+
+    let mint_event = test.env.events().all().last().unwrap();
+    let expected_mint_event: MintEvent = MintEvent {
+        to: test.user.clone(),
+        token_in: test.token_0.address.clone(),
+        amount_in: 100_000_000,
+        amounts: vec![&test.env, 40_000_000, amount_1, amount_2],
+        shares: 100_000_000 - MINIMUM_SHARES,
+    };
+    assert_eq!(
+        vec![&test.env, mint_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapBasket", symbol_short!("mint")).into_val(&test.env),
+                (expected_mint_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn mint_in_proportion_to_holdings() {
+    let test = SoroswapBasketTest::setup_minted();
+    let holdings = test.contract.get_holdings();
+
+    // token_1 pays now: every component receives its share of the value of the basket
+    let shares = test.contract.mint_with(&test.user, &test.token_1.address, &50_000_000, &0);
+    let new_holdings = test.contract.get_holdings();
+
+    // The shares are the smallest fraction added to a component, the rest stays in the basket
+    let mut expected_shares = i128::MAX;
+    for (holding, new_holding) in holdings.iter().zip(new_holdings.iter()) {
+        assert!(new_holding > holding);
+        expected_shares = expected_shares.min((new_holding - holding) * 100_000_000 / holding);
+    }
+    assert_eq!(shares, expected_shares);
+    assert_eq!(test.contract.shares(&test.user), 100_000_000 - MINIMUM_SHARES + shares);
+    assert_eq!(test.contract.total_shares(), 100_000_000 + shares);
+
+    // 50_000_000 token_1 are worth about 25_000_000 token_0, a quarter of the first mint
+    assert!(shares > 20_000_000 && shares < 30_000_000);
+}
+
+#[test]
+fn mint_min_shares_not_met() {
+    let test = SoroswapBasketTest::setup_minted();
+    let result = test.contract.try_mint_with(&test.user, &test.token_0.address, &10_000_000, &20_000_000);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InsufficientSharesMinted)));
+    assert_eq!(test.contract.total_shares(), 100_000_000);
+}
+
+#[test]
+fn first_mint_too_small() {
+    let test = SoroswapBasketTest::setup_initialized();
+
+    // 3 token_0 split 1, 0 and 2: the basket would never hold token_1
+    let result = test.contract.try_mint_with(&test.user, &test.token_0.address, &3, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InsufficientSharesMinted)));
+
+    // Every component is bought, but nothing is left once the minimum shares are kept
+    let result = test.contract.try_mint_with(&test.user, &test.token_0.address, &MINIMUM_SHARES, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InsufficientSharesMinted)));
+    assert_eq!(test.contract.total_shares(), 0);
+}
+
+#[test]
+fn mint_ignores_donations() {
+    let test = SoroswapBasketTest::setup_minted();
+    let holdings = test.contract.get_holdings();
+
+    // token_1 sent straight to the basket does not count as holdings
+    test.token_1.transfer(&test.user, &test.contract.address, &50_000_000);
+    assert_eq!(test.contract.get_holdings(), holdings);
+
+    // The next mint is still split by the value of the holdings, and priced against them
+    let shares = test.contract.mint_with(&test.user, &test.token_0.address, &10_000_000, &0);
+    let new_holdings = test.contract.get_holdings();
+    let mut expected_shares = i128::MAX;
+    for (holding, new_holding) in holdings.iter().zip(new_holdings.iter()) {
+        expected_shares = expected_shares.min((new_holding - holding) * 100_000_000 / holding);
+    }
+    assert_eq!(shares, expected_shares);
+    assert!(shares > 9_000_000 && shares < 10_000_000);
+    assert_eq!(test.token_1.balance(&test.contract.address), new_holdings.get(1).unwrap() + 50_000_000);
+}
+
+#[test]
+fn mint_invalid_amount() {
+    let test = SoroswapBasketTest::setup_initialized();
+    let result = test.contract.try_mint_with(&test.user, &test.token_0.address, &0, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidAmount)));
+    let result = test.contract.try_mint_with(&test.user, &test.token_0.address, &100_000_000, &-1);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidAmount)));
+}
+
+#[test]
+fn mint_pair_does_not_exist() {
+    let test = SoroswapBasketTest::setup_initialized();
+    let token_3 = create_token_contract(&test.env, &test.admin);
+    token_3.mint(&test.user, &1_000_000_000);
+
+    let result = test.contract.try_mint_with(&test.user, &token_3.address, &100_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::PairDoesNotExist)));
+
+    test.contract.mint_with(&test.user, &test.token_0.address, &100_000_000, &0);
+    let result = test.contract.try_mint_with(&test.user, &token_3.address, &100_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::PairDoesNotExist)));
+}
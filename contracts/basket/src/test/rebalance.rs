@@ -0,0 +1,140 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, IntoVal, symbol_short};
+
+use crate::test::SoroswapBasketTest;
+use crate::error::SoroswapBasketError;
+use crate::event::{RebalanceEvent, NewWeightsEvent, NewMaxSlippageEvent};
+
+#[test]
+fn rebalance() {
+    let test = SoroswapBasketTest::setup_minted();
+    let holdings = test.contract.get_holdings();
+
+    let expected_amount_out = test.quote(&test.token_0.address, &test.token_1.address, 5_000_000);
+    let amount_out = test.contract.rebalance(&test.token_0.address, &test.token_1.address, &5_000_000, &expected_amount_out);
+    assert_eq!(amount_out, expected_amount_out);
+    assert_eq!(
+        test.contract.get_holdings(),
+        vec![
+            &test.env,
+            holdings.get(0).unwrap() - 5_000_000,
+            holdings.get(1).unwrap() + expected_amount_out,
+            holdings.get(2).unwrap(),
+        ]
+    );
+
+    let rebalance_event = test.env.events().all().last().unwrap();
+    let expected_rebalance_event: RebalanceEvent = RebalanceEvent {
+        token_in: test.token_0.address.clone(),
+        token_out: test.token_1.address.clone(),
+        amount_in: 5_000_000,
+        amount_out: expected_amount_out,
+    };
+    assert_eq!(
+        vec![&test.env, rebalance_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapBasket", symbol_short!("rebalance")).into_val(&test.env),
+                (expected_rebalance_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn rebalance_slippage_too_high() {
+    let test = SoroswapBasketTest::setup_minted();
+
+    // Selling 30_000_000 token_0 moves the price of the pair by about 3%, above the maximum of 1%
+    let result = test.contract.try_rebalance(&test.token_0.address, &test.token_1.address, &30_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::SlippageTooHigh)));
+
+    test.contract.set_max_slippage(&500);
+    test.contract.rebalance(&test.token_0.address, &test.token_1.address, &30_000_000, &0);
+}
+
+#[test]
+fn rebalance_amount_out_min_not_met() {
+    let test = SoroswapBasketTest::setup_minted();
+    let expected_amount_out = test.quote(&test.token_0.address, &test.token_1.address, 5_000_000);
+    let result = test.contract.try_rebalance(&test.token_0.address, &test.token_1.address, &5_000_000, &(expected_amount_out + 1));
+    assert!(result.is_err());
+}
+
+#[test]
+fn rebalance_invalid() {
+    let test = SoroswapBasketTest::setup_minted();
+    let token_3 = Address::generate(&test.env);
+
+    let result = test.contract.try_rebalance(&token_3, &test.token_1.address, &5_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::NotAComponent)));
+    let result = test.contract.try_rebalance(&test.token_0.address, &token_3, &5_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::NotAComponent)));
+    let result = test.contract.try_rebalance(&test.token_0.address, &test.token_0.address, &5_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::LibrarySortIdenticalTokens)));
+    let result = test.contract.try_rebalance(&test.token_0.address, &test.token_1.address, &0, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidAmount)));
+
+    // The first mint left 40_000_000 token_0 in the basket
+    let result = test.contract.try_rebalance(&test.token_0.address, &test.token_1.address, &40_000_001, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InsufficientHoldings)));
+
+    // Tokens sent to the basket directly can not be rebalanced
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000);
+    let result = test.contract.try_rebalance(&test.token_0.address, &test.token_1.address, &40_000_001, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InsufficientHoldings)));
+}
+
+#[test]
+fn set_weights() {
+    let test = SoroswapBasketTest::setup_initialized();
+    let weights = vec![&test.env, 2000, 4000, 4000];
+    test.contract.set_weights(&weights);
+    assert_eq!(test.contract.get_weights(), weights);
+
+    let weights_event = test.env.events().all().last().unwrap();
+    let expected_weights_event: NewWeightsEvent = NewWeightsEvent {
+        weights,
+    };
+    assert_eq!(
+        vec![&test.env, weights_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapBasket", symbol_short!("weights")).into_val(&test.env),
+                (expected_weights_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    let result = test.contract.try_set_weights(&vec![&test.env, 5000, 5000]);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidWeights)));
+}
+
+#[test]
+fn set_max_slippage() {
+    let test = SoroswapBasketTest::setup_initialized();
+    test.contract.set_max_slippage(&300);
+    assert_eq!(test.contract.get_max_slippage(), 300);
+
+    let slippage_event = test.env.events().all().last().unwrap();
+    let expected_slippage_event: NewMaxSlippageEvent = NewMaxSlippageEvent {
+        max_slippage_bps: 300,
+    };
+    assert_eq!(
+        vec![&test.env, slippage_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapBasket", symbol_short!("slippage")).into_val(&test.env),
+                (expected_slippage_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    let result = test.contract.try_set_max_slippage(&10_001);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidMaxSlippage)));
+}
@@ -0,0 +1,116 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, IntoVal, symbol_short};
+
+use crate::test::SoroswapBasketTest;
+use crate::error::SoroswapBasketError;
+use crate::event::{RedeemEvent, TransferEvent};
+use crate::MINIMUM_SHARES;
+
+#[test]
+fn redeem_to_component() {
+    let test = SoroswapBasketTest::setup_minted();
+    let holdings = test.contract.get_holdings();
+    let (holding_0, holding_1, holding_2) = (holdings.get(0).unwrap(), holdings.get(1).unwrap(), holdings.get(2).unwrap());
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    // Half of the shares own half of every component, sold for token_0 on their own pairs
+    let expected_amount_out = holding_0 / 2
+        + test.quote(&test.token_1.address, &test.token_0.address, holding_1 / 2)
+        + test.quote(&test.token_2.address, &test.token_0.address, holding_2 / 2);
+    let amount_out = test.contract.redeem_to(&test.user, &test.token_0.address, &50_000_000, &expected_amount_out);
+
+    assert_eq!(amount_out, expected_amount_out);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 + expected_amount_out);
+    assert_eq!(test.contract.shares(&test.user), 50_000_000 - MINIMUM_SHARES);
+    assert_eq!(test.contract.total_shares(), 50_000_000);
+    assert_eq!(
+        test.contract.get_holdings(),
+        vec![&test.env, holding_0 - holding_0 / 2, holding_1 - holding_1 / 2, holding_2 - holding_2 / 2]
+    );
+
+    let redeem_event = test.env.events().all().last().unwrap();
+    let expected_redeem_event: RedeemEvent = RedeemEvent {
+        from: test.user.clone(),
+        token_out: test.token_0.address.clone(),
+        shares: 50_000_000,
+        amounts: vec![&test.env, holding_0 / 2, holding_1 / 2, holding_2 / 2],
+        amount_out: expected_amount_out,
+    };
+    assert_eq!(
+        vec![&test.env, redeem_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapBasket", symbol_short!("redeem")).into_val(&test.env),
+                (expected_redeem_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn redeem_all() {
+    let test = SoroswapBasketTest::setup_minted();
+    let holdings = test.contract.get_holdings();
+    test.contract.redeem_to(&test.user, &test.token_1.address, &(100_000_000 - MINIMUM_SHARES), &0);
+    assert_eq!(test.contract.total_shares(), MINIMUM_SHARES);
+    assert_eq!(test.contract.shares(&test.user), 0);
+
+    // The minimum shares kept by the basket still own their part of every component
+    for (holding, new_holding) in holdings.iter().zip(test.contract.get_holdings().iter()) {
+        assert_eq!(new_holding, holding - holding * (100_000_000 - MINIMUM_SHARES) / 100_000_000);
+        assert!(new_holding > 0);
+    }
+}
+
+#[test]
+fn redeem_min_out_not_met() {
+    let test = SoroswapBasketTest::setup_minted();
+    let result = test.contract.try_redeem_to(&test.user, &test.token_0.address, &50_000_000, &50_000_000);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InsufficientOutputAmount)));
+    assert_eq!(test.contract.shares(&test.user), 100_000_000 - MINIMUM_SHARES);
+}
+
+#[test]
+fn redeem_insufficient_shares() {
+    let test = SoroswapBasketTest::setup_minted();
+    let result = test.contract.try_redeem_to(&test.user, &test.token_0.address, &(100_000_000 - MINIMUM_SHARES + 1), &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InsufficientShares)));
+    let result = test.contract.try_redeem_to(&test.user, &test.token_0.address, &0, &0);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InvalidAmount)));
+}
+
+#[test]
+fn transfer_shares() {
+    let test = SoroswapBasketTest::setup_minted();
+    let holder = Address::generate(&test.env);
+    test.contract.transfer(&test.user, &holder, &40_000_000);
+    assert_eq!(test.contract.shares(&test.user), 60_000_000 - MINIMUM_SHARES);
+    assert_eq!(test.contract.shares(&holder), 40_000_000);
+    assert_eq!(test.contract.total_shares(), 100_000_000);
+
+    let transfer_event = test.env.events().all().last().unwrap();
+    let expected_transfer_event: TransferEvent = TransferEvent {
+        from: test.user.clone(),
+        to: holder.clone(),
+        shares: 40_000_000,
+    };
+    assert_eq!(
+        vec![&test.env, transfer_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapBasket", symbol_short!("transfer")).into_val(&test.env),
+                (expected_transfer_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The new holder can redeem them
+    test.contract.redeem_to(&holder, &test.token_0.address, &40_000_000, &0);
+    assert!(test.token_0.balance(&holder) > 0);
+
+    let result = test.contract.try_transfer(&holder, &test.user, &1);
+    assert_eq!(result, Err(Ok(SoroswapBasketError::InsufficientShares)));
+}
@@ -0,0 +1,70 @@
+use soroban_sdk::{self, contracterror};
+use soroswap_library::SoroswapLibraryError;
+
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapDcaError {
+    /// SoroswapDca: not yet initialized
+    NotInitialized = 901,
+
+    /// SoroswapDca: already initialized
+    InitializeAlreadyInitialized = 902,
+
+    /// SoroswapDca: total and slice amounts must be positive and the minimum price can not be negative
+    InvalidAmount = 903,
+
+    /// SoroswapDca: interval must be positive
+    InvalidInterval = 904,
+
+    /// SoroswapDca: order does not exist
+    OrderDoesNotExist = 905,
+
+    /// SoroswapDca: pair does not exist
+    PairDoesNotExist = 906,
+
+    /// SoroswapDca: the next slice of the order is not due yet
+    SliceNotDue = 907,
+
+    /// SoroswapDca: the pair gives less than the minimum price of the order
+    PriceBelowMinimum = 908,
+
+    /// SoroswapDca: keeper bounty can not be above MAX_KEEPER_BOUNTY_BPS
+    InvalidKeeperBounty = 909,
+
+    /// SoroswapLibrary: insufficient amount
+    LibraryInsufficientAmount = 910,
+
+    /// SoroswapLibrary: insufficient liquidity
+    LibraryInsufficientLiquidity = 911,
+
+    /// SoroswapLibrary: insufficient input amount
+    LibraryInsufficientInputAmount = 912,
+
+    /// SoroswapLibrary: insufficient output amount
+    LibraryInsufficientOutputAmount = 913,
+
+    /// SoroswapLibrary: invalid path
+    LibraryInvalidPath = 914,
+
+    /// SoroswapLibrary: token_a and token_b have identical addresses
+    LibrarySortIdenticalTokens = 915,
+
+    /// SoroswapLibrary: arithmetic overflow
+    LibraryOverflow = 916,
+}
+
+impl From<SoroswapLibraryError> for SoroswapDcaError {
+    fn from(err: SoroswapLibraryError) -> Self {
+        match err {
+            SoroswapLibraryError::InsufficientAmount => SoroswapDcaError::LibraryInsufficientAmount,
+            SoroswapLibraryError::InsufficientLiquidity => SoroswapDcaError::LibraryInsufficientLiquidity,
+            SoroswapLibraryError::InsufficientInputAmount => SoroswapDcaError::LibraryInsufficientInputAmount,
+            SoroswapLibraryError::InsufficientOutputAmount => SoroswapDcaError::LibraryInsufficientOutputAmount,
+            SoroswapLibraryError::InvalidPath => SoroswapDcaError::LibraryInvalidPath,
+            SoroswapLibraryError::SortIdenticalTokens => SoroswapDcaError::LibrarySortIdenticalTokens,
+            SoroswapLibraryError::Overflow => SoroswapDcaError::LibraryOverflow,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address};
+
+// INITIALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub router: Address,
+    pub factory: Address
+}
+
+/// Publishes an `InitializedEvent` when the contract is initialized.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `router` - The Soroswap router the slices are swapped through.
+/// * `factory` - The factory of the router.
+pub(crate) fn initialized(e: &Env, router: Address, factory: Address) {
+    let event = InitializedEvent {
+        router,
+        factory,
+    };
+    e.events().publish(("SoroswapDca", symbol_short!("init")), event);
+}
+
+// CREATE ORDER EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreateOrderEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_per_slice: i128,
+    pub min_price: i128,
+    pub interval: u64,
+    pub keeper_bounty_bps: u32
+}
+
+/// Publishes a `CreateOrderEvent` when an order is created and its deposit escrowed.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The created order.
+pub(crate) fn create(e: &Env, event: CreateOrderEvent) {
+    e.events().publish(("SoroswapDca", symbol_short!("create")), event);
+}
+
+// SLICE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SliceEvent {
+    pub order_id: u64,
+    pub keeper: Address,
+    pub slice: u32,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub keeper_bounty: i128,
+    pub amount_in_remaining: i128
+}
+
+/// Publishes a `SliceEvent` every time a keeper executes a slice of an order.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The slice: its index, the input sold, the output sent to the owner, the bounty paid
+///   to the keeper and the input left in the order.
+pub(crate) fn slice(e: &Env, event: SliceEvent) {
+    e.events().publish(("SoroswapDca", symbol_short!("slice")), event);
+}
+
+// CANCEL ORDER EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelOrderEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub amount_in_refunded: i128
+}
+
+/// Publishes a `CancelOrderEvent` when the owner cancels an order.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `order_id` - The cancelled order.
+/// * `owner` - The owner of the order.
+/// * `amount_in_refunded` - The unsold deposit sent back to the owner.
+pub(crate) fn cancel(e: &Env, order_id: u64, owner: Address, amount_in_refunded: i128) {
+    let event = CancelOrderEvent {
+        order_id,
+        owner,
+        amount_in_refunded,
+    };
+    e.events().publish(("SoroswapDca", symbol_short!("cancel")), event);
+}
@@ -0,0 +1,355 @@
+#![no_std]
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol, Vec};
+
+mod router;
+mod factory;
+mod test;
+mod event;
+mod storage;
+mod error;
+mod order;
+
+use router::SoroswapRouterClient;
+use factory::SoroswapFactoryClient;
+use storage::{
+    put_router,
+    has_router,
+    get_router,
+    get_factory,
+    extend_instance_ttl,
+    next_order_id,
+    put_order,
+    get_order,
+    remove_order,
+};
+use order::{slice_amount_in, slice_amount_out_min, keeper_bounty};
+pub use error::SoroswapDcaError;
+pub use order::{DcaOrder, MAX_KEEPER_BOUNTY_BPS};
+
+fn check_initialized(e: &Env) -> Result<(), SoroswapDcaError> {
+    if has_router(e) {
+        Ok(())
+    } else {
+        Err(SoroswapDcaError::NotInitialized)
+    }
+}
+
+fn load_order(e: &Env, order_id: u64) -> Result<DcaOrder, SoroswapDcaError> {
+    get_order(e, order_id).ok_or(SoroswapDcaError::OrderDoesNotExist)
+}
+
+/// Swaps `amount_in` of the escrowed `token_in` of `order` through the router, requiring at least
+/// `amount_out_min`, and returns the output, which stays in this contract.
+fn swap_escrow(e: &Env, order: &DcaOrder, amount_in: i128, amount_out_min: i128) -> Result<i128, SoroswapDcaError> {
+    let dca = e.current_contract_address();
+    let pair = soroswap_library::pair_for(e.clone(), get_factory(e), order.token_in.clone(), order.token_out.clone())?;
+
+    // The router pays the pair with the tokens of this contract, which has to authorize that transfer
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: order.token_in.clone(),
+                fn_name: Symbol::new(e, "transfer"),
+                args: (dca.clone(), pair, amount_in).into_val(e),
+            },
+            sub_invocations: Vec::new(e),
+        }),
+    ]);
+
+    let amounts = SoroswapRouterClient::new(e, &get_router(e)).swap_exact_tokens_from(
+        &dca,
+        &amount_in,
+        &amount_out_min,
+        &vec![e, order.token_in.clone(), order.token_out.clone()],
+        &dca,
+        &(e.ledger().timestamp() + 1),
+    );
+    Ok(amounts.get(amounts.len() - 1).unwrap())
+}
+
+pub trait SoroswapDcaTrait {
+
+    /// Initializes the contract with the Soroswap router the slices are swapped through.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `router` - The address of the Soroswap router. Its factory is read from it.
+    fn initialize(e: Env, router: Address) -> Result<(), SoroswapDcaError>;
+
+    /// Creates an order selling `amount_in` of `token_in` for `token_out` in slices of `amount_per_slice`,
+    /// one every `interval` seconds, escrowing `amount_in`. The first slice is due right away.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The depositor. Must authorize the call.
+    /// * `token_in` - The token sold.
+    /// * `token_out` - The token bought. The pair of both tokens must exist.
+    /// * `amount_in` - The total amount of `token_in` to sell.
+    /// * `amount_per_slice` - The amount of `token_in` sold by every slice.
+    /// * `min_price` - The minimum amount of `token_out` per `token_in` of every slice, scaled by `PRICE_SCALE`.
+    /// * `interval` - Seconds between two slices.
+    /// * `keeper_bounty_bps` - Basis points of every slice surplus above the minimum paid to the keeper, up to `MAX_KEEPER_BOUNTY_BPS`.
+    ///
+    /// # Returns
+    /// The id of the order.
+    fn create_order(
+        e: Env,
+        owner: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        amount_per_slice: i128,
+        min_price: i128,
+        interval: u64,
+        keeper_bounty_bps: u32,
+    ) -> Result<u64, SoroswapDcaError>;
+
+    /// Executes the next slice of an order once it is due. Anyone can trigger it: the slice is
+    /// swapped through the router, the keeper receives its bounty out of the surplus above the
+    /// minimum price and the owner the rest, never less than the minimum. The next slice is due
+    /// `interval` seconds after this one was due, or after now if that is later, so missed slices
+    /// are not run back to back. The order is removed once its deposit is sold.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `keeper` - The address that triggers the slice and receives the bounty. Must authorize the call.
+    /// * `order_id` - The id of the order.
+    ///
+    /// # Returns
+    /// A tuple with the amount of `token_out` sent to the owner and the keeper bounty.
+    fn execute_slice(e: Env, keeper: Address, order_id: u64) -> Result<(i128, i128), SoroswapDcaError>;
+
+    /// Cancels an order and refunds its unsold deposit to the owner.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `order_id` - The id of the order. Its owner must authorize the call.
+    ///
+    /// # Returns
+    /// The amount of `token_in` refunded.
+    fn cancel_order(e: Env, order_id: u64) -> Result<i128, SoroswapDcaError>;
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the router the slices are swapped through.
+    fn get_router(e: Env) -> Result<Address, SoroswapDcaError>;
+
+    /// Returns an active order, with its progress.
+    fn get_order(e: Env, order_id: u64) -> Result<DcaOrder, SoroswapDcaError>;
+}
+
+#[contract]
+struct SoroswapDca;
+
+#[contractimpl]
+impl SoroswapDcaTrait for SoroswapDca {
+
+    /// Initializes the contract with the Soroswap router the slices are swapped through.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `router` - The address of the Soroswap router. Its factory is read from it.
+    fn initialize(e: Env, router: Address) -> Result<(), SoroswapDcaError> {
+        if has_router(&e) {
+            return Err(SoroswapDcaError::InitializeAlreadyInitialized);
+        }
+        let factory = SoroswapRouterClient::new(&e, &router).get_factory();
+        put_router(&e, &router, &factory);
+        event::initialized(&e, router, factory);
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Creates an order selling `amount_in` of `token_in` for `token_out` in slices of `amount_per_slice`,
+    /// one every `interval` seconds, escrowing `amount_in`. The first slice is due right away.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The depositor. Must authorize the call.
+    /// * `token_in` - The token sold.
+    /// * `token_out` - The token bought. The pair of both tokens must exist.
+    /// * `amount_in` - The total amount of `token_in` to sell.
+    /// * `amount_per_slice` - The amount of `token_in` sold by every slice.
+    /// * `min_price` - The minimum amount of `token_out` per `token_in` of every slice, scaled by `PRICE_SCALE`.
+    /// * `interval` - Seconds between two slices.
+    /// * `keeper_bounty_bps` - Basis points of every slice surplus above the minimum paid to the keeper, up to `MAX_KEEPER_BOUNTY_BPS`.
+    ///
+    /// # Returns
+    /// The id of the order.
+    fn create_order(
+        e: Env,
+        owner: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        amount_per_slice: i128,
+        min_price: i128,
+        interval: u64,
+        keeper_bounty_bps: u32,
+    ) -> Result<u64, SoroswapDcaError> {
+        check_initialized(&e)?;
+        if amount_in <= 0 || amount_per_slice <= 0 || min_price < 0 {
+            return Err(SoroswapDcaError::InvalidAmount);
+        }
+        if interval == 0 {
+            return Err(SoroswapDcaError::InvalidInterval);
+        }
+        if keeper_bounty_bps > MAX_KEEPER_BOUNTY_BPS {
+            return Err(SoroswapDcaError::InvalidKeeperBounty);
+        }
+        extend_instance_ttl(&e);
+        owner.require_auth();
+
+        let factory = SoroswapFactoryClient::new(&e, &get_factory(&e));
+        if !factory.pair_exists(&token_in, &token_out) {
+            return Err(SoroswapDcaError::PairDoesNotExist);
+        }
+
+        TokenClient::new(&e, &token_in).transfer(&owner, &e.current_contract_address(), &amount_in);
+
+        let order = DcaOrder {
+            id: next_order_id(&e),
+            owner,
+            token_in,
+            token_out,
+            amount_in,
+            amount_per_slice,
+            min_price,
+            interval,
+            keeper_bounty_bps,
+            next_slice_at: e.ledger().timestamp(),
+            slices_executed: 0,
+            amount_in_remaining: amount_in,
+            amount_out_total: 0,
+        };
+        put_order(&e, &order);
+
+        event::create(&e, event::CreateOrderEvent {
+            order_id: order.id,
+            owner: order.owner,
+            token_in: order.token_in,
+            token_out: order.token_out,
+            amount_in,
+            amount_per_slice,
+            min_price,
+            interval,
+            keeper_bounty_bps,
+        });
+        Ok(order.id)
+    }
+
+    /// Executes the next slice of an order once it is due. Anyone can trigger it: the slice is
+    /// swapped through the router, the keeper receives its bounty out of the surplus above the
+    /// minimum price and the owner the rest, never less than the minimum. The next slice is due
+    /// `interval` seconds after this one was due, or after now if that is later, so missed slices
+    /// are not run back to back. The order is removed once its deposit is sold.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `keeper` - The address that triggers the slice and receives the bounty. Must authorize the call.
+    /// * `order_id` - The id of the order.
+    ///
+    /// # Returns
+    /// A tuple with the amount of `token_out` sent to the owner and the keeper bounty.
+    fn execute_slice(e: Env, keeper: Address, order_id: u64) -> Result<(i128, i128), SoroswapDcaError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        keeper.require_auth();
+
+        let mut order = load_order(&e, order_id)?;
+        if e.ledger().timestamp() < order.next_slice_at {
+            return Err(SoroswapDcaError::SliceNotDue);
+        }
+
+        // The pair has to give at least the minimum price for this slice
+        let amount_in = slice_amount_in(&order);
+        let amount_out_min = slice_amount_out_min(&order, amount_in);
+        let (reserve_in, reserve_out) = soroswap_library::get_reserves(
+            e.clone(),
+            get_factory(&e),
+            order.token_in.clone(),
+            order.token_out.clone(),
+        )?;
+        if soroswap_library::get_amount_out(amount_in, reserve_in, reserve_out)? < amount_out_min {
+            return Err(SoroswapDcaError::PriceBelowMinimum);
+        }
+
+        let amount_out = swap_escrow(&e, &order, amount_in, amount_out_min)?;
+        // The keeper bounty comes out of the surplus above the minimum price
+        let bounty = keeper_bounty(&order, amount_out.checked_sub(amount_out_min).unwrap());
+        let owner_amount_out = amount_out.checked_sub(bounty).unwrap();
+        let token_out = TokenClient::new(&e, &order.token_out);
+        token_out.transfer(&e.current_contract_address(), &order.owner, &owner_amount_out);
+        if bounty > 0 {
+            token_out.transfer(&e.current_contract_address(), &keeper, &bounty);
+        }
+
+        let slice = order.slices_executed;
+        order.slices_executed += 1;
+        order.amount_in_remaining = order.amount_in_remaining.checked_sub(amount_in).unwrap();
+        order.amount_out_total = order.amount_out_total.checked_add(owner_amount_out).unwrap();
+        // A late slice does not let the missed ones run back to back
+        let now = e.ledger().timestamp();
+        order.next_slice_at = order.next_slice_at.checked_add(order.interval).unwrap()
+            .max(now.checked_add(order.interval).unwrap());
+        if order.amount_in_remaining == 0 {
+            remove_order(&e, order_id);
+        } else {
+            put_order(&e, &order);
+        }
+
+        event::slice(&e, event::SliceEvent {
+            order_id,
+            keeper,
+            slice,
+            amount_in,
+            amount_out: owner_amount_out,
+            keeper_bounty: bounty,
+            amount_in_remaining: order.amount_in_remaining,
+        });
+        Ok((owner_amount_out, bounty))
+    }
+
+    /// Cancels an order and refunds its unsold deposit to the owner.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `order_id` - The id of the order. Its owner must authorize the call.
+    ///
+    /// # Returns
+    /// The amount of `token_in` refunded.
+    fn cancel_order(e: Env, order_id: u64) -> Result<i128, SoroswapDcaError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+
+        let order = load_order(&e, order_id)?;
+        order.owner.require_auth();
+
+        remove_order(&e, order_id);
+        TokenClient::new(&e, &order.token_in).transfer(
+            &e.current_contract_address(),
+            &order.owner,
+            &order.amount_in_remaining,
+        );
+
+        event::cancel(&e, order_id, order.owner, order.amount_in_remaining);
+        Ok(order.amount_in_remaining)
+    }
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the router the slices are swapped through.
+    fn get_router(e: Env) -> Result<Address, SoroswapDcaError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_router(&e))
+    }
+
+    /// Returns an active order, with its progress.
+    fn get_order(e: Env, order_id: u64) -> Result<DcaOrder, SoroswapDcaError> {
+        load_order(&e, order_id)
+    }
+}
@@ -0,0 +1,67 @@
+use soroban_sdk::{contracttype, Address};
+use soroswap_library::PRICE_SCALE;
+
+/// Basis points of the surplus of a slice a keeper bounty can take at most.
+pub const MAX_KEEPER_BOUNTY_BPS: u32 = 100;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// An order selling a deposit of `token_in` for `token_out` in slices of `amount_per_slice`, one every
+/// `interval` seconds. The unsold amount is escrowed by the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DcaOrder {
+    /// The id of the order.
+    pub id: u64,
+    /// The depositor, who receives the output of every slice and the refund.
+    pub owner: Address,
+    /// The token sold.
+    pub token_in: Address,
+    /// The token bought.
+    pub token_out: Address,
+    /// The amount of `token_in` deposited.
+    pub amount_in: i128,
+    /// The amount of `token_in` sold by every slice. The last slice sells what is left.
+    pub amount_per_slice: i128,
+    /// The minimum amount of `token_out` per `token_in` of every slice, scaled by `PRICE_SCALE`.
+    pub min_price: i128,
+    /// Seconds between two slices.
+    pub interval: u64,
+    /// Basis points of the surplus of every slice above its minimum output paid to the keeper that
+    /// triggers it.
+    pub keeper_bounty_bps: u32,
+    /// The timestamp from which the next slice can be triggered.
+    pub next_slice_at: u64,
+    /// The number of slices executed.
+    pub slices_executed: u32,
+    /// The amount of `token_in` not sold yet.
+    pub amount_in_remaining: i128,
+    /// The amount of `token_out` sent to the owner so far.
+    pub amount_out_total: i128,
+}
+
+/// Returns the amount of `token_in` the next slice of `order` sells.
+pub fn slice_amount_in(order: &DcaOrder) -> i128 {
+    order.amount_per_slice.min(order.amount_in_remaining)
+}
+
+/// Returns the minimum amount of `token_out` for a slice of `amount_in`: the minimum price of the
+/// order applied to `amount_in`, rounded up.
+pub fn slice_amount_out_min(order: &DcaOrder, amount_in: i128) -> i128 {
+    let numerator = amount_in.checked_mul(order.min_price).unwrap();
+    let quotient = numerator.checked_div(PRICE_SCALE).unwrap();
+    if numerator % PRICE_SCALE != 0 {
+        quotient.checked_add(1).unwrap()
+    } else {
+        quotient
+    }
+}
+
+/// Returns the keeper bounty of a slice that bought `surplus` above its minimum output.
+pub fn keeper_bounty(order: &DcaOrder, surplus: i128) -> i128 {
+    surplus
+        .checked_mul(order.keeper_bounty_bps as i128)
+        .unwrap()
+        .checked_div(BPS_DENOMINATOR)
+        .unwrap()
+}
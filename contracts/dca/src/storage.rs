@@ -0,0 +1,71 @@
+use soroban_sdk::{contracttype, Env, Address};
+use crate::order::DcaOrder;
+
+#[derive(Clone)]
+#[contracttype]
+
+enum DataKey {
+    Router, // Address of the Soroswap Router the slices are swapped through. Instance Data Type
+    Factory, // Address of the Soroswap Factory of the router. Instance Data Type
+    NextOrderId, // Id of the next order created. Instance Data Type
+    Order(u64), // An active order. Persistent Data Type
+}
+
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+pub fn put_router(e: &Env, router: &Address, factory: &Address) {
+    e.storage().instance().set(&DataKey::Router, router);
+    e.storage().instance().set(&DataKey::Factory, factory);
+}
+
+pub fn has_router(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Router)
+}
+
+pub fn get_router(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Router).unwrap()
+}
+
+pub fn get_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Factory).unwrap()
+}
+
+/// Returns the id of the next order and increments it.
+pub fn next_order_id(e: &Env) -> u64 {
+    let order_id: u64 = e.storage().instance().get(&DataKey::NextOrderId).unwrap_or(0);
+    e.storage().instance().set(&DataKey::NextOrderId, &(order_id + 1));
+    order_id
+}
+
+pub fn put_order(e: &Env, order: &DcaOrder) {
+    let key = DataKey::Order(order.id);
+    e.storage().persistent().set(&key, order);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_order(e: &Env, order_id: u64) -> Option<DcaOrder> {
+    let key = DataKey::Order(order_id);
+    let order = e.storage().persistent().get::<DataKey, DcaOrder>(&key);
+    if order.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    order
+}
+
+pub fn remove_order(e: &Env, order_id: u64) {
+    e.storage().persistent().remove(&DataKey::Order(order_id));
+}
@@ -0,0 +1,174 @@
+#![cfg(test)]
+use crate::{SoroswapDca, SoroswapDcaClient};
+use soroban_sdk::{
+    Env, 
+    vec,
+    BytesN, 
+    Address, 
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(&e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+fn pair_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// SoroswapFactory Contract
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryClient<'a> {
+    let pair_hash = pair_contract_wasm(&e);  
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address); 
+    factory.initialize(&setter, &pair_hash);
+    factory
+}
+
+// SoroswapRouter Contract
+mod router {
+    soroban_sdk::contractimport!(file = "../router/target/wasm32-unknown-unknown/release/soroswap_router.wasm");
+    pub type SoroswapRouterClient<'a> = Client<'a>;
+}
+use router::SoroswapRouterClient;
+
+fn create_soroswap_router<'a>(e: & Env, factory: & Address) -> SoroswapRouterClient<'a> {
+    let router = SoroswapRouterClient::new(e, &e.register_contract_wasm(None, router::WASM));
+    router.initialize(factory);
+    router
+}
+
+// SoroswapDca Contract
+fn create_soroswap_dca<'a>(e: &Env) -> SoroswapDcaClient<'a> {
+    SoroswapDcaClient::new(e, &e.register_contract(None, SoroswapDca {}))
+}
+
+// SoroswapDca TEST
+
+pub struct SoroswapDcaTest<'a> {
+    env: Env,
+    contract: SoroswapDcaClient<'a>,
+    router: SoroswapRouterClient<'a>,
+    factory: SoroswapFactoryClient<'a>,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    admin: Address,
+    owner: Address,
+    keeper: Address,
+}
+
+impl<'a> SoroswapDcaTest<'a> {
+    /// Sets up a token_0/token_1 pair where 1 token_0 = 4 token_1, the time at 100, and an owner
+    /// holding 1_000_000_000 token_0. The admin provides the liquidity and moves the price.
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = 100;
+        });
+        let contract = create_soroswap_dca(&env);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let keeper = Address::generate(&env);
+
+        let token_0 = create_token_contract(&env, &admin);
+        let token_1 = create_token_contract(&env, &admin);
+        token_0.mint(&admin, &10_000_000_000);
+        token_1.mint(&admin, &10_000_000_000);
+        token_0.mint(&owner, &1_000_000_000);
+
+        let factory = create_soroswap_factory(&env, &admin);
+        let router = create_soroswap_router(&env, &factory.address);
+        env.budget().reset_unlimited();
+
+        // 1 token_0 = 4 token_1
+        router.add_liquidity(
+            &token_0.address,
+            &token_1.address,
+            &1_000_000_000,
+            &4_000_000_000,
+            &0,
+            &0,
+            &admin,
+            &1000,
+        );
+
+        SoroswapDcaTest {
+            env,
+            contract,
+            router,
+            factory,
+            token_0,
+            token_1,
+            admin,
+            owner,
+            keeper,
+        }
+    }
+
+    fn setup_initialized() -> Self {
+        let test = Self::setup();
+        test.contract.initialize(&test.router.address);
+        test
+    }
+
+    /// Creates an order of the owner selling 2_500_000 token_0 for token_1 in slices of 1_000_000 every
+    /// hour, at a minimum price of 3.9 token_1 per token_0 and a keeper bounty of 10 basis points.
+    fn create_sell_0(&self) -> u64 {
+        self.contract.create_order(
+            &self.owner,
+            &self.token_0.address,
+            &self.token_1.address,
+            &2_500_000,
+            &1_000_000,
+            &3_900_000_000_000_000_000,
+            &3600,
+            &10,
+        )
+    }
+
+    /// Sets the ledger timestamp, to make slices due.
+    fn set_timestamp(&self, timestamp: u64) {
+        self.env.ledger().with_mut(|li| {
+            li.timestamp = timestamp;
+        });
+    }
+
+    /// Swaps `amount_in` of `token_in` on the pair with the admin's funds, moving the price.
+    fn swap(&self, token_in: &Address, token_out: &Address, amount_in: i128) {
+        self.router.swap_exact_tokens_for_tokens(
+            &amount_in,
+            &0,
+            &vec![&self.env, token_in.clone(), token_out.clone()],
+            &self.admin,
+            &(self.env.ledger().timestamp() + 1),
+        );
+    }
+}
+
+// Test mods:
+pub mod initialize;
+pub mod create;
+pub mod slice;
+pub mod cancel;
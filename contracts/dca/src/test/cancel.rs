@@ -0,0 +1,40 @@
+use soroban_sdk::{testutils::Events, vec, IntoVal, symbol_short};
+
+use crate::test::SoroswapDcaTest;
+use crate::error::SoroswapDcaError;
+use crate::event::CancelOrderEvent;
+
+#[test]
+fn cancel_order_refunds_unsold_deposit() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let order_id = test.create_sell_0();
+    test.contract.execute_slice(&test.keeper, &order_id);
+
+    assert_eq!(test.contract.cancel_order(&order_id), 1_500_000);
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000 - 1_000_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(SoroswapDcaError::OrderDoesNotExist)));
+
+    let cancel_event = test.env.events().all().last().unwrap();
+    let expected_cancel_event: CancelOrderEvent = CancelOrderEvent {
+        order_id,
+        owner: test.owner.clone(),
+        amount_in_refunded: 1_500_000,
+    };
+    assert_eq!(
+        vec![&test.env, cancel_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapDca", symbol_short!("cancel")).into_val(&test.env),
+                (expected_cancel_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // A cancelled order can not run nor be cancelled again
+    let result = test.contract.try_execute_slice(&test.keeper, &order_id);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::OrderDoesNotExist)));
+    assert_eq!(test.contract.try_cancel_order(&order_id), Err(Ok(SoroswapDcaError::OrderDoesNotExist)));
+}
@@ -0,0 +1,94 @@
+use soroban_sdk::{testutils::Events, vec, IntoVal, symbol_short};
+
+use crate::test::{SoroswapDcaTest, create_token_contract};
+use crate::error::SoroswapDcaError;
+use crate::event::CreateOrderEvent;
+use crate::DcaOrder;
+
+#[test]
+fn create_order_escrows_deposit() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let order_id = test.create_sell_0();
+    assert_eq!(order_id, 0);
+
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000 - 2_500_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 2_500_000);
+
+    // The first slice is due right away
+    assert_eq!(test.contract.get_order(&order_id), DcaOrder {
+        id: 0,
+        owner: test.owner.clone(),
+        token_in: test.token_0.address.clone(),
+        token_out: test.token_1.address.clone(),
+        amount_in: 2_500_000,
+        amount_per_slice: 1_000_000,
+        min_price: 3_900_000_000_000_000_000,
+        interval: 3600,
+        keeper_bounty_bps: 10,
+        next_slice_at: 100,
+        slices_executed: 0,
+        amount_in_remaining: 2_500_000,
+        amount_out_total: 0,
+    });
+
+    let create_event = test.env.events().all().last().unwrap();
+    let expected_create_event: CreateOrderEvent = CreateOrderEvent {
+        order_id: 0,
+        owner: test.owner.clone(),
+        token_in: test.token_0.address.clone(),
+        token_out: test.token_1.address.clone(),
+        amount_in: 2_500_000,
+        amount_per_slice: 1_000_000,
+        min_price: 3_900_000_000_000_000_000,
+        interval: 3600,
+        keeper_bounty_bps: 10,
+    };
+    assert_eq!(
+        vec![&test.env, create_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapDca", symbol_short!("create")).into_val(&test.env),
+                (expected_create_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    assert_eq!(test.create_sell_0(), 1);
+}
+
+#[test]
+fn create_order_invalid_amounts() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let result = test.contract.try_create_order(&test.owner, &test.token_0.address, &test.token_1.address, &0, &1_000_000, &0, &3600, &10);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::InvalidAmount)));
+
+    let result = test.contract.try_create_order(&test.owner, &test.token_0.address, &test.token_1.address, &2_500_000, &0, &0, &3600, &10);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::InvalidAmount)));
+
+    let result = test.contract.try_create_order(&test.owner, &test.token_0.address, &test.token_1.address, &2_500_000, &1_000_000, &-1, &3600, &10);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::InvalidAmount)));
+}
+
+#[test]
+fn create_order_invalid_interval() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let result = test.contract.try_create_order(&test.owner, &test.token_0.address, &test.token_1.address, &2_500_000, &1_000_000, &0, &0, &10);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::InvalidInterval)));
+}
+
+#[test]
+fn create_order_invalid_keeper_bounty() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let result = test.contract.try_create_order(&test.owner, &test.token_0.address, &test.token_1.address, &2_500_000, &1_000_000, &0, &3600, &101);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::InvalidKeeperBounty)));
+}
+
+#[test]
+fn create_order_pair_does_not_exist() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    let result = test.contract.try_create_order(&test.owner, &test.token_0.address, &token_2.address, &2_500_000, &1_000_000, &0, &3600, &10);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::PairDoesNotExist)));
+}
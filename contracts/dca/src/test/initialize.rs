@@ -0,0 +1,61 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, IntoVal, symbol_short};
+
+use crate::test::SoroswapDcaTest;
+use crate::error::SoroswapDcaError;
+use crate::event::InitializedEvent;
+
+#[test]
+fn initialize_and_get_router() {
+    let test = SoroswapDcaTest::setup();
+    test.contract.initialize(&test.router.address);
+    assert_eq!(test.contract.get_router(), test.router.address);
+
+    // The factory is read from the router
+    let initialized_event = test.env.events().all().last().unwrap();
+    let expected_initialized_event: InitializedEvent = InitializedEvent {
+        router: test.router.address.clone(),
+        factory: test.factory.address.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, initialized_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapDca", symbol_short!("init")).into_val(&test.env),
+                (expected_initialized_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn not_yet_initialized() {
+    let test = SoroswapDcaTest::setup();
+    assert_eq!(test.contract.try_get_router(), Err(Ok(SoroswapDcaError::NotInitialized)));
+
+    let result = test.contract.try_create_order(
+        &test.owner,
+        &test.token_0.address,
+        &test.token_1.address,
+        &2_500_000,
+        &1_000_000,
+        &0,
+        &3600,
+        &10,
+    );
+    assert_eq!(result, Err(Ok(SoroswapDcaError::NotInitialized)));
+
+    let result = test.contract.try_execute_slice(&test.keeper, &0);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::NotInitialized)));
+
+    let result = test.contract.try_cancel_order(&0);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::NotInitialized)));
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let result = test.contract.try_initialize(&Address::generate(&test.env));
+    assert_eq!(result, Err(Ok(SoroswapDcaError::InitializeAlreadyInitialized)));
+}
@@ -0,0 +1,142 @@
+use soroban_sdk::{testutils::Events, vec, IntoVal, symbol_short};
+
+use crate::test::SoroswapDcaTest;
+use crate::error::SoroswapDcaError;
+use crate::event::SliceEvent;
+
+fn quote_sell_0(test: &SoroswapDcaTest, amount_in: i128) -> i128 {
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    test.router.router_get_amounts_out(&amount_in, &path).get(1).unwrap()
+}
+
+#[test]
+fn execute_slices_on_schedule() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let order_id = test.create_sell_0();
+
+    // First slice: 1_000_000 token_0 buy 3_984_027 token_1, the keeper gets 10 basis points of the
+    // 84_027 above the minimum of 3_900_000
+    let result = test.contract.execute_slice(&test.keeper, &order_id);
+    assert_eq!(result, (3_984_027 - 84, 84));
+    assert_eq!(test.token_1.balance(&test.owner), 3_984_027 - 84);
+    assert_eq!(test.token_1.balance(&test.keeper), 84);
+
+    let order = test.contract.get_order(&order_id);
+    assert_eq!(order.slices_executed, 1);
+    assert_eq!(order.amount_in_remaining, 1_500_000);
+    assert_eq!(order.amount_out_total, 3_984_027 - 84);
+    assert_eq!(order.next_slice_at, 100 + 3600);
+
+    let slice_event = test.env.events().all().last().unwrap();
+    let expected_slice_event: SliceEvent = SliceEvent {
+        order_id,
+        keeper: test.keeper.clone(),
+        slice: 0,
+        amount_in: 1_000_000,
+        amount_out: 3_984_027 - 84,
+        keeper_bounty: 84,
+        amount_in_remaining: 1_500_000,
+    };
+    assert_eq!(
+        vec![&test.env, slice_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapDca", symbol_short!("slice")).into_val(&test.env),
+                (expected_slice_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The second slice is not due until an interval has passed
+    test.set_timestamp(100 + 3599);
+    let result = test.contract.try_execute_slice(&test.keeper, &order_id);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::SliceNotDue)));
+
+    test.set_timestamp(100 + 3600);
+    let amount_out = quote_sell_0(&test, 1_000_000);
+    let bounty = (amount_out - 3_900_000) * 10 / 10_000;
+    assert_eq!(test.contract.execute_slice(&test.keeper, &order_id), (amount_out - bounty, bounty));
+    assert!(amount_out - bounty >= 3_900_000);
+    assert_eq!(test.contract.get_order(&order_id).amount_in_remaining, 500_000);
+
+    // The last slice sells what is left and removes the order
+    test.set_timestamp(100 + 2 * 3600);
+    let last_amount_out = quote_sell_0(&test, 500_000);
+    let last_bounty = (last_amount_out - 1_950_000) * 10 / 10_000;
+    assert_eq!(test.contract.execute_slice(&test.keeper, &order_id), (last_amount_out - last_bounty, last_bounty));
+    assert!(last_amount_out - last_bounty >= 1_950_000);
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(SoroswapDcaError::OrderDoesNotExist)));
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+
+    let slice_event = test.env.events().all().last().unwrap();
+    let expected_slice_event: SliceEvent = SliceEvent {
+        order_id,
+        keeper: test.keeper.clone(),
+        slice: 2,
+        amount_in: 500_000,
+        amount_out: last_amount_out - last_bounty,
+        keeper_bounty: last_bounty,
+        amount_in_remaining: 0,
+    };
+    assert_eq!(
+        vec![&test.env, slice_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapDca", symbol_short!("slice")).into_val(&test.env),
+                (expected_slice_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn execute_missed_slices() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let order_id = test.create_sell_0();
+    test.contract.execute_slice(&test.keeper, &order_id);
+
+    // Two intervals later only one slice runs, the next one is due an interval after it
+    test.set_timestamp(100 + 2 * 3600 + 60);
+    test.contract.execute_slice(&test.keeper, &order_id);
+    assert_eq!(test.contract.get_order(&order_id).next_slice_at, 100 + 3 * 3600 + 60);
+    let result = test.contract.try_execute_slice(&test.keeper, &order_id);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::SliceNotDue)));
+
+    test.set_timestamp(100 + 3 * 3600 + 60);
+    test.contract.execute_slice(&test.keeper, &order_id);
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(SoroswapDcaError::OrderDoesNotExist)));
+}
+
+#[test]
+fn execute_slice_price_below_minimum() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let order_id = test.create_sell_0();
+
+    // Selling token_0 on the pair pushes its price below 3.9 token_1
+    test.swap(&test.token_0.address, &test.token_1.address, 50_000_000);
+    assert!(quote_sell_0(&test, 1_000_000) < 3_900_000);
+    let result = test.contract.try_execute_slice(&test.keeper, &order_id);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::PriceBelowMinimum)));
+
+    // The slice stays due and can run once the price recovers
+    let order = test.contract.get_order(&order_id);
+    assert_eq!(order.slices_executed, 0);
+    assert_eq!(order.next_slice_at, 100);
+
+    test.swap(&test.token_1.address, &test.token_0.address, 400_000_000);
+    assert!(quote_sell_0(&test, 1_000_000) >= 3_900_000);
+    test.contract.execute_slice(&test.keeper, &order_id);
+    assert_eq!(test.contract.get_order(&order_id).slices_executed, 1);
+}
+
+#[test]
+fn execute_slice_order_does_not_exist() {
+    let test = SoroswapDcaTest::setup_initialized();
+    let result = test.contract.try_execute_slice(&test.keeper, &0);
+    assert_eq!(result, Err(Ok(SoroswapDcaError::OrderDoesNotExist)));
+}
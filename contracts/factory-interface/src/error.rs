@@ -20,5 +20,17 @@ pub enum FactoryError {
 
     /// SoroswapFactory: index does not exist
     IndexDoesNotExist = 206,
+
+    /// SoroswapFactory: flash loan fee is out of bounds
+    FlashLoanFeeOutOfBounds = 207,
+
+    /// SoroswapFactory: the PMM pool Wasm hash has not been set
+    PmmPoolWasmHashNotSet = 208,
+    /// SoroswapFactory: PMM pool already exists between token_a and token_b
+    CreatePmmPoolAlreadyExists = 209,
+    /// SoroswapFactory: PMM pool does not exist
+    PmmPoolDoesNotExist = 210,
+    /// SoroswapFactory: k must be between 0 and 1e18, excluded, and the maximum oracle age must be positive
+    InvalidPmmPoolParameters = 211,
 }
 
@@ -25,6 +25,9 @@ pub trait SoroswapFactoryTrait {
     /// Checks if fees are enabled.
     fn fees_enabled(e: Env) -> Result<bool, FactoryError>;
 
+    /// Returns the fee, in basis points, that pairs charge on flash loans.
+    fn flash_loan_fee(e: Env) -> Result<u32, FactoryError>;
+
     /// Returns the total number of pairs created through the factory so far.
     fn all_pairs_length(e: Env) -> Result<u32, FactoryError>;
 
@@ -37,6 +40,12 @@ pub trait SoroswapFactoryTrait {
     /// Returns a boolean indicating if a pair exists for the given `token_a` and `token_b`.
     fn pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError>;
 
+    /// Returns the address of the PMM pool for `token_a` and `token_b`, if it has been created.
+    fn get_pmm_pool(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Returns a boolean indicating if a PMM pool exists for the given `token_a` and `token_b`.
+    fn pmm_pool_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError>;
+
     /*  *** State-Changing Functions: *** */
 
     /// Sets the `fee_to_setter` address and initializes the factory.
@@ -72,6 +81,49 @@ pub trait SoroswapFactoryTrait {
     /// * `is_enabled` - A boolean indicating whether fees are enabled or disabled.
     fn set_fees_enabled(e: Env, is_enabled: bool)-> Result<(), FactoryError>;
 
+    /// Sets the fee, in basis points, that pairs charge on flash loans.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `fee_bps` - The new flash loan fee in basis points.
+    fn set_flash_loan_fee(e: Env, fee_bps: u32)-> Result<(), FactoryError>;
+
+    /// Sets the price guard of the pair for `token_a` and `token_b`: the maximum price impact allowed
+    /// for a single swap and for all the swaps in the same ledger, in basis points. 0 disables a guard.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `max_swap_bps` - The maximum price impact of a single swap.
+    /// * `max_ledger_bps` - The maximum cumulative price impact of the swaps in a ledger.
+    fn set_price_guard(e: Env, token_a: Address, token_b: Address, max_swap_bps: u32, max_ledger_bps: u32) -> Result<(), FactoryError>;
+
+    /// Sets the liquidity cooldown of the pair for `token_a` and `token_b`: the number of ledgers
+    /// that deposited liquidity stays locked before it can be withdrawn. 0 disables the cooldown.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `ledgers` - The number of ledgers.
+    fn set_liquidity_cooldown(e: Env, token_a: Address, token_b: Address, ledgers: u32) -> Result<(), FactoryError>;
+
+    /// Sets the batch auction window of the pair for `token_a` and `token_b`: the number of ledgers
+    /// that a batch collects swap orders before they are settled at a uniform price. While it is
+    /// enabled, the pair only swaps through batch orders. 0 disables the batch auction.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `window` - The number of ledgers.
+    fn set_batch_auction(e: Env, token_a: Address, token_b: Address, window: u32) -> Result<(), FactoryError>;
+
     /// Creates a pair for `token_a` and `token_b` if one doesn't exist already.
     /// 
     /// # Arguments
@@ -80,4 +132,59 @@ pub trait SoroswapFactoryTrait {
     /// * `token_a` - The address of the first token in the pair.
     /// * `token_b` - The address of the second token in the pair.
     fn create_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Creates a pair for `token_a` and `token_b` with a hook contract attached to it.
+    /// Only the `fee_to_setter` can create pairs with hooks. As anyone can create the pair first with
+    /// `create_pair`, this fails with `CreatePairAlreadyExists` if it exists; use `set_pair_hook` then.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `hook` - The address of the hook contract, implementing `SoroswapHookTrait`.
+    fn create_pair_with_hook(e: Env, token_a: Address, token_b: Address, hook: Address) -> Result<Address, FactoryError>;
+
+    /// Attaches a hook contract to the existing pair for `token_a` and `token_b`, which must not have one.
+    /// Only the `fee_to_setter` can set hooks.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `hook` - The address of the hook contract, implementing `SoroswapHookTrait`.
+    fn set_pair_hook(e: Env, token_a: Address, token_b: Address, hook: Address) -> Result<(), FactoryError>;
+
+    /// Sets the Wasm hash of the SoroswapPmmPool contract, enabling `create_pmm_pool`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pmm_pool_wasm_hash` - The Wasm hash of the SoroswapPmmPool contract.
+    fn set_pmm_pool_wasm_hash(e: Env, pmm_pool_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Creates a proactive market maker pool for `token_a` and `token_b`, centred on the price of an oracle.
+    /// Only the `fee_to_setter` can create PMM pools, and there can be one per pair of tokens.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pool.
+    /// * `token_b` - The address of the second token in the pool.
+    /// * `oracle` - The address of the oracle, implementing `PmmOracleTrait` and pricing the lower token address in the other one.
+    /// * `k` - The slippage parameter, scaled by 1e18.
+    /// * `max_oracle_age` - The number of seconds after which an oracle price is too old to trade.
+    fn create_pmm_pool(e: Env, token_a: Address, token_b: Address, oracle: Address, k: i128, max_oracle_age: u64) -> Result<Address, FactoryError>;
+
+    /// Replaces the oracle of the PMM pool for `token_a` and `token_b`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pool.
+    /// * `token_b` - The address of the second token in the pool.
+    /// * `oracle` - The address of the new oracle.
+    /// * `max_oracle_age` - The number of seconds after which an oracle price is too old to trade.
+    fn set_pmm_pool_oracle(e: Env, token_a: Address, token_b: Address, oracle: Address, max_oracle_age: u64) -> Result<(), FactoryError>;
 }
@@ -1,5 +1,5 @@
 //! Definition of the Events used in the contract
-use soroban_sdk::{contracttype, symbol_short, Env, Address};
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN};
 
 // INITIALIZED
 #[contracttype]
@@ -44,6 +44,27 @@ pub(crate) fn new_pair(
 
 
 
+// NEW HOOK ATTACHED TO A PAIR: new_hook
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewHookEvent {
+    pub pair: Address,
+    pub hook: Address
+}
+
+pub(crate) fn new_hook(
+    e: &Env,
+    pair: Address,
+    hook: Address) {
+    
+    let event: NewHookEvent = NewHookEvent {
+        pair: pair,
+        hook: hook,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("new_hook")), event);
+}
+
+
 // NEW "FEE TO" SETTED: new_fee_to // Event is "fee_to"
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -106,3 +127,164 @@ pub(crate) fn new_fees_enabled(
     };
     e.events().publish(("SoroswapFactory", symbol_short!("fees")), event);
 }
+
+
+
+// NEW FLASH LOAN FEE
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewFlashLoanFeeEvent {
+    pub fee_bps: u32
+}
+
+pub(crate) fn new_flash_loan_fee(
+    e: &Env,
+    fee_bps: u32) {
+    
+    let event: NewFlashLoanFeeEvent = NewFlashLoanFeeEvent {
+        fee_bps: fee_bps,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("flash_fee")), event);
+}
+
+
+// NEW PRICE GUARD FOR A PAIR
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewPriceGuardEvent {
+    pub pair: Address,
+    pub max_swap_bps: u32,
+    pub max_ledger_bps: u32,
+}
+
+pub(crate) fn new_price_guard(
+    e: &Env,
+    pair: Address,
+    max_swap_bps: u32,
+    max_ledger_bps: u32) {
+    
+    let event: NewPriceGuardEvent = NewPriceGuardEvent {
+        pair: pair,
+        max_swap_bps: max_swap_bps,
+        max_ledger_bps: max_ledger_bps,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("guard")), event);
+}
+
+
+// NEW LIQUIDITY COOLDOWN FOR A PAIR
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewLiquidityCooldownEvent {
+    pub pair: Address,
+    pub ledgers: u32,
+}
+
+pub(crate) fn new_liquidity_cooldown(
+    e: &Env,
+    pair: Address,
+    ledgers: u32) {
+    
+    let event: NewLiquidityCooldownEvent = NewLiquidityCooldownEvent {
+        pair: pair,
+        ledgers: ledgers,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("cooldown")), event);
+}
+
+
+// NEW BATCH AUCTION WINDOW FOR A PAIR
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewBatchAuctionEvent {
+    pub pair: Address,
+    pub window: u32,
+}
+
+pub(crate) fn new_batch_auction(
+    e: &Env,
+    pair: Address,
+    window: u32) {
+    
+    let event: NewBatchAuctionEvent = NewBatchAuctionEvent {
+        pair: pair,
+        window: window,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("batch")), event);
+}
+
+
+
+// NEW PMM POOL WASM HASH
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewPmmPoolWasmHashEvent {
+    pub pmm_pool_wasm_hash: BytesN<32>,
+}
+
+pub(crate) fn new_pmm_pool_wasm_hash(
+    e: &Env,
+    pmm_pool_wasm_hash: BytesN<32>) {
+    
+    let event: NewPmmPoolWasmHashEvent = NewPmmPoolWasmHashEvent {
+        pmm_pool_wasm_hash: pmm_pool_wasm_hash,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("pmm_wasm")), event);
+}
+
+
+// NEW PMM POOL CREATED EVENT: new_pmm
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewPmmPoolEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pool: Address,
+    pub oracle: Address,
+    pub k: i128,
+    pub max_oracle_age: u64,
+}
+
+pub(crate) fn new_pmm_pool(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pool: Address,
+    oracle: Address,
+    k: i128,
+    max_oracle_age: u64) {
+    
+    let event: NewPmmPoolEvent = NewPmmPoolEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pool: pool,
+        oracle: oracle,
+        k: k,
+        max_oracle_age: max_oracle_age,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("new_pmm")), event);
+}
+
+
+// NEW ORACLE FOR A PMM POOL
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewPmmPoolOracleEvent {
+    pub pool: Address,
+    pub oracle: Address,
+    pub max_oracle_age: u64,
+}
+
+pub(crate) fn new_pmm_pool_oracle(
+    e: &Env,
+    pool: Address,
+    oracle: Address,
+    max_oracle_age: u64) {
+    
+    let event: NewPmmPoolOracleEvent = NewPmmPoolOracleEvent {
+        pool: pool,
+        oracle: oracle,
+        max_oracle_age: max_oracle_age,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("pmm_orcl")), event);
+}
@@ -2,6 +2,7 @@
 
 mod event;
 mod pair;
+mod pmm_pool;
 mod test;
 mod storage;
 
@@ -12,6 +13,7 @@ use soroban_sdk::{
 };
 use soroswap_factory_interface::{SoroswapFactoryTrait, FactoryError};
 use pair::{create_contract, Pair, PairError};
+use pmm_pool::{create_pmm_contract, PMM_K_SCALE};
 use storage::*;
 
 impl From<PairError> for FactoryError {
@@ -83,6 +85,23 @@ fn fees_enabled(e: Env) -> Result<bool, FactoryError> {
     Ok(get_fees_enabled(&e))
 }
 
+/// Returns the fee, in basis points, that pairs charge on flash loans.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn flash_loan_fee(e: Env) -> Result<u32, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    Ok(get_flash_loan_fee(&e))
+}
+
 /// Returns the total number of pairs created through the factory so far.
 /// 
 /// # Arguments
@@ -161,6 +180,46 @@ fn pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, Facto
     Ok(get_pair_exists(&e, token_pair))
 }
 
+/// Returns the address of the PMM pool for `token_a` and `token_b`, if it has been created.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pool.
+/// * `token_b` - The address of the second token in the pool.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the PMM pool does not exist.
+fn get_pmm_pool(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    let token_pair = Pair::new(token_a, token_b)?;
+    get_pmm_pool_address_by_token_pair(&e, token_pair)
+}
+
+/// Checks if a PMM pool exists for the given `token_a` and `token_b`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pool.
+/// * `token_b` - The address of the second token in the pool.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn pmm_pool_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    let token_pair = Pair::new(token_a, token_b)?;
+    Ok(get_pmm_pool_exists(&e, token_pair))
+}
+
 
 /* *** State-Changing Functions: *** */
 
@@ -261,6 +320,124 @@ fn set_fees_enabled(e: Env, is_enabled: bool) -> Result<(), FactoryError> {
     Ok(())
 }
 
+/// Sets the fee, in basis points, that pairs charge on flash loans.
+/// The fee is paid to the liquidity providers, and the protocol gets its share through `mint_fee`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `fee_bps` - The new flash loan fee in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, or if `fee_bps` is greater than `MAX_FLASH_LOAN_FEE_BPS`.
+fn set_flash_loan_fee(e: Env, fee_bps: u32) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if fee_bps > MAX_FLASH_LOAN_FEE_BPS {
+        return Err(FactoryError::FlashLoanFeeOutOfBounds);
+    }
+
+    put_flash_loan_fee(&e, &fee_bps);
+    event::new_flash_loan_fee(&e, fee_bps);
+    Ok(())
+}
+
+/// Sets the price guard of the pair for `token_a` and `token_b`, protecting its users against sandwich attacks.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `max_swap_bps` - The maximum price impact of a single swap, in basis points. 0 disables this guard.
+/// * `max_ledger_bps` - The maximum cumulative price impact of the swaps in a ledger, in basis points. 0 disables this guard.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, or if the pair does not exist.
+fn set_price_guard(e: Env, token_a: Address, token_b: Address, max_swap_bps: u32, max_ledger_bps: u32) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = get_pair_address_by_token_pair(&e, token_pair)?;
+    pair::Client::new(&e, &pair_address).set_price_guard(&max_swap_bps, &max_ledger_bps);
+
+    event::new_price_guard(&e, pair_address, max_swap_bps, max_ledger_bps);
+    Ok(())
+}
+
+/// Sets the liquidity cooldown of the pair for `token_a` and `token_b`, deterring just-in-time liquidity.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `ledgers` - The number of ledgers that deposited liquidity stays locked. 0 disables the cooldown.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, or if the pair does not exist.
+fn set_liquidity_cooldown(e: Env, token_a: Address, token_b: Address, ledgers: u32) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = get_pair_address_by_token_pair(&e, token_pair)?;
+    pair::Client::new(&e, &pair_address).set_liquidity_cooldown(&ledgers);
+
+    event::new_liquidity_cooldown(&e, pair_address, ledgers);
+    Ok(())
+}
+
+/// Sets the batch auction window of the pair for `token_a` and `token_b`. While it is enabled, swap orders are
+/// collected during the window and settled at a uniform price, so their order can't be exploited.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `window` - The number of ledgers that a batch collects orders. 0 disables the batch auction.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, or if the pair does not exist.
+fn set_batch_auction(e: Env, token_a: Address, token_b: Address, window: u32) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = get_pair_address_by_token_pair(&e, token_pair)?;
+    pair::Client::new(&e, &pair_address).set_batch_auction(&window);
+
+    event::new_batch_auction(&e, pair_address, window);
+    Ok(())
+}
+
 /// Creates a pair for `token_a` and `token_b` if one doesn't exist already.
 /// 
 /// # Arguments
@@ -301,5 +478,175 @@ fn create_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, Fa
     Ok(pair_address)
 }
 
+/// Creates a pair for `token_a` and `token_b` and attaches a hook contract to it.
+/// The hook will be called by the pair before and after every swap, deposit and withdraw.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `hook` - The address of the hook contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, or in any of the cases where `create_pair` fails.
+/// As `create_pair` is permissionless, the pair may have been created first: `set_pair_hook` attaches the hook then.
+fn create_pair_with_hook(e: Env, token_a: Address, token_b: Address, hook: Address) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let pair_address = Self::create_pair(e.clone(), token_a, token_b)?;
+    pair::Client::new(&e, &pair_address).set_hook(&hook);
+
+    event::new_hook(&e, pair_address.clone(), hook);
+
+    Ok(pair_address)
+}
+
+/// Attaches a hook contract to the existing pair for `token_a` and `token_b`.
+/// The hook will be called by the pair before and after every swap, deposit and withdraw.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `hook` - The address of the hook contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, or if the pair does not exist. Panics if the pair already has a hook.
+fn set_pair_hook(e: Env, token_a: Address, token_b: Address, hook: Address) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = get_pair_address_by_token_pair(&e, token_pair)?;
+    pair::Client::new(&e, &pair_address).set_hook(&hook);
+
+    event::new_hook(&e, pair_address, hook);
+    Ok(())
+}
+
+/// Sets the Wasm hash of the SoroswapPmmPool contract, enabling `create_pmm_pool`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `pmm_pool_wasm_hash` - The Wasm hash of the SoroswapPmmPool contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn set_pmm_pool_wasm_hash(e: Env, pmm_pool_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    put_pmm_pool_wasm_hash(&e, pmm_pool_wasm_hash.clone());
+    event::new_pmm_pool_wasm_hash(&e, pmm_pool_wasm_hash);
+    Ok(())
+}
+
+/// Creates a proactive market maker pool for `token_a` and `token_b`, centred on the price of an oracle.
+/// The pool quotes the oracle price while its reserves match their targets, and refuses swaps when the
+/// oracle price is stale.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pool.
+/// * `token_b` - The address of the second token in the pool.
+/// * `oracle` - The address of the oracle, pricing the lower token address in the other one.
+/// * `k` - The slippage parameter, scaled by 1e18. Must be lower than 1e18.
+/// * `max_oracle_age` - The number of seconds after which an oracle price is too old to trade.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, if the PMM pool Wasm hash has not been set, if the parameters are invalid, or if the PMM pool already exists.
+fn create_pmm_pool(e: Env, token_a: Address, token_b: Address, oracle: Address, k: i128, max_oracle_age: u64) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if k < 0 || k >= PMM_K_SCALE || max_oracle_age == 0 {
+        return Err(FactoryError::InvalidPmmPoolParameters);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    if get_pmm_pool_exists(&e, token_pair.clone()) {
+        return Err(FactoryError::CreatePmmPoolAlreadyExists);
+    }
+
+    let pmm_pool_wasm_hash = get_pmm_pool_wasm_hash(&e)?;
+    let pool_address = create_pmm_contract(&e, pmm_pool_wasm_hash, &token_pair);
+
+    pmm_pool::Client::new(&e, &pool_address).initialize(
+        &e.current_contract_address(),
+        token_pair.token_0(),
+        token_pair.token_1(),
+        &oracle,
+        &k,
+        &max_oracle_age,
+    );
+
+    put_pmm_pool_address_by_token_pair(&e, token_pair.clone(), &pool_address);
+
+    event::new_pmm_pool(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pool_address.clone(), oracle, k, max_oracle_age);
+
+    Ok(pool_address)
+}
+
+/// Replaces the oracle of the PMM pool for `token_a` and `token_b`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pool.
+/// * `token_b` - The address of the second token in the pool.
+/// * `oracle` - The address of the new oracle.
+/// * `max_oracle_age` - The number of seconds after which an oracle price is too old to trade.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, if `max_oracle_age` is 0, or if the PMM pool does not exist.
+fn set_pmm_pool_oracle(e: Env, token_a: Address, token_b: Address, oracle: Address, max_oracle_age: u64) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if max_oracle_age == 0 {
+        return Err(FactoryError::InvalidPmmPoolParameters);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pool_address = get_pmm_pool_address_by_token_pair(&e, token_pair)?;
+    pmm_pool::Client::new(&e, &pool_address).set_oracle(&oracle, &max_oracle_age);
+
+    event::new_pmm_pool_oracle(&e, pool_address, oracle, max_oracle_age);
+    Ok(())
+}
+
 
 }
@@ -0,0 +1,27 @@
+// Import necessary types from the Soroban SDK
+#![allow(unused)]
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+use crate::pair::Pair;
+
+soroban_sdk::contractimport!(
+    file = "../pmm-pool/target/wasm32-unknown-unknown/release/soroswap_pmm_pool.wasm"
+);
+
+/// Largest slippage parameter, excluded: `k` is scaled by 1e18 and must stay below 1.
+pub const PMM_K_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Salt of the PMM pool of `token_pair`: the salt of its pair followed by "PMM", so the pool and the
+/// pair of the same tokens get different addresses.
+pub fn pmm_pool_salt(e: &Env, token_pair: &Pair) -> BytesN<32> {
+    let mut salt = Bytes::new(e);
+    salt.append(&token_pair.token_0().clone().to_xdr(e));
+    salt.append(&token_pair.token_1().clone().to_xdr(e));
+    salt.append(&Bytes::from_slice(e, b"PMM"));
+    e.crypto().sha256(&salt)
+}
+
+pub fn create_pmm_contract(e: &Env, pmm_pool_wasm_hash: BytesN<32>, token_pair: &Pair) -> Address {
+    e.deployer()
+        .with_current_contract(pmm_pool_salt(e, token_pair))
+        .deploy(pmm_pool_wasm_hash)
+}
@@ -15,10 +15,18 @@ pub enum DataKey {
     FeesEnabled, // Bool. Instance storage
     TotalPairs, // Total pairs created by the Factory. u32, Instance storage
     PairAddressesNIndexed(u32), // Addresses of pairs created by the Factory. Persistent Storage
-    PairAddressesByTokens(Pair)
+    PairAddressesByTokens(Pair),
+    FlashLoanFee, // u32. Instance storage
+    PmmPoolWasmHash, // BytesN<32>. Persistent storage
+    PmmPoolAddressesByTokens(Pair), // Address of the PMM pool of a pair of tokens. Persistent storage
 }
 
 
+/// Flash loan fee, in basis points, until the `fee_to_setter` changes it (0.3%, same as the swap fee).
+pub const DEFAULT_FLASH_LOAN_FEE_BPS: u32 = 30;
+/// Maximum flash loan fee, in basis points, that can be set (1%).
+pub const MAX_FLASH_LOAN_FEE_BPS: u32 = 100;
+
 const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
@@ -106,6 +114,10 @@ pub fn get_fees_enabled(e: &Env) -> bool {
     }
 }
 
+pub fn get_flash_loan_fee(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::FlashLoanFee).unwrap_or(DEFAULT_FLASH_LOAN_FEE_BPS)
+}
+
 pub fn get_fee_to_setter(e: &Env) -> Address {
     e.storage().instance().get(&DataKey::FeeToSetter).unwrap()
 }
@@ -128,6 +140,10 @@ pub fn put_fees_enabled(e: &Env, is_enabled: &bool) {
     e.storage().instance().set(&DataKey::FeesEnabled, is_enabled);
 }
 
+pub fn put_flash_loan_fee(e: &Env, fee_bps: &u32) {
+    e.storage().instance().set(&DataKey::FlashLoanFee, fee_bps);
+}
+
 pub fn put_pair_wasm_hash(e: &Env, pair_wasm_hash: BytesN<32>) {
     let key = DataKey::PairWasmHash;
     e.storage().persistent().set(&key, &pair_wasm_hash);
@@ -136,6 +152,46 @@ pub fn put_pair_wasm_hash(e: &Env, pair_wasm_hash: BytesN<32>) {
             .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT)
 }
 
+pub fn get_pmm_pool_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError> {
+    let key = DataKey::PmmPoolWasmHash;
+    get_persistent_extend_or_error(&e, &key, FactoryError::PmmPoolWasmHashNotSet)
+}
+
+pub fn put_pmm_pool_wasm_hash(e: &Env, pmm_pool_wasm_hash: BytesN<32>) {
+    let key = DataKey::PmmPoolWasmHash;
+    e.storage().persistent().set(&key, &pmm_pool_wasm_hash);
+    e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT)
+}
+
+pub fn put_pmm_pool_address_by_token_pair(e: &Env, token_pair: Pair, pool_address: &Address) {
+    let key = DataKey::PmmPoolAddressesByTokens(token_pair);
+    e.storage()
+        .persistent()
+        .set(&key, &pool_address);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT)
+}
+
+pub fn get_pmm_pool_address_by_token_pair(e: &Env, token_pair: Pair) -> Result<Address, FactoryError> {
+    let key = DataKey::PmmPoolAddressesByTokens(token_pair);
+    get_persistent_extend_or_error(&e, &key, FactoryError::PmmPoolDoesNotExist)
+}
+
+pub fn get_pmm_pool_exists(e: &Env, token_pair: Pair) -> bool {
+    let key = DataKey::PmmPoolAddressesByTokens(token_pair);
+    if e.storage().persistent().has(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        true
+    } else {
+        false
+    }
+}
+
 pub fn add_pair_to_all_pairs(e: &Env, pair_address: &Address) {
     // total_pairs is the total amount of pairs created by the Factory
     let mut total_pairs = get_total_pairs(e);
@@ -30,6 +30,17 @@ fn pair_token_wasm(e: &Env) -> BytesN<32> {
     e.deployer().upload_contract_wasm(WASM)
 }
 
+//  **** PMM POOL WASM ****
+fn pmm_pool_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(pmm_pool::WASM)
+}
+
+mod pmm_pool {
+    soroban_sdk::contractimport!(file = "../pmm-pool/target/wasm32-unknown-unknown/release/soroswap_pmm_pool.wasm");
+    pub type SoroswapPmmPoolClient<'a> = Client<'a>;
+}
+use pmm_pool::SoroswapPmmPoolClient;
+
 // **** TOKEN CONTRACT ****
 mod pair {
     soroban_sdk::contractimport!(file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm");
@@ -55,6 +66,7 @@ pub struct SoroswapFactoryTest<'a> {
     token_2: TokenClient<'a>,
     token_3: TokenClient<'a>,
     pair_wasm: BytesN<32>,
+    pmm_pool_wasm: BytesN<32>,
     contract: SoroswapFactoryClient<'a>,
 }
 
@@ -84,6 +96,7 @@ impl<'a> SoroswapFactoryTest<'a> {
 
         
         let pair_wasm = pair_token_wasm(&env);  
+        let pmm_pool_wasm = pmm_pool_wasm(&env);
         let contract = create_factory_contract(&env);
 
         // TODO: Get rid of this hack?
@@ -99,6 +112,7 @@ impl<'a> SoroswapFactoryTest<'a> {
             token_2,
             token_3,
             pair_wasm,
+            pmm_pool_wasm,
             contract,
         }
     }
@@ -108,5 +122,6 @@ mod initialize;
 mod fee_to_setter;
 mod pairs;
 mod events;
+mod pmm_pools;
 
 pub mod deterministic;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use soroban_sdk::{testutils::{Events}, vec, IntoVal, symbol_short};
+use soroban_sdk::{testutils::{Events, Address as _}, vec, Address, IntoVal, symbol_short};
 use soroban_sdk::{xdr::{ToXdr}, Bytes}; // For determinisitic address
 use crate::test::{SoroswapFactoryTest};
 use crate::event::{
@@ -6,7 +6,15 @@ use crate::event::{
     NewPairEvent,
     FeeToSettedEvent,
     NewSetterEvent,
-    NewFeesEnabledEvent};
+    NewFeesEnabledEvent,
+    NewHookEvent,
+    NewFlashLoanFeeEvent,
+    NewPriceGuardEvent,
+    NewLiquidityCooldownEvent,
+    NewBatchAuctionEvent,
+    NewPmmPoolWasmHashEvent,
+    NewPmmPoolEvent,
+    NewPmmPoolOracleEvent};
 
 
 #[test]
@@ -403,4 +411,259 @@ fn fees_enabled_event() {
         ]
     );
 
-}
\ No newline at end of file
+}
+
+#[test]
+fn new_hook_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let hook = Address::generate(&test.env);
+    let pair_address = test.contract.create_pair_with_hook(&test.token_0.address, &test.token_1.address, &hook);
+
+    let new_hook_event = test.env.events().all().last().unwrap();
+
+    let expected_new_hook_event: NewHookEvent = NewHookEvent {
+        pair: pair_address.clone(),
+        hook: hook.clone(),
+    };
+
+    assert_eq!(
+        vec![&test.env, new_hook_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("new_hook")).into_val(&test.env),
+                (expected_new_hook_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    let false_new_hook_event: NewHookEvent = NewHookEvent {
+        pair: pair_address,
+        hook: test.user.clone(),
+    };
+
+    assert_ne!(
+        vec![&test.env, new_hook_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("new_hook")).into_val(&test.env),
+                (false_new_hook_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn new_flash_loan_fee_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_flash_loan_fee(&5);
+
+    let new_flash_loan_fee_event = test.env.events().all().last().unwrap();
+
+    let expected_new_flash_loan_fee_event: NewFlashLoanFeeEvent = NewFlashLoanFeeEvent {
+        fee_bps: 5
+    };
+
+    assert_eq!(
+        vec![&test.env, new_flash_loan_fee_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("flash_fee")).into_val(&test.env),
+                (expected_new_flash_loan_fee_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    let false_new_flash_loan_fee_event: NewFlashLoanFeeEvent = NewFlashLoanFeeEvent {
+        fee_bps: 30
+    };
+
+    assert_ne!(
+        vec![&test.env, new_flash_loan_fee_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("flash_fee")).into_val(&test.env),
+                (false_new_flash_loan_fee_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn new_price_guard_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.set_price_guard(&test.token_0.address, &test.token_1.address, &100, &300);
+
+    let new_price_guard_event = test.env.events().all().last().unwrap();
+
+    let expected_new_price_guard_event: NewPriceGuardEvent = NewPriceGuardEvent {
+        pair: pair_address,
+        max_swap_bps: 100,
+        max_ledger_bps: 300,
+    };
+
+    assert_eq!(
+        vec![&test.env, new_price_guard_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("guard")).into_val(&test.env),
+                (expected_new_price_guard_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn new_liquidity_cooldown_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.set_liquidity_cooldown(&test.token_0.address, &test.token_1.address, &10);
+
+    let new_liquidity_cooldown_event = test.env.events().all().last().unwrap();
+
+    let expected_new_liquidity_cooldown_event: NewLiquidityCooldownEvent = NewLiquidityCooldownEvent {
+        pair: pair_address,
+        ledgers: 10,
+    };
+
+    assert_eq!(
+        vec![&test.env, new_liquidity_cooldown_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("cooldown")).into_val(&test.env),
+                (expected_new_liquidity_cooldown_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn new_batch_auction_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.set_batch_auction(&test.token_0.address, &test.token_1.address, &5);
+
+    let new_batch_auction_event = test.env.events().all().last().unwrap();
+
+    let expected_new_batch_auction_event: NewBatchAuctionEvent = NewBatchAuctionEvent {
+        pair: pair_address,
+        window: 5,
+    };
+
+    assert_eq!(
+        vec![&test.env, new_batch_auction_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("batch")).into_val(&test.env),
+                (expected_new_batch_auction_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn new_pmm_pool_wasm_hash_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+
+    let new_pmm_pool_wasm_hash_event = test.env.events().all().last().unwrap();
+
+    let expected_new_pmm_pool_wasm_hash_event: NewPmmPoolWasmHashEvent = NewPmmPoolWasmHashEvent {
+        pmm_pool_wasm_hash: test.pmm_pool_wasm.clone(),
+    };
+
+    assert_eq!(
+        vec![&test.env, new_pmm_pool_wasm_hash_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("pmm_wasm")).into_val(&test.env),
+                (expected_new_pmm_pool_wasm_hash_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn new_pmm_pool_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+    let oracle = Address::generate(&test.env);
+    let pool_address = test.contract.create_pmm_pool(&test.token_1.address, &test.token_0.address, &oracle, &100_000_000_000_000_000, &600);
+
+    let new_pmm_pool_event = test.env.events().all().last().unwrap();
+
+    let expected_new_pmm_pool_event: NewPmmPoolEvent = NewPmmPoolEvent {
+        token_0: test.token_0.address.clone(),
+        token_1: test.token_1.address.clone(),
+        pool: pool_address,
+        oracle,
+        k: 100_000_000_000_000_000,
+        max_oracle_age: 600,
+    };
+
+    assert_eq!(
+        vec![&test.env, new_pmm_pool_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("new_pmm")).into_val(&test.env),
+                (expected_new_pmm_pool_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn new_pmm_pool_oracle_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+    let pool_address = test.contract.create_pmm_pool(&test.token_0.address, &test.token_1.address, &Address::generate(&test.env), &0, &600);
+    let new_oracle = Address::generate(&test.env);
+    test.contract.set_pmm_pool_oracle(&test.token_0.address, &test.token_1.address, &new_oracle, &300);
+
+    let new_pmm_pool_oracle_event = test.env.events().all().last().unwrap();
+
+    let expected_new_pmm_pool_oracle_event: NewPmmPoolOracleEvent = NewPmmPoolOracleEvent {
+        pool: pool_address,
+        oracle: new_oracle,
+        max_oracle_age: 300,
+    };
+
+    assert_eq!(
+        vec![&test.env, new_pmm_pool_oracle_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("pmm_orcl")).into_val(&test.env),
+                (expected_new_pmm_pool_oracle_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
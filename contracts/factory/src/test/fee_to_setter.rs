@@ -1,5 +1,6 @@
 extern crate std;
 use crate::test::{SoroswapFactoryTest};
+use soroswap_factory_interface::{FactoryError};
 use soroban_sdk::{
     IntoVal,
     testutils::{
@@ -201,4 +202,66 @@ fn changing_fees_enabled_with_mock_auth_not_allowed() {
     ])
     .set_fees_enabled(&false);
 
-}
\ No newline at end of file
+}
+#[test]
+fn changing_flash_loan_fee() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    // Default flash loan fee is 0.3%
+    assert_eq!(test.contract.flash_loan_fee(), 30);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.admin.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_flash_loan_fee",
+                    args: (5_u32,).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_flash_loan_fee(&5);
+    assert_eq!(test.contract.flash_loan_fee(), 5);
+
+    // Max flash loan fee is 1%
+    test.env.mock_all_auths();
+    test.contract.set_flash_loan_fee(&100);
+    assert_eq!(test.contract.flash_loan_fee(), 100);
+    let res = test.contract.try_set_flash_loan_fee(&101);
+    assert_eq!(res, Err(Ok(FactoryError::FlashLoanFeeOutOfBounds)));
+    assert_eq!(test.contract.flash_loan_fee(), 100);
+}
+
+#[test]
+fn flash_loan_fee_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    assert_eq!(test.contract.try_flash_loan_fee(), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_set_flash_loan_fee(&5), Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+#[should_panic]
+fn changing_flash_loan_fee_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_flash_loan_fee",
+                    args: (5_u32,).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_flash_loan_fee(&5);
+
+}
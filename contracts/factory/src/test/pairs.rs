@@ -1,5 +1,7 @@
 use crate::test::{SoroswapFactoryTest, SoroswapPairClient};
 use soroban_sdk::{xdr::{ToXdr},
+    testutils::Address as _,
+    Address,
     Bytes,
 };
 use soroswap_factory_interface::{FactoryError};
@@ -106,3 +108,167 @@ fn no_pair_index_does_not_exist() {
     assert_eq!(res, Err(Ok(FactoryError::IndexDoesNotExist)));
 
 }
+
+#[test]
+fn create_pair_with_hook() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let hook = Address::generate(&test.env);
+    let pair_address = test.contract.create_pair_with_hook(&test.token_0.address, &test.token_1.address, &hook);
+
+    assert_eq!(test.contract.all_pairs_length(), 1);
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.hook(), Some(hook));
+
+    // Pairs created the usual way do not have a hook
+    let pair_address_2 = test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address_2).hook(), None);
+}
+
+#[test]
+fn create_pair_with_hook_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let hook = Address::generate(&test.env);
+    let res = test.contract.try_create_pair_with_hook(&test.token_0.address, &test.token_1.address, &hook);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn create_pair_with_hook_already_exists() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    let hook = Address::generate(&test.env);
+    let res = test.contract.try_create_pair_with_hook(&test.token_1.address, &test.token_0.address, &hook);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn set_pair_hook() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let hook = Address::generate(&test.env);
+
+    let res = test.contract.try_set_pair_hook(&test.token_0.address, &test.token_1.address, &hook);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+
+    // Anyone can create the pair before the setter, who attaches the hook afterwards
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.set_pair_hook(&test.token_1.address, &test.token_0.address, &hook);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).hook(), Some(hook));
+}
+
+#[test]
+#[should_panic]
+fn set_pair_hook_already_set() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let hook = Address::generate(&test.env);
+    test.contract.create_pair_with_hook(&test.token_0.address, &test.token_1.address, &hook);
+
+    test.contract.set_pair_hook(&test.token_0.address, &test.token_1.address, &Address::generate(&test.env));
+}
+
+#[test]
+#[should_panic]
+fn set_pair_hook_not_setter() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    // Only the fee_to_setter can set hooks
+    test.env.mock_auths(&[]);
+    test.contract.set_pair_hook(&test.token_0.address, &test.token_1.address, &Address::generate(&test.env));
+}
+
+#[test]
+#[should_panic]
+fn create_pair_with_hook_not_setter() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let hook = Address::generate(&test.env);
+
+    // Only the fee_to_setter can create pairs with hooks
+    test.env.mock_auths(&[]);
+    test.contract.create_pair_with_hook(&test.token_0.address, &test.token_1.address, &hook);
+}
+
+#[test]
+fn set_price_guard() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_set_price_guard(&test.token_0.address, &test.token_1.address, &100, &300);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.set_price_guard(&test.token_1.address, &test.token_0.address, &100, &300);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).price_guard(), (100, 300));
+}
+
+#[test]
+#[should_panic]
+fn set_price_guard_not_setter() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    // Only the fee_to_setter can set the price guard
+    test.env.mock_auths(&[]);
+    test.contract.set_price_guard(&test.token_0.address, &test.token_1.address, &100, &300);
+}
+
+#[test]
+fn set_liquidity_cooldown() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_set_liquidity_cooldown(&test.token_0.address, &test.token_1.address, &10);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.set_liquidity_cooldown(&test.token_1.address, &test.token_0.address, &10);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).liquidity_cooldown(), 10);
+}
+
+#[test]
+#[should_panic]
+fn set_liquidity_cooldown_not_setter() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    // Only the fee_to_setter can set the liquidity cooldown
+    test.env.mock_auths(&[]);
+    test.contract.set_liquidity_cooldown(&test.token_0.address, &test.token_1.address, &10);
+}
+
+#[test]
+fn set_batch_auction() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_set_batch_auction(&test.token_0.address, &test.token_1.address, &5);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.set_batch_auction(&test.token_1.address, &test.token_0.address, &5);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).batch_auction(), 5);
+}
+
+#[test]
+#[should_panic]
+fn set_batch_auction_not_setter() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    // Only the fee_to_setter can set the batch auction
+    test.env.mock_auths(&[]);
+    test.contract.set_batch_auction(&test.token_0.address, &test.token_1.address, &5);
+}
@@ -0,0 +1,159 @@
+use crate::test::{SoroswapFactoryTest, SoroswapPmmPoolClient};
+use soroban_sdk::{xdr::{ToXdr},
+    testutils::Address as _,
+    Address,
+    Bytes,
+};
+use soroswap_factory_interface::{FactoryError};
+
+const K: i128 = 100_000_000_000_000_000;
+const MAX_ORACLE_AGE: u64 = 600;
+
+#[test]
+fn create_pmm_pool_wasm_hash_not_set() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let oracle = Address::generate(&test.env);
+
+    let res = test.contract.try_create_pmm_pool(&test.token_0.address, &test.token_1.address, &oracle, &K, &MAX_ORACLE_AGE);
+    assert_eq!(res, Err(Ok(FactoryError::PmmPoolWasmHashNotSet)));
+}
+
+#[test]
+fn create_pmm_pool() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+    let oracle = Address::generate(&test.env);
+
+    assert_eq!(test.contract.pmm_pool_exists(&test.token_0.address, &test.token_1.address), false);
+
+    let pool_address = test.contract.create_pmm_pool(&test.token_1.address, &test.token_0.address, &oracle, &K, &MAX_ORACLE_AGE);
+
+    assert_eq!(test.contract.pmm_pool_exists(&test.token_0.address, &test.token_1.address), true);
+    assert_eq!(test.contract.pmm_pool_exists(&test.token_1.address, &test.token_0.address), true);
+    assert_eq!(test.contract.get_pmm_pool(&test.token_0.address, &test.token_1.address), pool_address);
+    assert_eq!(test.contract.get_pmm_pool(&test.token_1.address, &test.token_0.address), pool_address);
+
+    // PMM pools are not pairs, and do not count in all_pairs
+    assert_eq!(test.contract.pair_exists(&test.token_0.address, &test.token_1.address), false);
+    assert_eq!(test.contract.all_pairs_length(), 0);
+
+    // Calculating pool address:
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&Bytes::from_slice(&test.env, b"PMM"));
+    let bytes_n_32_salt = test.env.crypto().sha256(&salt);
+    let deterministic_pool_address = test.env.deployer().with_address(test.contract.address.clone(), bytes_n_32_salt).deployed_address();
+    assert_eq!(pool_address, deterministic_pool_address);
+
+    let pool = SoroswapPmmPoolClient::new(&test.env, &pool_address);
+    assert_eq!(pool.factory(), test.contract.address);
+    assert_eq!(pool.token_0(), test.token_0.address);
+    assert_eq!(pool.token_1(), test.token_1.address);
+    assert_eq!(pool.oracle(), oracle);
+    assert_eq!(pool.k(), K);
+    assert_eq!(pool.max_oracle_age(), MAX_ORACLE_AGE);
+}
+
+#[test]
+fn create_pmm_pool_next_to_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pool_address = test.contract.create_pmm_pool(&test.token_0.address, &test.token_1.address, &Address::generate(&test.env), &K, &MAX_ORACLE_AGE);
+
+    assert_ne!(pair_address, pool_address);
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+}
+
+#[test]
+fn double_pmm_pool_creation() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+    let oracle = Address::generate(&test.env);
+
+    test.contract.create_pmm_pool(&test.token_0.address, &test.token_1.address, &oracle, &K, &MAX_ORACLE_AGE);
+    let res = test.contract.try_create_pmm_pool(&test.token_1.address, &test.token_0.address, &oracle, &K, &MAX_ORACLE_AGE);
+
+    assert_eq!(res, Err(Ok(FactoryError::CreatePmmPoolAlreadyExists)));
+}
+
+#[test]
+fn create_pmm_pool_identical_tokens() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+
+    let res = test.contract.try_create_pmm_pool(&test.token_0.address, &test.token_0.address, &Address::generate(&test.env), &K, &MAX_ORACLE_AGE);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+}
+
+#[test]
+fn create_pmm_pool_invalid_parameters() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+    let oracle = Address::generate(&test.env);
+
+    let res = test.contract.try_create_pmm_pool(&test.token_0.address, &test.token_1.address, &oracle, &-1, &MAX_ORACLE_AGE);
+    assert_eq!(res, Err(Ok(FactoryError::InvalidPmmPoolParameters)));
+
+    let res = test.contract.try_create_pmm_pool(&test.token_0.address, &test.token_1.address, &oracle, &1_000_000_000_000_000_000, &MAX_ORACLE_AGE);
+    assert_eq!(res, Err(Ok(FactoryError::InvalidPmmPoolParameters)));
+
+    let res = test.contract.try_create_pmm_pool(&test.token_0.address, &test.token_1.address, &oracle, &K, &0);
+    assert_eq!(res, Err(Ok(FactoryError::InvalidPmmPoolParameters)));
+}
+
+#[test]
+fn get_pmm_pool_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_get_pmm_pool(&test.token_0.address, &test.token_1.address);
+    assert_eq!(res, Err(Ok(FactoryError::PmmPoolDoesNotExist)));
+}
+
+#[test]
+fn pmm_pool_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+
+    let res = test.contract.try_pmm_pool_exists(&test.token_0.address, &test.token_1.address);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn set_pmm_pool_oracle() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+    let pool_address = test.contract.create_pmm_pool(&test.token_0.address, &test.token_1.address, &Address::generate(&test.env), &K, &MAX_ORACLE_AGE);
+
+    let new_oracle = Address::generate(&test.env);
+    test.contract.set_pmm_pool_oracle(&test.token_1.address, &test.token_0.address, &new_oracle, &300);
+
+    let pool = SoroswapPmmPoolClient::new(&test.env, &pool_address);
+    assert_eq!(pool.oracle(), new_oracle);
+    assert_eq!(pool.max_oracle_age(), 300);
+
+    let res = test.contract.try_set_pmm_pool_oracle(&test.token_0.address, &test.token_1.address, &new_oracle, &0);
+    assert_eq!(res, Err(Ok(FactoryError::InvalidPmmPoolParameters)));
+}
+
+#[test]
+fn set_pmm_pool_oracle_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pmm_pool_wasm_hash(&test.pmm_pool_wasm);
+
+    let res = test.contract.try_set_pmm_pool_oracle(&test.token_0.address, &test.token_1.address, &Address::generate(&test.env), &MAX_ORACLE_AGE);
+    assert_eq!(res, Err(Ok(FactoryError::PmmPoolDoesNotExist)));
+}
@@ -0,0 +1,29 @@
+#![deny(warnings)]
+#![no_std]
+
+use soroban_sdk::{contractclient, contractspecfn, Address, Bytes, Env};
+pub struct Spec;
+
+/// Interface for SoroswapFlashLoanReceiver
+#[contractspecfn(name = "Spec", export = false)]
+#[contractclient(name = "SoroswapFlashLoanReceiverClient")]
+
+/// Trait defining the interface that a contract borrowing from a Soroswap Pair through `flash_loan` must implement.
+/// The `pair` argument is always the address of the calling pair, so receivers can use
+/// `pair.require_auth()` to make sure they are being called by the pair itself.
+pub trait SoroswapFlashLoanReceiverTrait {
+
+    /// Called by the pair right after sending `amount` of `token` to the receiver.
+    /// Before returning, the receiver must transfer `amount + fee` of `token` back to the pair,
+    /// otherwise the whole flash loan is reverted.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the lending pair.
+    /// * `token` - The address of the borrowed token.
+    /// * `amount` - The amount of `token` that was lent.
+    /// * `fee` - The fee, in `token`, that must be paid on top of `amount`.
+    /// * `data` - Arbitrary data forwarded from the `flash_loan` call.
+    fn on_flash_loan(e: Env, pair: Address, token: Address, amount: i128, fee: i128, data: Bytes);
+}
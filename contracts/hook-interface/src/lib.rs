@@ -0,0 +1,100 @@
+#![deny(warnings)]
+#![no_std]
+
+use soroban_sdk::{contractclient, contractspecfn, contracttype, Address, Env};
+pub struct Spec;
+
+/// Swap fee, in basis points, charged by a pair when its hook does not adjust it (0.3%).
+pub const DEFAULT_FEE_BPS: u32 = 30;
+
+/// Maximum swap fee, in basis points, that a hook is allowed to set. Hooks can only give rebates: the
+/// library and the router quote every pair with the default fee, so a higher fee would make their swaps
+/// fail the K check.
+pub const MAX_FEE_BPS: u32 = DEFAULT_FEE_BPS;
+
+/// Answer of a hook to a `before_swap` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeforeSwapResponse {
+    /// If `false`, the pair rejects the swap.
+    pub allow: bool,
+    /// Fee in basis points to charge on the amounts in. Must be lower or equal than `MAX_FEE_BPS`.
+    pub fee_bps: u32,
+}
+
+/// Interface for SoroswapHook
+#[contractspecfn(name = "Spec", export = false)]
+#[contractclient(name = "SoroswapHookClient")]
+
+/// Trait defining the interface that a hook contract attached to a Soroswap Pair must implement.
+/// The `pair` argument is always the address of the calling pair, so hooks can use
+/// `pair.require_auth()` to make sure they are being called by the pair itself.
+pub trait SoroswapHookTrait {
+
+    /// Called by the pair before executing a swap.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the calling pair.
+    /// * `amount_0_out` - The amount of token 0 that will be sent out.
+    /// * `amount_1_out` - The amount of token 1 that will be sent out.
+    /// * `to` - The address that will receive the output tokens.
+    fn before_swap(e: Env, pair: Address, amount_0_out: i128, amount_1_out: i128, to: Address) -> BeforeSwapResponse;
+
+    /// Called by the pair after a swap has been executed and the reserves have been updated.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the calling pair.
+    /// * `amount_0_in` - The amount of token 0 that was sent in.
+    /// * `amount_1_in` - The amount of token 1 that was sent in.
+    /// * `amount_0_out` - The amount of token 0 that was sent out.
+    /// * `amount_1_out` - The amount of token 1 that was sent out.
+    /// * `to` - The address that received the output tokens.
+    fn after_swap(e: Env, pair: Address, amount_0_in: i128, amount_1_in: i128, amount_0_out: i128, amount_1_out: i128, to: Address);
+
+    /// Called by the pair before minting LP tokens. Returns `false` to reject the deposit.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the calling pair.
+    /// * `to` - The address that will receive the LP tokens.
+    fn before_deposit(e: Env, pair: Address, to: Address) -> bool;
+
+    /// Called by the pair after a deposit has been executed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the calling pair.
+    /// * `to` - The address that received the LP tokens.
+    /// * `amount_0` - The amount of token 0 deposited.
+    /// * `amount_1` - The amount of token 1 deposited.
+    /// * `liquidity` - The amount of LP tokens minted.
+    fn after_deposit(e: Env, pair: Address, to: Address, amount_0: i128, amount_1: i128, liquidity: i128);
+
+    /// Called by the pair before burning LP tokens. Returns `false` to reject the withdraw.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the calling pair.
+    /// * `to` - The address that will receive the withdrawn tokens.
+    /// * `liquidity` - The amount of LP tokens that will be burned.
+    fn before_withdraw(e: Env, pair: Address, to: Address, liquidity: i128) -> bool;
+
+    /// Called by the pair after a withdraw has been executed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the calling pair.
+    /// * `to` - The address that received the withdrawn tokens.
+    /// * `liquidity` - The amount of LP tokens burned.
+    /// * `amount_0` - The amount of token 0 withdrawn.
+    /// * `amount_1` - The amount of token 1 withdrawn.
+    fn after_withdraw(e: Env, pair: Address, to: Address, liquidity: i128, amount_0: i128, amount_1: i128);
+}
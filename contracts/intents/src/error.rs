@@ -0,0 +1,28 @@
+use soroban_sdk::{self, contracterror};
+
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapIntentsError {
+    /// SoroswapIntents: not yet initialized
+    NotInitialized = 701,
+
+    /// SoroswapIntents: already initialized
+    InitializeAlreadyInitialized = 702,
+
+    /// SoroswapIntents: negative amount is not allowed
+    NegativeNotAllowed = 703,
+
+    /// SoroswapIntents: deadline expired
+    DeadlineExpired = 704,
+
+    /// SoroswapIntents: the owner of the intent has not registered a signer
+    SignerNotSet = 705,
+
+    /// SoroswapIntents: the nonce has already been used or cancelled
+    NonceAlreadyUsed = 706,
+
+    /// SoroswapIntents: the token in and the token out must be different
+    InvalidTokens = 707,
+}
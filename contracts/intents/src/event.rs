@@ -0,0 +1,94 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN};
+
+// INITIALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub router: Address
+}
+
+/// Publishes an `InitializedEvent` when the contract is initialized.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `router` - The Soroswap router the intents are executed through.
+pub(crate) fn initialized(e: &Env, router: Address) {
+    let event = InitializedEvent {
+        router,
+    };
+    e.events().publish(("SoroswapIntents", symbol_short!("init")), event);
+}
+
+// SIGNER EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerEvent {
+    pub owner: Address,
+    pub signer: BytesN<32>
+}
+
+/// Publishes a `SignerEvent` when an owner registers the key that signs its intents.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `owner` - The account whose intents are signed by `signer`.
+/// * `signer` - The ed25519 public key.
+pub(crate) fn signer(e: &Env, owner: Address, signer: BytesN<32>) {
+    let event = SignerEvent {
+        owner,
+        signer,
+    };
+    e.events().publish(("SoroswapIntents", symbol_short!("signer")), event);
+}
+
+// SETTLE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettleEvent {
+    pub owner: Address,
+    pub nonce: u64,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub relayer_fee: i128,
+    pub recipient: Address,
+    pub relayer: Address
+}
+
+/// Publishes a `SettleEvent` when a relayer settles an intent.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The settled intent: the amounts swapped, the amount sent to the recipient and the
+///   fee paid to the relayer.
+pub(crate) fn settle(e: &Env, event: SettleEvent) {
+    e.events().publish(("SoroswapIntents", symbol_short!("settle")), event);
+}
+
+// CANCEL EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelEvent {
+    pub owner: Address,
+    pub nonce: u64
+}
+
+/// Publishes a `CancelEvent` when an owner cancels a nonce.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `owner` - The owner of the cancelled intent.
+/// * `nonce` - The nonce that can no longer be settled.
+pub(crate) fn cancel(e: &Env, owner: Address, nonce: u64) {
+    let event = CancelEvent {
+        owner,
+        nonce,
+    };
+    e.events().publish(("SoroswapIntents", symbol_short!("cancel")), event);
+}
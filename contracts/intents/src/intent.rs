@@ -0,0 +1,36 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env};
+
+/// A swap signed off-chain by the signer registered for `owner`, that any relayer can settle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapIntent {
+    /// The account that pays `amount_in`. It must have approved this contract on `token_in`.
+    pub owner: Address,
+    /// The token sold.
+    pub token_in: Address,
+    /// The token bought, the relayer fee is paid in it.
+    pub token_out: Address,
+    /// The exact amount of `token_in` sold.
+    pub amount_in: i128,
+    /// The minimum amount of `token_out` sent to `recipient`, after the relayer fee.
+    pub amount_out_min: i128,
+    /// The amount of `token_out` paid to the relayer that settles the intent.
+    pub relayer_fee: i128,
+    /// The address that receives the bought tokens.
+    pub recipient: Address,
+    /// The intent can not be settled at or after this timestamp.
+    pub deadline: u64,
+    /// A number the owner has not used before, so the intent can only be settled once.
+    pub nonce: u64,
+}
+
+/// Returns the payload the signer of `intent` signs: the sha256 hash of this contract address
+/// followed by the intent, both XDR encoded. Binding the contract address keeps a signature from
+/// being replayed on another deployment.
+pub fn intent_hash(e: &Env, intent: &SwapIntent) -> BytesN<32> {
+    let mut payload = Bytes::new(e);
+    payload.append(&e.current_contract_address().to_xdr(e));
+    payload.append(&intent.clone().to_xdr(e));
+    e.crypto().sha256(&payload).into()
+}
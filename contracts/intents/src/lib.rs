@@ -0,0 +1,274 @@
+#![no_std]
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contractimpl, vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
+
+mod router;
+mod test;
+mod event;
+mod storage;
+mod error;
+mod intent;
+
+use router::SoroswapRouterClient;
+use storage::{
+    put_router,
+    has_router,
+    get_router,
+    extend_instance_ttl,
+    put_signer,
+    get_signer,
+    set_nonce_used,
+    is_nonce_used,
+};
+pub use error::SoroswapIntentsError;
+pub use intent::SwapIntent;
+
+fn check_nonnegative_amount(amount: i128) -> Result<(), SoroswapIntentsError> {
+    if amount < 0 {
+        Err(SoroswapIntentsError::NegativeNotAllowed)
+    } else {
+        Ok(())
+    }
+}
+
+fn ensure_deadline(e: &Env, timestamp: u64) -> Result<(), SoroswapIntentsError> {
+    let ledger_timestamp = e.ledger().timestamp();
+    if ledger_timestamp >= timestamp {
+        Err(SoroswapIntentsError::DeadlineExpired)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_initialized(e: &Env) -> Result<(), SoroswapIntentsError> {
+    if has_router(e) {
+        Ok(())
+    } else {
+        Err(SoroswapIntentsError::NotInitialized)
+    }
+}
+
+pub trait SoroswapIntentsTrait {
+
+    /// Initializes the contract with the Soroswap router the intents are executed through.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `router` - The address of the Soroswap router.
+    fn initialize(e: Env, router: Address) -> Result<(), SoroswapIntentsError>;
+
+    /// Registers the ed25519 public key that signs the intents of `owner`, replacing any previous one.
+    /// Intents signed by a replaced key can no longer be settled.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The account the intents spend from. Must authorize the call.
+    /// * `signer` - The ed25519 public key.
+    fn set_signer(e: Env, owner: Address, signer: BytesN<32>) -> Result<(), SoroswapIntentsError>;
+
+    /// Settles an intent signed off-chain. The signature is verified against the signer of the owner,
+    /// `amount_in` is pulled from the owner through its allowance and swapped on the direct pair of
+    /// the router. `relayer_fee` of the output goes to `relayer` and the rest to the recipient.
+    /// The owner does not need to authorize the transaction. An invalid signature aborts it.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `intent` - The signed intent.
+    /// * `signature` - The ed25519 signature of `get_intent_hash(intent)`.
+    /// * `relayer` - The address that submits the intent and receives the fee. Must authorize the call.
+    ///
+    /// # Returns
+    /// The amount of `token_out` sent to the recipient.
+    fn settle(e: Env, intent: SwapIntent, signature: BytesN<64>, relayer: Address) -> Result<i128, SoroswapIntentsError>;
+
+    /// Cancels the intent of `owner` with `nonce`, so it can no longer be settled.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The owner of the intent. Must authorize the call.
+    /// * `nonce` - The nonce of the intent.
+    fn cancel(e: Env, owner: Address, nonce: u64) -> Result<(), SoroswapIntentsError>;
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the router the intents are executed through.
+    fn get_router(e: Env) -> Result<Address, SoroswapIntentsError>;
+
+    /// Returns the ed25519 public key registered for `owner`, if any.
+    fn get_signer(e: Env, owner: Address) -> Option<BytesN<32>>;
+
+    /// Returns whether the nonce of `owner` has been settled or cancelled.
+    fn is_nonce_used(e: Env, owner: Address, nonce: u64) -> bool;
+
+    /// Returns the payload the signer of `intent` has to sign.
+    fn get_intent_hash(e: Env, intent: SwapIntent) -> BytesN<32>;
+}
+
+#[contract]
+struct SoroswapIntents;
+
+#[contractimpl]
+impl SoroswapIntentsTrait for SoroswapIntents {
+
+    /// Initializes the contract with the Soroswap router the intents are executed through.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `router` - The address of the Soroswap router.
+    fn initialize(e: Env, router: Address) -> Result<(), SoroswapIntentsError> {
+        if !has_router(&e) {
+            put_router(&e, &router);
+            event::initialized(&e, router);
+            extend_instance_ttl(&e);
+            Ok(())
+        } else {
+            Err(SoroswapIntentsError::InitializeAlreadyInitialized)
+        }
+    }
+
+    /// Registers the ed25519 public key that signs the intents of `owner`, replacing any previous one.
+    /// Intents signed by a replaced key can no longer be settled.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The account the intents spend from. Must authorize the call.
+    /// * `signer` - The ed25519 public key.
+    fn set_signer(e: Env, owner: Address, signer: BytesN<32>) -> Result<(), SoroswapIntentsError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        owner.require_auth();
+
+        put_signer(&e, &owner, &signer);
+        event::signer(&e, owner, signer);
+        Ok(())
+    }
+
+    /// Settles an intent signed off-chain. The signature is verified against the signer of the owner,
+    /// `amount_in` is pulled from the owner through its allowance and swapped on the direct pair of
+    /// the router. `relayer_fee` of the output goes to `relayer` and the rest to the recipient.
+    /// The owner does not need to authorize the transaction. An invalid signature aborts it.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `intent` - The signed intent.
+    /// * `signature` - The ed25519 signature of `get_intent_hash(intent)`.
+    /// * `relayer` - The address that submits the intent and receives the fee. Must authorize the call.
+    ///
+    /// # Returns
+    /// The amount of `token_out` sent to the recipient.
+    fn settle(e: Env, intent: SwapIntent, signature: BytesN<64>, relayer: Address) -> Result<i128, SoroswapIntentsError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(intent.amount_in)?;
+        check_nonnegative_amount(intent.amount_out_min)?;
+        check_nonnegative_amount(intent.relayer_fee)?;
+        if intent.token_in == intent.token_out {
+            return Err(SoroswapIntentsError::InvalidTokens);
+        }
+        extend_instance_ttl(&e);
+        relayer.require_auth();
+        ensure_deadline(&e, intent.deadline)?;
+
+        let signer = get_signer(&e, &intent.owner).ok_or(SoroswapIntentsError::SignerNotSet)?;
+        if is_nonce_used(&e, &intent.owner, intent.nonce) {
+            return Err(SoroswapIntentsError::NonceAlreadyUsed);
+        }
+        e.crypto().ed25519_verify(&signer, &intent::intent_hash(&e, &intent).into(), &signature);
+        set_nonce_used(&e, &intent.owner, intent.nonce);
+
+        // Pull the input from the owner, who approved this contract beforehand
+        let settlement = e.current_contract_address();
+        TokenClient::new(&e, &intent.token_in).transfer_from(&settlement, &intent.owner, &settlement, &intent.amount_in);
+
+        // The router pays the pair with the tokens of this contract, which has to authorize that transfer
+        let router = SoroswapRouterClient::new(&e, &get_router(&e));
+        let pair = router.router_pair_for(&intent.token_in, &intent.token_out);
+        e.authorize_as_current_contract(vec![
+            &e,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: intent.token_in.clone(),
+                    fn_name: Symbol::new(&e, "transfer"),
+                    args: (settlement.clone(), pair, intent.amount_in).into_val(&e),
+                },
+                sub_invocations: Vec::new(&e),
+            }),
+        ]);
+
+        let amount_out_min = intent.amount_out_min.checked_add(intent.relayer_fee).unwrap();
+        let amounts = router.swap_exact_tokens_from(
+            &settlement,
+            &intent.amount_in,
+            &amount_out_min,
+            &vec![&e, intent.token_in.clone(), intent.token_out.clone()],
+            &settlement,
+            &intent.deadline,
+        );
+        let amount_out = amounts.get(amounts.len() - 1).unwrap();
+
+        // Pay the relayer and send the rest to the recipient
+        let token_out = TokenClient::new(&e, &intent.token_out);
+        if intent.relayer_fee > 0 {
+            token_out.transfer(&settlement, &relayer, &intent.relayer_fee);
+        }
+        let recipient_amount = amount_out.checked_sub(intent.relayer_fee).unwrap();
+        token_out.transfer(&settlement, &intent.recipient, &recipient_amount);
+
+        event::settle(&e, event::SettleEvent {
+            owner: intent.owner,
+            nonce: intent.nonce,
+            token_in: intent.token_in,
+            token_out: intent.token_out,
+            amount_in: intent.amount_in,
+            amount_out: recipient_amount,
+            relayer_fee: intent.relayer_fee,
+            recipient: intent.recipient,
+            relayer,
+        });
+
+        Ok(recipient_amount)
+    }
+
+    /// Cancels the intent of `owner` with `nonce`, so it can no longer be settled.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The owner of the intent. Must authorize the call.
+    /// * `nonce` - The nonce of the intent.
+    fn cancel(e: Env, owner: Address, nonce: u64) -> Result<(), SoroswapIntentsError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        owner.require_auth();
+
+        if is_nonce_used(&e, &owner, nonce) {
+            return Err(SoroswapIntentsError::NonceAlreadyUsed);
+        }
+        set_nonce_used(&e, &owner, nonce);
+        event::cancel(&e, owner, nonce);
+        Ok(())
+    }
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the router the intents are executed through.
+    fn get_router(e: Env) -> Result<Address, SoroswapIntentsError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_router(&e))
+    }
+
+    /// Returns the ed25519 public key registered for `owner`, if any.
+    fn get_signer(e: Env, owner: Address) -> Option<BytesN<32>> {
+        get_signer(&e, &owner)
+    }
+
+    /// Returns whether the nonce of `owner` has been settled or cancelled.
+    fn is_nonce_used(e: Env, owner: Address, nonce: u64) -> bool {
+        is_nonce_used(&e, &owner, nonce)
+    }
+
+    /// Returns the payload the signer of `intent` has to sign.
+    fn get_intent_hash(e: Env, intent: SwapIntent) -> BytesN<32> {
+        intent::intent_hash(&e, &intent)
+    }
+}
@@ -0,0 +1,65 @@
+use soroban_sdk::{contracttype, Env, Address, BytesN};
+
+#[derive(Clone)]
+#[contracttype]
+
+enum DataKey {
+    Router, // Address of the Soroswap Router the intents are executed through. Instance Data Type
+    Signer(Address), // ed25519 public key that signs the intents of an owner. Persistent Data Type
+    Nonce(Address, u64), // Whether a nonce of an owner has been used or cancelled. Persistent Data Type
+}
+
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+pub fn put_router(e: &Env, router: &Address) {
+    e.storage().instance().set(&DataKey::Router, router);
+}
+
+pub fn has_router(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Router)
+}
+
+pub fn get_router(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Router).unwrap()
+}
+
+pub fn put_signer(e: &Env, owner: &Address, signer: &BytesN<32>) {
+    let key = DataKey::Signer(owner.clone());
+    e.storage().persistent().set(&key, signer);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_signer(e: &Env, owner: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::Signer(owner.clone());
+    let signer = e.storage().persistent().get::<DataKey, BytesN<32>>(&key);
+    if signer.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    signer
+}
+
+pub fn set_nonce_used(e: &Env, owner: &Address, nonce: u64) {
+    let key = DataKey::Nonce(owner.clone(), nonce);
+    e.storage().persistent().set(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn is_nonce_used(e: &Env, owner: &Address, nonce: u64) -> bool {
+    e.storage().persistent().has(&DataKey::Nonce(owner.clone(), nonce))
+}
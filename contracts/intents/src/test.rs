@@ -0,0 +1,173 @@
+#![cfg(test)]
+extern crate std;
+use crate::{SoroswapIntents, SoroswapIntentsClient, SwapIntent};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    Env, 
+    BytesN, 
+    Address, 
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(&e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+fn pair_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// SoroswapFactory Contract
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryClient<'a> {
+    let pair_hash = pair_contract_wasm(&e);  
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address); 
+    factory.initialize(&setter, &pair_hash);
+    factory
+}
+
+// SoroswapRouter Contract
+mod router {
+    soroban_sdk::contractimport!(file = "../router/target/wasm32-unknown-unknown/release/soroswap_router.wasm");
+    pub type SoroswapRouterClient<'a> = Client<'a>;
+}
+use router::SoroswapRouterClient;
+
+fn create_soroswap_router<'a>(e: & Env, factory: & Address) -> SoroswapRouterClient<'a> {
+    let router = SoroswapRouterClient::new(e, &e.register_contract_wasm(None, router::WASM));
+    router.initialize(factory);
+    router
+}
+
+// SoroswapIntents Contract
+fn create_soroswap_intents<'a>(e: &Env) -> SoroswapIntentsClient<'a> {
+    SoroswapIntentsClient::new(e, &e.register_contract(None, SoroswapIntents {}))
+}
+
+/// Signs the payload of `intent` with `signing_key`.
+pub fn sign(test: &SoroswapIntentsTest, signing_key: &SigningKey, intent: &SwapIntent) -> BytesN<64> {
+    let payload = test.contract.get_intent_hash(intent);
+    BytesN::from_array(&test.env, &signing_key.sign(&payload.to_array()).to_bytes())
+}
+
+// SoroswapIntents TEST
+
+pub struct SoroswapIntentsTest<'a> {
+    env: Env,
+    contract: SoroswapIntentsClient<'a>,
+    router: SoroswapRouterClient<'a>,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    owner: Address,
+    relayer: Address,
+    recipient: Address,
+    signing_key: SigningKey,
+}
+
+impl<'a> SoroswapIntentsTest<'a> {
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = 100;
+        });
+        let contract = create_soroswap_intents(&env);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        let recipient = Address::generate(&env);
+
+        let mut token_0 = create_token_contract(&env, &admin);
+        let mut token_1 = create_token_contract(&env, &admin);
+        if &token_1.address < &token_0.address {
+            std::mem::swap(&mut token_0, &mut token_1);
+        }
+        token_0.mint(&admin, &10_000_000_000_000_000_000);
+        token_1.mint(&admin, &10_000_000_000_000_000_000);
+        token_0.mint(&owner, &1_000_000_000);
+        token_1.mint(&owner, &1_000_000_000);
+
+        let factory = create_soroswap_factory(&env, &admin);
+        let router = create_soroswap_router(&env, &factory.address);
+        env.budget().reset_unlimited();
+
+        // 1 token_0 = 4 token_1
+        router.add_liquidity(
+            &token_0.address,
+            &token_1.address,
+            &1_000_000_000,
+            &4_000_000_000,
+            &0,
+            &0,
+            &admin,
+            &1000,
+        );
+
+        let signing_key = SigningKey::from_bytes(&[7; 32]);
+
+        SoroswapIntentsTest {
+            env,
+            contract,
+            router,
+            token_0,
+            token_1,
+            owner,
+            relayer,
+            recipient,
+            signing_key,
+        }
+    }
+
+    /// Initializes the contract, registers the signer of the owner and lets the contract spend the
+    /// owner's token_0 and token_1.
+    fn setup_initialized() -> Self {
+        let test = Self::setup();
+        test.contract.initialize(&test.router.address);
+        let signer = BytesN::from_array(&test.env, &test.signing_key.verifying_key().to_bytes());
+        test.contract.set_signer(&test.owner, &signer);
+        test.token_0.approve(&test.owner, &test.contract.address, &1_000_000_000, &1000);
+        test.token_1.approve(&test.owner, &test.contract.address, &1_000_000_000, &1000);
+        test
+    }
+
+    /// An intent selling 1_000_000 token_0 with a relayer fee of 10_000 token_1.
+    fn intent(&self, nonce: u64) -> SwapIntent {
+        SwapIntent {
+            owner: self.owner.clone(),
+            token_in: self.token_0.address.clone(),
+            token_out: self.token_1.address.clone(),
+            amount_in: 1_000_000,
+            amount_out_min: 3_900_000,
+            relayer_fee: 10_000,
+            recipient: self.recipient.clone(),
+            deadline: 1000,
+            nonce,
+        }
+    }
+}
+
+// Test mods:
+pub mod initialize;
+pub mod settle;
+pub mod cancel;
@@ -0,0 +1,58 @@
+use soroban_sdk::{testutils::Events, vec, IntoVal, symbol_short};
+
+use crate::test::{SoroswapIntentsTest, sign};
+use crate::error::SoroswapIntentsError;
+use crate::event::CancelEvent;
+
+#[test]
+fn cancel_nonce() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    assert!(!test.contract.is_nonce_used(&test.owner, &3));
+
+    test.contract.cancel(&test.owner, &3);
+    assert!(test.contract.is_nonce_used(&test.owner, &3));
+    assert!(!test.contract.is_nonce_used(&test.owner, &4));
+
+    let cancel_event = test.env.events().all().last().unwrap();
+    let expected_cancel_event: CancelEvent = CancelEvent {
+        owner: test.owner.clone(),
+        nonce: 3,
+    };
+    assert_eq!(
+        vec![&test.env, cancel_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapIntents", symbol_short!("cancel")).into_val(&test.env),
+                (expected_cancel_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    let result = test.contract.try_cancel(&test.owner, &3);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NonceAlreadyUsed)));
+}
+
+#[test]
+fn cancelled_intent_can_not_be_settled() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let intent = test.intent(3);
+    let signature = sign(&test, &test.signing_key, &intent);
+
+    test.contract.cancel(&test.owner, &3);
+    let result = test.contract.try_settle(&intent, &signature, &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NonceAlreadyUsed)));
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000);
+}
+
+#[test]
+fn settled_nonce_can_not_be_cancelled() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let intent = test.intent(3);
+    let signature = sign(&test, &test.signing_key, &intent);
+
+    test.contract.settle(&intent, &signature, &test.relayer);
+    let result = test.contract.try_cancel(&test.owner, &3);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NonceAlreadyUsed)));
+}
@@ -0,0 +1,77 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, BytesN, IntoVal, symbol_short};
+
+use crate::test::SoroswapIntentsTest;
+use crate::error::SoroswapIntentsError;
+use crate::event::{InitializedEvent, SignerEvent};
+
+#[test]
+fn initialize_and_get_router() {
+    let test = SoroswapIntentsTest::setup();
+    test.contract.initialize(&test.router.address);
+    assert_eq!(test.contract.get_router(), test.router.address);
+
+    let initialized_event = test.env.events().all().last().unwrap();
+    let expected_initialized_event: InitializedEvent = InitializedEvent {
+        router: test.router.address.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, initialized_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapIntents", symbol_short!("init")).into_val(&test.env),
+                (expected_initialized_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn not_yet_initialized() {
+    let test = SoroswapIntentsTest::setup();
+    assert_eq!(test.contract.try_get_router(), Err(Ok(SoroswapIntentsError::NotInitialized)));
+
+    let signer = BytesN::from_array(&test.env, &test.signing_key.verifying_key().to_bytes());
+    let result = test.contract.try_set_signer(&test.owner, &signer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NotInitialized)));
+
+    let result = test.contract.try_cancel(&test.owner, &0);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NotInitialized)));
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapIntentsTest::setup();
+    test.contract.initialize(&test.router.address);
+    let result = test.contract.try_initialize(&Address::generate(&test.env));
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn set_signer() {
+    let test = SoroswapIntentsTest::setup();
+    test.contract.initialize(&test.router.address);
+    assert_eq!(test.contract.get_signer(&test.owner), None);
+
+    let signer = BytesN::from_array(&test.env, &test.signing_key.verifying_key().to_bytes());
+    test.contract.set_signer(&test.owner, &signer);
+    assert_eq!(test.contract.get_signer(&test.owner), Some(signer.clone()));
+
+    let signer_event = test.env.events().all().last().unwrap();
+    let expected_signer_event: SignerEvent = SignerEvent {
+        owner: test.owner.clone(),
+        signer: signer.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, signer_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapIntents", symbol_short!("signer")).into_val(&test.env),
+                (expected_signer_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,198 @@
+use ed25519_dalek::SigningKey;
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    BytesN,
+    IntoVal,
+    symbol_short,
+};
+
+use crate::test::{SoroswapIntentsTest, sign};
+use crate::error::SoroswapIntentsError;
+use crate::event::SettleEvent;
+
+#[test]
+fn settle_pays_relayer_and_recipient() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let intent = test.intent(0);
+    let signature = sign(&test, &test.signing_key, &intent);
+
+    let expected_amount_out = 3_984_027;
+    let quote = test.router.router_get_amounts_out(&intent.amount_in, &vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]);
+    assert_eq!(quote.get(1).unwrap(), expected_amount_out);
+
+    let result = test.contract.settle(&intent, &signature, &test.relayer);
+    assert_eq!(result, expected_amount_out - 10_000);
+
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000 - 1_000_000);
+    assert_eq!(test.token_1.balance(&test.owner), 1_000_000_000);
+    assert_eq!(test.token_1.balance(&test.recipient), expected_amount_out - 10_000);
+    assert_eq!(test.token_1.balance(&test.relayer), 10_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+    assert!(test.contract.is_nonce_used(&test.owner, &0));
+
+    let settle_event = test.env.events().all().last().unwrap();
+    let expected_settle_event: SettleEvent = SettleEvent {
+        owner: test.owner.clone(),
+        nonce: 0,
+        token_in: test.token_0.address.clone(),
+        token_out: test.token_1.address.clone(),
+        amount_in: 1_000_000,
+        amount_out: expected_amount_out - 10_000,
+        relayer_fee: 10_000,
+        recipient: test.recipient.clone(),
+        relayer: test.relayer.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, settle_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapIntents", symbol_short!("settle")).into_val(&test.env),
+                (expected_settle_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn settle_only_needs_relayer_auth() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let intent = test.intent(0);
+    let signature = sign(&test, &test.signing_key, &intent);
+
+    // The owner does not authorize anything in the settlement transaction
+    test.env.mock_auths(&[MockAuth {
+        address: &test.relayer,
+        invoke: &MockAuthInvoke {
+            contract: &test.contract.address,
+            fn_name: "settle",
+            args: (intent.clone(), signature.clone(), test.relayer.clone()).into_val(&test.env),
+            sub_invokes: &[],
+        },
+    }]);
+    let result = test.contract.settle(&intent, &signature, &test.relayer);
+    assert_eq!(result, 3_984_027 - 10_000);
+    assert_eq!(test.token_1.balance(&test.recipient), 3_984_027 - 10_000);
+}
+
+#[test]
+fn settle_replayed_intent() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let intent = test.intent(0);
+    let signature = sign(&test, &test.signing_key, &intent);
+
+    test.contract.settle(&intent, &signature, &test.relayer);
+    let result = test.contract.try_settle(&intent, &signature, &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NonceAlreadyUsed)));
+
+    // Another nonce is a different intent
+    let intent = test.intent(1);
+    let signature = sign(&test, &test.signing_key, &intent);
+    test.contract.settle(&intent, &signature, &test.relayer);
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000 - 2_000_000);
+}
+
+#[test]
+fn settle_not_yet_initialized() {
+    let test = SoroswapIntentsTest::setup();
+    let intent = test.intent(0);
+    let result = test.contract.try_settle(&intent, &BytesN::from_array(&test.env, &[0; 64]), &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NotInitialized)));
+}
+
+#[test]
+fn settle_signer_not_set() {
+    let test = SoroswapIntentsTest::setup();
+    test.contract.initialize(&test.router.address);
+    let intent = test.intent(0);
+    let signature = sign(&test, &test.signing_key, &intent);
+    let result = test.contract.try_settle(&intent, &signature, &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::SignerNotSet)));
+}
+
+#[test]
+fn settle_deadline_expired() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let mut intent = test.intent(0);
+    intent.deadline = 100;
+    let signature = sign(&test, &test.signing_key, &intent);
+    let result = test.contract.try_settle(&intent, &signature, &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::DeadlineExpired)));
+}
+
+#[test]
+fn settle_negative_amounts() {
+    let test = SoroswapIntentsTest::setup_initialized();
+
+    let mut intent = test.intent(0);
+    intent.amount_in = -1;
+    let result = test.contract.try_settle(&intent, &sign(&test, &test.signing_key, &intent), &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NegativeNotAllowed)));
+
+    let mut intent = test.intent(0);
+    intent.amount_out_min = -1;
+    let result = test.contract.try_settle(&intent, &sign(&test, &test.signing_key, &intent), &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NegativeNotAllowed)));
+
+    let mut intent = test.intent(0);
+    intent.relayer_fee = -1;
+    let result = test.contract.try_settle(&intent, &sign(&test, &test.signing_key, &intent), &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::NegativeNotAllowed)));
+}
+
+#[test]
+fn settle_same_tokens() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let mut intent = test.intent(0);
+    intent.token_out = intent.token_in.clone();
+    let result = test.contract.try_settle(&intent, &sign(&test, &test.signing_key, &intent), &test.relayer);
+    assert_eq!(result, Err(Ok(SoroswapIntentsError::InvalidTokens)));
+}
+
+#[test]
+fn settle_insufficient_output_amount() {
+    let test = SoroswapIntentsTest::setup_initialized();
+
+    // The output covers the minimum, but not the minimum plus the relayer fee
+    let mut intent = test.intent(0);
+    intent.amount_out_min = 3_984_027 - 9_999;
+    let signature = sign(&test, &test.signing_key, &intent);
+    let result = test.contract.try_settle(&intent, &signature, &test.relayer);
+    assert!(result.is_err());
+    assert!(!test.contract.is_nonce_used(&test.owner, &0));
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000);
+}
+
+#[test]
+#[should_panic]
+fn settle_signed_by_another_key() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let intent = test.intent(0);
+    let signature = sign(&test, &SigningKey::from_bytes(&[8; 32]), &intent);
+    test.contract.settle(&intent, &signature, &test.relayer);
+}
+
+#[test]
+#[should_panic]
+fn settle_tampered_intent() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let mut intent = test.intent(0);
+    let signature = sign(&test, &test.signing_key, &intent);
+    intent.relayer_fee = 1_000_000;
+    test.contract.settle(&intent, &signature, &test.relayer);
+}
+
+#[test]
+#[should_panic]
+fn settle_signed_by_replaced_signer() {
+    let test = SoroswapIntentsTest::setup_initialized();
+    let intent = test.intent(0);
+    let signature = sign(&test, &test.signing_key, &intent);
+
+    let new_key = SigningKey::from_bytes(&[8; 32]);
+    test.contract.set_signer(&test.owner, &BytesN::from_array(&test.env, &new_key.verifying_key().to_bytes()));
+    test.contract.settle(&intent, &signature, &test.relayer);
+}
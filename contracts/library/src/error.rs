@@ -21,4 +21,7 @@ pub enum SoroswapLibraryError {
 
     /// SoroswapLibrary: token_a and token_b have identical addresses
     SortIdenticalTokens = 306,
+
+    /// SoroswapLibrary: arithmetic overflow
+    Overflow = 307,
 }
\ No newline at end of file
@@ -10,6 +10,8 @@ mod reserves;
 mod quotes;
 mod error;
 mod math;
+mod pmm;
+mod pmm_curve;
 
 
 pub use tokens::{
@@ -24,7 +26,23 @@ pub use quotes::{
     get_amount_out, 
     get_amount_in, 
     get_amounts_out, 
-    get_amounts_in
+    get_amounts_in,
+    get_zap_swap_amount,
+    get_amount_in_for_price,
+    get_amount_in_for_price_limit,
+    get_spot_price,
+    get_execution_price,
+    get_price_impact_bps,
+    get_arbitrage_amount_in,
+    get_cycle_arbitrage_amount_in,
+    PRICE_SCALE
+};
+pub use pmm::{
+    pmm_pool_for,
+    get_pmm_amount_out,
+    get_pmm_quote,
+    PmmState,
+    SoroswapPmmPoolClient,
 };
 pub use error::SoroswapLibraryError;
 
@@ -141,6 +159,156 @@ pub trait SoroswapLibraryTrait {
     ///
     /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
     fn get_amounts_in(e: Env, factory: Address, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, SoroswapLibraryError>;
+
+    /// Given an amount of one asset to be fully deposited into a pair, returns how much of it has to be swapped
+    /// for the other asset first, so that after the swap both amounts match the new reserves ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The amount of the asset to be deposited.
+    /// * `reserve_in` - Reserves of that asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the amount to swap, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn get_zap_swap_amount(amount_in: i128, reserve_in: i128) -> Result<i128, SoroswapLibraryError>;
+
+    /// Given the reserves of a pair, returns the largest input amount that keeps the marginal price of the pair
+    /// at or above `price_limit`, the minimum amount of output token per input token scaled by `PRICE_SCALE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `price_limit` - The minimum marginal price, scaled by `PRICE_SCALE`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, and `Err` indicates an error such as insufficient liquidity, a non-positive price limit or an overflow.
+    fn get_amount_in_for_price(reserve_in: i128, reserve_out: i128, price_limit: i128) -> Result<i128, SoroswapLibraryError>;
+
+    /// Returns the largest input amount that keeps the marginal price of a trading route at or above `price_limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `price_limit` - The minimum marginal price of the route, scaled by `PRICE_SCALE`.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, and `Err` indicates an error such as an invalid path.
+    fn get_amount_in_for_price_limit(e: Env, factory: Address, price_limit: i128, path: Vec<Address>) -> Result<i128, SoroswapLibraryError>;
+
+    /// Returns the spot price of a pair: the amount of output token per input token at the current
+    /// reserves, before the 0.3% fee, scaled by `PRICE_SCALE` and rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the spot price, and `Err` indicates an error such as insufficient liquidity or an overflow.
+    fn get_spot_price(reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError>;
+
+    /// Returns the execution price of a swap: the amount of output token received per input token paid,
+    /// scaled by `PRICE_SCALE` and rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the swap.
+    /// * `amount_out` - The output amount of the swap.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the execution price, and `Err` indicates an error such as insufficient input or output amount, or an overflow.
+    fn get_execution_price(amount_in: i128, amount_out: i128) -> Result<i128, SoroswapLibraryError>;
+
+    /// Returns how much worse the execution price is than the spot price, in basis points of the spot
+    /// price and rounded up. It includes the 0.3% fee, and is zero when the execution price is not below
+    /// the spot price.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot_price` - The spot price before the swap, scaled by `PRICE_SCALE`.
+    /// * `execution_price` - The execution price of the swap, scaled by `PRICE_SCALE`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the price impact, and `Err` indicates an error such as a non-positive spot price or a negative execution price.
+    fn get_price_impact_bps(spot_price: i128, execution_price: i128) -> Result<i128, SoroswapLibraryError>;
+
+    /// Given the reserves of the virtual pair of a cyclic route, returns the input amount that maximizes
+    /// the profit of the round trip.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserve_in` - Reserves of the input asset in the virtual pair.
+    /// * `reserve_out` - Reserves of the output asset, the same token, in the virtual pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the optimal input amount, zero if the route is not profitable, and `Err` indicates an error such as insufficient liquidity.
+    fn get_arbitrage_amount_in(reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError>;
+
+    /// Returns the input amount that maximizes the profit of a cyclic trading route, such as XLM -> USDC -> EURC -> XLM.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `path` - Vector of token addresses representing the path. The first and last tokens must be the same.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the optimal input amount, zero if the route is not profitable, and `Err` indicates an error such as a path that is not a cycle.
+    fn get_cycle_arbitrage_amount_in(e: Env, factory: Address, path: Vec<Address>) -> Result<i128, SoroswapLibraryError>;
+
+    /// Calculates the deterministic address of the PMM pool of two tokens without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address of the PMM pool, and `Err` indicates an error such as identical tokens.
+    fn pmm_pool_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError>;
+
+    /// Given an input amount and the state of a PMM pool, returns the maximum output amount of the other token,
+    /// after the 0.3% fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state of the pool, with its targets adjusted to the oracle price, as returned by `get_pmm_state`.
+    /// * `amount_in` - The input amount.
+    /// * `token_0_in` - Whether the input token is token 0 of the pool.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn get_pmm_amount_out(state: PmmState, amount_in: i128, token_0_in: bool) -> Result<i128, SoroswapLibraryError>;
+
+    /// Returns the maximum output amount of `token_out` for `amount_in` of `token_in` in the PMM pool of the two tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_in` - The address of the input token.
+    /// * `token_out` - The address of the output token.
+    /// * `amount_in` - The input amount.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as identical tokens, insufficient input amount or liquidity.
+    fn get_pmm_quote(e: Env, factory: Address, token_in: Address, token_out: Address, amount_in: i128) -> Result<i128, SoroswapLibraryError>;
     
 
 
@@ -281,6 +449,178 @@ impl SoroswapLibraryTrait for SoroswapLibrary {
         get_amounts_in(e, factory, amount_out, path)
     }
 
+    /// Given an amount of one asset to be fully deposited into a pair, returns how much of it has to be swapped
+    /// for the other asset first, so that after the swap both amounts match the new reserves ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The amount of the asset to be deposited.
+    /// * `reserve_in` - Reserves of that asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the amount to swap, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn get_zap_swap_amount(amount_in: i128, reserve_in: i128) -> Result<i128, SoroswapLibraryError> {
+        get_zap_swap_amount(amount_in, reserve_in)
+    }
+
+    /// Given the reserves of a pair, returns the largest input amount that keeps the marginal price of the pair
+    /// at or above `price_limit`, the minimum amount of output token per input token scaled by `PRICE_SCALE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `price_limit` - The minimum marginal price, scaled by `PRICE_SCALE`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, and `Err` indicates an error such as insufficient liquidity, a non-positive price limit or an overflow.
+    fn get_amount_in_for_price(reserve_in: i128, reserve_out: i128, price_limit: i128) -> Result<i128, SoroswapLibraryError> {
+        get_amount_in_for_price(reserve_in, reserve_out, price_limit)
+    }
+
+    /// Returns the largest input amount that keeps the marginal price of a trading route at or above `price_limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `price_limit` - The minimum marginal price of the route, scaled by `PRICE_SCALE`.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, and `Err` indicates an error such as an invalid path.
+    fn get_amount_in_for_price_limit(e: Env, factory: Address, price_limit: i128, path: Vec<Address>) -> Result<i128, SoroswapLibraryError> {
+        get_amount_in_for_price_limit(e, factory, price_limit, path)
+    }
+
+    /// Returns the spot price of a pair: the amount of output token per input token at the current
+    /// reserves, before the 0.3% fee, scaled by `PRICE_SCALE` and rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the spot price, and `Err` indicates an error such as insufficient liquidity or an overflow.
+    fn get_spot_price(reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError> {
+        get_spot_price(reserve_in, reserve_out)
+    }
+
+    /// Returns the execution price of a swap: the amount of output token received per input token paid,
+    /// scaled by `PRICE_SCALE` and rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the swap.
+    /// * `amount_out` - The output amount of the swap.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the execution price, and `Err` indicates an error such as insufficient input or output amount, or an overflow.
+    fn get_execution_price(amount_in: i128, amount_out: i128) -> Result<i128, SoroswapLibraryError> {
+        get_execution_price(amount_in, amount_out)
+    }
+
+    /// Returns how much worse the execution price is than the spot price, in basis points of the spot
+    /// price and rounded up. It includes the 0.3% fee, and is zero when the execution price is not below
+    /// the spot price.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot_price` - The spot price before the swap, scaled by `PRICE_SCALE`.
+    /// * `execution_price` - The execution price of the swap, scaled by `PRICE_SCALE`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the price impact, and `Err` indicates an error such as a non-positive spot price or a negative execution price.
+    fn get_price_impact_bps(spot_price: i128, execution_price: i128) -> Result<i128, SoroswapLibraryError> {
+        get_price_impact_bps(spot_price, execution_price)
+    }
+
+    /// Given the reserves of the virtual pair of a cyclic route, returns the input amount that maximizes
+    /// the profit of the round trip.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserve_in` - Reserves of the input asset in the virtual pair.
+    /// * `reserve_out` - Reserves of the output asset, the same token, in the virtual pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the optimal input amount, zero if the route is not profitable, and `Err` indicates an error such as insufficient liquidity.
+    fn get_arbitrage_amount_in(reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError> {
+        get_arbitrage_amount_in(reserve_in, reserve_out)
+    }
+
+    /// Returns the input amount that maximizes the profit of a cyclic trading route, such as XLM -> USDC -> EURC -> XLM.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `path` - Vector of token addresses representing the path. The first and last tokens must be the same.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the optimal input amount, zero if the route is not profitable, and `Err` indicates an error such as a path that is not a cycle.
+    fn get_cycle_arbitrage_amount_in(e: Env, factory: Address, path: Vec<Address>) -> Result<i128, SoroswapLibraryError> {
+        get_cycle_arbitrage_amount_in(e, factory, path)
+    }
+
+    /// Calculates the deterministic address of the PMM pool of two tokens without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address of the PMM pool, and `Err` indicates an error such as identical tokens.
+    fn pmm_pool_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+        pmm_pool_for(e, factory, token_a, token_b)
+    }
+
+    /// Given an input amount and the state of a PMM pool, returns the maximum output amount of the other token,
+    /// after the 0.3% fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state of the pool, with its targets adjusted to the oracle price, as returned by `get_pmm_state`.
+    /// * `amount_in` - The input amount.
+    /// * `token_0_in` - Whether the input token is token 0 of the pool.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn get_pmm_amount_out(state: PmmState, amount_in: i128, token_0_in: bool) -> Result<i128, SoroswapLibraryError> {
+        get_pmm_amount_out(state, amount_in, token_0_in)
+    }
+
+    /// Returns the maximum output amount of `token_out` for `amount_in` of `token_in` in the PMM pool of the two tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_in` - The address of the input token.
+    /// * `token_out` - The address of the output token.
+    /// * `amount_in` - The input amount.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as identical tokens, insufficient input amount or liquidity.
+    fn get_pmm_quote(e: Env, factory: Address, token_in: Address, token_out: Address, amount_in: i128) -> Result<i128, SoroswapLibraryError> {
+        get_pmm_quote(e, factory, token_in, token_out, amount_in)
+    }
+
 
 
 }
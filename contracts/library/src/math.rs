@@ -11,4 +11,61 @@ impl CheckedCeilingDiv for i128 {
             Some(result)
         }
     }
-}
\ No newline at end of file
+}
+
+/// Computes `a * b / c` rounded down for non-negative `a`, `b` and positive `c`, keeping the
+/// intermediate product in 256 bits. Returns `None` if the result does not fit in an `i128`.
+pub fn checked_mul_div(a: i128, b: i128, c: i128) -> Option<i128> {
+    if a < 0 || b < 0 || c <= 0 {
+        return None;
+    }
+    if let Some(product) = a.checked_mul(b) {
+        return Some(product / c);
+    }
+
+    // 256-bit product split in two 128-bit words
+    let (a, b, c) = (a as u128, b as u128, c as u128);
+    let mask = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & mask);
+    let (b_hi, b_lo) = (b >> 64, b & mask);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let cross = (lo_lo >> 64) + (hi_lo & mask) + (lo_hi & mask);
+    let low = (cross << 64) | (lo_lo & mask);
+    let high = a_hi * b_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    if high >= c {
+        return None;
+    }
+
+    // Long division one bit at a time. The remainder stays below c < 2^127, so it never overflows.
+    let mut quotient: u128 = 0;
+    let mut remainder: u128 = high;
+    for i in (0..128).rev() {
+        remainder = (remainder << 1) | ((low >> i) & 1);
+        quotient <<= 1;
+        if remainder >= c {
+            remainder -= c;
+            quotient |= 1;
+        }
+    }
+    i128::try_from(quotient).ok()
+}
+
+/// Computes `a * b / c` rounded up, with the same bounds as `checked_mul_div`.
+pub fn checked_mul_div_ceil(a: i128, b: i128, c: i128) -> Option<i128> {
+    if a < 0 || b < 0 || c <= 0 {
+        return None;
+    }
+    if let Some(product) = a.checked_mul(b) {
+        return product.checked_ceiling_div(c);
+    }
+    let quotient = checked_mul_div(a, b, c)?;
+    // The remainder is below c, so the low 128 bits of a * b - quotient * c are exact
+    let remainder = (a as u128).wrapping_mul(b as u128).wrapping_sub((quotient as u128).wrapping_mul(c as u128));
+    if remainder != 0 {
+        quotient.checked_add(1)
+    } else {
+        Some(quotient)
+    }
+}
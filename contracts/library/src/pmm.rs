@@ -0,0 +1,113 @@
+//! Quotes for the oracle-pegged PMM pools created through `SoroswapFactory::create_pmm_pool`.
+//!
+//! The curve follows DODO V1: each pool keeps a target amount of each token, quotes the oracle price
+//! while its reserves match their targets, and moves away from it as one reserve runs below its
+//! target, faster the higher `k` is. The curve itself is in `pmm_curve`, shared with the pool.
+use soroban_sdk::{contractclient, contracttype, Address, Env, xdr::ToXdr, BytesN, Bytes};
+use crate::error::SoroswapLibraryError;
+use crate::math::CheckedCeilingDiv;
+use crate::pmm_curve::{sell_token_0, sell_token_1};
+use crate::tokens::sort_tokens;
+
+/// Everything the curve of a PMM pool needs to quote a trade, as returned by its `get_pmm_state`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PmmState {
+    /// Oracle price of token 0: the amount of token 1 per token 0, scaled by `PRICE_SCALE`.
+    pub price: i128,
+    /// Slippage parameter, scaled by `PRICE_SCALE`. Lower than `PRICE_SCALE`.
+    pub k: i128,
+    pub reserve_0: i128,
+    pub reserve_1: i128,
+    pub target_0: i128,
+    pub target_1: i128,
+}
+
+/// The part of the SoroswapPmmPool interface needed to quote it.
+#[contractclient(name = "SoroswapPmmPoolClient")]
+pub trait SoroswapPmmPoolInterface {
+    /// Returns the state of the pool with its targets adjusted to the current oracle price. Fails if the
+    /// oracle price is stale.
+    fn get_pmm_state(e: Env) -> PmmState;
+}
+
+/// Generates the salt of the PMM pool of two sorted token addresses: the salt of their pair followed by "PMM".
+fn pmm_pool_salt(e: &Env, token_0: Address, token_1: Address) -> BytesN<32> {
+    let mut salt = Bytes::new(e);
+    salt.append(&token_0.to_xdr(e));
+    salt.append(&token_1.to_xdr(e));
+    salt.append(&Bytes::from_slice(e, b"PMM"));
+    e.crypto().sha256(&salt).into()
+}
+
+/// Calculates the deterministic address of the PMM pool of two tokens without making any external calls.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address of the PMM pool, and `Err` indicates an error such as identical tokens.
+pub fn pmm_pool_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+    let (token_0, token_1) = sort_tokens(token_a, token_b)?;
+    let salt = pmm_pool_salt(&e, token_0, token_1);
+    Ok(e.deployer().with_address(factory, salt).deployed_address())
+}
+
+/// Given an input amount and the state of a PMM pool, returns the maximum output amount of the other token,
+/// after the 0.3% fee.
+///
+/// # Arguments
+///
+/// * `state` - The state of the pool, with its targets adjusted to the oracle price, as returned by `get_pmm_state`.
+/// * `amount_in` - The input amount.
+/// * `token_0_in` - Whether the input token is token 0 of the pool.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
+pub fn get_pmm_amount_out(state: PmmState, amount_in: i128, token_0_in: bool) -> Result<i128, SoroswapLibraryError> {
+    if amount_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if state.reserve_0 <= 0 || state.reserve_1 <= 0 || state.price <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+
+    let fee = (amount_in.checked_mul(3).unwrap()).checked_ceiling_div(1000).unwrap();
+    let amount_in_less_fee = amount_in.checked_sub(fee).unwrap();
+
+    let (amount_out, reserve_out) = if token_0_in {
+        (sell_token_0(&state, amount_in_less_fee), state.reserve_1)
+    } else {
+        (sell_token_1(&state, amount_in_less_fee), state.reserve_0)
+    };
+    if amount_out >= reserve_out {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    Ok(amount_out)
+}
+
+/// Returns the maximum output amount of `token_out` for `amount_in` of `token_in` in the PMM pool of the two tokens.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_in` - The address of the input token.
+/// * `token_out` - The address of the output token.
+/// * `amount_in` - The input amount.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as identical tokens, insufficient input amount or liquidity.
+pub fn get_pmm_quote(e: Env, factory: Address, token_in: Address, token_out: Address, amount_in: i128) -> Result<i128, SoroswapLibraryError> {
+    let (token_0, _) = sort_tokens(token_in.clone(), token_out.clone())?;
+    let pool_address = pmm_pool_for(e.clone(), factory, token_in.clone(), token_out)?;
+    let state = SoroswapPmmPoolClient::new(&e, &pool_address).get_pmm_state();
+    get_pmm_amount_out(state, amount_in, token_in == token_0)
+}
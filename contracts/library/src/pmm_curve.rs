@@ -0,0 +1,126 @@
+//! Proactive market maker curve, following DODO V1.
+//!
+//! The pool keeps a target amount of each token: what it would hold if every trade had happened at
+//! the oracle price. While the reserves match their targets the pool quotes the oracle price, and the
+//! price moves away from it as one reserve runs below its target, faster the higher `k` is. With
+//! `k = 0` the pool trades at the oracle price, and with `k` close to `PRICE_SCALE` the curve is
+//! close to a constant product one.
+//!
+//! The PMM pool (`contracts/pmm-pool`) compiles this same file, so the library quotes exactly what the
+//! pool accepts. It only relies on `crate::PRICE_SCALE`, `crate::PmmState` and `crate::math`, which
+//! both crates provide.
+use crate::math::{checked_mul_div, checked_mul_div_ceil, CheckedCeilingDiv};
+use crate::{PmmState, PRICE_SCALE};
+use num_integer::Roots;
+
+pub(crate) fn mul_scaled(a: i128, b: i128) -> i128 {
+    checked_mul_div(a, b, PRICE_SCALE).unwrap()
+}
+
+pub(crate) fn div_scaled_ceil(a: i128, b: i128) -> i128 {
+    checked_mul_div_ceil(a, PRICE_SCALE, b).unwrap()
+}
+
+/// Returns the price of token 1 in token 0, scaled by `PRICE_SCALE`.
+pub(crate) fn reciprocal_price(price: i128) -> i128 {
+    checked_mul_div(PRICE_SCALE, PRICE_SCALE, price).unwrap()
+}
+
+/// Integral of the price curve between `v_2` and `v_1`, for a reserve with target `v_0`:
+/// `i * (v_1 - v_2) * (1 - k + k * v_0^2 / (v_1 * v_2))`.
+fn general_integrate(v_0: i128, v_1: i128, v_2: i128, i: i128, k: i128) -> i128 {
+    let fair_amount = mul_scaled(i, v_1.checked_sub(v_2).unwrap());
+    let v_0_v_0_v_1_v_2 = div_scaled_ceil(checked_mul_div(v_0, v_0, v_1).unwrap(), v_2);
+    let penalty = mul_scaled(k, v_0_v_0_v_1_v_2);
+    mul_scaled(fair_amount, PRICE_SCALE.checked_sub(k).unwrap().checked_add(penalty).unwrap())
+}
+
+/// Returns `sqrt(b^2 + 4 * a_c * q_0)` rounded down. When the radicand does not fit in an `i128`, the
+/// factors are scaled down and the root is rounded up instead, which only makes the pool pay out less.
+fn discriminant_root(b: i128, a_c: i128, q_0: i128) -> i128 {
+    let radicand = |b: i128, a_c: i128, q_0: i128| {
+        b.checked_mul(b)?.checked_add(a_c.checked_mul(q_0)?.checked_mul(4)?)
+    };
+    if let Some(radicand) = radicand(b, a_c, q_0) {
+        return radicand.sqrt();
+    }
+    let mut scale: i128 = 2;
+    loop {
+        let (b_scaled, a_c_scaled, q_0_scaled) = (
+            b.checked_ceiling_div(scale).unwrap(),
+            a_c.checked_ceiling_div(scale).unwrap(),
+            q_0.checked_ceiling_div(scale).unwrap(),
+        );
+        if let Some(radicand) = radicand(b_scaled, a_c_scaled, q_0_scaled) {
+            return radicand.sqrt().checked_add(1).unwrap().checked_mul(scale).unwrap();
+        }
+        scale = scale.checked_mul(2).unwrap();
+    }
+}
+
+/// Returns the reserve `q_2` left after selling an amount worth `i_delta_b` at the oracle price into
+/// a reserve `q_1` with target `q_0`, solving `(1 - k) * q_2^2 + b * q_2 - k * q_0^2 = 0`.
+/// Rounded up, so the pool never pays out more than the curve allows.
+fn solve_quadratic_for_trade(q_0: i128, q_1: i128, i_delta_b: i128, k: i128) -> i128 {
+    // -b = (1 - k) * q_1 - k * q_0^2 / q_1 - i_delta_b
+    let k_q_0 = mul_scaled(k, q_0);
+    let k_q_0_q_0_q_1 = checked_mul_div(k_q_0, q_0, q_1).unwrap().checked_add(i_delta_b).unwrap();
+    let one_minus_k = PRICE_SCALE.checked_sub(k).unwrap();
+    let b = mul_scaled(one_minus_k, q_1);
+    let (b, minus_b_positive) = if b >= k_q_0_q_0_q_1 {
+        (b.checked_sub(k_q_0_q_0_q_1).unwrap(), true)
+    } else {
+        (k_q_0_q_0_q_1.checked_sub(b).unwrap(), false)
+    };
+
+    // sqrt(b^2 + 4 * (1 - k) * k * q_0^2)
+    let square_root = discriminant_root(b, mul_scaled(one_minus_k, k_q_0), q_0);
+
+    let numerator = if minus_b_positive {
+        b.checked_add(square_root).unwrap()
+    } else {
+        square_root.checked_sub(b).unwrap()
+    };
+    div_scaled_ceil(numerator, one_minus_k.checked_mul(2).unwrap())
+}
+
+/// Returns the amount of the output token received for `amount_in` of the input token, where
+/// `price_in` is the oracle price of the input token in the output token.
+fn sell(
+    reserve_in: i128,
+    target_in: i128,
+    reserve_out: i128,
+    target_out: i128,
+    price_in: i128,
+    k: i128,
+    amount_in: i128,
+) -> i128 {
+    if reserve_in < target_in {
+        // The input token is short: the trade brings it back to its target first
+        let back_to_target_in = target_in.checked_sub(reserve_in).unwrap();
+        let back_to_target_out = reserve_out.checked_sub(target_out).unwrap();
+        if amount_in < back_to_target_in {
+            let amount_out = general_integrate(target_in, reserve_in.checked_add(amount_in).unwrap(), reserve_in, price_in, k);
+            return amount_out.min(back_to_target_out);
+        }
+        let remaining_in = amount_in.checked_sub(back_to_target_in).unwrap();
+        let remaining_i_delta = mul_scaled(price_in, remaining_in);
+        let new_target_out = solve_quadratic_for_trade(target_out, target_out, remaining_i_delta, k);
+        return back_to_target_out.checked_add(target_out.checked_sub(new_target_out).unwrap()).unwrap();
+    }
+    let i_delta = mul_scaled(price_in, amount_in);
+    let new_reserve_out = solve_quadratic_for_trade(target_out, reserve_out, i_delta, k);
+    reserve_out.checked_sub(new_reserve_out).unwrap().max(0)
+}
+
+/// Returns the amount of token 1 received for `amount_in` of token 0, before any fee, for a state
+/// whose targets have already been adjusted.
+pub(crate) fn sell_token_0(state: &PmmState, amount_in: i128) -> i128 {
+    sell(state.reserve_0, state.target_0, state.reserve_1, state.target_1, state.price, state.k, amount_in)
+}
+
+/// Returns the amount of token 0 received for `amount_in` of token 1, before any fee, for a state
+/// whose targets have already been adjusted.
+pub(crate) fn sell_token_1(state: &PmmState, amount_in: i128) -> i128 {
+    sell(state.reserve_1, state.target_1, state.reserve_0, state.target_0, reciprocal_price(state.price), state.k, amount_in)
+}
@@ -1,7 +1,8 @@
 use soroban_sdk::{Address, Env, Vec};
 use crate::reserves::{get_reserves};
 use crate::error::SoroswapLibraryError;
-use crate::math::CheckedCeilingDiv;
+use crate::math::{CheckedCeilingDiv, checked_mul_div};
+use num_integer::Roots;
 
 
 /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
@@ -77,6 +78,176 @@ pub fn get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> R
     Ok(numerator.checked_ceiling_div(denominator).unwrap().checked_add(1).unwrap())
 }
 
+/// Given an amount of one asset to be fully deposited into a pair, returns how much of it has to be swapped
+/// for the other asset first, so that after the swap both amounts match the new reserves ratio.
+/// Solves `s^2 * 997 + s * 1997 * reserve_in - amount_in * reserve_in * 1000 = 0`, which accounts for the 0.3% fee:
+///
+/// `s = (sqrt(reserve_in * (3988009 * reserve_in + 3988000 * amount_in)) - 1997 * reserve_in) / 1994`
+///
+/// # Arguments
+///
+/// * `amount_in` - The amount of the asset to be deposited.
+/// * `reserve_in` - Reserves of that asset in the pair.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the amount to swap, and `Err` indicates an error such as insufficient input amount or liquidity.
+pub fn get_zap_swap_amount(amount_in: i128, reserve_in: i128) -> Result<i128, SoroswapLibraryError> {
+    if amount_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if reserve_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+
+    // The swap amount scales linearly with amount_in and reserve_in, so both are scaled down
+    // while the square root argument would overflow.
+    let mut scale: i128 = 1;
+    loop {
+        let scaled_amount_in = amount_in / scale;
+        let scaled_reserve_in = reserve_in / scale;
+        let radicand = scaled_reserve_in
+            .checked_mul(3988009)
+            .and_then(|r| r.checked_add(scaled_amount_in.checked_mul(3988000)?))
+            .and_then(|r| r.checked_mul(scaled_reserve_in));
+        if let Some(radicand) = radicand {
+            let swap_amount = (radicand.sqrt() - scaled_reserve_in * 1997) / 1994;
+            return Ok(swap_amount * scale);
+        }
+        scale *= 2;
+    }
+}
+
+/// Scale of the fixed-point prices used by `get_amount_in_for_price`: a price of `PRICE_SCALE`
+/// means one unit of the output token per unit of the input token.
+pub const PRICE_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Given the reserves of a pair, returns the largest input amount that keeps the marginal price of the
+/// pair at or above `price_limit`, the minimum amount of output token per input token scaled by `PRICE_SCALE`.
+///
+/// After swapping `x` (with the 0.3% fee), the marginal price is `997000 * reserve_in * reserve_out / (1000 * reserve_in + 997 * x)^2`,
+/// so the largest input is:
+/// `x = (sqrt(997000 * reserve_in * reserve_out * PRICE_SCALE / price_limit) - 1000 * reserve_in) / 997`
+///
+/// # Arguments
+///
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `price_limit` - The minimum marginal price, scaled by `PRICE_SCALE`.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, zero if the current price is already below the limit, and `Err` indicates an error such as insufficient liquidity, a non-positive price limit or an overflow.
+pub fn get_amount_in_for_price(reserve_in: i128, reserve_out: i128, price_limit: i128) -> Result<i128, SoroswapLibraryError> {
+    if price_limit <= 0 {
+        return Err(SoroswapLibraryError::InsufficientAmount);
+    }
+    if reserve_in <= 0 || reserve_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+
+    let scaled_reserve_in = reserve_in.checked_mul(1000).ok_or(SoroswapLibraryError::Overflow)?;
+    let scaled_reserve_out = reserve_out.checked_mul(997)
+        .and_then(|reserve_out| checked_mul_div(reserve_out, PRICE_SCALE, price_limit))
+        .ok_or(SoroswapLibraryError::Overflow)?;
+
+    // Both factors are scaled down while the square root argument would overflow.
+    let mut scale: i128 = 1;
+    let target_reserve_in = loop {
+        if let Some(radicand) = (scaled_reserve_in / scale).checked_mul(scaled_reserve_out / scale) {
+            break radicand.sqrt().checked_mul(scale).ok_or(SoroswapLibraryError::Overflow)?;
+        }
+        scale *= 2;
+    };
+
+    if target_reserve_in <= scaled_reserve_in {
+        return Ok(0);
+    }
+    Ok((target_reserve_in - scaled_reserve_in) / 997)
+}
+
+/// Folds the pairs of a trading route into a single virtual pair that gives the same output (before
+/// rounding) for any input, and returns its reserves.
+///
+/// Chaining `(a, b)` with `(b', c)` gives the virtual pair `(a * b' / (b' + 0.997 * b), 0.997 * b * c / (b' + 0.997 * b))`.
+fn get_virtual_reserves(e: Env, factory: Address, path: Vec<Address>) -> Result<(i128, i128), SoroswapLibraryError> {
+    let (mut virtual_in, mut virtual_out) = get_reserves(e.clone(), factory.clone(), path.get(0).unwrap(), path.get(1).unwrap())?;
+    for i in 1..path.len() - 1 {
+        let (reserve_in, reserve_out) = get_reserves(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        if reserve_in <= 0 || reserve_out <= 0 {
+            return Err(SoroswapLibraryError::InsufficientLiquidity);
+        }
+        let scaled_reserve_in = reserve_in.checked_mul(1000).ok_or(SoroswapLibraryError::Overflow)?;
+        let scaled_virtual_out = virtual_out.checked_mul(997).ok_or(SoroswapLibraryError::Overflow)?;
+        let denominator = scaled_reserve_in.checked_add(scaled_virtual_out).ok_or(SoroswapLibraryError::Overflow)?;
+        virtual_in = checked_mul_div(virtual_in, scaled_reserve_in, denominator).ok_or(SoroswapLibraryError::Overflow)?;
+        virtual_out = checked_mul_div(scaled_virtual_out, reserve_out, denominator).ok_or(SoroswapLibraryError::Overflow)?;
+    }
+    Ok((virtual_in, virtual_out))
+}
+
+/// Returns the largest input amount that keeps the marginal price of a trading route at or above
+/// `price_limit`. The pairs of the route are folded into a single virtual pair with the same output
+/// for any input, and `get_amount_in_for_price` is applied to it.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `price_limit` - The minimum marginal price of the route, scaled by `PRICE_SCALE`.
+/// * `path` - Vector of token addresses representing the path.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, and `Err` indicates an error such as an invalid path.
+pub fn get_amount_in_for_price_limit(e: Env, factory: Address, price_limit: i128, path: Vec<Address>) -> Result<i128, SoroswapLibraryError> {
+    if path.len() < 2 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+    let (virtual_in, virtual_out) = get_virtual_reserves(e, factory, path)?;
+    get_amount_in_for_price(virtual_in, virtual_out, price_limit)
+}
+
+/// Given the reserves of the virtual pair of a cyclic route, which starts and ends with the same token,
+/// returns the input amount that maximizes the profit `get_amount_out(x) - x` of the round trip.
+///
+/// The profit grows while the marginal price of the route is above one, so with the 0.3% fee the
+/// optimal input is:
+/// `x = (sqrt(997000 * reserve_in * reserve_out) - 1000 * reserve_in) / 997`
+///
+/// # Arguments
+///
+/// * `reserve_in` - Reserves of the input asset in the virtual pair.
+/// * `reserve_out` - Reserves of the output asset, the same token, in the virtual pair.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the optimal input amount, zero if the route is not profitable, and `Err` indicates an error such as insufficient liquidity.
+pub fn get_arbitrage_amount_in(reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError> {
+    get_amount_in_for_price(reserve_in, reserve_out, PRICE_SCALE)
+}
+
+/// Returns the input amount that maximizes the profit of a cyclic trading route, such as
+/// XLM -> USDC -> EURC -> XLM. The pairs of the route are folded into a single virtual pair and
+/// `get_arbitrage_amount_in` is applied to it.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `path` - Vector of token addresses representing the path. The first and last tokens must be the same.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the optimal input amount, zero if the route is not profitable, and `Err` indicates an error such as a path that is not a cycle.
+pub fn get_cycle_arbitrage_amount_in(e: Env, factory: Address, path: Vec<Address>) -> Result<i128, SoroswapLibraryError> {
+    if path.len() < 3 || path.first() != path.last() {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+    let (virtual_in, virtual_out) = get_virtual_reserves(e, factory, path)?;
+    get_arbitrage_amount_in(virtual_in, virtual_out)
+}
+
 /// Performs chained getAmountOut calculations on any number of pairs.
 ///
 /// # Arguments
@@ -133,3 +304,70 @@ pub fn get_amounts_in(e: Env, factory: Address, amount_out: i128, path: Vec<Addr
 
     Ok(amounts)
 }
+
+/// Denominator of the price impact, in basis points.
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Returns the spot price of a pair: the amount of output token per input token at the current
+/// reserves, before the 0.3% fee, scaled by `PRICE_SCALE` and rounded down.
+///
+/// # Arguments
+///
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the spot price, and `Err` indicates an error such as insufficient liquidity or an overflow.
+pub fn get_spot_price(reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError> {
+    if reserve_in <= 0 || reserve_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    checked_mul_div(reserve_out, PRICE_SCALE, reserve_in).ok_or(SoroswapLibraryError::Overflow)
+}
+
+/// Returns the execution price of a swap: the amount of output token received per input token paid,
+/// scaled by `PRICE_SCALE` and rounded down.
+///
+/// # Arguments
+///
+/// * `amount_in` - The input amount of the swap.
+/// * `amount_out` - The output amount of the swap.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the execution price, and `Err` indicates an error such as insufficient input or output amount, or an overflow.
+pub fn get_execution_price(amount_in: i128, amount_out: i128) -> Result<i128, SoroswapLibraryError> {
+    if amount_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if amount_out < 0 {
+        return Err(SoroswapLibraryError::InsufficientOutputAmount);
+    }
+    checked_mul_div(amount_out, PRICE_SCALE, amount_in).ok_or(SoroswapLibraryError::Overflow)
+}
+
+/// Returns how much worse the execution price is than the spot price, in basis points of the spot
+/// price and rounded up, so it is never understated. It includes the 0.3% fee, and is zero when the
+/// execution price is not below the spot price.
+///
+/// `price_impact_bps = 10000 - floor(10000 * execution_price / spot_price)`
+///
+/// # Arguments
+///
+/// * `spot_price` - The spot price before the swap, scaled by `PRICE_SCALE`.
+/// * `execution_price` - The execution price of the swap, scaled by `PRICE_SCALE`.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the price impact, and `Err` indicates an error such as a non-positive spot price or a negative execution price.
+pub fn get_price_impact_bps(spot_price: i128, execution_price: i128) -> Result<i128, SoroswapLibraryError> {
+    if spot_price <= 0 || execution_price < 0 {
+        return Err(SoroswapLibraryError::InsufficientAmount);
+    }
+    if execution_price >= spot_price {
+        return Ok(0);
+    }
+    let execution_bps = checked_mul_div(execution_price, BPS_DENOMINATOR, spot_price).ok_or(SoroswapLibraryError::Overflow)?;
+    Ok(BPS_DENOMINATOR - execution_bps)
+}
@@ -166,4 +166,71 @@ fn get_amounts_in_invalid_path() {
     let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
     let result = test.contract.try_get_amounts_in(&test.factory.address, &1, &path);
     assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
-}
\ No newline at end of file
+}
+#[test]
+fn get_amount_in_for_price_limit() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    test.token_0.transfer(&test.user, &test.pair.address, &10000);
+    test.token_1.transfer(&test.user, &test.pair.address, &10000);
+    test.pair.deposit(&test.user);
+
+    // A single pair route matches the pair math
+    let price_limit = crate::PRICE_SCALE / 2;
+    let expected_amount_in = test.contract.get_amount_in_for_price(&10000, &10000, &price_limit);
+    assert_eq!(expected_amount_in, 4133);
+    assert_eq!(expected_amount_in, test.contract.get_amount_in_for_price_limit(&test.factory.address, &price_limit, &path));
+}
+
+#[test]
+fn get_amount_in_for_price_limit_invalid_path() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
+    let result = test.contract.try_get_amount_in_for_price_limit(&test.factory.address, &crate::PRICE_SCALE, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
+}
+
+#[test]
+fn get_amount_in_for_price_limit_overflow() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone(), test.token_1.address.clone()];
+
+    // Folding the second hop scales its reserves by 1000, which does not fit in an i128
+    let deep_reserve: i128 = i128::MAX / 1000 + 1;
+    test.token_0.mint(&test.user, &deep_reserve);
+    test.token_0.transfer(&test.user, &test.pair.address, &deep_reserve);
+    test.token_1.transfer(&test.user, &test.pair.address, &999);
+    test.pair.deposit(&test.user);
+
+    let result = test.contract.try_get_amount_in_for_price_limit(&test.factory.address, &crate::PRICE_SCALE, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+    let result = test.contract.try_get_cycle_arbitrage_amount_in(&test.factory.address, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+}
+
+#[test]
+fn get_cycle_arbitrage_amount_in() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+
+    test.token_0.transfer(&test.user, &test.pair.address, &10000);
+    test.token_1.transfer(&test.user, &test.pair.address, &10000);
+    test.pair.deposit(&test.user);
+
+    // A round trip on a single pair only pays the fee twice
+    assert_eq!(0, test.contract.get_cycle_arbitrage_amount_in(&test.factory.address, &path));
+}
+
+#[test]
+fn get_cycle_arbitrage_amount_in_invalid_path() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_0.address.clone()];
+    let result = test.contract.try_get_cycle_arbitrage_amount_in(&test.factory.address, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
+
+    // The route has to end with the token it starts with
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), test.token_1.address.clone()];
+    let result = test.contract.try_get_cycle_arbitrage_amount_in(&test.factory.address, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
+}
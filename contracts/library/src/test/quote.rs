@@ -1,5 +1,6 @@
 use crate::test::{SoroswapLibraryTest};
 use crate::error::SoroswapLibraryError;
+use crate::PmmState;
 
 #[test]
 fn quote_insufficient_amount() {
@@ -28,3 +29,263 @@ fn quote() {
     assert_eq!(2,test.contract.quote(&1, &100, &200));
     assert_eq!(1,test.contract.quote(&2, &200, &100));
 }
+
+#[test]
+fn get_zap_swap_amount_insufficient_input_amount() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_zap_swap_amount(&0, &100);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientInputAmount)));
+}
+
+#[test]
+fn get_zap_swap_amount_insufficient_liquidity() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_zap_swap_amount(&1, &0);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
+
+#[test]
+fn get_zap_swap_amount() {
+    let test = SoroswapLibraryTest::setup();
+    // (sqrt(1000000 * (3988009 * 1000000 + 3988000 * 1000)) - 1997 * 1000000) / 1994 = 500,2
+    assert_eq!(500, test.contract.get_zap_swap_amount(&1_000, &1_000_000));
+    // (sqrt(1000000 * (3988009 * 1000000 + 3988000 * 1000000)) - 1997 * 1000000) / 1994 = 414835,1
+    assert_eq!(414835, test.contract.get_zap_swap_amount(&1_000_000, &1_000_000));
+
+    // After swapping, the rest of the input matches the new reserves ratio
+    let (amount_in, reserve_in, reserve_out) = (1_000_000_i128, 7_000_000_i128, 3_000_000_i128);
+    let swap_amount = test.contract.get_zap_swap_amount(&amount_in, &reserve_in);
+    let amount_out = test.contract.get_amount_out(&swap_amount, &reserve_in, &reserve_out);
+    let amount_out_optimal = test.contract.quote(&(amount_in - swap_amount), &(reserve_in + swap_amount), &(reserve_out - amount_out));
+    assert!((amount_out_optimal - amount_out).abs() <= 1);
+}
+
+#[test]
+fn get_zap_swap_amount_large_reserves() {
+    let test = SoroswapLibraryTest::setup();
+    // The square root argument overflows i128, so the amounts are scaled down
+    let exact_swap_amount: i128 = 500_626_001_745_396_739_492_579_337;
+    let swap_amount = test.contract.get_zap_swap_amount(&1_000_000_000_000_000_000_000_000_000, &1_000_000_000_000_000_000_000_000_000_000);
+    assert!((swap_amount - exact_swap_amount).abs() < exact_swap_amount / 1_000_000_000);
+}
+
+#[test]
+fn get_amount_in_for_price_insufficient_amount() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_amount_in_for_price(&100, &200, &0);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientAmount)));
+}
+
+#[test]
+fn get_amount_in_for_price_insufficient_liquidity() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_amount_in_for_price(&0, &200, &crate::PRICE_SCALE);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    let result = test.contract.try_get_amount_in_for_price(&100, &0, &crate::PRICE_SCALE);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
+
+#[test]
+fn get_amount_in_for_price_overflow() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_amount_in_for_price(&100, &i128::MAX, &crate::PRICE_SCALE);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+    let result = test.contract.try_get_amount_in_for_price(&100, &1_000_000_000_000_000_000_000_000_000_000, &1);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+}
+
+#[test]
+fn get_amount_in_for_price() {
+    let test = SoroswapLibraryTest::setup();
+    let (reserve_in, reserve_out) = (1_000_000_000_i128, 4_000_000_000_i128);
+
+    // (sqrt(997000 * 1000000000 * 4000000000 / 2) - 1000 * 1000000000) / 997 = 413330640,5
+    let amount_in = test.contract.get_amount_in_for_price(&reserve_in, &reserve_out, &(2 * crate::PRICE_SCALE));
+    assert_eq!(413330640, amount_in);
+
+    // The next 1000 units would be bought at the limit price of 2
+    let amount_out = test.contract.get_amount_out(&amount_in, &reserve_in, &reserve_out);
+    let next_amount_out = test.contract.get_amount_out(&(amount_in + 1_000), &reserve_in, &reserve_out);
+    assert!((next_amount_out - amount_out - 2_000).abs() <= 1);
+
+    // The spot price is 3.988 after fees, so a higher limit can not be filled
+    assert_eq!(0, test.contract.get_amount_in_for_price(&reserve_in, &reserve_out, &(4 * crate::PRICE_SCALE)));
+}
+
+#[test]
+fn get_amount_in_for_price_large_reserves() {
+    let test = SoroswapLibraryTest::setup();
+    // Both the output reserve times the price scale and the square root argument overflow i128
+    let exact_amount_in: i128 = 413_330_640_570_018_326_894_007_655;
+    let amount_in = test.contract.get_amount_in_for_price(
+        &1_000_000_000_000_000_000_000_000_000,
+        &4_000_000_000_000_000_000_000_000_000,
+        &(2 * crate::PRICE_SCALE));
+    assert!((amount_in - exact_amount_in).abs() < exact_amount_in / 1_000_000_000);
+}
+
+#[test]
+fn get_arbitrage_amount_in() {
+    let test = SoroswapLibraryTest::setup();
+    let (reserve_in, reserve_out) = (1_000_000_000_i128, 1_200_000_000_i128);
+
+    // (sqrt(997000 * 1000000000 * 1200000000) - 1000 * 1000000000) / 997 = 94082961,7
+    let amount_in = test.contract.get_arbitrage_amount_in(&reserve_in, &reserve_out);
+    assert_eq!(94082961, amount_in);
+
+    // Trading more or less than the optimal amount makes less profit
+    let profit = |amount: i128| test.contract.get_amount_out(&amount, &reserve_in, &reserve_out) - amount;
+    assert_eq!(8825048, profit(amount_in));
+    assert!(profit(amount_in - 1_000_000) < profit(amount_in));
+    assert!(profit(amount_in + 1_000_000) < profit(amount_in));
+
+    // The fee takes more than the price difference, so there is nothing to trade
+    assert_eq!(0, test.contract.get_arbitrage_amount_in(&1_000_000_000, &1_002_000_000));
+}
+
+#[test]
+fn get_arbitrage_amount_in_insufficient_liquidity() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_arbitrage_amount_in(&0, &200);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
+
+#[test]
+fn get_spot_price_insufficient_liquidity() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_spot_price(&0, &200);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    let result = test.contract.try_get_spot_price(&100, &0);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
+
+#[test]
+fn get_spot_price() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(4 * crate::PRICE_SCALE, test.contract.get_spot_price(&1_000_000_000, &4_000_000_000));
+    // Rounded down
+    assert_eq!(333_333_333_333_333_333, test.contract.get_spot_price(&3, &1));
+}
+
+#[test]
+fn get_execution_price() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(3_984_027_000_000_000_000, test.contract.get_execution_price(&1_000_000, &3_984_027));
+    assert_eq!(333_333_333_333_333_333, test.contract.get_execution_price(&3, &1));
+
+    let result = test.contract.try_get_execution_price(&0, &1);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientInputAmount)));
+    let result = test.contract.try_get_execution_price(&1, &-1);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientOutputAmount)));
+}
+
+#[test]
+fn get_spot_and_execution_price_overflow() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_spot_price(&1, &i128::MAX);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+    let result = test.contract.try_get_execution_price(&1, &i128::MAX);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+}
+
+#[test]
+fn get_price_impact_bps() {
+    let test = SoroswapLibraryTest::setup();
+    let (reserve_in, reserve_out) = (1_000_000_000_i128, 4_000_000_000_i128);
+
+    // Swapping 1000000 loses 39.9 basis points to the fee and the price move, rounded up to 40
+    let amount_out = test.contract.get_amount_out(&1_000_000, &reserve_in, &reserve_out);
+    assert_eq!(3_984_027, amount_out);
+    let spot_price = test.contract.get_spot_price(&reserve_in, &reserve_out);
+    let execution_price = test.contract.get_execution_price(&1_000_000, &amount_out);
+    assert_eq!(40, test.contract.get_price_impact_bps(&spot_price, &execution_price));
+
+    assert_eq!(5_000, test.contract.get_price_impact_bps(&(2 * crate::PRICE_SCALE), &crate::PRICE_SCALE));
+    assert_eq!(3_334, test.contract.get_price_impact_bps(&(3 * crate::PRICE_SCALE), &(2 * crate::PRICE_SCALE)));
+    assert_eq!(0, test.contract.get_price_impact_bps(&crate::PRICE_SCALE, &(2 * crate::PRICE_SCALE)));
+}
+
+#[test]
+fn get_price_impact_bps_invalid_prices() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_price_impact_bps(&0, &crate::PRICE_SCALE);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientAmount)));
+    let result = test.contract.try_get_price_impact_bps(&crate::PRICE_SCALE, &-1);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientAmount)));
+}
+
+// PMM pool with 1 token_0 = 2 token_1 at the oracle, k = 0.1, and reserves on target
+fn pmm_state() -> PmmState {
+    PmmState {
+        price: 2_000_000_000_000_000_000,
+        k: 100_000_000_000_000_000,
+        reserve_0: 10_000_000_000,
+        reserve_1: 20_000_000_000,
+        target_0: 10_000_000_000,
+        target_1: 20_000_000_000,
+    }
+}
+
+#[test]
+fn get_pmm_amount_out() {
+    let test = SoroswapLibraryTest::setup();
+    // 997_000_000 after the fee, worth 1_994_000_000 at the oracle price, minus the slippage
+    assert_eq!(test.contract.get_pmm_amount_out(&pmm_state(), &1_000_000_000, &true), 1_972_419_518);
+    assert_eq!(test.contract.get_pmm_amount_out(&pmm_state(), &2_000_000_000, &false), 986_209_759);
+}
+
+#[test]
+fn get_pmm_amount_out_at_oracle_price() {
+    let test = SoroswapLibraryTest::setup();
+    let state = PmmState { k: 0, ..pmm_state() };
+    assert_eq!(test.contract.get_pmm_amount_out(&state, &1_000_000_000, &true), 1_994_000_000);
+}
+
+#[test]
+fn get_pmm_amount_out_below_target() {
+    let test = SoroswapLibraryTest::setup();
+    // After selling token_1 into the pool, token_0 is below its target and gets a premium
+    let state = PmmState {
+        reserve_0: 9_992_146_042,
+        reserve_1: 20_027_580_482,
+        target_0: 10_002_999_439,
+        target_1: 20_005_871_330,
+        ..pmm_state()
+    };
+    assert_eq!(test.contract.get_pmm_amount_out(&state, &10_000_000, &true), 19_942_342);
+    assert_eq!(test.contract.get_pmm_amount_out(&state, &100_000_000, &true), 199_243_402);
+}
+
+#[test]
+fn get_pmm_amount_out_large_reserves() {
+    let test = SoroswapLibraryTest::setup();
+    // The same pool with 18 decimals tokens: the curve keeps its intermediate products in 256 bits
+    let state = PmmState {
+        reserve_0: 1_000_000_000_000_000_000_000_000,
+        reserve_1: 2_000_000_000_000_000_000_000_000,
+        target_0: 1_000_000_000_000_000_000_000_000,
+        target_1: 2_000_000_000_000_000_000_000_000,
+        ..pmm_state()
+    };
+    assert_eq!(test.contract.get_pmm_amount_out(&state, &100_000_000_000_000_000_000_000, &true), 197_241_951_803_234_811_175_822);
+    assert_eq!(test.contract.get_pmm_amount_out(&state, &200_000_000_000_000_000_000_000, &false), 98_620_975_901_617_405_587_911);
+}
+
+#[test]
+fn get_pmm_amount_out_insufficient_input_amount() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_pmm_amount_out(&pmm_state(), &0, &true);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientInputAmount)));
+}
+
+#[test]
+fn get_pmm_amount_out_insufficient_liquidity() {
+    let test = SoroswapLibraryTest::setup();
+    let state = PmmState { reserve_1: 0, ..pmm_state() };
+    let result = test.contract.try_get_pmm_amount_out(&state, &1_000_000_000, &true);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+
+    let state = PmmState { price: 0, ..pmm_state() };
+    let result = test.contract.try_get_pmm_amount_out(&state, &1_000_000_000, &true);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
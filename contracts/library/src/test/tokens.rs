@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, String};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, String};
 use crate::test::{SoroswapLibraryTest};
 use crate::error::SoroswapLibraryError;
 
@@ -27,4 +27,18 @@ fn pair_for() {
     let test = SoroswapLibraryTest::setup();
     assert_eq!(test.pair.address,test.contract.pair_for(&test.factory.address, &test.token_0.address, &test.token_1.address));
     assert_eq!(test.pair.address,test.contract.pair_for(&test.factory.address, &test.token_1.address, &test.token_0.address));
-}
\ No newline at end of file
+}
+#[test]
+fn pmm_pool_for() {
+    let test = SoroswapLibraryTest::setup();
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&Bytes::from_slice(&test.env, b"PMM"));
+    let salt: BytesN<32> = test.env.crypto().sha256(&salt).into();
+    let expected = test.env.deployer().with_address(test.factory.address.clone(), salt).deployed_address();
+
+    assert_eq!(expected, test.contract.pmm_pool_for(&test.factory.address, &test.token_0.address, &test.token_1.address));
+    assert_eq!(expected, test.contract.pmm_pool_for(&test.factory.address, &test.token_1.address, &test.token_0.address));
+    assert_ne!(test.pair.address, expected);
+}
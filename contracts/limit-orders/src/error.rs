@@ -0,0 +1,70 @@
+use soroban_sdk::{self, contracterror};
+use soroswap_library::SoroswapLibraryError;
+
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapLimitOrdersError {
+    /// SoroswapLimitOrders: not yet initialized
+    NotInitialized = 801,
+
+    /// SoroswapLimitOrders: already initialized
+    InitializeAlreadyInitialized = 802,
+
+    /// SoroswapLimitOrders: amounts must be positive
+    InvalidAmount = 803,
+
+    /// SoroswapLimitOrders: the order has expired
+    OrderExpired = 804,
+
+    /// SoroswapLimitOrders: order does not exist
+    OrderDoesNotExist = 805,
+
+    /// SoroswapLimitOrders: pair does not exist
+    PairDoesNotExist = 806,
+
+    /// SoroswapLimitOrders: fill amount must be positive and not above the remaining amount of the order
+    InvalidFillAmount = 807,
+
+    /// SoroswapLimitOrders: the pair does not give the limit price of the order
+    LimitPriceNotReached = 808,
+
+    /// SoroswapLimitOrders: keeper bounty can not be above 10000 basis points
+    InvalidKeeperBounty = 809,
+
+    /// SoroswapLibrary: insufficient amount
+    LibraryInsufficientAmount = 810,
+
+    /// SoroswapLibrary: insufficient liquidity
+    LibraryInsufficientLiquidity = 811,
+
+    /// SoroswapLibrary: insufficient input amount
+    LibraryInsufficientInputAmount = 812,
+
+    /// SoroswapLibrary: insufficient output amount
+    LibraryInsufficientOutputAmount = 813,
+
+    /// SoroswapLibrary: invalid path
+    LibraryInvalidPath = 814,
+
+    /// SoroswapLibrary: token_a and token_b have identical addresses
+    LibrarySortIdenticalTokens = 815,
+
+    /// SoroswapLibrary: arithmetic overflow
+    LibraryOverflow = 816,
+}
+
+impl From<SoroswapLibraryError> for SoroswapLimitOrdersError {
+    fn from(err: SoroswapLibraryError) -> Self {
+        match err {
+            SoroswapLibraryError::InsufficientAmount => SoroswapLimitOrdersError::LibraryInsufficientAmount,
+            SoroswapLibraryError::InsufficientLiquidity => SoroswapLimitOrdersError::LibraryInsufficientLiquidity,
+            SoroswapLibraryError::InsufficientInputAmount => SoroswapLimitOrdersError::LibraryInsufficientInputAmount,
+            SoroswapLibraryError::InsufficientOutputAmount => SoroswapLimitOrdersError::LibraryInsufficientOutputAmount,
+            SoroswapLibraryError::InvalidPath => SoroswapLimitOrdersError::LibraryInvalidPath,
+            SoroswapLibraryError::SortIdenticalTokens => SoroswapLimitOrdersError::LibrarySortIdenticalTokens,
+            SoroswapLibraryError::Overflow => SoroswapLimitOrdersError::LibraryOverflow,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address};
+
+// INITIALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub router: Address,
+    pub factory: Address
+}
+
+/// Publishes an `InitializedEvent` when the contract is initialized.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `router` - The Soroswap router the orders are filled through.
+/// * `factory` - The factory of the router.
+pub(crate) fn initialized(e: &Env, router: Address, factory: Address) {
+    let event = InitializedEvent {
+        router,
+        factory,
+    };
+    e.events().publish(("SoroswapLimitOrders", symbol_short!("init")), event);
+}
+
+// PLACE ORDER EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlaceOrderEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out_min: i128,
+    pub keeper_bounty_bps: u32,
+    pub expiration: u64
+}
+
+/// Publishes a `PlaceOrderEvent` when an order is placed and its input escrowed.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The placed order.
+pub(crate) fn place(e: &Env, event: PlaceOrderEvent) {
+    e.events().publish(("SoroswapLimitOrders", symbol_short!("place")), event);
+}
+
+// FILL ORDER EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FillOrderEvent {
+    pub order_id: u64,
+    pub keeper: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub keeper_bounty: i128,
+    pub amount_in_remaining: i128
+}
+
+/// Publishes a `FillOrderEvent` when a keeper fills an order, totally or partially.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The fill: the input swapped, the output sent to the owner, the bounty paid to the
+///   keeper and the input left in the order.
+pub(crate) fn fill(e: &Env, event: FillOrderEvent) {
+    e.events().publish(("SoroswapLimitOrders", symbol_short!("fill")), event);
+}
+
+// CANCEL ORDER EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelOrderEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub amount_in_refunded: i128
+}
+
+/// Publishes a `CancelOrderEvent` when the owner cancels an order.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `order_id` - The cancelled order.
+/// * `owner` - The owner of the order.
+/// * `amount_in_refunded` - The escrowed input sent back to the owner.
+pub(crate) fn cancel(e: &Env, order_id: u64, owner: Address, amount_in_refunded: i128) {
+    let event = CancelOrderEvent {
+        order_id,
+        owner,
+        amount_in_refunded,
+    };
+    e.events().publish(("SoroswapLimitOrders", symbol_short!("cancel")), event);
+}
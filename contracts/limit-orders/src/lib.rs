@@ -0,0 +1,399 @@
+#![no_std]
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol, Vec};
+
+mod router;
+mod factory;
+mod test;
+mod event;
+mod storage;
+mod error;
+mod order;
+
+use router::SoroswapRouterClient;
+use factory::SoroswapFactoryClient;
+use storage::{
+    put_router,
+    has_router,
+    get_router,
+    get_factory,
+    extend_instance_ttl,
+    next_order_id,
+    put_order,
+    get_order,
+    add_order,
+    remove_order,
+    get_owner_orders,
+    get_pair_orders,
+};
+use order::{limit_amount_out, keeper_bounty};
+pub use error::SoroswapLimitOrdersError;
+pub use order::{LimitOrder, MAX_KEEPER_BOUNTY_BPS};
+
+fn check_positive_amount(amount: i128) -> Result<(), SoroswapLimitOrdersError> {
+    if amount <= 0 {
+        Err(SoroswapLimitOrdersError::InvalidAmount)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_initialized(e: &Env) -> Result<(), SoroswapLimitOrdersError> {
+    if has_router(e) {
+        Ok(())
+    } else {
+        Err(SoroswapLimitOrdersError::NotInitialized)
+    }
+}
+
+fn check_not_expired(e: &Env, expiration: u64) -> Result<(), SoroswapLimitOrdersError> {
+    if e.ledger().timestamp() >= expiration {
+        Err(SoroswapLimitOrdersError::OrderExpired)
+    } else {
+        Ok(())
+    }
+}
+
+fn load_order(e: &Env, order_id: u64) -> Result<LimitOrder, SoroswapLimitOrdersError> {
+    get_order(e, order_id).ok_or(SoroswapLimitOrdersError::OrderDoesNotExist)
+}
+
+/// Returns up to `limit` of the orders in `order_ids`, starting at `offset`.
+fn orders_page(e: &Env, order_ids: Vec<u64>, offset: u32, limit: u32) -> Vec<LimitOrder> {
+    let mut orders: Vec<LimitOrder> = Vec::new(e);
+    let end = offset.saturating_add(limit).min(order_ids.len());
+    for i in offset..end {
+        orders.push_back(get_order(e, order_ids.get(i).unwrap()).unwrap());
+    }
+    orders
+}
+
+/// Swaps `amount_in` of the escrowed `token_in` of `order` through the router, requiring at least
+/// `amount_out_min`, and returns the output, which stays in this contract.
+fn swap_escrow(e: &Env, order: &LimitOrder, amount_in: i128, amount_out_min: i128) -> i128 {
+    let limit_orders = e.current_contract_address();
+
+    // The router pays the pair with the tokens of this contract, which has to authorize that transfer
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: order.token_in.clone(),
+                fn_name: Symbol::new(e, "transfer"),
+                args: (limit_orders.clone(), order.pair.clone(), amount_in).into_val(e),
+            },
+            sub_invocations: Vec::new(e),
+        }),
+    ]);
+
+    let amounts = SoroswapRouterClient::new(e, &get_router(e)).swap_exact_tokens_from(
+        &limit_orders,
+        &amount_in,
+        &amount_out_min,
+        &vec![e, order.token_in.clone(), order.token_out.clone()],
+        &limit_orders,
+        &(e.ledger().timestamp() + 1),
+    );
+    amounts.get(amounts.len() - 1).unwrap()
+}
+
+pub trait SoroswapLimitOrdersTrait {
+
+    /// Initializes the contract with the Soroswap router the orders are filled through.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `router` - The address of the Soroswap router. Its factory is read from it.
+    fn initialize(e: Env, router: Address) -> Result<(), SoroswapLimitOrdersError>;
+
+    /// Places an order selling `amount_in` of `token_in` for at least `amount_out_min` of `token_out`,
+    /// escrowing `amount_in` in this contract until the order is filled or cancelled.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The maker of the order. Must authorize the call.
+    /// * `token_in` - The token sold.
+    /// * `token_out` - The token bought. The pair of both tokens must exist.
+    /// * `amount_in` - The amount of `token_in` to sell.
+    /// * `amount_out_min` - The minimum amount of `token_out` for `amount_in`, which sets the limit price.
+    /// * `keeper_bounty_bps` - Basis points of the surplus above the limit price paid to keepers.
+    /// * `expiration` - The order can not be filled at or after this timestamp.
+    ///
+    /// # Returns
+    /// The id of the order.
+    fn place_order(
+        e: Env,
+        owner: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        keeper_bounty_bps: u32,
+        expiration: u64,
+    ) -> Result<u64, SoroswapLimitOrdersError>;
+
+    /// Fills `amount_in` of an order once `soroswap_library::get_amount_out` on its pair meets the
+    /// limit price. The swap executes through the router; the keeper receives its bounty out of
+    /// the surplus above the limit price and the owner the rest of the output.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `keeper` - The address that fills the order and receives the bounty. Must authorize the call.
+    /// * `order_id` - The id of the order.
+    /// * `amount_in` - The amount of the remaining `token_in` to fill.
+    ///
+    /// # Returns
+    /// A tuple with the amount of `token_out` sent to the owner and the keeper bounty.
+    fn fill_order(e: Env, keeper: Address, order_id: u64, amount_in: i128) -> Result<(i128, i128), SoroswapLimitOrdersError>;
+
+    /// Cancels an open order, expired or not, and refunds its remaining input to the owner.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `order_id` - The id of the order. Its owner must authorize the call.
+    ///
+    /// # Returns
+    /// The amount of `token_in` refunded.
+    fn cancel_order(e: Env, order_id: u64) -> Result<i128, SoroswapLimitOrdersError>;
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the router the orders are filled through.
+    fn get_router(e: Env) -> Result<Address, SoroswapLimitOrdersError>;
+
+    /// Returns an open order.
+    fn get_order(e: Env, order_id: u64) -> Result<LimitOrder, SoroswapLimitOrdersError>;
+
+    /// Returns up to `limit` open orders of `owner`, oldest first, skipping the first `offset`.
+    fn get_orders_by_owner(e: Env, owner: Address, offset: u32, limit: u32) -> Vec<LimitOrder>;
+
+    /// Returns up to `limit` open orders on the pair of `token_a` and `token_b`, in both directions,
+    /// oldest first, skipping the first `offset`.
+    fn get_orders_by_pair(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<LimitOrder>, SoroswapLimitOrdersError>;
+}
+
+#[contract]
+struct SoroswapLimitOrders;
+
+#[contractimpl]
+impl SoroswapLimitOrdersTrait for SoroswapLimitOrders {
+
+    /// Initializes the contract with the Soroswap router the orders are filled through.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `router` - The address of the Soroswap router. Its factory is read from it.
+    fn initialize(e: Env, router: Address) -> Result<(), SoroswapLimitOrdersError> {
+        if has_router(&e) {
+            return Err(SoroswapLimitOrdersError::InitializeAlreadyInitialized);
+        }
+        let factory = SoroswapRouterClient::new(&e, &router).get_factory();
+        put_router(&e, &router, &factory);
+        event::initialized(&e, router, factory);
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Places an order selling `amount_in` of `token_in` for at least `amount_out_min` of `token_out`,
+    /// escrowing `amount_in` in this contract until the order is filled or cancelled.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The maker of the order. Must authorize the call.
+    /// * `token_in` - The token sold.
+    /// * `token_out` - The token bought. The pair of both tokens must exist.
+    /// * `amount_in` - The amount of `token_in` to sell.
+    /// * `amount_out_min` - The minimum amount of `token_out` for `amount_in`, which sets the limit price.
+    /// * `keeper_bounty_bps` - Basis points of the surplus above the limit price paid to keepers.
+    /// * `expiration` - The order can not be filled at or after this timestamp.
+    ///
+    /// # Returns
+    /// The id of the order.
+    fn place_order(
+        e: Env,
+        owner: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        keeper_bounty_bps: u32,
+        expiration: u64,
+    ) -> Result<u64, SoroswapLimitOrdersError> {
+        check_initialized(&e)?;
+        check_positive_amount(amount_in)?;
+        check_positive_amount(amount_out_min)?;
+        if keeper_bounty_bps > MAX_KEEPER_BOUNTY_BPS {
+            return Err(SoroswapLimitOrdersError::InvalidKeeperBounty);
+        }
+        extend_instance_ttl(&e);
+        owner.require_auth();
+        check_not_expired(&e, expiration)?;
+
+        let factory = SoroswapFactoryClient::new(&e, &get_factory(&e));
+        if !factory.pair_exists(&token_in, &token_out) {
+            return Err(SoroswapLimitOrdersError::PairDoesNotExist);
+        }
+        let pair = soroswap_library::pair_for(e.clone(), get_factory(&e), token_in.clone(), token_out.clone())?;
+
+        TokenClient::new(&e, &token_in).transfer(&owner, &e.current_contract_address(), &amount_in);
+
+        let order = LimitOrder {
+            id: next_order_id(&e),
+            owner,
+            pair,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out_min,
+            amount_in_remaining: amount_in,
+            amount_out_filled: 0,
+            keeper_bounty_bps,
+            expiration,
+        };
+        add_order(&e, &order);
+
+        event::place(&e, event::PlaceOrderEvent {
+            order_id: order.id,
+            owner: order.owner,
+            token_in: order.token_in,
+            token_out: order.token_out,
+            amount_in,
+            amount_out_min,
+            keeper_bounty_bps,
+            expiration,
+        });
+        Ok(order.id)
+    }
+
+    /// Fills `amount_in` of an order once `soroswap_library::get_amount_out` on its pair meets the
+    /// limit price. The swap executes through the router; the keeper receives its bounty out of
+    /// the surplus above the limit price and the owner the rest of the output.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `keeper` - The address that fills the order and receives the bounty. Must authorize the call.
+    /// * `order_id` - The id of the order.
+    /// * `amount_in` - The amount of the remaining `token_in` to fill.
+    ///
+    /// # Returns
+    /// A tuple with the amount of `token_out` sent to the owner and the keeper bounty.
+    fn fill_order(e: Env, keeper: Address, order_id: u64, amount_in: i128) -> Result<(i128, i128), SoroswapLimitOrdersError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        keeper.require_auth();
+
+        let mut order = load_order(&e, order_id)?;
+        check_not_expired(&e, order.expiration)?;
+        if amount_in <= 0 || amount_in > order.amount_in_remaining {
+            return Err(SoroswapLimitOrdersError::InvalidFillAmount);
+        }
+
+        // The pair has to give at least the limit price for this fill
+        let (reserve_in, reserve_out) = soroswap_library::get_reserves(
+            e.clone(),
+            get_factory(&e),
+            order.token_in.clone(),
+            order.token_out.clone(),
+        )?;
+        let expected_amount_out = soroswap_library::get_amount_out(amount_in, reserve_in, reserve_out)?;
+        let owed_amount_out = limit_amount_out(&order, amount_in);
+        if expected_amount_out < owed_amount_out {
+            return Err(SoroswapLimitOrdersError::LimitPriceNotReached);
+        }
+
+        let amount_out = swap_escrow(&e, &order, amount_in, owed_amount_out);
+
+        // The keeper bounty comes out of the surplus above the limit price
+        let bounty = keeper_bounty(&order, amount_out.checked_sub(owed_amount_out).unwrap());
+        let owner_amount_out = amount_out.checked_sub(bounty).unwrap();
+        let token_out = TokenClient::new(&e, &order.token_out);
+        token_out.transfer(&e.current_contract_address(), &order.owner, &owner_amount_out);
+        if bounty > 0 {
+            token_out.transfer(&e.current_contract_address(), &keeper, &bounty);
+        }
+
+        order.amount_in_remaining = order.amount_in_remaining.checked_sub(amount_in).unwrap();
+        order.amount_out_filled = order.amount_out_filled.checked_add(owner_amount_out).unwrap();
+        if order.amount_in_remaining == 0 {
+            remove_order(&e, &order);
+        } else {
+            put_order(&e, &order);
+        }
+
+        event::fill(&e, event::FillOrderEvent {
+            order_id,
+            keeper,
+            amount_in,
+            amount_out: owner_amount_out,
+            keeper_bounty: bounty,
+            amount_in_remaining: order.amount_in_remaining,
+        });
+        Ok((owner_amount_out, bounty))
+    }
+
+    /// Cancels an open order, expired or not, and refunds its remaining input to the owner.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `order_id` - The id of the order. Its owner must authorize the call.
+    ///
+    /// # Returns
+    /// The amount of `token_in` refunded.
+    fn cancel_order(e: Env, order_id: u64) -> Result<i128, SoroswapLimitOrdersError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+
+        let order = load_order(&e, order_id)?;
+        order.owner.require_auth();
+
+        remove_order(&e, &order);
+        TokenClient::new(&e, &order.token_in).transfer(
+            &e.current_contract_address(),
+            &order.owner,
+            &order.amount_in_remaining,
+        );
+
+        event::cancel(&e, order_id, order.owner, order.amount_in_remaining);
+        Ok(order.amount_in_remaining)
+    }
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the router the orders are filled through.
+    fn get_router(e: Env) -> Result<Address, SoroswapLimitOrdersError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_router(&e))
+    }
+
+    /// Returns an open order.
+    fn get_order(e: Env, order_id: u64) -> Result<LimitOrder, SoroswapLimitOrdersError> {
+        load_order(&e, order_id)
+    }
+
+    /// Returns up to `limit` open orders of `owner`, oldest first, skipping the first `offset`.
+    fn get_orders_by_owner(e: Env, owner: Address, offset: u32, limit: u32) -> Vec<LimitOrder> {
+        orders_page(&e, get_owner_orders(&e, owner), offset, limit)
+    }
+
+    /// Returns up to `limit` open orders on the pair of `token_a` and `token_b`, in both directions,
+    /// oldest first, skipping the first `offset`.
+    fn get_orders_by_pair(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<LimitOrder>, SoroswapLimitOrdersError> {
+        check_initialized(&e)?;
+        let pair = soroswap_library::pair_for(e.clone(), get_factory(&e), token_a, token_b)?;
+        Ok(orders_page(&e, get_pair_orders(&e, pair), offset, limit))
+    }
+}
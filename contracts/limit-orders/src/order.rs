@@ -0,0 +1,56 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Basis points of the surplus a keeper bounty can take at most.
+pub const MAX_KEEPER_BOUNTY_BPS: u32 = 10_000;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// A resting order selling `token_in` for `token_out` at a limit price of `amount_out_min` per
+/// `amount_in`. The unfilled amount of `token_in` is escrowed by the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LimitOrder {
+    /// The id of the order.
+    pub id: u64,
+    /// The maker, who receives the output and the refunds.
+    pub owner: Address,
+    /// The Soroswap pair the order is filled against.
+    pub pair: Address,
+    /// The token sold.
+    pub token_in: Address,
+    /// The token bought.
+    pub token_out: Address,
+    /// The amount of `token_in` of the order.
+    pub amount_in: i128,
+    /// The minimum amount of `token_out` for the whole `amount_in`. Partial fills get it pro rata.
+    pub amount_out_min: i128,
+    /// The amount of `token_in` not filled yet.
+    pub amount_in_remaining: i128,
+    /// The amount of `token_out` sent to the owner so far.
+    pub amount_out_filled: i128,
+    /// Basis points of the surplus above the limit price paid to the keeper of each fill.
+    pub keeper_bounty_bps: u32,
+    /// The order can not be filled at or after this timestamp.
+    pub expiration: u64,
+}
+
+/// Returns the amount of `token_out` the owner is owed for a fill of `amount_in`: the limit price
+/// of the order applied to `amount_in`, rounded up.
+pub fn limit_amount_out(order: &LimitOrder, amount_in: i128) -> i128 {
+    let numerator = amount_in.checked_mul(order.amount_out_min).unwrap();
+    let quotient = numerator.checked_div(order.amount_in).unwrap();
+    if numerator % order.amount_in != 0 {
+        quotient.checked_add(1).unwrap()
+    } else {
+        quotient
+    }
+}
+
+/// Returns the keeper bounty of a fill: the order's basis points of the surplus above the limit price.
+pub fn keeper_bounty(order: &LimitOrder, surplus: i128) -> i128 {
+    surplus
+        .checked_mul(order.keeper_bounty_bps as i128)
+        .unwrap()
+        .checked_div(BPS_DENOMINATOR)
+        .unwrap()
+}
@@ -0,0 +1,123 @@
+use soroban_sdk::{contracttype, Env, Address, Vec};
+use crate::order::LimitOrder;
+
+#[derive(Clone)]
+#[contracttype]
+
+enum DataKey {
+    Router, // Address of the Soroswap Router the orders are filled through. Instance Data Type
+    Factory, // Address of the Soroswap Factory of the router. Instance Data Type
+    NextOrderId, // Id of the next order placed. Instance Data Type
+    Order(u64), // An open order. Persistent Data Type
+    OwnerOrders(Address), // Ids of the open orders of an owner, oldest first. Persistent Data Type
+    PairOrders(Address), // Ids of the open orders on a pair, oldest first. Persistent Data Type
+}
+
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+pub fn put_router(e: &Env, router: &Address, factory: &Address) {
+    e.storage().instance().set(&DataKey::Router, router);
+    e.storage().instance().set(&DataKey::Factory, factory);
+}
+
+pub fn has_router(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Router)
+}
+
+pub fn get_router(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Router).unwrap()
+}
+
+pub fn get_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Factory).unwrap()
+}
+
+/// Returns the id of the next order and increments it.
+pub fn next_order_id(e: &Env) -> u64 {
+    let order_id: u64 = e.storage().instance().get(&DataKey::NextOrderId).unwrap_or(0);
+    e.storage().instance().set(&DataKey::NextOrderId, &(order_id + 1));
+    order_id
+}
+
+pub fn put_order(e: &Env, order: &LimitOrder) {
+    let key = DataKey::Order(order.id);
+    e.storage().persistent().set(&key, order);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_order(e: &Env, order_id: u64) -> Option<LimitOrder> {
+    let key = DataKey::Order(order_id);
+    let order = e.storage().persistent().get::<DataKey, LimitOrder>(&key);
+    if order.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    order
+}
+
+/// Stores a new order and adds it to the indexes of its owner and its pair.
+pub fn add_order(e: &Env, order: &LimitOrder) {
+    put_order(e, order);
+    push_index(e, DataKey::OwnerOrders(order.owner.clone()), order.id);
+    push_index(e, DataKey::PairOrders(order.pair.clone()), order.id);
+}
+
+/// Removes a filled or cancelled order and drops it from the indexes of its owner and its pair.
+pub fn remove_order(e: &Env, order: &LimitOrder) {
+    e.storage().persistent().remove(&DataKey::Order(order.id));
+    remove_index(e, DataKey::OwnerOrders(order.owner.clone()), order.id);
+    remove_index(e, DataKey::PairOrders(order.pair.clone()), order.id);
+}
+
+pub fn get_owner_orders(e: &Env, owner: Address) -> Vec<u64> {
+    get_index(e, DataKey::OwnerOrders(owner))
+}
+
+pub fn get_pair_orders(e: &Env, pair: Address) -> Vec<u64> {
+    get_index(e, DataKey::PairOrders(pair))
+}
+
+fn get_index(e: &Env, key: DataKey) -> Vec<u64> {
+    if let Some(order_ids) = e.storage().persistent().get::<DataKey, Vec<u64>>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        order_ids
+    } else {
+        Vec::new(e)
+    }
+}
+
+fn push_index(e: &Env, key: DataKey, order_id: u64) {
+    let mut order_ids = get_index(e, key.clone());
+    order_ids.push_back(order_id);
+    e.storage().persistent().set(&key, &order_ids);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+fn remove_index(e: &Env, key: DataKey, order_id: u64) {
+    let mut order_ids = get_index(e, key.clone());
+    if let Some(index) = order_ids.first_index_of(order_id) {
+        order_ids.remove(index);
+    }
+    if order_ids.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &order_ids);
+    }
+}
@@ -0,0 +1,170 @@
+#![cfg(test)]
+extern crate std;
+use crate::{SoroswapLimitOrders, SoroswapLimitOrdersClient};
+use soroban_sdk::{
+    Env, 
+    vec,
+    BytesN, 
+    Address, 
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(&e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+fn pair_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// SoroswapFactory Contract
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryClient<'a> {
+    let pair_hash = pair_contract_wasm(&e);  
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address); 
+    factory.initialize(&setter, &pair_hash);
+    factory
+}
+
+// SoroswapRouter Contract
+mod router {
+    soroban_sdk::contractimport!(file = "../router/target/wasm32-unknown-unknown/release/soroswap_router.wasm");
+    pub type SoroswapRouterClient<'a> = Client<'a>;
+}
+use router::SoroswapRouterClient;
+
+fn create_soroswap_router<'a>(e: & Env, factory: & Address) -> SoroswapRouterClient<'a> {
+    let router = SoroswapRouterClient::new(e, &e.register_contract_wasm(None, router::WASM));
+    router.initialize(factory);
+    router
+}
+
+// SoroswapLimitOrders Contract
+fn create_soroswap_limit_orders<'a>(e: &Env) -> SoroswapLimitOrdersClient<'a> {
+    SoroswapLimitOrdersClient::new(e, &e.register_contract(None, SoroswapLimitOrders {}))
+}
+
+// SoroswapLimitOrders TEST
+
+pub struct SoroswapLimitOrdersTest<'a> {
+    env: Env,
+    contract: SoroswapLimitOrdersClient<'a>,
+    router: SoroswapRouterClient<'a>,
+    factory: SoroswapFactoryClient<'a>,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    admin: Address,
+    maker: Address,
+    keeper: Address,
+}
+
+impl<'a> SoroswapLimitOrdersTest<'a> {
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = 100;
+        });
+        let contract = create_soroswap_limit_orders(&env);
+
+        let admin = Address::generate(&env);
+        let maker = Address::generate(&env);
+        let keeper = Address::generate(&env);
+
+        let mut token_0 = create_token_contract(&env, &admin);
+        let mut token_1 = create_token_contract(&env, &admin);
+        if &token_1.address < &token_0.address {
+            std::mem::swap(&mut token_0, &mut token_1);
+        }
+        token_0.mint(&admin, &10_000_000_000_000_000_000);
+        token_1.mint(&admin, &10_000_000_000_000_000_000);
+        token_0.mint(&maker, &1_000_000_000);
+        token_1.mint(&maker, &1_000_000_000);
+
+        let factory = create_soroswap_factory(&env, &admin);
+        let router = create_soroswap_router(&env, &factory.address);
+        env.budget().reset_unlimited();
+
+        // 1 token_0 = 4 token_1
+        router.add_liquidity(
+            &token_0.address,
+            &token_1.address,
+            &1_000_000_000,
+            &4_000_000_000,
+            &0,
+            &0,
+            &admin,
+            &1000,
+        );
+
+        SoroswapLimitOrdersTest {
+            env,
+            contract,
+            router,
+            factory,
+            token_0,
+            token_1,
+            admin,
+            maker,
+            keeper,
+        }
+    }
+
+    fn setup_initialized() -> Self {
+        let test = Self::setup();
+        test.contract.initialize(&test.router.address);
+        test
+    }
+
+    /// Places an order of the maker selling `amount_in` token_0 for at least `amount_out_min` token_1,
+    /// with a keeper bounty of half the surplus, expiring at 1000.
+    fn place_sell_0(&self, amount_in: i128, amount_out_min: i128) -> u64 {
+        self.contract.place_order(
+            &self.maker,
+            &self.token_0.address,
+            &self.token_1.address,
+            &amount_in,
+            &amount_out_min,
+            &5_000,
+            &1000,
+        )
+    }
+
+    /// Swaps `amount_in` of `token_in` on the pair with the admin's funds, moving the price.
+    fn swap(&self, token_in: &Address, token_out: &Address, amount_in: i128) {
+        self.router.swap_exact_tokens_for_tokens(
+            &amount_in,
+            &0,
+            &vec![&self.env, token_in.clone(), token_out.clone()],
+            &self.admin,
+            &1000,
+        );
+    }
+}
+
+// Test mods:
+pub mod initialize;
+pub mod place;
+pub mod fill;
+pub mod cancel;
+pub mod views;
@@ -0,0 +1,52 @@
+use soroban_sdk::{testutils::{Events, Ledger}, vec, IntoVal, symbol_short};
+
+use crate::test::SoroswapLimitOrdersTest;
+use crate::error::SoroswapLimitOrdersError;
+use crate::event::CancelOrderEvent;
+
+#[test]
+fn cancel_order_refunds_remaining_input() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let order_id = test.place_sell_0(1_000_000, 3_900_000);
+    test.contract.fill_order(&test.keeper, &order_id, &400_000);
+
+    assert_eq!(test.contract.cancel_order(&order_id), 600_000);
+    assert_eq!(test.token_0.balance(&test.maker), 1_000_000_000 - 400_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(SoroswapLimitOrdersError::OrderDoesNotExist)));
+
+    let cancel_event = test.env.events().all().last().unwrap();
+    let expected_cancel_event: CancelOrderEvent = CancelOrderEvent {
+        order_id,
+        owner: test.maker.clone(),
+        amount_in_refunded: 600_000,
+    };
+    assert_eq!(
+        vec![&test.env, cancel_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapLimitOrders", symbol_short!("cancel")).into_val(&test.env),
+                (expected_cancel_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // A cancelled order can not be filled nor cancelled again
+    let result = test.contract.try_fill_order(&test.keeper, &order_id, &600_000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::OrderDoesNotExist)));
+    assert_eq!(test.contract.try_cancel_order(&order_id), Err(Ok(SoroswapLimitOrdersError::OrderDoesNotExist)));
+}
+
+#[test]
+fn cancel_expired_order() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let order_id = test.place_sell_0(1_000_000, 3_900_000);
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 2000;
+    });
+    assert_eq!(test.contract.cancel_order(&order_id), 1_000_000);
+    assert_eq!(test.token_0.balance(&test.maker), 1_000_000_000);
+}
@@ -0,0 +1,150 @@
+use soroban_sdk::{testutils::{Events, Ledger}, vec, IntoVal, symbol_short};
+
+use crate::test::SoroswapLimitOrdersTest;
+use crate::error::SoroswapLimitOrdersError;
+use crate::event::FillOrderEvent;
+
+fn quote_sell_0(test: &SoroswapLimitOrdersTest, amount_in: i128) -> i128 {
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    test.router.router_get_amounts_out(&amount_in, &path).get(1).unwrap()
+}
+
+#[test]
+fn fill_order_pays_owner_and_keeper() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let order_id = test.place_sell_0(1_000_000, 3_900_000);
+    assert_eq!(quote_sell_0(&test, 1_000_000), 3_984_027);
+
+    // The surplus above the limit is 84_027, half of it goes to the keeper
+    let result = test.contract.fill_order(&test.keeper, &order_id, &1_000_000);
+    assert_eq!(result, (3_942_014, 42_013));
+
+    assert_eq!(test.token_1.balance(&test.maker), 1_000_000_000 + 3_942_014);
+    assert_eq!(test.token_1.balance(&test.keeper), 42_013);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+
+    // A filled order is removed
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(SoroswapLimitOrdersError::OrderDoesNotExist)));
+
+    let fill_event = test.env.events().all().last().unwrap();
+    let expected_fill_event: FillOrderEvent = FillOrderEvent {
+        order_id,
+        keeper: test.keeper.clone(),
+        amount_in: 1_000_000,
+        amount_out: 3_942_014,
+        keeper_bounty: 42_013,
+        amount_in_remaining: 0,
+    };
+    assert_eq!(
+        vec![&test.env, fill_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapLimitOrders", symbol_short!("fill")).into_val(&test.env),
+                (expected_fill_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn fill_order_once_price_reaches_limit() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+
+    // Sell 1_000_000 token_0 for at least 4_000_000 token_1, above the current price
+    let order_id = test.place_sell_0(1_000_000, 4_000_000);
+    let result = test.contract.try_fill_order(&test.keeper, &order_id, &1_000_000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::LimitPriceNotReached)));
+    assert_eq!(test.token_0.balance(&test.contract.address), 1_000_000);
+
+    // Buying token_0 on the pair moves its price up
+    test.swap(&test.token_1.address, &test.token_0.address, 500_000_000);
+    let amount_out = quote_sell_0(&test, 1_000_000);
+    assert!(amount_out > 4_000_000);
+
+    let bounty = (amount_out - 4_000_000) / 2;
+    let result = test.contract.fill_order(&test.keeper, &order_id, &1_000_000);
+    assert_eq!(result, (amount_out - bounty, bounty));
+    assert_eq!(test.token_1.balance(&test.maker), 1_000_000_000 + amount_out - bounty);
+}
+
+#[test]
+fn fill_order_partially() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let order_id = test.place_sell_0(1_000_000, 3_900_000);
+
+    // 400_000 at the limit price are owed 1_560_000
+    let amount_out = quote_sell_0(&test, 400_000);
+    let bounty = (amount_out - 1_560_000) / 2;
+    let result = test.contract.fill_order(&test.keeper, &order_id, &400_000);
+    assert_eq!(result, (amount_out - bounty, bounty));
+
+    let order = test.contract.get_order(&order_id);
+    assert_eq!(order.amount_in_remaining, 600_000);
+    assert_eq!(order.amount_out_filled, amount_out - bounty);
+    assert_eq!(test.token_0.balance(&test.contract.address), 600_000);
+
+    // The rest of the order, owed 2_340_000
+    let second_amount_out = quote_sell_0(&test, 600_000);
+    let second_bounty = (second_amount_out - 2_340_000) / 2;
+    let result = test.contract.fill_order(&test.keeper, &order_id, &600_000);
+    assert_eq!(result, (second_amount_out - second_bounty, second_bounty));
+
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(SoroswapLimitOrdersError::OrderDoesNotExist)));
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(
+        test.token_1.balance(&test.maker),
+        1_000_000_000 + amount_out - bounty + second_amount_out - second_bounty
+    );
+    assert_eq!(test.token_1.balance(&test.keeper), bounty + second_bounty);
+}
+
+#[test]
+fn fill_order_without_keeper_bounty() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let order_id = test.contract.place_order(
+        &test.maker,
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000_000,
+        &3_900_000,
+        &0,
+        &1000,
+    );
+    let result = test.contract.fill_order(&test.keeper, &order_id, &1_000_000);
+    assert_eq!(result, (3_984_027, 0));
+    assert_eq!(test.token_1.balance(&test.keeper), 0);
+}
+
+#[test]
+fn fill_order_invalid_amount() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let order_id = test.place_sell_0(1_000_000, 3_900_000);
+
+    let result = test.contract.try_fill_order(&test.keeper, &order_id, &0);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::InvalidFillAmount)));
+
+    let result = test.contract.try_fill_order(&test.keeper, &order_id, &1_000_001);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::InvalidFillAmount)));
+}
+
+#[test]
+fn fill_order_expired() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let order_id = test.place_sell_0(1_000_000, 3_900_000);
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 1000;
+    });
+    let result = test.contract.try_fill_order(&test.keeper, &order_id, &1_000_000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::OrderExpired)));
+}
+
+#[test]
+fn fill_order_does_not_exist() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let result = test.contract.try_fill_order(&test.keeper, &0, &1_000_000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::OrderDoesNotExist)));
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, IntoVal, symbol_short};
+
+use crate::test::SoroswapLimitOrdersTest;
+use crate::error::SoroswapLimitOrdersError;
+use crate::event::InitializedEvent;
+
+#[test]
+fn initialize_and_get_router() {
+    let test = SoroswapLimitOrdersTest::setup();
+    test.contract.initialize(&test.router.address);
+    assert_eq!(test.contract.get_router(), test.router.address);
+
+    // The factory is read from the router
+    let initialized_event = test.env.events().all().last().unwrap();
+    let expected_initialized_event: InitializedEvent = InitializedEvent {
+        router: test.router.address.clone(),
+        factory: test.factory.address.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, initialized_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapLimitOrders", symbol_short!("init")).into_val(&test.env),
+                (expected_initialized_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn not_yet_initialized() {
+    let test = SoroswapLimitOrdersTest::setup();
+    assert_eq!(test.contract.try_get_router(), Err(Ok(SoroswapLimitOrdersError::NotInitialized)));
+
+    let result = test.contract.try_place_order(
+        &test.maker,
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000_000,
+        &3_900_000,
+        &5_000,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::NotInitialized)));
+
+    let result = test.contract.try_fill_order(&test.keeper, &0, &1_000_000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::NotInitialized)));
+
+    let result = test.contract.try_cancel_order(&0);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::NotInitialized)));
+
+    let result = test.contract.try_get_orders_by_pair(&test.token_0.address, &test.token_1.address, &0, &10);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::NotInitialized)));
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let result = test.contract.try_initialize(&Address::generate(&test.env));
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::InitializeAlreadyInitialized)));
+}
@@ -0,0 +1,92 @@
+use soroban_sdk::{testutils::Events, vec, IntoVal, symbol_short};
+
+use crate::test::{SoroswapLimitOrdersTest, create_token_contract};
+use crate::error::SoroswapLimitOrdersError;
+use crate::event::PlaceOrderEvent;
+use crate::LimitOrder;
+
+#[test]
+fn place_order_escrows_input() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let order_id = test.place_sell_0(1_000_000, 3_900_000);
+    assert_eq!(order_id, 0);
+
+    assert_eq!(test.token_0.balance(&test.maker), 1_000_000_000 - 1_000_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 1_000_000);
+
+    let pair = test.router.router_pair_for(&test.token_0.address, &test.token_1.address);
+    assert_eq!(test.contract.get_order(&order_id), LimitOrder {
+        id: 0,
+        owner: test.maker.clone(),
+        pair,
+        token_in: test.token_0.address.clone(),
+        token_out: test.token_1.address.clone(),
+        amount_in: 1_000_000,
+        amount_out_min: 3_900_000,
+        amount_in_remaining: 1_000_000,
+        amount_out_filled: 0,
+        keeper_bounty_bps: 5_000,
+        expiration: 1000,
+    });
+
+    let place_event = test.env.events().all().last().unwrap();
+    let expected_place_event: PlaceOrderEvent = PlaceOrderEvent {
+        order_id: 0,
+        owner: test.maker.clone(),
+        token_in: test.token_0.address.clone(),
+        token_out: test.token_1.address.clone(),
+        amount_in: 1_000_000,
+        amount_out_min: 3_900_000,
+        keeper_bounty_bps: 5_000,
+        expiration: 1000,
+    };
+    assert_eq!(
+        vec![&test.env, place_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapLimitOrders", symbol_short!("place")).into_val(&test.env),
+                (expected_place_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // Ids are sequential
+    assert_eq!(test.place_sell_0(1_000_000, 3_900_000), 1);
+}
+
+#[test]
+fn place_order_invalid_amounts() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let result = test.contract.try_place_order(&test.maker, &test.token_0.address, &test.token_1.address, &0, &3_900_000, &5_000, &1000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::InvalidAmount)));
+
+    let result = test.contract.try_place_order(&test.maker, &test.token_0.address, &test.token_1.address, &1_000_000, &-1, &5_000, &1000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::InvalidAmount)));
+}
+
+#[test]
+fn place_order_invalid_keeper_bounty() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let result = test.contract.try_place_order(&test.maker, &test.token_0.address, &test.token_1.address, &1_000_000, &3_900_000, &10_001, &1000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::InvalidKeeperBounty)));
+
+    // The whole surplus can go to the keeper
+    test.contract.place_order(&test.maker, &test.token_0.address, &test.token_1.address, &1_000_000, &3_900_000, &10_000, &1000);
+}
+
+#[test]
+fn place_order_expired() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let result = test.contract.try_place_order(&test.maker, &test.token_0.address, &test.token_1.address, &1_000_000, &3_900_000, &5_000, &100);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::OrderExpired)));
+}
+
+#[test]
+fn place_order_pair_does_not_exist() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    let result = test.contract.try_place_order(&test.maker, &test.token_0.address, &token_2.address, &1_000_000, &3_900_000, &5_000, &1000);
+    assert_eq!(result, Err(Ok(SoroswapLimitOrdersError::PairDoesNotExist)));
+}
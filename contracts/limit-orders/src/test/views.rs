@@ -0,0 +1,71 @@
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::test::SoroswapLimitOrdersTest;
+
+#[test]
+fn orders_by_owner_and_pair() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    let other_maker = Address::generate(&test.env);
+    test.token_1.transfer(&test.maker, &other_maker, &100_000_000);
+
+    let first = test.place_sell_0(1_000_000, 3_900_000);
+    let second = test.contract.place_order(
+        &other_maker,
+        &test.token_1.address,
+        &test.token_0.address,
+        &4_000_000,
+        &900_000,
+        &5_000,
+        &1000,
+    );
+    let third = test.place_sell_0(2_000_000, 7_800_000);
+
+    let maker_orders = test.contract.get_orders_by_owner(&test.maker, &0, &10);
+    assert_eq!(maker_orders.len(), 2);
+    assert_eq!(maker_orders.get(0).unwrap().id, first);
+    assert_eq!(maker_orders.get(1).unwrap().id, third);
+
+    let other_orders = test.contract.get_orders_by_owner(&other_maker, &0, &10);
+    assert_eq!(other_orders.len(), 1);
+    assert_eq!(other_orders.get(0).unwrap().id, second);
+
+    // Both directions of the pair, in either token order
+    let pair_orders = test.contract.get_orders_by_pair(&test.token_1.address, &test.token_0.address, &0, &10);
+    assert_eq!(pair_orders.len(), 3);
+    assert_eq!(pair_orders.get(0).unwrap().id, first);
+    assert_eq!(pair_orders.get(1).unwrap().id, second);
+    assert_eq!(pair_orders.get(2).unwrap().id, third);
+
+    // Filled and cancelled orders leave the indexes
+    test.contract.fill_order(&test.keeper, &first, &1_000_000);
+    test.contract.cancel_order(&second);
+    let pair_orders = test.contract.get_orders_by_pair(&test.token_0.address, &test.token_1.address, &0, &10);
+    assert_eq!(pair_orders.len(), 1);
+    assert_eq!(pair_orders.get(0).unwrap().id, third);
+    assert_eq!(test.contract.get_orders_by_owner(&other_maker, &0, &10).len(), 0);
+}
+
+#[test]
+fn orders_pagination() {
+    let test = SoroswapLimitOrdersTest::setup_initialized();
+    for _ in 0..5 {
+        test.place_sell_0(1_000_000, 3_900_000);
+    }
+
+    let page = test.contract.get_orders_by_owner(&test.maker, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 0);
+    assert_eq!(page.get(1).unwrap().id, 1);
+
+    let page = test.contract.get_orders_by_owner(&test.maker, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 2);
+    assert_eq!(page.get(1).unwrap().id, 3);
+
+    let page = test.contract.get_orders_by_pair(&test.token_0.address, &test.token_1.address, &4, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 4);
+
+    assert_eq!(test.contract.get_orders_by_owner(&test.maker, &5, &2).len(), 0);
+    assert_eq!(test.contract.get_orders_by_owner(&test.maker, &7, &2).len(), 0);
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{self, contracterror};
+
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapMigratorError {
+    /// SoroswapMigrator: not yet initialized
+    NotInitialized = 601,
+
+    /// SoroswapMigrator: already initialized
+    InitializeAlreadyInitialized = 602,
+
+    /// SoroswapMigrator: the old and the new factory must be different
+    InitializeSameFactory = 603,
+
+    /// SoroswapMigrator: negative amount is not allowed
+    NegativeNotAllowed = 604,
+
+    /// SoroswapMigrator: deadline expired
+    DeadlineExpired = 605,
+
+    /// SoroswapMigrator: pair does not exist in the old factory
+    PairDoesNotExist = 606,
+
+    /// SoroswapMigrator: insufficient a amount
+    InsufficientAAmount = 607,
+
+    /// SoroswapMigrator: insufficient b amount
+    InsufficientBAmount = 608,
+}
@@ -0,0 +1,54 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address};
+
+// INITIALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub old_factory: Address,
+    pub new_factory: Address
+}
+
+/// Publishes an `InitializedEvent` when the migrator is initialized.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `old_factory` - The factory the positions are migrated from.
+/// * `new_factory` - The factory the positions are migrated to.
+pub(crate) fn initialized(e: &Env, old_factory: Address, new_factory: Address) {
+    let event = InitializedEvent {
+        old_factory,
+        new_factory,
+    };
+    e.events().publish(("SoroswapMigrator", symbol_short!("init")), event);
+}
+
+// MIGRATE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateEvent {
+    pub token_a: Address,
+    pub token_b: Address,
+    pub old_pair: Address,
+    pub new_pair: Address,
+    pub old_liquidity: i128,
+    pub amount_a: i128,
+    pub amount_b: i128,
+    pub new_liquidity: i128,
+    pub refund_a: i128,
+    pub refund_b: i128,
+    pub from: Address,
+    pub to: Address
+}
+
+/// Publishes a `MigrateEvent` when a position is moved from the old factory to the new one.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `event` - The migrated position: the LP tokens burned in the old pair, the amounts deposited
+///   in the new pair, the LP tokens minted for them and the amounts refunded to `from`.
+pub(crate) fn migrate(e: &Env, event: MigrateEvent) {
+    e.events().publish(("SoroswapMigrator", symbol_short!("migrate")), event);
+}
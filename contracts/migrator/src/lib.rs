@@ -0,0 +1,263 @@
+#![no_std]
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+mod pair;
+mod factory;
+mod test;
+mod event;
+mod storage;
+mod error;
+
+use factory::SoroswapFactoryClient;
+use pair::SoroswapPairClient;
+use storage::{
+    put_factories,
+    has_factories,
+    get_old_factory,
+    get_new_factory,
+    extend_instance_ttl,
+};
+pub use error::SoroswapMigratorError;
+pub use event::MigrateEvent;
+
+fn check_nonnegative_amount(amount: i128) -> Result<(), SoroswapMigratorError> {
+    if amount < 0 {
+        Err(SoroswapMigratorError::NegativeNotAllowed)
+    } else {
+        Ok(())
+    }
+}
+
+fn ensure_deadline(e: &Env, timestamp: u64) -> Result<(), SoroswapMigratorError> {
+    let ledger_timestamp = e.ledger().timestamp();
+    if ledger_timestamp >= timestamp {
+        Err(SoroswapMigratorError::DeadlineExpired)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_initialized(e: &Env) -> Result<(), SoroswapMigratorError> {
+    if has_factories(e) {
+        Ok(())
+    } else {
+        Err(SoroswapMigratorError::NotInitialized)
+    }
+}
+
+/// Returns the amounts of token A and B, out of `amount_a` and `amount_b`, that match the ratio of
+/// the reserves of the target pair. When the pair has no reserves everything is deposited.
+fn deposit_amounts(reserve_a: i128, reserve_b: i128, amount_a: i128, amount_b: i128) -> (i128, i128) {
+    if reserve_a == 0 && reserve_b == 0 {
+        return (amount_a, amount_b);
+    }
+    let amount_b_optimal = amount_a.checked_mul(reserve_b).unwrap().checked_div(reserve_a).unwrap();
+    if amount_b_optimal <= amount_b {
+        (amount_a, amount_b_optimal)
+    } else {
+        let amount_a_optimal = amount_b.checked_mul(reserve_a).unwrap().checked_div(reserve_b).unwrap();
+        (amount_a_optimal, amount_b)
+    }
+}
+
+pub trait SoroswapMigratorTrait {
+
+    /// Initializes the contract with the factory positions are migrated from and the factory they are
+    /// migrated to.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `old_factory` - The address of the Soroswap factory whose pairs are migrated.
+    /// * `new_factory` - The address of the Soroswap factory receiving the liquidity.
+    fn initialize(e: Env, old_factory: Address, new_factory: Address) -> Result<(), SoroswapMigratorError>;
+
+    /// Moves a liquidity position from the `token_a`/`token_b` pair of the old factory to the same pair
+    /// of the new factory, creating it if needed. The LP tokens are burned through
+    /// `SoroswapPair::withdraw`, the tokens are deposited at the new pair's ratio and the leftovers
+    /// are refunded to `from`.
+    ///
+    /// # Arguments
+    /// * `from` - The owner of the LP tokens of the old pair.
+    /// * `token_a` - The first token of the pair.
+    /// * `token_b` - The second token of the pair.
+    /// * `liquidity` - The LP tokens of the old pair to migrate.
+    /// * `amount_a_min` - The minimum amount of token A to deposit in the new pair.
+    /// * `amount_b_min` - The minimum amount of token B to deposit in the new pair.
+    /// * `to` - The receiver of the LP tokens of the new pair.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amounts of token A and B deposited in the new pair and the LP tokens minted.
+    fn migrate(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), SoroswapMigratorError>;
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the factory positions are migrated from.
+    fn get_old_factory(e: Env) -> Result<Address, SoroswapMigratorError>;
+
+    /// Returns the factory positions are migrated to.
+    fn get_new_factory(e: Env) -> Result<Address, SoroswapMigratorError>;
+}
+
+#[contract]
+struct SoroswapMigrator;
+
+#[contractimpl]
+impl SoroswapMigratorTrait for SoroswapMigrator {
+
+    /// Initializes the contract with the factory positions are migrated from and the factory they are
+    /// migrated to.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `old_factory` - The address of the Soroswap factory whose pairs are migrated.
+    /// * `new_factory` - The address of the Soroswap factory receiving the liquidity.
+    fn initialize(e: Env, old_factory: Address, new_factory: Address) -> Result<(), SoroswapMigratorError> {
+        if has_factories(&e) {
+            return Err(SoroswapMigratorError::InitializeAlreadyInitialized);
+        }
+        if old_factory == new_factory {
+            return Err(SoroswapMigratorError::InitializeSameFactory);
+        }
+        put_factories(&e, &old_factory, &new_factory);
+        event::initialized(&e, old_factory, new_factory);
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Moves a liquidity position from the `token_a`/`token_b` pair of the old factory to the same pair
+    /// of the new factory, creating it if needed. The LP tokens are burned through
+    /// `SoroswapPair::withdraw`, the tokens are deposited at the new pair's ratio and the leftovers
+    /// are refunded to `from`.
+    ///
+    /// # Arguments
+    /// * `from` - The owner of the LP tokens of the old pair.
+    /// * `token_a` - The first token of the pair.
+    /// * `token_b` - The second token of the pair.
+    /// * `liquidity` - The LP tokens of the old pair to migrate.
+    /// * `amount_a_min` - The minimum amount of token A to deposit in the new pair.
+    /// * `amount_b_min` - The minimum amount of token B to deposit in the new pair.
+    /// * `to` - The receiver of the LP tokens of the new pair.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amounts of token A and B deposited in the new pair and the LP tokens minted.
+    fn migrate(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), SoroswapMigratorError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(liquidity)?;
+        check_nonnegative_amount(amount_a_min)?;
+        check_nonnegative_amount(amount_b_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let old_factory = SoroswapFactoryClient::new(&e, &get_old_factory(&e));
+        if !old_factory.pair_exists(&token_a, &token_b) {
+            return Err(SoroswapMigratorError::PairDoesNotExist);
+        }
+        let old_pair_address = old_factory.get_pair(&token_a, &token_b);
+        let old_pair = SoroswapPairClient::new(&e, &old_pair_address);
+
+        // Burn the LP tokens in the old pair, the withdrawn tokens stay in this contract
+        TokenClient::new(&e, &old_pair_address).transfer(&from, &old_pair_address, &liquidity);
+        let (amount_0, amount_1) = old_pair.withdraw(&e.current_contract_address());
+        let (withdrawn_a, withdrawn_b) = if token_a == old_pair.token_0() {
+            (amount_0, amount_1)
+        } else {
+            (amount_1, amount_0)
+        };
+
+        // Checks if the pair exists in the new factory; otherwise, creates it
+        let new_factory = SoroswapFactoryClient::new(&e, &get_new_factory(&e));
+        if !new_factory.pair_exists(&token_a, &token_b) {
+            new_factory.create_pair(&token_a, &token_b);
+        }
+        let new_pair_address = new_factory.get_pair(&token_a, &token_b);
+        let new_pair = SoroswapPairClient::new(&e, &new_pair_address);
+
+        let (reserve_0, reserve_1) = new_pair.get_reserves();
+        let (reserve_a, reserve_b) = if token_a == new_pair.token_0() {
+            (reserve_0, reserve_1)
+        } else {
+            (reserve_1, reserve_0)
+        };
+        let (amount_a, amount_b) = deposit_amounts(reserve_a, reserve_b, withdrawn_a, withdrawn_b);
+        if amount_a < amount_a_min {
+            return Err(SoroswapMigratorError::InsufficientAAmount);
+        }
+        if amount_b < amount_b_min {
+            return Err(SoroswapMigratorError::InsufficientBAmount);
+        }
+
+        // Deposit in the new pair and refund what it did not take
+        let migrator = e.current_contract_address();
+        let token_a_client = TokenClient::new(&e, &token_a);
+        let token_b_client = TokenClient::new(&e, &token_b);
+        token_a_client.transfer(&migrator, &new_pair_address, &amount_a);
+        token_b_client.transfer(&migrator, &new_pair_address, &amount_b);
+        let new_liquidity = new_pair.deposit(&to);
+
+        let refund_a = withdrawn_a.checked_sub(amount_a).unwrap();
+        let refund_b = withdrawn_b.checked_sub(amount_b).unwrap();
+        if refund_a > 0 {
+            token_a_client.transfer(&migrator, &from, &refund_a);
+        }
+        if refund_b > 0 {
+            token_b_client.transfer(&migrator, &from, &refund_b);
+        }
+
+        event::migrate(&e, MigrateEvent {
+            token_a,
+            token_b,
+            old_pair: old_pair_address,
+            new_pair: new_pair_address,
+            old_liquidity: liquidity,
+            amount_a,
+            amount_b,
+            new_liquidity,
+            refund_a,
+            refund_b,
+            from,
+            to,
+        });
+
+        Ok((amount_a, amount_b, new_liquidity))
+    }
+
+    /*  *** Read only functions: *** */
+
+    /// Returns the factory positions are migrated from.
+    fn get_old_factory(e: Env) -> Result<Address, SoroswapMigratorError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_old_factory(&e))
+    }
+
+    /// Returns the factory positions are migrated to.
+    fn get_new_factory(e: Env) -> Result<Address, SoroswapMigratorError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_new_factory(&e))
+    }
+}
@@ -0,0 +1,4 @@
+soroban_sdk::contractimport!(
+    file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+);
+pub type SoroswapPairClient<'a> = Client<'a>;
\ No newline at end of file
@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Env, Address};
+
+#[derive(Clone)]
+#[contracttype]
+
+enum DataKey {
+    OldFactory, // Address of the Factory the positions are migrated from. Instance Data Type
+    NewFactory, // Address of the Factory the positions are migrated to. Instance Data Type
+}
+
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+pub fn put_factories(e: &Env, old_factory: &Address, new_factory: &Address) {
+    e.storage().instance().set(&DataKey::OldFactory, old_factory);
+    e.storage().instance().set(&DataKey::NewFactory, new_factory);
+}
+
+pub fn has_factories(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::OldFactory)
+}
+
+pub fn get_old_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::OldFactory).unwrap()
+}
+
+pub fn get_new_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::NewFactory).unwrap()
+}
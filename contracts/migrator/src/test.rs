@@ -0,0 +1,132 @@
+#![cfg(test)]
+extern crate std;
+use crate::{SoroswapMigrator, SoroswapMigratorClient};
+use soroban_sdk::{
+    Env, 
+    BytesN, 
+    Address, 
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(&e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+// Pair Contract
+mod pair {
+    soroban_sdk::contractimport!(file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm");
+   pub type SoroswapPairClient<'a> = Client<'a>;
+}
+use pair::SoroswapPairClient;
+
+
+fn pair_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// SoroswapFactory Contract
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryClient<'a> {
+    let pair_hash = pair_contract_wasm(&e);  
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address); 
+    factory.initialize(&setter, &pair_hash);
+    factory
+}
+
+// SoroswapMigrator Contract
+fn create_soroswap_migrator<'a>(e: &Env) -> SoroswapMigratorClient<'a> {
+    SoroswapMigratorClient::new(e, &e.register_contract(None, SoroswapMigrator {}))
+}
+
+/// Deposits `amount_0` and `amount_1` in the pair of `factory`, creating it if needed, and returns
+/// the pair and the LP tokens minted to `to`.
+pub fn add_liquidity<'a>(
+    test: &SoroswapMigratorTest<'a>,
+    factory: &SoroswapFactoryClient,
+    amount_0: i128,
+    amount_1: i128,
+    to: &Address,
+) -> (SoroswapPairClient<'a>, i128) {
+    if !factory.pair_exists(&test.token_0.address, &test.token_1.address) {
+        factory.create_pair(&test.token_0.address, &test.token_1.address);
+    }
+    let pair = SoroswapPairClient::new(&test.env, &factory.get_pair(&test.token_0.address, &test.token_1.address));
+    test.token_0.transfer(&test.user, &pair.address, &amount_0);
+    test.token_1.transfer(&test.user, &pair.address, &amount_1);
+    let liquidity = pair.deposit(to);
+    (pair, liquidity)
+}
+
+// SoroswapMigrator TEST
+
+pub struct SoroswapMigratorTest<'a> {
+    env: Env,
+    contract: SoroswapMigratorClient<'a>,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    old_factory: SoroswapFactoryClient<'a>,
+    new_factory: SoroswapFactoryClient<'a>,
+    user: Address,
+}
+
+impl<'a> SoroswapMigratorTest<'a> {
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = 100;
+        });
+        let contract = create_soroswap_migrator(&env);
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        assert_ne!(admin, user);
+
+        let mut token_0 = create_token_contract(&env, &admin);
+        let mut token_1 = create_token_contract(&env, &admin);
+        if &token_1.address < &token_0.address {
+            std::mem::swap(&mut token_0, &mut token_1);
+        }
+        token_0.mint(&user, &10_000_000_000_000_000_000);
+        token_1.mint(&user, &10_000_000_000_000_000_000);
+
+        // Two factory instances side by side, deploying the same pair wasm
+        let old_factory = create_soroswap_factory(&env, &admin);
+        let new_factory = create_soroswap_factory(&env, &admin);
+        env.budget().reset_unlimited();
+
+        SoroswapMigratorTest {
+            env,
+            contract,
+            token_0,
+            token_1,
+            old_factory,
+            new_factory,
+            user,
+        }
+    }
+}
+
+// Test mods:
+pub mod initialize;
+pub mod migrate;
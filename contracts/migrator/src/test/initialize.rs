@@ -0,0 +1,52 @@
+use soroban_sdk::{testutils::Events, vec, IntoVal, symbol_short};
+
+use crate::test::SoroswapMigratorTest;
+use crate::error::SoroswapMigratorError;
+use crate::event::InitializedEvent;
+
+#[test]
+fn initialize_and_get_factories() {
+    let test = SoroswapMigratorTest::setup();
+    test.contract.initialize(&test.old_factory.address, &test.new_factory.address);
+    assert_eq!(test.contract.get_old_factory(), test.old_factory.address);
+    assert_eq!(test.contract.get_new_factory(), test.new_factory.address);
+
+    let initialized_event = test.env.events().all().last().unwrap();
+    let expected_initialized_event: InitializedEvent = InitializedEvent {
+        old_factory: test.old_factory.address.clone(),
+        new_factory: test.new_factory.address.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, initialized_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapMigrator", symbol_short!("init")).into_val(&test.env),
+                (expected_initialized_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn get_factories_not_yet_initialized() {
+    let test = SoroswapMigratorTest::setup();
+    assert_eq!(test.contract.try_get_old_factory(), Err(Ok(SoroswapMigratorError::NotInitialized)));
+    assert_eq!(test.contract.try_get_new_factory(), Err(Ok(SoroswapMigratorError::NotInitialized)));
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapMigratorTest::setup();
+    test.contract.initialize(&test.old_factory.address, &test.new_factory.address);
+    let result = test.contract.try_initialize(&test.new_factory.address, &test.old_factory.address);
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_same_factory() {
+    let test = SoroswapMigratorTest::setup();
+    let result = test.contract.try_initialize(&test.old_factory.address, &test.old_factory.address);
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::InitializeSameFactory)));
+}
@@ -0,0 +1,216 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, IntoVal, symbol_short};
+
+use crate::test::{SoroswapMigratorTest, SoroswapPairClient, add_liquidity};
+use crate::error::SoroswapMigratorError;
+use crate::event::MigrateEvent;
+
+fn setup_initialized<'a>() -> SoroswapMigratorTest<'a> {
+    let test = SoroswapMigratorTest::setup();
+    test.contract.initialize(&test.old_factory.address, &test.new_factory.address);
+    test
+}
+
+#[test]
+fn migrate_not_yet_initialized() {
+    let test = SoroswapMigratorTest::setup();
+    let result = test.contract.try_migrate(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000,
+        &0,
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::NotInitialized)));
+}
+
+#[test]
+fn migrate_negative_amounts() {
+    let test = setup_initialized();
+    let result = test.contract.try_migrate(&test.user, &test.token_0.address, &test.token_1.address, &-1, &0, &0, &test.user, &1000);
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::NegativeNotAllowed)));
+
+    let result = test.contract.try_migrate(&test.user, &test.token_0.address, &test.token_1.address, &1_000, &-1, &0, &test.user, &1000);
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::NegativeNotAllowed)));
+
+    let result = test.contract.try_migrate(&test.user, &test.token_0.address, &test.token_1.address, &1_000, &0, &-1, &test.user, &1000);
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::NegativeNotAllowed)));
+}
+
+#[test]
+fn migrate_deadline_expired() {
+    let test = setup_initialized();
+    let result = test.contract.try_migrate(&test.user, &test.token_0.address, &test.token_1.address, &1_000, &0, &0, &test.user, &100);
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::DeadlineExpired)));
+}
+
+#[test]
+fn migrate_pair_does_not_exist() {
+    let test = setup_initialized();
+
+    // A pair in the new factory only is not enough
+    add_liquidity(&test, &test.new_factory, 1_000_000_000, 4_000_000_000, &test.user);
+    let result = test.contract.try_migrate(&test.user, &test.token_0.address, &test.token_1.address, &1_000, &0, &0, &test.user, &1000);
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::PairDoesNotExist)));
+}
+
+#[test]
+fn migrate_creates_new_pair() {
+    let test = setup_initialized();
+    let (old_pair, liquidity) = add_liquidity(&test, &test.old_factory, 1_000_000_000, 4_000_000_000, &test.user);
+    assert_eq!(liquidity, 2_000_000_000 - 1_000);
+    assert!(!test.new_factory.pair_exists(&test.token_0.address, &test.token_1.address));
+
+    let user_balance_0 = test.token_0.balance(&test.user);
+    let user_balance_1 = test.token_1.balance(&test.user);
+
+    let result = test.contract.migrate(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &(1_000_000_000 - 500),
+        &(4_000_000_000 - 2_000),
+        &test.user,
+        &1000,
+    );
+
+    // The old pair pays out its balances pro rata, and the new pair locks MINIMUM_LIQUIDITY again
+    assert_eq!(result, (1_000_000_000 - 500, 4_000_000_000 - 2_000, 2_000_000_000 - 2_000));
+
+    let new_pair_address = test.new_factory.get_pair(&test.token_0.address, &test.token_1.address);
+    assert_ne!(new_pair_address, old_pair.address);
+    let new_pair = SoroswapPairClient::new(&test.env, &new_pair_address);
+    assert_eq!(new_pair.get_reserves(), (1_000_000_000 - 500, 4_000_000_000 - 2_000));
+    assert_eq!(new_pair.balance(&test.user), 2_000_000_000 - 2_000);
+    assert_eq!(old_pair.balance(&test.user), 0);
+    assert_eq!(old_pair.get_reserves(), (500, 2_000));
+
+    // Nothing is refunded and nothing stays in the migrator
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0);
+    assert_eq!(test.token_1.balance(&test.user), user_balance_1);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn migrate_into_existing_pair_refunds_leftovers() {
+    let test = setup_initialized();
+    let lp = Address::generate(&test.env);
+    let receiver = Address::generate(&test.env);
+    let (old_pair, liquidity) = add_liquidity(&test, &test.old_factory, 1_000_000_000, 4_000_000_000, &test.user);
+
+    // The new pair trades at a different ratio: 1 token_0 = 2 token_1
+    let (new_pair, _) = add_liquidity(&test, &test.new_factory, 1_000_000_000, 2_000_000_000, &lp);
+
+    let user_balance_0 = test.token_0.balance(&test.user);
+    let user_balance_1 = test.token_1.balance(&test.user);
+
+    // Half of the position withdraws 500_000_000 - 250 of token_0 and 2_000_000_000 - 1_000 of token_1
+    let migrated = liquidity / 2;
+    let result = test.contract.migrate(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &migrated,
+        &0,
+        &0,
+        &receiver,
+        &1000,
+    );
+
+    // Only the token_1 matching the new ratio is deposited, the rest goes back to the user
+    assert_eq!(result, (500_000_000 - 250, 1_000_000_000 - 500, 707_106_427));
+    assert_eq!(new_pair.balance(&receiver), 707_106_427);
+    assert_eq!(new_pair.balance(&test.user), 0);
+    assert_eq!(old_pair.balance(&test.user), liquidity - migrated);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0);
+    assert_eq!(test.token_1.balance(&test.user), user_balance_1 + 1_000_000_000 - 500);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+
+    let migrate_event = test.env.events().all().last().unwrap();
+    let expected_migrate_event: MigrateEvent = MigrateEvent {
+        token_a: test.token_0.address.clone(),
+        token_b: test.token_1.address.clone(),
+        old_pair: old_pair.address.clone(),
+        new_pair: new_pair.address.clone(),
+        old_liquidity: migrated,
+        amount_a: 500_000_000 - 250,
+        amount_b: 1_000_000_000 - 500,
+        new_liquidity: 707_106_427,
+        refund_a: 0,
+        refund_b: 1_000_000_000 - 500,
+        from: test.user.clone(),
+        to: receiver.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, migrate_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapMigrator", symbol_short!("migrate")).into_val(&test.env),
+                (expected_migrate_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn migrate_reversed_token_order() {
+    let test = setup_initialized();
+    let (_, liquidity) = add_liquidity(&test, &test.old_factory, 1_000_000_000, 4_000_000_000, &test.user);
+    add_liquidity(&test, &test.new_factory, 1_000_000_000, 2_000_000_000, &test.user);
+
+    // Amounts follow the order of the arguments
+    let result = test.contract.migrate(
+        &test.user,
+        &test.token_1.address,
+        &test.token_0.address,
+        &(liquidity / 2),
+        &(1_000_000_000 - 500),
+        &(500_000_000 - 250),
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, (1_000_000_000 - 500, 500_000_000 - 250, 707_106_427));
+}
+
+#[test]
+fn migrate_insufficient_amounts() {
+    let test = setup_initialized();
+    let (old_pair, liquidity) = add_liquidity(&test, &test.old_factory, 1_000_000_000, 4_000_000_000, &test.user);
+    add_liquidity(&test, &test.new_factory, 1_000_000_000, 2_000_000_000, &test.user);
+
+    // The new pair only takes half of the withdrawn token_1
+    let result = test.contract.try_migrate(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &(4_000_000_000 - 2_000),
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::InsufficientBAmount)));
+
+    // More token_0 than the position holds
+    let result = test.contract.try_migrate(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &1_000_000_000,
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(SoroswapMigratorError::InsufficientAAmount)));
+
+    // The position is left untouched
+    assert_eq!(old_pair.balance(&test.user), liquidity);
+}
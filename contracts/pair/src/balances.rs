@@ -13,11 +13,12 @@ pub fn get_balance(e: &Env, contract_id: Address) -> i128 {
 pub fn get_balance_0(e: &Env) -> i128 {
     // How many "A TOKENS" does the Liquidity Pool holds?
     // How many "A TOKENS" does this contract holds?
-    get_balance(e, get_token_0(e))
+    // Tokens held for batch orders are not part of the pool
+    get_balance(e, get_token_0(e)).checked_sub(get_batch_escrow_0(e)).unwrap()
 }
 
 pub fn get_balance_1(e: &Env) -> i128 {
-    get_balance(e, get_token_1(e))
+    get_balance(e, get_token_1(e)).checked_sub(get_batch_escrow_1(e)).unwrap()
 }
 
 pub fn get_balance_shares(e: &Env) -> i128 {
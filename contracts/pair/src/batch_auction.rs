@@ -0,0 +1,130 @@
+use soroban_sdk::{contracttype, vec, Address, Env, Vec};
+use crate::storage::*;
+use crate::error::SoroswapPairError;
+use crate::math::CheckedCeilingDiv;
+
+// Optional frequent batch auction mode, set through the factory. While it is enabled `swap` is disabled,
+// and swaps are submitted as orders that are collected during a window of ledgers. Once the window is
+// closed anyone can settle the batch, and every order clears at the same price, so the order of the
+// transactions inside a window does not matter.
+//
+// The clearing price adds every input (minus its fee) to the reserves: token 0 is sold at
+// (reserve_1 + total_1_in) / (reserve_0 + total_0_in). The inputs of one side pay the outputs of the
+// other, the pool trades the difference, and the product of the reserves is kept, before the fees.
+// Orders whose minimum output is not met at that price are left out and the price is computed again,
+// until every remaining order is satisfied. Orders left out can be withdrawn by their owner.
+
+/// Maximum number of orders in a batch, so that it can always be settled in one transaction.
+pub const MAX_BATCH_ORDERS: u32 = 32;
+
+/// Orders collected during a window of ledgers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Batch {
+    /// First ledger sequence when the batch is closed and can be settled
+    pub end_ledger: u32,
+    pub order_ids: Vec<u64>,
+}
+
+/// A swap waiting for the settlement of its batch, or left out of it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchOrder {
+    pub owner: Address,
+    pub batch_id: u64,
+    /// Whether the order sells token 0 for token 1, or token 1 for token 0
+    pub token_0_in: bool,
+    pub amount_in: i128,
+    pub min_amount_out: i128,
+    /// Swap fee of the order, set when it was submitted
+    pub fee_bps: u32,
+}
+
+impl BatchOrder {
+    pub fn fee(&self) -> i128 {
+        (self.amount_in.checked_mul(self.fee_bps as i128).unwrap()).checked_ceiling_div(10000).unwrap()
+    }
+
+    fn amount_in_less_fee(&self) -> i128 {
+        self.amount_in.checked_sub(self.fee()).unwrap()
+    }
+}
+
+/// Adds `order_id` to the open batch and returns its id. If the last batch is already closed, a new one
+/// is opened, collecting orders for `window` ledgers.
+pub fn add_to_open_batch(e: &Env, window: u32, order_id: u64) -> Result<u64, SoroswapPairError> {
+    let sequence = e.ledger().sequence();
+    let (last_batch_id, end_ledger) = get_last_batch(e);
+
+    if last_batch_id > 0 && sequence < end_ledger {
+        let mut batch = get_batch(e, last_batch_id).unwrap();
+        if batch.order_ids.len() >= MAX_BATCH_ORDERS {
+            return Err(SoroswapPairError::BatchFull);
+        }
+        batch.order_ids.push_back(order_id);
+        put_batch(e, last_batch_id, &batch);
+        return Ok(last_batch_id);
+    }
+
+    let batch_id = last_batch_id.checked_add(1).unwrap();
+    let batch = Batch {
+        end_ledger: sequence.checked_add(window).unwrap(),
+        order_ids: vec![e, order_id],
+    };
+    put_last_batch(e, batch_id, batch.end_ledger);
+    put_batch(e, batch_id, &batch);
+    Ok(batch_id)
+}
+
+/// Returns the amount out of every order at the uniform clearing price, 0 for the orders left out.
+pub fn clear(e: &Env, reserve_0: i128, reserve_1: i128, orders: &Vec<BatchOrder>) -> Vec<i128> {
+    let mut filled: Vec<bool> = Vec::new(e);
+    for _ in orders.iter() {
+        filled.push_back(true);
+    }
+
+    // Every round leaves out at least one order or returns, so this ends after at most len + 1 rounds
+    loop {
+        let (mut total_0_in, mut total_1_in) = (0_i128, 0_i128);
+        for (order, is_filled) in orders.iter().zip(filled.iter()) {
+            if !is_filled {
+                continue;
+            }
+            if order.token_0_in {
+                total_0_in = total_0_in.checked_add(order.amount_in_less_fee()).unwrap();
+            } else {
+                total_1_in = total_1_in.checked_add(order.amount_in_less_fee()).unwrap();
+            }
+        }
+        let pool_0 = reserve_0.checked_add(total_0_in).unwrap();
+        let pool_1 = reserve_1.checked_add(total_1_in).unwrap();
+
+        let mut amounts_out: Vec<i128> = Vec::new(e);
+        let mut left_out = false;
+        for (i, order) in orders.iter().enumerate() {
+            if !filled.get(i as u32).unwrap() {
+                amounts_out.push_back(0);
+                continue;
+            }
+            let amount_in = order.amount_in_less_fee();
+            let amount_out = if amount_in == 0 {
+                0
+            } else if order.token_0_in {
+                amount_in.checked_mul(pool_1).unwrap().checked_div(pool_0).unwrap()
+            } else {
+                amount_in.checked_mul(pool_0).unwrap().checked_div(pool_1).unwrap()
+            };
+            if amount_out == 0 || amount_out < order.min_amount_out {
+                filled.set(i as u32, false);
+                left_out = true;
+                amounts_out.push_back(0);
+            } else {
+                amounts_out.push_back(amount_out);
+            }
+        }
+
+        if !left_out {
+            return amounts_out;
+        }
+    }
+}
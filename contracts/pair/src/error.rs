@@ -45,6 +45,49 @@ pub enum SoroswapPairError {
 
     /// SoroswapPair: OVERFLOW while updating
     UpdateOverflow = 118,
+
+    /// SoroswapPair: hook has already been set
+    HookAlreadySet = 119,
+    /// SoroswapPair: operation rejected by the hook
+    HookRejected = 120,
+    /// SoroswapPair: fee returned by the hook is out of bounds
+    HookFeeOutOfBounds = 121,
+
+    /// SoroswapPair: token is not part of the pair while doing flash loan
+    FlashLoanInvalidToken = 122,
+    /// SoroswapPair: insufficient amount requested while doing flash loan
+    FlashLoanInsufficientAmount = 123,
+    /// SoroswapPair: insufficient liquidity to do the flash loan
+    FlashLoanInsufficientLiquidity = 124,
+    /// SoroswapPair: amount plus fee was not returned while doing flash loan
+    FlashLoanNotRepaid = 125,
+
+    /// SoroswapPair: price impact of the swap is higher than the maximum allowed
+    SwapPriceImpactExceeded = 126,
+    /// SoroswapPair: cumulative price impact in this ledger is higher than the maximum allowed
+    SwapLedgerPriceImpactExceeded = 127,
+
+    /// SoroswapPair: sent shares are still locked by the liquidity cooldown while doing withdraw
+    WithdrawLiquidityLocked = 128,
+
+    /// SoroswapPair: swaps are submitted as batch orders while the batch auction is enabled
+    SwapBatchAuctionOnly = 129,
+    /// SoroswapPair: the batch auction is not enabled while submitting an order
+    BatchAuctionDisabled = 130,
+    /// SoroswapPair: token is not part of the pair while submitting an order
+    BatchOrderInvalidToken = 131,
+    /// SoroswapPair: invalid amount in or minimum amount out while submitting an order
+    BatchOrderInvalidAmount = 132,
+    /// SoroswapPair: the open batch already has the maximum number of orders
+    BatchFull = 133,
+    /// SoroswapPair: there is no batch to settle
+    BatchNothingToSettle = 134,
+    /// SoroswapPair: the window of the batch is not closed yet while settling it
+    BatchNotClosed = 135,
+    /// SoroswapPair: the batch order does not exist
+    BatchOrderNotFound = 136,
+    /// SoroswapPair: the batch of the order has not been settled yet while withdrawing it
+    BatchOrderPending = 137,
 }
 
 
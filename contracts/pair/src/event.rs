@@ -1,5 +1,5 @@
 //! Definition of the Events used in the contract
-use soroban_sdk::{contracttype, symbol_short, Env, Address};
+use soroban_sdk::{contracttype, symbol_short, Env, Address, Symbol};
 
 // DEPOSIT EVENT
 #[contracttype]
@@ -131,4 +131,131 @@ pub(crate) fn skim(e: &Env, skimmed_0: i128, skimmed_1: i128) {
         skimmed_1: skimmed_1,
     };
     e.events().publish(("SoroswapPair", symbol_short!("skim")), event);
-}
\ No newline at end of file
+}
+
+
+// FLASH LOAN EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlashLoanEvent {
+    pub receiver: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub fee: i128,
+}
+
+pub(crate) fn flash_loan(e: &Env, receiver: Address, token: Address, amount: i128, fee: i128) {
+    let event: FlashLoanEvent = FlashLoanEvent {
+        receiver: receiver,
+        token: token,
+        amount: amount,
+        fee: fee,
+    };
+    // "flash_loan" does not fit in a short symbol
+    e.events().publish(("SoroswapPair", Symbol::new(e, "flash_loan")), event);
+}
+
+
+// BATCH AUCTION EVENTS
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchOrderEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub batch_id: u64,
+    pub token_0_in: bool,
+    pub amount_in: i128,
+    pub min_amount_out: i128,
+}
+
+pub(crate) fn batch_order(
+    e: &Env,
+    order_id: u64,
+    owner: Address,
+    batch_id: u64,
+    token_0_in: bool,
+    amount_in: i128,
+    min_amount_out: i128,
+) {
+    let event: BatchOrderEvent = BatchOrderEvent {
+        order_id: order_id,
+        owner: owner,
+        batch_id: batch_id,
+        token_0_in: token_0_in,
+        amount_in: amount_in,
+        min_amount_out: min_amount_out,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("order")), event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchFillEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+}
+
+pub(crate) fn batch_fill(e: &Env, order_id: u64, owner: Address, amount_in: i128, amount_out: i128) {
+    let event: BatchFillEvent = BatchFillEvent {
+        order_id: order_id,
+        owner: owner,
+        amount_in: amount_in,
+        amount_out: amount_out,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("fill")), event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchSettleEvent {
+    pub batch_id: u64,
+    pub amount_0_in: i128,
+    pub amount_1_in: i128,
+    pub amount_0_out: i128,
+    pub amount_1_out: i128,
+    pub filled_orders: u32,
+    pub unfilled_orders: u32,
+}
+
+pub(crate) fn batch_settle(
+    e: &Env,
+    batch_id: u64,
+    amount_0_in: i128,
+    amount_1_in: i128,
+    amount_0_out: i128,
+    amount_1_out: i128,
+    filled_orders: u32,
+    unfilled_orders: u32,
+) {
+    let event: BatchSettleEvent = BatchSettleEvent {
+        batch_id: batch_id,
+        amount_0_in: amount_0_in,
+        amount_1_in: amount_1_in,
+        amount_0_out: amount_0_out,
+        amount_1_out: amount_1_out,
+        filled_orders: filled_orders,
+        unfilled_orders: unfilled_orders,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("settle")), event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchUnfilledEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub amount_in: i128,
+}
+
+pub(crate) fn batch_unfilled(e: &Env, order_id: u64, owner: Address, amount_in: i128) {
+    let event: BatchUnfilledEvent = BatchUnfilledEvent {
+        order_id: order_id,
+        owner: owner,
+        amount_in: amount_in,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("unfilled")), event);
+}
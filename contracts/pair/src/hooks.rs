@@ -0,0 +1,85 @@
+use soroban_sdk::{Address, Env};
+use soroswap_hook_interface::{SoroswapHookClient, DEFAULT_FEE_BPS, MAX_FEE_BPS};
+use crate::storage::get_hook;
+use crate::error::SoroswapPairError;
+
+// If a hook has been set for this pair, it is called before and after every swap, deposit and withdraw.
+// Before-hooks can veto the operation, and the swap before-hook can lower the fee, always between
+// 0 and MAX_FEE_BPS, which is DEFAULT_FEE_BPS. Pairs without a hook keep charging DEFAULT_FEE_BPS.
+
+/// Returns the swap fee in basis points, asking the hook if there is one.
+pub fn before_swap(e: &Env, amount_0_out: i128, amount_1_out: i128, to: &Address) -> Result<u32, SoroswapPairError> {
+    match get_hook(e) {
+        Some(hook) => {
+            let response = SoroswapHookClient::new(e, &hook).before_swap(
+                &e.current_contract_address(),
+                &amount_0_out,
+                &amount_1_out,
+                to,
+            );
+            if !response.allow {
+                return Err(SoroswapPairError::HookRejected);
+            }
+            if response.fee_bps > MAX_FEE_BPS {
+                return Err(SoroswapPairError::HookFeeOutOfBounds);
+            }
+            Ok(response.fee_bps)
+        }
+        None => Ok(DEFAULT_FEE_BPS),
+    }
+}
+
+pub fn after_swap(e: &Env, amount_0_in: i128, amount_1_in: i128, amount_0_out: i128, amount_1_out: i128, to: &Address) {
+    if let Some(hook) = get_hook(e) {
+        SoroswapHookClient::new(e, &hook).after_swap(
+            &e.current_contract_address(),
+            &amount_0_in,
+            &amount_1_in,
+            &amount_0_out,
+            &amount_1_out,
+            to,
+        );
+    }
+}
+
+pub fn before_deposit(e: &Env, to: &Address) -> Result<(), SoroswapPairError> {
+    if let Some(hook) = get_hook(e) {
+        if !SoroswapHookClient::new(e, &hook).before_deposit(&e.current_contract_address(), to) {
+            return Err(SoroswapPairError::HookRejected);
+        }
+    }
+    Ok(())
+}
+
+pub fn after_deposit(e: &Env, to: &Address, amount_0: i128, amount_1: i128, liquidity: i128) {
+    if let Some(hook) = get_hook(e) {
+        SoroswapHookClient::new(e, &hook).after_deposit(
+            &e.current_contract_address(),
+            to,
+            &amount_0,
+            &amount_1,
+            &liquidity,
+        );
+    }
+}
+
+pub fn before_withdraw(e: &Env, to: &Address, liquidity: i128) -> Result<(), SoroswapPairError> {
+    if let Some(hook) = get_hook(e) {
+        if !SoroswapHookClient::new(e, &hook).before_withdraw(&e.current_contract_address(), to, &liquidity) {
+            return Err(SoroswapPairError::HookRejected);
+        }
+    }
+    Ok(())
+}
+
+pub fn after_withdraw(e: &Env, to: &Address, liquidity: i128, amount_0: i128, amount_1: i128) {
+    if let Some(hook) = get_hook(e) {
+        SoroswapHookClient::new(e, &hook).after_withdraw(
+            &e.current_contract_address(),
+            to,
+            &liquidity,
+            &amount_0,
+            &amount_1,
+        );
+    }
+}
@@ -1,7 +1,8 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contractmeta, Address, Env, String}; 
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Bytes, Env, String, Vec}; 
 use num_integer::Roots; 
 use soroswap_factory_interface::SoroswapFactoryClient;
+use soroswap_flash_loan_interface::SoroswapFlashLoanReceiverClient;
 use soroban_token_sdk::metadata::TokenMetadata;
 
 
@@ -13,6 +14,10 @@ mod error;
 mod test;
 mod math;
 mod strings;
+mod hooks;
+mod price_guard;
+mod stats;
+mod batch_auction;
 
 // ANY TOKEN CONTRACT
 // TODO: Simplify this and use a any_token_interface
@@ -23,10 +28,12 @@ pub mod any_token {
 
 use storage::*;
 use balances::*;
-use soroswap_pair_token::{SoroswapPairToken, internal_mint, internal_burn, write_metadata};
+use soroswap_pair_token::{SoroswapPairToken, internal_mint, internal_burn, write_metadata, read_locked, write_lock};
 use error::SoroswapPairError;
 use math::CheckedCeilingDiv;
 use strings::TakeFirstNCharsAndConcat;
+use stats::PairStats;
+use batch_auction::{Batch, BatchOrder};
 
 static MINIMUM_LIQUIDITY: i128 = 1000;
 
@@ -49,13 +56,25 @@ fn create_name(e: &Env, symbol_0: &String, symbol_1: &String) -> String {
 // Metadata that is added on to the WASM custom section
 contractmeta!(
     key = "Description",
-    val = "Soroswap.Finance Protocol - Constant product AMM with a .3% swap fee"
+    val = "Soroswap.Finance Protocol - Constant product AMM with a .3% swap fee, lowered by the rebates of its hook"
 );
 
 pub trait SoroswapPairTrait{
     // Sets the token contract addresses for this pool
     fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address)-> Result<(), SoroswapPairError>;
 
+    // Sets the hook contract for this pool. Can only be called once, by the factory
+    fn set_hook(e: Env, hook: Address) -> Result<(), SoroswapPairError>;
+
+    // Sets the maximum price impact per swap and per ledger, in basis points. Can only be called by the factory
+    fn set_price_guard(e: Env, max_swap_bps: u32, max_ledger_bps: u32) -> Result<(), SoroswapPairError>;
+
+    // Sets the number of ledgers that deposited liquidity stays locked. Can only be called by the factory
+    fn set_liquidity_cooldown(e: Env, ledgers: u32) -> Result<(), SoroswapPairError>;
+
+    // Sets the number of ledgers that a batch auction collects orders. Can only be called by the factory
+    fn set_batch_auction(e: Env, window: u32) -> Result<(), SoroswapPairError>;
+
     fn deposit(e:Env, to: Address)  -> Result<i128, SoroswapPairError>;
 
     // Swaps. This function should be called from another contract that has already sent tokens to the pair contract
@@ -63,6 +82,18 @@ pub trait SoroswapPairTrait{
 
     fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapPairError>;
 
+    // Escrows `amount_in` of `token_in` from `owner` as an order of the open batch auction
+    fn submit_order(e: Env, owner: Address, token_in: Address, amount_in: i128, min_amount_out: i128) -> Result<u64, SoroswapPairError>;
+
+    // Settles the oldest closed batch at a uniform price. Anyone can call it
+    fn settle_batch(e: Env) -> Result<u64, SoroswapPairError>;
+
+    // Returns an order left out of its batch to its owner
+    fn withdraw_order(e: Env, order_id: u64) -> Result<i128, SoroswapPairError>;
+
+    // Lends `amount` of `token` to `receiver`, that must return it plus a fee before the end of the call
+    fn flash_loan(e: Env, receiver: Address, token: Address, amount: i128, data: Bytes) -> Result<i128, SoroswapPairError>;
+
     // transfers the excess token balances from the pair to the specified to address, 
     // ensuring that the balances match the reserves by subtracting the reserve amounts 
     // from the current balances.
@@ -76,11 +107,21 @@ pub trait SoroswapPairTrait{
     fn token_0(e: Env) -> Address;
     fn token_1(e: Env) -> Address;
     fn factory(e: Env) -> Address;
+    fn hook(e: Env) -> Option<Address>;
+    fn price_guard(e: Env) -> (u32, u32);
+    fn liquidity_cooldown(e: Env) -> u32;
+    fn batch_auction(e: Env) -> u32;
+    fn get_batch(e: Env, batch_id: u64) -> Option<Batch>;
+    fn get_batch_order(e: Env, order_id: u64) -> Option<BatchOrder>;
+    fn next_batch_to_settle(e: Env) -> u64;
 
     fn k_last(e: Env) -> i128;
 
     fn get_reserves(e: Env) -> (i128, i128);
 
+    // Cumulative volume, fees, swap count and protocol fee of the pair
+    fn stats(e: Env) -> PairStats;
+
 }
 
 #[contract]
@@ -132,6 +173,96 @@ impl SoroswapPairTrait for SoroswapPair {
         Ok(())
     }
 
+    /// Sets the hook contract that will be called before and after every swap, deposit and withdraw.
+    /// The hook can only be set once, by the factory.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `hook` - The address of a contract implementing `SoroswapHookTrait`.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`: The Soroswap pair has not been initialized.
+    /// - `SoroswapPairError::HookAlreadySet`: The hook has already been set.
+    fn set_hook(e: Env, hook: Address) -> Result<(), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+
+        if has_hook(&e) {
+            return Err(SoroswapPairError::HookAlreadySet);
+        }
+
+        put_hook(&e, hook);
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Sets the maximum price impact allowed for a single swap and for all the swaps in the same ledger,
+    /// in basis points of the pre-trade spot price. Only the factory can set the price guard.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `max_swap_bps` - The maximum price impact of a single swap. 0 disables this guard.
+    /// * `max_ledger_bps` - The maximum cumulative price impact of the swaps in a ledger. 0 disables this guard.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`: The Soroswap pair has not been initialized.
+    fn set_price_guard(e: Env, max_swap_bps: u32, max_ledger_bps: u32) -> Result<(), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+
+        put_max_swap_price_impact(&e, max_swap_bps);
+        put_max_ledger_price_impact(&e, max_ledger_bps);
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Sets the number of ledgers that LP tokens minted by `deposit` stay locked for their holder.
+    /// Locked LP tokens can't be transferred, so they can't be sent back to the pair to be withdrawn.
+    /// This deters just-in-time liquidity. Only the factory can set the liquidity cooldown.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `ledgers` - The number of ledgers. 0 disables the cooldown.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`: The Soroswap pair has not been initialized.
+    fn set_liquidity_cooldown(e: Env, ledgers: u32) -> Result<(), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+
+        put_liquidity_cooldown(&e, ledgers);
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Enables the frequent batch auction mode, or disables it with a window of 0. While it is enabled,
+    /// `swap` is disabled and swaps are submitted with `submit_order`. Orders are collected during
+    /// `window` ledgers and then cleared together at a uniform price, so their order does not matter.
+    /// Only the factory can set the batch auction.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `window` - The number of ledgers that a batch collects orders. 0 disables the batch auction.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`: The Soroswap pair has not been initialized.
+    fn set_batch_auction(e: Env, window: u32) -> Result<(), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+
+        put_batch_window(&e, window);
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
     /// Returns the address of the first token in the Soroswap pair.
     fn token_0(e: Env) -> Address {
         extend_instance_ttl(&e);
@@ -150,6 +281,48 @@ impl SoroswapPairTrait for SoroswapPair {
         get_factory(&e)
     }
 
+    /// Returns the address of the hook contract, if one has been set.
+    fn hook(e: Env) -> Option<Address> {
+        extend_instance_ttl(&e);
+        get_hook(&e)
+    }
+
+    /// Returns the maximum price impact per swap and per ledger, in basis points. 0 means disabled.
+    fn price_guard(e: Env) -> (u32, u32) {
+        extend_instance_ttl(&e);
+        (get_max_swap_price_impact(&e), get_max_ledger_price_impact(&e))
+    }
+
+    /// Returns the number of ledgers that deposited liquidity stays locked. 0 means disabled.
+    fn liquidity_cooldown(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_liquidity_cooldown(&e)
+    }
+
+    /// Returns the number of ledgers that a batch auction collects orders. 0 means disabled.
+    fn batch_auction(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_batch_window(&e)
+    }
+
+    /// Returns the batch with id `batch_id`, if it has not been settled yet.
+    fn get_batch(e: Env, batch_id: u64) -> Option<Batch> {
+        extend_instance_ttl(&e);
+        get_batch(&e, batch_id)
+    }
+
+    /// Returns the order with id `order_id`, if it is waiting for its batch or has been left out of it.
+    fn get_batch_order(e: Env, order_id: u64) -> Option<BatchOrder> {
+        extend_instance_ttl(&e);
+        get_batch_order(&e, order_id)
+    }
+
+    /// Returns the id of the oldest batch that has not been settled yet.
+    fn next_batch_to_settle(e: Env) -> u64 {
+        extend_instance_ttl(&e);
+        get_next_batch_to_settle(&e)
+    }
+
     /// Deposits tokens into the Soroswap pair and mints LP tokens in return.
     ///
     /// # Arguments
@@ -165,6 +338,7 @@ impl SoroswapPairTrait for SoroswapPair {
     /// - `SoroswapPairError::DepositInsufficientFirstLiquidity`: Insufficient first liquidity minted.
     /// - `SoroswapPairError::DepositInsufficientLiquidityMinted`: Insufficient liquidity minted.
     /// - `SoroswapPairError::UpdateOverflow`: Overflow occurred during update.
+    /// - `SoroswapPairError::HookRejected`: The hook rejected the deposit.
     fn deposit(e: Env, to: Address) -> Result<i128, SoroswapPairError> {
         extend_instance_ttl(&e);
         
@@ -172,6 +346,8 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::NotInitialized)
         }
 
+        hooks::before_deposit(&e, &to)?;
+
         let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
         let amount_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapPairError::DepositInsufficientAmountToken0)?;
@@ -207,6 +383,9 @@ impl SoroswapPairTrait for SoroswapPair {
         }
 
         internal_mint(e.clone(), to.clone(), liquidity.clone());
+        if get_liquidity_cooldown(&e) > 0 {
+            write_lock(&e, to.clone(), liquidity);
+        }
         update(&e, balance_0, balance_1);
 
         (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
@@ -214,7 +393,8 @@ impl SoroswapPairTrait for SoroswapPair {
             put_klast(&e, reserve_0.checked_mul(reserve_1).unwrap());
         }
 
-        event::deposit(&e, to, amount_0, amount_1, liquidity, reserve_0, reserve_1);
+        event::deposit(&e, to.clone(), amount_0, amount_1, liquidity, reserve_0, reserve_1);
+        hooks::after_deposit(&e, &to, amount_0, amount_1, liquidity);
 
         Ok(liquidity) 
     }
@@ -227,7 +407,7 @@ impl SoroswapPairTrait for SoroswapPair {
     /// * `amount_1_out` - The desired amount of the second token to receive.
     /// * `to` - The address where the swapped tokens will be sent.
     ////// # Errors
-    /// Returns an error if the swap cannot be executed. Possible errors include:
+    /// Returns an error if the swap cannot be executed:
     /// - `SoroswapPairError::NotInitialized`
     /// - `SoroswapPairError::SwapInsufficientOutputAmount`
     /// - `SoroswapPairError::SwapNegativesOutNotSupported`
@@ -235,13 +415,21 @@ impl SoroswapPairTrait for SoroswapPair {
     /// - `SoroswapPairError::SwapInvalidTo`
     /// - `SoroswapPairError::SwapInsufficientInputAmount`
     /// - `SoroswapPairError::SwapNegativesInNotSupported`
-    /// - `SoroswapPairError::SwapKConstantNotMet`: If the K constant condition is not met after the swap.
+    /// - `SoroswapPairError::SwapKConstantNotMet`: If the K constant is not met after the swap.
+    /// - `SoroswapPairError::HookRejected`
+    /// - `SoroswapPairError::HookFeeOutOfBounds`
+    /// - `SoroswapPairError::SwapPriceImpactExceeded`
+    /// - `SoroswapPairError::SwapLedgerPriceImpactExceeded`
+    /// - `SoroswapPairError::SwapBatchAuctionOnly`
     fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapPairError> {
         extend_instance_ttl(&e);
 
         if !has_token_0(&e) {
             return Err(SoroswapPairError::NotInitialized);
         }
+        if get_batch_window(&e) > 0 {
+            return Err(SoroswapPairError::SwapBatchAuctionOnly);
+        }
     
         let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
     
@@ -258,6 +446,8 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::SwapInvalidTo);
         }
 
+        let fee_bps = hooks::before_swap(&e, amount_0_out, amount_1_out, &to)?;
+
         if amount_0_out > 0 {
             transfer_token_0_from_pair(&e, &to, amount_0_out);
         }
@@ -285,8 +475,8 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::SwapNegativesInNotSupported);
         }
 
-        let fee_0 = (amount_0_in.checked_mul(3).unwrap()).checked_ceiling_div(1000).unwrap();
-        let fee_1 = (amount_1_in.checked_mul(3).unwrap()).checked_ceiling_div(1000).unwrap();
+        let fee_0 = (amount_0_in.checked_mul(fee_bps as i128).unwrap()).checked_ceiling_div(10000).unwrap();
+        let fee_1 = (amount_1_in.checked_mul(fee_bps as i128).unwrap()).checked_ceiling_div(10000).unwrap();
 
         let balance_0_minus_fee = balance_0.checked_sub(fee_0).unwrap();
         let balance_1_minus_fee = balance_1.checked_sub(fee_1).unwrap();
@@ -296,14 +486,238 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::SwapKConstantNotMet);
         }
 
+        price_guard::check(&e, reserve_0, reserve_1, balance_0, balance_1)?;
+
         update(&e, balance_0, balance_1);
+        stats::add_swap(&e, amount_0_in, amount_1_in, fee_0, fee_1);
         
-        event::swap(&e, to, amount_0_in, amount_1_in, amount_0_out, amount_1_out);
+        event::swap(&e, to.clone(), amount_0_in, amount_1_in, amount_0_out, amount_1_out);
+        hooks::after_swap(&e, amount_0_in, amount_1_in, amount_0_out, amount_1_out, &to);
 
         Ok(())
     }
 
 
+    /// Submits a swap to the open batch auction, escrowing `amount_in` of `token_in` from `owner` in the pair.
+    /// If the last batch is closed, a new one is opened. The order is filled when the batch is settled,
+    /// at the uniform price of the batch and after the swap fee, unless that price gives less than
+    /// `min_amount_out`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `owner` - The address sending `token_in` and receiving the other token. Must authorize the call.
+    /// * `token_in` - The token to sell, token 0 or token 1.
+    /// * `amount_in` - The amount of `token_in` to sell.
+    /// * `min_amount_out` - The minimum amount of the other token to receive.
+    ///
+    /// # Returns
+    /// The id of the order.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::BatchAuctionDisabled`
+    /// - `SoroswapPairError::BatchOrderInvalidToken`
+    /// - `SoroswapPairError::BatchOrderInvalidAmount`
+    /// - `SoroswapPairError::BatchFull`
+    /// - `SoroswapPairError::HookRejected`
+    /// - `SoroswapPairError::HookFeeOutOfBounds`
+    fn submit_order(e: Env, owner: Address, token_in: Address, amount_in: i128, min_amount_out: i128) -> Result<u64, SoroswapPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        let window = get_batch_window(&e);
+        if window == 0 {
+            return Err(SoroswapPairError::BatchAuctionDisabled);
+        }
+        owner.require_auth();
+
+        let token_0_in = if token_in == get_token_0(&e) {
+            true
+        } else if token_in == get_token_1(&e) {
+            false
+        } else {
+            return Err(SoroswapPairError::BatchOrderInvalidToken);
+        };
+        if amount_in <= 0 || min_amount_out < 0 {
+            return Err(SoroswapPairError::BatchOrderInvalidAmount);
+        }
+
+        // The hook sees the order as a swap of its minimum amount out to its owner
+        let fee_bps = if token_0_in {
+            hooks::before_swap(&e, 0, min_amount_out, &owner)?
+        } else {
+            hooks::before_swap(&e, min_amount_out, 0, &owner)?
+        };
+
+        let order_id = get_next_batch_order_id(&e);
+        let batch_id = batch_auction::add_to_open_batch(&e, window, order_id)?;
+
+        any_token::TokenClient::new(&e, &token_in).transfer(&owner, &e.current_contract_address(), &amount_in);
+        if token_0_in {
+            put_batch_escrow_0(&e, get_batch_escrow_0(&e).checked_add(amount_in).unwrap());
+        } else {
+            put_batch_escrow_1(&e, get_batch_escrow_1(&e).checked_add(amount_in).unwrap());
+        }
+
+        put_batch_order(&e, order_id, &BatchOrder {
+            owner: owner.clone(),
+            batch_id,
+            token_0_in,
+            amount_in,
+            min_amount_out,
+            fee_bps,
+        });
+        put_next_batch_order_id(&e, order_id.checked_add(1).unwrap());
+
+        event::batch_order(&e, order_id, owner, batch_id, token_0_in, amount_in, min_amount_out);
+        Ok(order_id)
+    }
+
+    /// Settles the oldest batch that has not been settled yet, once its window is closed. Every order clears at
+    /// the same price, which adds the inputs of all the orders to the reserves. Orders whose minimum amount out
+    /// is not met are left out, and the price is computed again without them. Filled orders are paid to their
+    /// owners, and orders left out stay in the pair until they are withdrawn. Anyone can settle a batch.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The id of the settled batch.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::BatchNothingToSettle`
+    /// - `SoroswapPairError::BatchNotClosed`
+    fn settle_batch(e: Env) -> Result<u64, SoroswapPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+
+        let batch_id = get_next_batch_to_settle(&e);
+        let (last_batch_id, _) = get_last_batch(&e);
+        if batch_id > last_batch_id {
+            return Err(SoroswapPairError::BatchNothingToSettle);
+        }
+        let batch = get_batch(&e, batch_id).unwrap();
+        if e.ledger().sequence() < batch.end_ledger {
+            return Err(SoroswapPairError::BatchNotClosed);
+        }
+
+        let mut orders: Vec<BatchOrder> = Vec::new(&e);
+        for order_id in batch.order_ids.iter() {
+            // Orders are only removed once their batch has been settled
+            orders.push_back(get_batch_order(&e, order_id).unwrap());
+        }
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let amounts_out = batch_auction::clear(&e, reserve_0, reserve_1, &orders);
+
+        let (mut amount_0_in, mut amount_1_in, mut amount_0_out, mut amount_1_out) = (0_i128, 0_i128, 0_i128, 0_i128);
+        let (mut fee_0, mut fee_1) = (0_i128, 0_i128);
+        let mut filled_orders: u32 = 0;
+        for ((order_id, order), amount_out) in batch.order_ids.iter().zip(orders.iter()).zip(amounts_out.iter()) {
+            if amount_out == 0 {
+                continue;
+            }
+            if order.token_0_in {
+                amount_0_in = amount_0_in.checked_add(order.amount_in).unwrap();
+                fee_0 = fee_0.checked_add(order.fee()).unwrap();
+                amount_1_out = amount_1_out.checked_add(amount_out).unwrap();
+                transfer_token_1_from_pair(&e, &order.owner, amount_out);
+            } else {
+                amount_1_in = amount_1_in.checked_add(order.amount_in).unwrap();
+                fee_1 = fee_1.checked_add(order.fee()).unwrap();
+                amount_0_out = amount_0_out.checked_add(amount_out).unwrap();
+                transfer_token_0_from_pair(&e, &order.owner, amount_out);
+            }
+            // Every filled order counts as a swap
+            if order.token_0_in {
+                stats::add_swap(&e, order.amount_in, 0, order.fee(), 0);
+            } else {
+                stats::add_swap(&e, 0, order.amount_in, 0, order.fee());
+            }
+            remove_batch_order(&e, order_id);
+            filled_orders = filled_orders.checked_add(1).unwrap();
+            event::batch_fill(&e, order_id, order.owner.clone(), order.amount_in, amount_out);
+        }
+
+        // The inputs of the filled orders join the reserves
+        put_batch_escrow_0(&e, get_batch_escrow_0(&e).checked_sub(amount_0_in).unwrap());
+        put_batch_escrow_1(&e, get_batch_escrow_1(&e).checked_sub(amount_1_in).unwrap());
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let balance_0_minus_fee = balance_0.checked_sub(fee_0).unwrap();
+        let balance_1_minus_fee = balance_1.checked_sub(fee_1).unwrap();
+        if balance_0_minus_fee.checked_mul(balance_1_minus_fee).unwrap() <
+            reserve_0.checked_mul(reserve_1).unwrap() {
+            return Err(SoroswapPairError::SwapKConstantNotMet);
+        }
+
+        update(&e, balance_0, balance_1);
+
+        // Hooks see every filled order as a swap to its owner
+        for (order, amount_out) in orders.iter().zip(amounts_out.iter()) {
+            if amount_out == 0 {
+                continue;
+            }
+            if order.token_0_in {
+                hooks::after_swap(&e, order.amount_in, 0, 0, amount_out, &order.owner);
+            } else {
+                hooks::after_swap(&e, 0, order.amount_in, amount_out, 0, &order.owner);
+            }
+        }
+
+        remove_batch(&e, batch_id);
+        put_next_batch_to_settle(&e, batch_id.checked_add(1).unwrap());
+
+        let unfilled_orders = batch.order_ids.len().checked_sub(filled_orders).unwrap();
+        event::batch_settle(&e, batch_id, amount_0_in, amount_1_in, amount_0_out, amount_1_out, filled_orders, unfilled_orders);
+        Ok(batch_id)
+    }
+
+    /// Returns the tokens of an order that was left out of its batch to its owner. Orders can't be withdrawn
+    /// before their batch has been settled.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `order_id` - The id of the order. Its owner must authorize the call.
+    ///
+    /// # Returns
+    /// The amount of the input token returned.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::BatchOrderNotFound`
+    /// - `SoroswapPairError::BatchOrderPending`
+    fn withdraw_order(e: Env, order_id: u64) -> Result<i128, SoroswapPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        let order = get_batch_order(&e, order_id).ok_or(SoroswapPairError::BatchOrderNotFound)?;
+        if order.batch_id >= get_next_batch_to_settle(&e) {
+            return Err(SoroswapPairError::BatchOrderPending);
+        }
+        order.owner.require_auth();
+
+        remove_batch_order(&e, order_id);
+        if order.token_0_in {
+            put_batch_escrow_0(&e, get_batch_escrow_0(&e).checked_sub(order.amount_in).unwrap());
+            transfer_token_0_from_pair(&e, &order.owner, order.amount_in);
+        } else {
+            put_batch_escrow_1(&e, get_batch_escrow_1(&e).checked_sub(order.amount_in).unwrap());
+            transfer_token_1_from_pair(&e, &order.owner, order.amount_in);
+        }
+
+        event::batch_unfilled(&e, order_id, order.owner, order.amount_in);
+        Ok(order.amount_in)
+    }
+
     /// Withdraws liquidity from the Soroswap pair, burning LP tokens and returning the corresponding tokens to the user.
     ///
     /// # Arguments
@@ -326,11 +740,18 @@ impl SoroswapPairTrait for SoroswapPair {
 
         let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         let (mut balance_0, mut balance_1) = (get_balance_0(&e), get_balance_1(&e));
-        let user_sent_shares = balance_shares.checked_sub(MINIMUM_LIQUIDITY).unwrap();
+        // LP tokens deposited directly to the pair stay here until the liquidity cooldown has passed
+        let locked_shares = read_locked(&e, e.current_contract_address());
+        let user_sent_shares = balance_shares.checked_sub(MINIMUM_LIQUIDITY).unwrap().checked_sub(locked_shares).unwrap();
 
         if user_sent_shares <= 0 {
+            if locked_shares > 0 {
+                return Err(SoroswapPairError::WithdrawLiquidityLocked);
+            }
             return Err(SoroswapPairError::WithdrawInsufficientSentShares);
         }
+
+        hooks::before_withdraw(&e, &to, user_sent_shares)?;
     
 
         let fee_on: bool = mint_fee(&e, reserve_0, reserve_1);
@@ -357,10 +778,84 @@ impl SoroswapPairTrait for SoroswapPair {
             put_klast(&e, reserve_0.checked_mul(reserve_1).unwrap());
         }
 
-        event::withdraw(&e, to, user_sent_shares, amount_0, amount_1, reserve_0, reserve_1);
+        event::withdraw(&e, to.clone(), user_sent_shares, amount_0, amount_1, reserve_0, reserve_1);
+        hooks::after_withdraw(&e, &to, user_sent_shares, amount_0, amount_1);
         Ok((amount_0, amount_1))
     }
 
+    /// Lends `amount` of one token of the pair to `receiver` and calls its `on_flash_loan` function,
+    /// that must send back `amount` plus the flash loan fee set in the factory.
+    /// The fee goes to the LPs, and the protocol takes its cut through `mint_fee`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `receiver` - A contract implementing `SoroswapFlashLoanReceiverTrait`. Must authorize the loan.
+    /// * `token` - The token to borrow, token 0 or token 1.
+    /// * `amount` - The amount of `token` to borrow.
+    /// * `data` - Arbitrary data forwarded to the receiver.
+    ///
+    /// # Returns
+    /// The fee paid by the receiver.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::FlashLoanInvalidToken`
+    /// - `SoroswapPairError::FlashLoanInsufficientAmount`
+    /// - `SoroswapPairError::FlashLoanInsufficientLiquidity`
+    /// - `SoroswapPairError::FlashLoanNotRepaid`
+    fn flash_loan(e: Env, receiver: Address, token: Address, amount: i128, data: Bytes) -> Result<i128, SoroswapPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        receiver.require_auth();
+
+        let is_token_0 = if token == get_token_0(&e) {
+            true
+        } else if token == get_token_1(&e) {
+            false
+        } else {
+            return Err(SoroswapPairError::FlashLoanInvalidToken);
+        };
+
+        if amount <= 0 {
+            return Err(SoroswapPairError::FlashLoanInsufficientAmount);
+        }
+        let reserve = if is_token_0 { get_reserve_0(&e) } else { get_reserve_1(&e) };
+        if amount >= reserve {
+            return Err(SoroswapPairError::FlashLoanInsufficientLiquidity);
+        }
+
+        let fee_bps = SoroswapFactoryClient::new(&e, &get_factory(&e)).flash_loan_fee();
+        let fee = (amount.checked_mul(fee_bps as i128).unwrap()).checked_ceiling_div(10000).unwrap();
+
+        // We compare against the balance and not the reserve, so unsynced tokens can't be used to pay the loan
+        let balance_before = if is_token_0 { get_balance_0(&e) } else { get_balance_1(&e) };
+
+        transfer(&e, token.clone(), &receiver, amount);
+        // Soroban does not allow re-entrancy, so the receiver can't call this pair during the callback
+        SoroswapFlashLoanReceiverClient::new(&e, &receiver).on_flash_loan(
+            &e.current_contract_address(),
+            &token,
+            &amount,
+            &fee,
+            &data,
+        );
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let balance_after = if is_token_0 { balance_0 } else { balance_1 };
+        if balance_after < balance_before.checked_add(fee).unwrap() {
+            return Err(SoroswapPairError::FlashLoanNotRepaid);
+        }
+
+        update(&e, balance_0, balance_1);
+        stats::add_flash_loan_fee(&e, is_token_0, fee);
+
+        event::flash_loan(&e, receiver, token, amount, fee);
+        Ok(fee)
+    }
+
     /// Skims excess tokens from reserves and sends them to the specified address.
     ///
     /// # Arguments
@@ -403,6 +898,21 @@ impl SoroswapPairTrait for SoroswapPair {
     }
 
 
+    /// Returns the cumulative statistics of the pair: volume and LP fees per token, number of swaps
+    /// and LP tokens minted as protocol fee. Counters are never reset, so figures for a time window
+    /// can be computed by subtracting two snapshots.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// A `PairStats` snapshot, including the ledger sequence and timestamp when it was taken.
+    fn stats(e: Env) -> PairStats {
+        extend_instance_ttl(&e);
+
+        stats::read_stats(&e)
+    }
+
     /// Returns the value of the last product of reserves (`K`) stored in the contract.
     ///
     /// # Arguments
@@ -459,6 +969,7 @@ fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> bool{
 
                 if liquidity_pool_shares_fees > 0 {
                     internal_mint(e.clone(), fee_to, liquidity_pool_shares_fees);
+                    stats::add_protocol_fee(&e, liquidity_pool_shares_fees);
                 }
             }
         }
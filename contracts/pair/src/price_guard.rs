@@ -0,0 +1,52 @@
+use soroban_sdk::Env;
+use crate::storage::*;
+use crate::error::SoroswapPairError;
+use crate::math::CheckedCeilingDiv;
+
+// Optional safeguards against sandwich attacks on thin pairs, set through the factory.
+// The price impact of a swap is the largest relative movement, in basis points, of the two spot prices
+// (token 0 in token 1 and token 1 in token 0), rounded up. A limit of 0 disables the guard.
+// A tripped guard reverts the swap, so it can't publish an event: its error code is the signal.
+
+/// Returns the price impact in bps of going from (reserve_0, reserve_1) to (balance_0, balance_1)
+pub fn price_impact_bps(reserve_0: i128, reserve_1: i128, balance_0: i128, balance_1: i128) -> i128 {
+    // price_0 before = reserve_1 / reserve_0, price_0 after = balance_1 / balance_0
+    let before = reserve_1.checked_mul(balance_0).unwrap();
+    let after = balance_1.checked_mul(reserve_0).unwrap();
+    let difference = (after.checked_sub(before).unwrap()).abs();
+    // dividing by the smaller of both gives the movement of the price that went up, that is the larger one
+    difference.checked_mul(10000).unwrap().checked_ceiling_div(before.min(after)).unwrap()
+}
+
+/// Checks the price guards for a swap that moves the reserves to (balance_0, balance_1),
+/// and adds its price impact to the cumulative price impact of the current ledger.
+pub fn check(e: &Env, reserve_0: i128, reserve_1: i128, balance_0: i128, balance_1: i128) -> Result<(), SoroswapPairError> {
+    let max_swap = get_max_swap_price_impact(e);
+    let max_ledger = get_max_ledger_price_impact(e);
+    if max_swap == 0 && max_ledger == 0 {
+        return Ok(());
+    }
+
+    let price_impact = price_impact_bps(reserve_0, reserve_1, balance_0, balance_1);
+
+    if max_swap > 0 && price_impact > max_swap as i128 {
+        return Err(SoroswapPairError::SwapPriceImpactExceeded);
+    }
+
+    if max_ledger > 0 {
+        let sequence = e.ledger().sequence();
+        let (last_sequence, last_price_impact) = get_ledger_price_impact(e);
+        let ledger_price_impact = if last_sequence == sequence {
+            last_price_impact.checked_add(price_impact).unwrap()
+        } else {
+            price_impact
+        };
+
+        if ledger_price_impact > max_ledger as i128 {
+            return Err(SoroswapPairError::SwapLedgerPriceImpactExceeded);
+        }
+        put_ledger_price_impact(e, sequence, ledger_price_impact);
+    }
+
+    Ok(())
+}
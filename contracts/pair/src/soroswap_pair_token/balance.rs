@@ -1,4 +1,5 @@
 use crate::soroswap_pair_token::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use crate::soroswap_pair_token::lock::read_locked;
 use soroban_sdk::{Address, Env};
 
 pub fn read_balance(e: &Env, addr: Address) -> i128 {
@@ -35,5 +36,9 @@ pub fn spend_balance(e: &Env, addr: Address, amount: i128) {
     if balance < amount {
         panic!("insufficient balance");
     }
+    // Recently deposited liquidity can't be moved until the pair's liquidity cooldown has passed
+    if balance - amount < read_locked(e, addr.clone()) {
+        panic!("insufficient unlocked balance");
+    }
     write_balance(e, addr, balance - amount);
 }
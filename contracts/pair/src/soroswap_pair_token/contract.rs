@@ -2,6 +2,7 @@
 //! interface.
 use crate::soroswap_pair_token::allowance::{read_allowance, spend_allowance, write_allowance};
 use crate::soroswap_pair_token::balance::{read_balance, receive_balance, spend_balance};
+use crate::soroswap_pair_token::lock::read_locked;
 use crate::soroswap_pair_token::metadata::{read_decimal, read_name, read_symbol};
 use crate::soroswap_pair_token::total_supply::{read_total_supply, increase_total_supply, decrease_total_supply};
 
@@ -55,6 +56,11 @@ impl SoroswapPairToken {
         read_total_supply(&e)
     }
 
+    /// Returns the amount of LP tokens of `id` that can't be transferred yet because of the liquidity cooldown
+    pub fn locked_balance(e: Env, id: Address) -> i128 {
+        read_locked(&e, id)
+    }
+
     #[cfg(test)]
     pub fn get_allowance(e: Env, from: Address, spender: Address) -> Option<AllowanceValue> {
         let key = DataKey::Allowance(AllowanceDataKey { from, spender });
@@ -0,0 +1,71 @@
+use crate::soroswap_pair_token::storage_types::{DataKey, LockValue, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use crate::storage::get_liquidity_cooldown;
+use soroban_sdk::{Address, Env};
+
+// LP tokens minted by `deposit` are locked for the holder until the pair's liquidity cooldown has passed.
+// Only the unlocked part of a balance can be transferred or burned, so locked LP tokens can't be sent
+// back to the pair to be withdrawn. LP tokens received through a transfer are never locked.
+//
+// Anyone can deposit for someone else, so a deposit must never delay the tokens that were already locked.
+// Every holder keeps at most two locks: the oldest one keeps its unlock ledger, and later deposits are
+// added to the last one, which unlocks a full cooldown after the last of them. Locks store their unlock
+// ledger, so a new liquidity cooldown only applies to the deposits made after it is set.
+
+fn is_locked(e: &Env, unlock_ledger: u32) -> bool {
+    e.ledger().sequence() < unlock_ledger
+}
+
+/// Returns the amount of LP tokens of `addr` that are still locked
+pub fn read_locked(e: &Env, addr: Address) -> i128 {
+    let key = DataKey::Lock(addr);
+    let mut locked = 0;
+    if let Some(lock) = e.storage().persistent().get::<DataKey, LockValue>(&key) {
+        if is_locked(e, lock.unlock_ledger) {
+            locked += lock.amount;
+        }
+        if is_locked(e, lock.last_unlock_ledger) {
+            locked += lock.last_amount;
+        }
+    }
+    locked
+}
+
+/// Locks `amount` more LP tokens of `addr`, deposited in the current ledger.
+/// Tokens that were already locked keep their unlock ledger.
+pub fn write_lock(e: &Env, addr: Address, amount: i128) {
+    let key = DataKey::Lock(addr);
+    let mut lock = e.storage().persistent().get::<DataKey, LockValue>(&key).unwrap_or(LockValue {
+        amount: 0,
+        unlock_ledger: 0,
+        last_amount: 0,
+        last_unlock_ledger: 0,
+    });
+    let unlock_ledger = e.ledger().sequence().saturating_add(get_liquidity_cooldown(e));
+
+    // Forget the locks that already passed
+    if !is_locked(e, lock.unlock_ledger) {
+        if is_locked(e, lock.last_unlock_ledger) {
+            lock.amount = lock.last_amount;
+            lock.unlock_ledger = lock.last_unlock_ledger;
+        } else {
+            lock.amount = 0;
+        }
+        lock.last_amount = 0;
+    } else if !is_locked(e, lock.last_unlock_ledger) {
+        lock.last_amount = 0;
+    }
+
+    if lock.amount == 0 || lock.unlock_ledger == unlock_ledger {
+        lock.amount = lock.amount.checked_add(amount).unwrap();
+        lock.unlock_ledger = unlock_ledger;
+    } else {
+        // After a shorter cooldown is set, the last lock may unlock later than a new deposit would
+        lock.last_amount = lock.last_amount.checked_add(amount).unwrap();
+        lock.last_unlock_ledger = lock.last_unlock_ledger.max(unlock_ledger);
+    }
+
+    e.storage().persistent().set(&key, &lock);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
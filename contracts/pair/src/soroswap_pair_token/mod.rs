@@ -2,6 +2,7 @@
 
 mod allowance;
 mod balance;
+mod lock;
 mod contract;
 mod metadata;
 mod storage_types;
@@ -11,3 +12,4 @@ pub use contract::SoroswapPairTokenClient;
 pub use contract::SoroswapPairToken;
 pub use contract::{internal_mint, internal_burn};
 pub use metadata::write_metadata;
+pub use lock::{read_locked, write_lock};
@@ -20,10 +20,19 @@ pub struct AllowanceValue {
     pub expiration_ledger: u32,
 }
 
+#[contracttype]
+pub struct LockValue {
+    pub amount: i128,
+    pub unlock_ledger: u32,
+    pub last_amount: i128,
+    pub last_unlock_ledger: u32,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Allowance(AllowanceDataKey),
     Balance(Address),
-    TotalSupply
+    TotalSupply,
+    Lock(Address)
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+use soroban_sdk::{contracttype, Env};
+use crate::storage::{get_stats, put_stats};
+
+// Cumulative counters that are never reset. To get figures for a time window (e.g. 24h volume or APR),
+// take two snapshots with `stats()` and subtract them; `ledger` and `timestamp` tell when each was taken.
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct PairStats {
+    /// Total amount of token 0 swapped into the pair
+    pub volume_0: i128,
+    /// Total amount of token 1 swapped into the pair
+    pub volume_1: i128,
+    /// Total fees paid in token 0 to the LPs, by swaps and flash loans
+    pub fees_0: i128,
+    /// Total fees paid in token 1 to the LPs, by swaps and flash loans
+    pub fees_1: i128,
+    /// Number of swaps
+    pub swap_count: u64,
+    /// Total LP tokens minted to `fee_to` as protocol fee
+    pub protocol_fee_liquidity: i128,
+    /// Ledger sequence when the snapshot was taken
+    pub ledger: u32,
+    /// Ledger timestamp when the snapshot was taken
+    pub timestamp: u64,
+}
+
+pub fn read_stats(e: &Env) -> PairStats {
+    let mut stats = get_stats(e);
+    stats.ledger = e.ledger().sequence();
+    stats.timestamp = e.ledger().timestamp();
+    stats
+}
+
+pub fn add_swap(e: &Env, amount_0_in: i128, amount_1_in: i128, fee_0: i128, fee_1: i128) {
+    let mut stats = get_stats(e);
+    stats.volume_0 = stats.volume_0.checked_add(amount_0_in).unwrap();
+    stats.volume_1 = stats.volume_1.checked_add(amount_1_in).unwrap();
+    stats.fees_0 = stats.fees_0.checked_add(fee_0).unwrap();
+    stats.fees_1 = stats.fees_1.checked_add(fee_1).unwrap();
+    stats.swap_count = stats.swap_count.checked_add(1).unwrap();
+    put_stats(e, &stats);
+}
+
+pub fn add_flash_loan_fee(e: &Env, is_token_0: bool, fee: i128) {
+    let mut stats = get_stats(e);
+    if is_token_0 {
+        stats.fees_0 = stats.fees_0.checked_add(fee).unwrap();
+    } else {
+        stats.fees_1 = stats.fees_1.checked_add(fee).unwrap();
+    }
+    put_stats(e, &stats);
+}
+
+pub fn add_protocol_fee(e: &Env, liquidity: i128) {
+    let mut stats = get_stats(e);
+    stats.protocol_fee_liquidity = stats.protocol_fee_liquidity.checked_add(liquidity).unwrap();
+    put_stats(e, &stats);
+}
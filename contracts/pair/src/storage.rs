@@ -1,5 +1,7 @@
-use soroban_sdk::{ Env, Address, ConversionError,
+use soroban_sdk::{ contracttype, Env, Address, ConversionError,
     TryFromVal, Val}; 
+use crate::stats::PairStats;
+use crate::batch_auction::{Batch, BatchOrder};
 
 #[derive(Clone, Copy)] 
 #[repr(u32)]
@@ -10,15 +12,37 @@ pub enum DataKey {
     Reserve0 = 2, // reserve0, instance type of data;
     Reserve1 = 3, // reserve1, instance type of data;
     Factory = 4, // factory, instance type of data;
-    KLast = 5 // last k, instance type of data;
+    KLast = 5, // last k, instance type of data;
+    Hook = 6, // hook contract, instance type of data;
+    MaxSwapPriceImpact = 7, // max price impact per swap in bps, instance type of data;
+    MaxLedgerPriceImpact = 8, // max cumulative price impact per ledger in bps, instance type of data;
+    LedgerPriceImpact = 9, // (ledger sequence, cumulative price impact in bps), instance type of data;
+    LiquidityCooldown = 10, // ledgers that deposited liquidity stays locked, instance type of data;
+    Stats = 11, // cumulative pair statistics, instance type of data;
+    BatchWindow = 12, // ledgers that a batch auction collects orders, instance type of data;
+    BatchEscrow0 = 13, // token 0 held for batch orders, instance type of data;
+    BatchEscrow1 = 14, // token 1 held for batch orders, instance type of data;
+    NextBatchOrderId = 15, // id of the next batch order, instance type of data;
+    LastBatch = 16, // (id, end ledger) of the last batch opened, instance type of data;
+    NextBatchToSettle = 17, // id of the oldest batch not settled yet, instance type of data;
 
 }
 
+// Batches and batch orders, persistent type of data
+#[contracttype]
+#[derive(Clone)]
+pub enum BatchDataKey {
+    Batch(u64),
+    Order(u64),
+}
+
 // We will follow the token standar for instance bumping
 
 const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 impl TryFromVal<Env, DataKey> for Val {
     type Error = ConversionError;
@@ -75,6 +99,92 @@ get(&DataKey::KLast) {
     }
 }
 
+pub fn get_hook(e: &Env) -> Option<Address> {
+    e.storage().instance().
+get(&DataKey::Hook)
+}
+
+pub fn has_hook(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Hook)
+}
+
+// Price guards are disabled (0) by default
+pub fn get_max_swap_price_impact(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::MaxSwapPriceImpact).unwrap_or(0)
+}
+
+pub fn get_max_ledger_price_impact(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::MaxLedgerPriceImpact).unwrap_or(0)
+}
+
+pub fn get_ledger_price_impact(e: &Env) -> (u32, i128) {
+    e.storage().instance().
+get(&DataKey::LedgerPriceImpact).unwrap_or((0, 0))
+}
+
+// Liquidity cooldown is disabled (0) by default
+pub fn get_liquidity_cooldown(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::LiquidityCooldown).unwrap_or(0)
+}
+
+pub fn get_stats(e: &Env) -> PairStats {
+    e.storage().instance().
+get(&DataKey::Stats).unwrap_or_default()
+}
+
+// Batch auction is disabled (0) by default
+pub fn get_batch_window(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::BatchWindow).unwrap_or(0)
+}
+
+pub fn get_batch_escrow_0(e: &Env) -> i128 {
+    e.storage().instance().
+get(&DataKey::BatchEscrow0).unwrap_or(0)
+}
+
+pub fn get_batch_escrow_1(e: &Env) -> i128 {
+    e.storage().instance().
+get(&DataKey::BatchEscrow1).unwrap_or(0)
+}
+
+pub fn get_next_batch_order_id(e: &Env) -> u64 {
+    e.storage().instance().
+get(&DataKey::NextBatchOrderId).unwrap_or(1)
+}
+
+// Batch ids start at 1, so (0, 0) means that no batch has been opened yet
+pub fn get_last_batch(e: &Env) -> (u64, u32) {
+    e.storage().instance().
+get(&DataKey::LastBatch).unwrap_or((0, 0))
+}
+
+pub fn get_next_batch_to_settle(e: &Env) -> u64 {
+    e.storage().instance().
+get(&DataKey::NextBatchToSettle).unwrap_or(1)
+}
+
+pub fn get_batch(e: &Env, batch_id: u64) -> Option<Batch> {
+    let key = BatchDataKey::Batch(batch_id);
+    let batch = e.storage().persistent().get(&key);
+    if batch.is_some() {
+        e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    batch
+}
+
+pub fn get_batch_order(e: &Env, order_id: u64) -> Option<BatchOrder> {
+    let key = BatchDataKey::Order(order_id);
+    let order = e.storage().persistent().get(&key);
+    if order.is_some() {
+        e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    order
+}
+
 pub fn put_factory(e: &Env, factory: Address) {
     e.storage().instance().
 set(&DataKey::Factory, &factory);
@@ -110,4 +220,90 @@ set(&DataKey::Reserve1, &amount)
 pub fn put_klast(e: &Env, klast: i128) {
     e.storage().instance().
 set(&DataKey::KLast, &klast);
-}
\ No newline at end of file
+}
+
+pub fn put_hook(e: &Env, hook: Address) {
+    e.storage().instance().
+set(&DataKey::Hook, &hook);
+}
+
+pub fn put_max_swap_price_impact(e: &Env, max_bps: u32) {
+    e.storage().instance().
+set(&DataKey::MaxSwapPriceImpact, &max_bps);
+}
+
+pub fn put_max_ledger_price_impact(e: &Env, max_bps: u32) {
+    e.storage().instance().
+set(&DataKey::MaxLedgerPriceImpact, &max_bps);
+}
+
+pub fn put_ledger_price_impact(e: &Env, sequence: u32, price_impact_bps: i128) {
+    e.storage().instance().
+set(&DataKey::LedgerPriceImpact, &(sequence, price_impact_bps));
+}
+
+pub fn put_liquidity_cooldown(e: &Env, ledgers: u32) {
+    e.storage().instance().
+set(&DataKey::LiquidityCooldown, &ledgers);
+}
+
+pub fn put_stats(e: &Env, stats: &PairStats) {
+    e.storage().instance().
+set(&DataKey::Stats, stats);
+}
+
+pub fn put_batch_window(e: &Env, ledgers: u32) {
+    e.storage().instance().
+set(&DataKey::BatchWindow, &ledgers);
+}
+
+pub fn put_batch_escrow_0(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("put_batch_escrow_0: amount cannot be negative")
+    }
+    e.storage().instance().
+set(&DataKey::BatchEscrow0, &amount);
+}
+
+pub fn put_batch_escrow_1(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("put_batch_escrow_1: amount cannot be negative")
+    }
+    e.storage().instance().
+set(&DataKey::BatchEscrow1, &amount);
+}
+
+pub fn put_next_batch_order_id(e: &Env, order_id: u64) {
+    e.storage().instance().
+set(&DataKey::NextBatchOrderId, &order_id);
+}
+
+pub fn put_last_batch(e: &Env, batch_id: u64, end_ledger: u32) {
+    e.storage().instance().
+set(&DataKey::LastBatch, &(batch_id, end_ledger));
+}
+
+pub fn put_next_batch_to_settle(e: &Env, batch_id: u64) {
+    e.storage().instance().
+set(&DataKey::NextBatchToSettle, &batch_id);
+}
+
+pub fn put_batch(e: &Env, batch_id: u64, batch: &Batch) {
+    let key = BatchDataKey::Batch(batch_id);
+    e.storage().persistent().set(&key, batch);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn remove_batch(e: &Env, batch_id: u64) {
+    e.storage().persistent().remove(&BatchDataKey::Batch(batch_id));
+}
+
+pub fn put_batch_order(e: &Env, order_id: u64, order: &BatchOrder) {
+    let key = BatchDataKey::Order(order_id);
+    e.storage().persistent().set(&key, order);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn remove_batch_order(e: &Env, order_id: u64) {
+    e.storage().persistent().remove(&BatchDataKey::Order(order_id));
+}
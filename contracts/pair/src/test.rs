@@ -134,6 +134,12 @@ mod fee;
 mod skim;
 mod sync;
 mod events;
+mod hooks;
+mod flash_loan;
+mod price_guard;
+mod liquidity_cooldown;
+mod stats;
+mod batch_auction;
 // mod decode; // wont be used for now
 
 // Test forked by stellar/soroban-examples
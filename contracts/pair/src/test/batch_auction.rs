@@ -0,0 +1,259 @@
+use crate::test::{SoroswapPairTest};
+use crate::test::deposit::add_liquidity;
+use crate::test::pair::{SoroswapPairError, Batch, BatchOrder};
+use crate::event::BatchSettleEvent;
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, vec, Address, IntoVal, symbol_short};
+
+fn advance_ledgers(test: &SoroswapPairTest, ledgers: u32) {
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number = li.sequence_number + ledgers;
+    });
+}
+
+// Pair with (50_000_000, 100_000_000) reserves and a batch auction window of 5 ledgers
+fn setup_with_batch_auction<'a>() -> SoroswapPairTest<'a> {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    test.contract.set_batch_auction(&5);
+    test
+}
+
+fn new_user(test: &SoroswapPairTest) -> Address {
+    let user = Address::generate(&test.env);
+    test.token_0.mint(&user, &1_000_000_000);
+    test.token_1.mint(&user, &1_000_000_000);
+    user
+}
+
+#[test]
+fn batch_auction_disabled_by_default() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    assert_eq!(test.contract.batch_auction(), 0);
+
+    let result = test.contract.try_submit_order(&test.user, &test.token_0.address, &10_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchAuctionDisabled)));
+    let result = test.contract.try_settle_batch();
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchNothingToSettle)));
+}
+
+#[test]
+fn set_batch_auction_not_yet_initialized() {
+    let test = SoroswapPairTest::setup();
+    let result = test.contract.try_set_batch_auction(&5);
+    assert_eq!(result, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn swap_disabled_in_batch_auction() {
+    let test = setup_with_batch_auction();
+    assert_eq!(test.contract.batch_auction(), 5);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    let result = test.contract.try_swap(&0, &16_624_979, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapBatchAuctionOnly)));
+
+    // Disabling the batch auction enables swaps again
+    test.contract.set_batch_auction(&0);
+    test.contract.swap(&0, &16_624_979, &test.user);
+}
+
+#[test]
+fn submit_order() {
+    let test = setup_with_batch_auction();
+    advance_ledgers(&test, 100);
+    let user_balance = test.token_0.balance(&test.user);
+
+    let order_id = test.contract.submit_order(&test.user, &test.token_0.address, &10_000_000, &15_000_000);
+    assert_eq!(order_id, 1);
+    assert_eq!(test.contract.get_batch_order(&order_id), Some(BatchOrder {
+        owner: test.user.clone(),
+        batch_id: 1,
+        token_0_in: true,
+        amount_in: 10_000_000,
+        min_amount_out: 15_000_000,
+        fee_bps: 30,
+    }));
+    assert_eq!(test.contract.get_batch(&1), Some(Batch {
+        end_ledger: 105,
+        order_ids: vec![&test.env, 1],
+    }));
+    assert_eq!(test.token_0.balance(&test.user), user_balance - 10_000_000);
+
+    // Escrowed tokens are not part of the reserves, and can't be skimmed or synced
+    assert_eq!(test.contract.get_reserves(), (50_000_000, 100_000_000));
+    let skimmer = Address::generate(&test.env);
+    test.contract.skim(&skimmer);
+    assert_eq!(test.token_0.balance(&skimmer), 0);
+    test.contract.sync();
+    assert_eq!(test.contract.get_reserves(), (50_000_000, 100_000_000));
+    assert_eq!(test.token_0.balance(&test.contract.address), 60_000_000);
+}
+
+#[test]
+fn submit_order_invalid() {
+    let test = setup_with_batch_auction();
+    let result = test.contract.try_submit_order(&test.user, &Address::generate(&test.env), &10_000_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchOrderInvalidToken)));
+    let result = test.contract.try_submit_order(&test.user, &test.token_0.address, &0, &0);
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchOrderInvalidAmount)));
+    let result = test.contract.try_submit_order(&test.user, &test.token_0.address, &10_000_000, &-1);
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchOrderInvalidAmount)));
+}
+
+#[test]
+fn settle_batch_not_closed() {
+    let test = setup_with_batch_auction();
+    test.contract.submit_order(&test.user, &test.token_0.address, &10_000_000, &0);
+
+    let result = test.contract.try_settle_batch();
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchNotClosed)));
+    advance_ledgers(&test, 4);
+    let result = test.contract.try_settle_batch();
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchNotClosed)));
+
+    advance_ledgers(&test, 1);
+    assert_eq!(test.contract.settle_batch(), 1);
+    assert_eq!(test.contract.next_batch_to_settle(), 2);
+    assert_eq!(test.contract.get_batch(&1), None);
+    let result = test.contract.try_settle_batch();
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchNothingToSettle)));
+}
+
+#[test]
+fn settle_batch_both_sides() {
+    let test = setup_with_batch_auction();
+    let user_2 = new_user(&test);
+    let (user_balance_1, user_2_balance_0) = (test.token_1.balance(&test.user), test.token_0.balance(&user_2));
+
+    let order_1 = test.contract.submit_order(&test.user, &test.token_0.address, &10_000_000, &0);
+    let order_2 = test.contract.submit_order(&user_2, &test.token_1.address, &10_000_000, &0);
+    advance_ledgers(&test, 5);
+    test.contract.settle_batch();
+
+    // 9_970_000 of each token after the fee: token 0 clears at 109_970_000 / 59_970_000
+    // A swap of 10_000_000 token 0 alone would have given 16_624_979 token 1
+    assert_eq!(test.token_1.balance(&test.user), user_balance_1 + 18_282_489);
+    assert_eq!(test.token_0.balance(&user_2), user_2_balance_0 + 5_436_945);
+    assert_eq!(test.contract.get_reserves(), (60_000_000 - 5_436_945, 110_000_000 - 18_282_489));
+    assert_eq!(test.contract.get_batch_order(&order_1), None);
+    assert_eq!(test.contract.get_batch_order(&order_2), None);
+
+    // Each filled order counts as a swap
+    let stats = test.contract.stats();
+    assert_eq!(stats.swap_count, 2);
+    assert_eq!((stats.volume_0, stats.volume_1), (10_000_000, 10_000_000));
+    assert_eq!((stats.fees_0, stats.fees_1), (30_000, 30_000));
+
+    let settle_event = test.env.events().all().last().unwrap();
+    let expected_settle_event: BatchSettleEvent = BatchSettleEvent {
+        batch_id: 1,
+        amount_0_in: 10_000_000,
+        amount_1_in: 10_000_000,
+        amount_0_out: 5_436_945,
+        amount_1_out: 18_282_489,
+        filled_orders: 2,
+        unfilled_orders: 0,
+    };
+    assert_eq!(
+        vec![&test.env, settle_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPair", symbol_short!("settle")).into_val(&test.env),
+                (expected_settle_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn settle_batch_uniform_price() {
+    let test = setup_with_batch_auction();
+    let user_2 = new_user(&test);
+    let (user_balance_1, user_2_balance_1) = (test.token_1.balance(&test.user), test.token_1.balance(&user_2));
+
+    // The order does not matter: both orders get the same price, 100_000_000 / 89_880_000
+    test.contract.submit_order(&user_2, &test.token_0.address, &30_000_000, &0);
+    test.contract.submit_order(&test.user, &test.token_0.address, &10_000_000, &0);
+    advance_ledgers(&test, 5);
+    test.contract.settle_batch();
+
+    assert_eq!(test.token_1.balance(&test.user), user_balance_1 + 11_092_567);
+    assert_eq!(test.token_1.balance(&user_2), user_2_balance_1 + 33_277_703);
+    assert_eq!(test.contract.get_reserves(), (90_000_000, 100_000_000 - 11_092_567 - 33_277_703));
+}
+
+#[test]
+fn settle_batch_min_amount_out_not_met() {
+    let test = setup_with_batch_auction();
+    let user_2 = new_user(&test);
+    let (user_balance_0, user_2_balance_1) = (test.token_0.balance(&test.user), test.token_1.balance(&user_2));
+
+    // At the price of both orders the first one would get 11_092_567, so it is left out
+    let order_1 = test.contract.submit_order(&test.user, &test.token_0.address, &10_000_000, &12_000_000);
+    test.contract.submit_order(&user_2, &test.token_0.address, &30_000_000, &0);
+    advance_ledgers(&test, 5);
+    test.contract.settle_batch();
+
+    // Without it, token 0 clears at 100_000_000 / 79_910_000
+    assert_eq!(test.token_1.balance(&user_2), user_2_balance_1 + 37_429_608);
+    assert_eq!(test.contract.get_reserves(), (80_000_000, 100_000_000 - 37_429_608));
+    assert_eq!(test.contract.get_batch_order(&order_1).unwrap().amount_in, 10_000_000);
+
+    assert_eq!(test.contract.withdraw_order(&order_1), 10_000_000);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0);
+    assert_eq!(test.contract.get_reserves(), (80_000_000, 100_000_000 - 37_429_608));
+    let result = test.contract.try_withdraw_order(&order_1);
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchOrderNotFound)));
+}
+
+#[test]
+fn withdraw_order_pending() {
+    let test = setup_with_batch_auction();
+    let order_id = test.contract.submit_order(&test.user, &test.token_0.address, &10_000_000, &0);
+    let result = test.contract.try_withdraw_order(&order_id);
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchOrderPending)));
+
+    advance_ledgers(&test, 5);
+    let result = test.contract.try_withdraw_order(&order_id);
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchOrderPending)));
+}
+
+#[test]
+fn orders_after_the_window_go_to_the_next_batch() {
+    let test = setup_with_batch_auction();
+    test.contract.submit_order(&test.user, &test.token_0.address, &10_000_000, &0);
+    advance_ledgers(&test, 4);
+    test.contract.submit_order(&test.user, &test.token_0.address, &10_000_000, &0);
+    advance_ledgers(&test, 1);
+    let order_id = test.contract.submit_order(&test.user, &test.token_1.address, &10_000_000, &0);
+
+    assert_eq!(test.contract.get_batch(&1).unwrap().order_ids, vec![&test.env, 1, 2]);
+    assert_eq!(test.contract.get_batch(&2).unwrap().order_ids, vec![&test.env, 3]);
+    assert_eq!(test.contract.get_batch_order(&order_id).unwrap().batch_id, 2);
+
+    // Batches are settled in order
+    assert_eq!(test.contract.settle_batch(), 1);
+    let result = test.contract.try_settle_batch();
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchNotClosed)));
+    advance_ledgers(&test, 5);
+    assert_eq!(test.contract.settle_batch(), 2);
+}
+
+#[test]
+fn batch_full() {
+    let test = setup_with_batch_auction();
+    for _ in 0..32 {
+        test.contract.submit_order(&test.user, &test.token_0.address, &10_000, &0);
+    }
+    let result = test.contract.try_submit_order(&test.user, &test.token_0.address, &10_000, &0);
+    assert_eq!(result, Err(Ok(SoroswapPairError::BatchFull)));
+
+    advance_ledgers(&test, 5);
+    test.contract.submit_order(&test.user, &test.token_0.address, &10_000, &0);
+    assert_eq!(test.contract.settle_batch(), 1);
+}
@@ -0,0 +1,188 @@
+use crate::test::{SoroswapPairTest};
+use crate::test::deposit::add_liquidity;
+use crate::test::pair::SoroswapPairError;
+use crate::event::FlashLoanEvent;
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, Bytes, IntoVal, Symbol};
+
+mod receiver;
+use receiver::{FlashLoanReceiver, FlashLoanReceiverClient};
+
+fn setup_receiver<'a>(test: &SoroswapPairTest) -> FlashLoanReceiverClient<'a> {
+    let receiver = FlashLoanReceiverClient::new(&test.env, &test.env.register_contract(None, FlashLoanReceiver));
+    // The receiver needs some tokens to pay the fee
+    test.token_0.transfer(&test.user, &receiver.address, &1_000_000);
+    test.token_1.transfer(&test.user, &receiver.address, &1_000_000);
+    receiver
+}
+
+#[test]
+fn flash_loan_not_yet_initialized() {
+    let test = SoroswapPairTest::setup();
+    let receiver = Address::generate(&test.env);
+    let res = test.contract.try_flash_loan(&receiver, &test.token_0.address, &1_000, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn flash_loan_invalid_token() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    let res = test.contract.try_flash_loan(&receiver.address, &test.contract.address, &1_000, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapPairError::FlashLoanInvalidToken)));
+}
+
+#[test]
+fn flash_loan_insufficient_amount() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    let res = test.contract.try_flash_loan(&receiver.address, &test.token_0.address, &0, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapPairError::FlashLoanInsufficientAmount)));
+    let res = test.contract.try_flash_loan(&receiver.address, &test.token_0.address, &-1, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapPairError::FlashLoanInsufficientAmount)));
+}
+
+#[test]
+fn flash_loan_insufficient_liquidity() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    let res = test.contract.try_flash_loan(&receiver.address, &test.token_0.address, &50_000_000, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapPairError::FlashLoanInsufficientLiquidity)));
+    let res = test.contract.try_flash_loan(&receiver.address, &test.token_1.address, &100_000_000, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapPairError::FlashLoanInsufficientLiquidity)));
+}
+
+#[test]
+fn flash_loan_not_repaid() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    let res = test.contract.try_flash_loan(&receiver.address, &test.token_0.address, &10_000_000, &Bytes::from_slice(&test.env, b"no_fee"));
+    assert_eq!(res, Err(Ok(SoroswapPairError::FlashLoanNotRepaid)));
+    assert_eq!(test.contract.get_reserves(), (50_000_000, 100_000_000));
+}
+
+#[test]
+fn flash_loan_unsynced_balance_does_not_pay_the_fee() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    // Tokens sent to the pair without a sync can't be used to pay the fee
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_flash_loan(&receiver.address, &test.token_0.address, &10_000_000, &Bytes::from_slice(&test.env, b"no_fee"));
+    assert_eq!(res, Err(Ok(SoroswapPairError::FlashLoanNotRepaid)));
+}
+
+#[test]
+fn flash_loan_token_0() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    // Default fee is 0.3%
+    assert_eq!(test.factory.flash_loan_fee(), 30);
+    let amount: i128 = 10_000_000;
+    let expected_fee: i128 = 30_000;
+
+    let fee = test.contract.flash_loan(&receiver.address, &test.token_0.address, &amount, &Bytes::new(&test.env));
+    assert_eq!(fee, expected_fee);
+    assert_eq!(receiver.last_loan(), (amount, expected_fee));
+
+    // The fee stays in the reserves, for the LPs
+    assert_eq!(test.contract.get_reserves(), (50_000_000 + expected_fee, 100_000_000));
+    assert_eq!(test.token_0.balance(&test.contract.address), 50_000_000 + expected_fee);
+    assert_eq!(test.token_0.balance(&receiver.address), 1_000_000 - expected_fee);
+    assert_eq!(test.token_1.balance(&receiver.address), 1_000_000);
+}
+
+#[test]
+fn flash_loan_token_1_fee_rounds_up() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    // 1_001 * 30 / 10_000 = 3.003 --> 4
+    let fee = test.contract.flash_loan(&receiver.address, &test.token_1.address, &1_001, &Bytes::new(&test.env));
+    assert_eq!(fee, 4);
+    assert_eq!(test.contract.get_reserves(), (50_000_000, 100_000_004));
+}
+
+#[test]
+fn flash_loan_custom_fee() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    test.factory.set_flash_loan_fee(&100);
+    let fee = test.contract.flash_loan(&receiver.address, &test.token_0.address, &10_000_000, &Bytes::new(&test.env));
+    assert_eq!(fee, 100_000);
+
+    test.factory.set_flash_loan_fee(&0);
+    let fee = test.contract.flash_loan(&receiver.address, &test.token_0.address, &10_000_000, &Bytes::from_slice(&test.env, b"no_fee"));
+    assert_eq!(fee, 0);
+    assert_eq!(test.contract.get_reserves(), (50_100_000, 100_000_000));
+}
+
+#[test]
+fn flash_loan_protocol_fee() {
+    let test = SoroswapPairTest::setup();
+    test.factory.set_fees_enabled(&true);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    test.contract.flash_loan(&receiver.address, &test.token_0.address, &10_000_000, &Bytes::new(&test.env));
+    assert_eq!(test.contract.get_reserves(), (50_030_000, 100_000_000));
+    assert_eq!(test.contract.balance(&test.admin), 0);
+
+    // The protocol takes its cut on the next deposit, through mint_fee
+    // n = total_supply*(root_k-root_klast)/(5*root_k + root_klast)
+    // = 70710678*(70731888-70710678)/(5*70731888+70710678) = 3534
+    add_liquidity(&test, &1_000_000, &2_000_000);
+    assert_eq!(test.contract.balance(&test.admin), 3534);
+}
+
+#[test]
+fn flash_loan_event() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    let receiver = setup_receiver(&test);
+
+    test.contract.flash_loan(&receiver.address, &test.token_0.address, &10_000_000, &Bytes::new(&test.env));
+    let flash_loan_event = test.env.events().all().last().unwrap();
+
+    let expected_flash_loan_event: FlashLoanEvent = FlashLoanEvent {
+        receiver: receiver.address.clone(),
+        token: test.token_0.address.clone(),
+        amount: 10_000_000,
+        fee: 30_000,
+    };
+
+    assert_eq!(
+        vec![&test.env, flash_loan_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPair", Symbol::new(&test.env, "flash_loan")).into_val(&test.env),
+                (expected_flash_loan_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,37 @@
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Bytes, Env};
+use soroswap_flash_loan_interface::SoroswapFlashLoanReceiverTrait;
+
+// FLASH LOAN RECEIVER: Pays back the loan plus the fee out of its own balance.
+// If `data` is "no_fee", it only pays back the borrowed amount.
+
+#[contracttype]
+#[derive(Clone)]
+enum ReceiverDataKey {
+    LastLoan,
+}
+
+#[contract]
+pub struct FlashLoanReceiver;
+
+#[contractimpl]
+impl FlashLoanReceiver {
+    /// Returns the (amount, fee) of the last loan received
+    pub fn last_loan(e: Env) -> (i128, i128) {
+        e.storage().instance().get(&ReceiverDataKey::LastLoan).unwrap_or((0, 0))
+    }
+}
+
+#[contractimpl]
+impl SoroswapFlashLoanReceiverTrait for FlashLoanReceiver {
+    fn on_flash_loan(e: Env, pair: Address, token: Address, amount: i128, fee: i128, data: Bytes) {
+        pair.require_auth();
+        e.storage().instance().set(&ReceiverDataKey::LastLoan, &(amount, fee));
+
+        let repay = if data == Bytes::from_slice(&e, b"no_fee") {
+            amount
+        } else {
+            amount.checked_add(fee).unwrap()
+        };
+        token::Client::new(&e, &token).transfer(&e.current_contract_address(), &pair, &repay);
+    }
+}
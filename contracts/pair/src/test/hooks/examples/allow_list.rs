@@ -0,0 +1,51 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+use soroswap_hook_interface::{SoroswapHookTrait, BeforeSwapResponse, DEFAULT_FEE_BPS};
+
+// ALLOW LIST HOOK: Only allowed addresses can swap, deposit or withdraw.
+
+#[contracttype]
+#[derive(Clone)]
+enum AllowListDataKey {
+    Allowed(Address),
+}
+
+#[contract]
+pub struct AllowListHook;
+
+#[contractimpl]
+impl AllowListHook {
+    pub fn allow(e: Env, user: Address) {
+        e.storage().instance().set(&AllowListDataKey::Allowed(user), &true);
+    }
+
+    pub fn is_allowed(e: Env, user: Address) -> bool {
+        e.storage().instance().has(&AllowListDataKey::Allowed(user))
+    }
+}
+
+#[contractimpl]
+impl SoroswapHookTrait for AllowListHook {
+    fn before_swap(e: Env, pair: Address, _amount_0_out: i128, _amount_1_out: i128, to: Address) -> BeforeSwapResponse {
+        pair.require_auth();
+        BeforeSwapResponse {
+            allow: Self::is_allowed(e, to),
+            fee_bps: DEFAULT_FEE_BPS,
+        }
+    }
+
+    fn after_swap(_e: Env, _pair: Address, _amount_0_in: i128, _amount_1_in: i128, _amount_0_out: i128, _amount_1_out: i128, _to: Address) {}
+
+    fn before_deposit(e: Env, pair: Address, to: Address) -> bool {
+        pair.require_auth();
+        Self::is_allowed(e, to)
+    }
+
+    fn after_deposit(_e: Env, _pair: Address, _to: Address, _amount_0: i128, _amount_1: i128, _liquidity: i128) {}
+
+    fn before_withdraw(e: Env, pair: Address, to: Address, _liquidity: i128) -> bool {
+        pair.require_auth();
+        Self::is_allowed(e, to)
+    }
+
+    fn after_withdraw(_e: Env, _pair: Address, _to: Address, _liquidity: i128, _amount_0: i128, _amount_1: i128) {}
+}
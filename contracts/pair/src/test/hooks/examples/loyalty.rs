@@ -0,0 +1,60 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+use soroswap_hook_interface::{SoroswapHookTrait, BeforeSwapResponse, DEFAULT_FEE_BPS};
+
+// LOYALTY HOOK: Gives one point per unit of token swapped in, and one point per LP token minted.
+// Points are removed when liquidity is withdrawn.
+
+#[contracttype]
+#[derive(Clone)]
+enum LoyaltyDataKey {
+    Points(Address),
+}
+
+#[contract]
+pub struct LoyaltyHook;
+
+fn add_points(e: &Env, user: Address, points: i128) {
+    let key = LoyaltyDataKey::Points(user);
+    let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
+    e.storage().instance().set(&key, &(current + points));
+}
+
+#[contractimpl]
+impl LoyaltyHook {
+    pub fn points(e: Env, user: Address) -> i128 {
+        e.storage().instance().get(&LoyaltyDataKey::Points(user)).unwrap_or(0)
+    }
+}
+
+#[contractimpl]
+impl SoroswapHookTrait for LoyaltyHook {
+    fn before_swap(_e: Env, _pair: Address, _amount_0_out: i128, _amount_1_out: i128, _to: Address) -> BeforeSwapResponse {
+        BeforeSwapResponse {
+            allow: true,
+            fee_bps: DEFAULT_FEE_BPS,
+        }
+    }
+
+    fn after_swap(e: Env, pair: Address, amount_0_in: i128, amount_1_in: i128, _amount_0_out: i128, _amount_1_out: i128, to: Address) {
+        pair.require_auth();
+        add_points(&e, to, amount_0_in + amount_1_in);
+    }
+
+    fn before_deposit(_e: Env, _pair: Address, _to: Address) -> bool {
+        true
+    }
+
+    fn after_deposit(e: Env, pair: Address, to: Address, _amount_0: i128, _amount_1: i128, liquidity: i128) {
+        pair.require_auth();
+        add_points(&e, to, liquidity);
+    }
+
+    fn before_withdraw(_e: Env, _pair: Address, _to: Address, _liquidity: i128) -> bool {
+        true
+    }
+
+    fn after_withdraw(e: Env, pair: Address, to: Address, liquidity: i128, _amount_0: i128, _amount_1: i128) {
+        pair.require_auth();
+        add_points(&e, to, -liquidity);
+    }
+}
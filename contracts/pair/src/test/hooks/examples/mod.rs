@@ -0,0 +1,5 @@
+// Example hooks. They are not meant to be deployed as they are, but to show how integrators
+// can build KYC checks, fee rebates and loyalty points on top of a Soroswap Pair.
+pub mod allow_list;
+pub mod rebate;
+pub mod loyalty;
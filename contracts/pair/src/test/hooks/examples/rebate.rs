@@ -0,0 +1,45 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+use soroswap_hook_interface::{SoroswapHookTrait, BeforeSwapResponse, DEFAULT_FEE_BPS};
+
+// REBATE HOOK: Charges a custom swap fee to some addresses.
+
+#[contracttype]
+#[derive(Clone)]
+enum RebateDataKey {
+    Fee(Address),
+}
+
+#[contract]
+pub struct RebateHook;
+
+#[contractimpl]
+impl RebateHook {
+    pub fn set_fee(e: Env, user: Address, fee_bps: u32) {
+        e.storage().instance().set(&RebateDataKey::Fee(user), &fee_bps);
+    }
+}
+
+#[contractimpl]
+impl SoroswapHookTrait for RebateHook {
+    fn before_swap(e: Env, pair: Address, _amount_0_out: i128, _amount_1_out: i128, to: Address) -> BeforeSwapResponse {
+        pair.require_auth();
+        BeforeSwapResponse {
+            allow: true,
+            fee_bps: e.storage().instance().get(&RebateDataKey::Fee(to)).unwrap_or(DEFAULT_FEE_BPS),
+        }
+    }
+
+    fn after_swap(_e: Env, _pair: Address, _amount_0_in: i128, _amount_1_in: i128, _amount_0_out: i128, _amount_1_out: i128, _to: Address) {}
+
+    fn before_deposit(_e: Env, _pair: Address, _to: Address) -> bool {
+        true
+    }
+
+    fn after_deposit(_e: Env, _pair: Address, _to: Address, _amount_0: i128, _amount_1: i128, _liquidity: i128) {}
+
+    fn before_withdraw(_e: Env, _pair: Address, _to: Address, _liquidity: i128) -> bool {
+        true
+    }
+
+    fn after_withdraw(_e: Env, _pair: Address, _to: Address, _liquidity: i128, _amount_0: i128, _amount_1: i128) {}
+}
@@ -0,0 +1,153 @@
+use crate::test::{SoroswapPairTest, SoroswapPairClient};
+use crate::test::deposit::add_liquidity;
+use crate::test::pair::SoroswapPairError;
+use soroban_sdk::{testutils::{Address as _}, Address};
+
+mod examples;
+use examples::allow_list::{AllowListHook, AllowListHookClient};
+use examples::rebate::{RebateHook, RebateHookClient};
+use examples::loyalty::{LoyaltyHook, LoyaltyHookClient};
+
+fn initialize_with_hook(test: &SoroswapPairTest, hook: &Address) {
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.set_hook(hook);
+}
+
+#[test]
+fn hook_none_by_default() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    assert_eq!(test.contract.hook(), None);
+}
+
+#[test]
+fn set_hook_not_yet_initialized() {
+    let test = SoroswapPairTest::setup();
+    let hook = test.env.register_contract(None, LoyaltyHook);
+    let result = test.contract.try_set_hook(&hook);
+    assert_eq!(result, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn set_hook_only_once() {
+    let test = SoroswapPairTest::setup();
+    let hook = test.env.register_contract(None, LoyaltyHook);
+    initialize_with_hook(&test, &hook);
+    assert_eq!(test.contract.hook(), Some(hook.clone()));
+
+    let other_hook = test.env.register_contract(None, LoyaltyHook);
+    let result = test.contract.try_set_hook(&other_hook);
+    assert_eq!(result, Err(Ok(SoroswapPairError::HookAlreadySet)));
+    assert_eq!(test.contract.hook(), Some(hook));
+}
+
+#[test]
+fn create_pair_with_hook_through_factory() {
+    let test = SoroswapPairTest::setup();
+    let hook = test.env.register_contract(None, LoyaltyHook);
+    let pair_address = test.factory.create_pair_with_hook(&test.token_0.address, &test.token_1.address, &hook);
+    let pair = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair.hook(), Some(hook));
+    assert_eq!(pair.factory(), test.factory.address);
+}
+
+#[test]
+fn allow_list_hook() {
+    let test = SoroswapPairTest::setup();
+    let hook = AllowListHookClient::new(&test.env, &test.env.register_contract(None, AllowListHook));
+    initialize_with_hook(&test, &hook.address);
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_0);
+    test.token_1.transfer(&test.user, &test.contract.address, &amount_1);
+    let result = test.contract.try_deposit(&test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::HookRejected)));
+
+    hook.allow(&test.user);
+    test.contract.deposit(&test.user);
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+
+    let swap_amount_0: i128 = 10_000_000;
+    let expected_output_amount_1: i128 = 16624979;
+    test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+
+    let not_allowed = Address::generate(&test.env);
+    let result = test.contract.try_swap(&0, &expected_output_amount_1, &not_allowed);
+    assert_eq!(result, Err(Ok(SoroswapPairError::HookRejected)));
+
+    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    assert_eq!(test.contract.get_reserves(), (amount_0 + swap_amount_0, amount_1 - expected_output_amount_1));
+
+    test.contract.transfer(&test.user, &test.contract.address, &1_000_000);
+    let result = test.contract.try_withdraw(&not_allowed);
+    assert_eq!(result, Err(Ok(SoroswapPairError::HookRejected)));
+    test.contract.withdraw(&test.user);
+}
+
+#[test]
+fn rebate_hook_zero_fee() {
+    let test = SoroswapPairTest::setup();
+    let hook = RebateHookClient::new(&test.env, &test.env.register_contract(None, RebateHook));
+    initialize_with_hook(&test, &hook.address);
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let swap_amount_0: i128 = 10_000_000;
+    // Without fee: 100_000_000 - (50_000_000*100_000_000)/60_000_000 = 16_666_666
+    let expected_output_amount_1: i128 = 16_666_666;
+    test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+
+    // With the default fee, this amount does not meet K
+    let result = test.contract.try_swap(&0, &expected_output_amount_1, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
+
+    hook.set_fee(&test.user, &0);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    assert_eq!(test.contract.get_reserves(), (amount_0 + swap_amount_0, amount_1 - expected_output_amount_1));
+}
+
+#[test]
+fn rebate_hook_fee_out_of_bounds() {
+    let test = SoroswapPairTest::setup();
+    let hook = RebateHookClient::new(&test.env, &test.env.register_contract(None, RebateHook));
+    initialize_with_hook(&test, &hook.address);
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    hook.set_fee(&test.user, &31);
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    let result = test.contract.try_swap(&0, &1_000, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::HookFeeOutOfBounds)));
+
+    // Hooks can only give rebates: the default 0.3% is the maximum allowed
+    hook.set_fee(&test.user, &30);
+    test.contract.swap(&0, &1_000, &test.user);
+}
+
+#[test]
+fn loyalty_hook() {
+    let test = SoroswapPairTest::setup();
+    let hook = LoyaltyHookClient::new(&test.env, &test.env.register_contract(None, LoyaltyHook));
+    initialize_with_hook(&test, &hook.address);
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    let liquidity = add_liquidity(&test, &amount_0, &amount_1);
+    assert_eq!(liquidity, 70_709_678);
+    assert_eq!(hook.points(&test.user), liquidity);
+
+    let swap_amount_0: i128 = 10_000_000;
+    let expected_output_amount_1: i128 = 16624979;
+    test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    assert_eq!(hook.points(&test.user), liquidity + swap_amount_0);
+
+    test.contract.transfer(&test.user, &test.contract.address, &1_000_000);
+    test.contract.withdraw(&test.user);
+    assert_eq!(hook.points(&test.user), liquidity + swap_amount_0 - 1_000_000);
+}
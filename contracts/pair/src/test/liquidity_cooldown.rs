@@ -0,0 +1,236 @@
+use crate::test::{SoroswapPairTest, SoroswapPairClient};
+use crate::test::deposit::add_liquidity;
+use crate::test::pair::SoroswapPairError;
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+
+fn advance_ledgers(test: &SoroswapPairTest, ledgers: u32) {
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number = li.sequence_number + ledgers;
+    });
+}
+
+fn setup_with_cooldown<'a>(cooldown: u32) -> SoroswapPairTest<'a> {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.set_liquidity_cooldown(&cooldown);
+    test
+}
+
+#[test]
+fn liquidity_cooldown_disabled_by_default() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    assert_eq!(test.contract.liquidity_cooldown(), 0);
+
+    // Deposit and withdraw in the same ledger
+    let liquidity = add_liquidity(&test, &50_000_000, &100_000_000);
+    assert_eq!(test.contract.locked_balance(&test.user), 0);
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity);
+    test.contract.withdraw(&test.user);
+}
+
+#[test]
+fn set_liquidity_cooldown_not_yet_initialized() {
+    let test = SoroswapPairTest::setup();
+    let result = test.contract.try_set_liquidity_cooldown(&10);
+    assert_eq!(result, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn locked_liquidity_can_not_be_withdrawn() {
+    let test = setup_with_cooldown(10);
+    assert_eq!(test.contract.liquidity_cooldown(), 10);
+
+    let liquidity = add_liquidity(&test, &50_000_000, &100_000_000);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity);
+
+    // Locked LP tokens can't be sent back to the pair
+    assert!(test.contract.try_transfer(&test.user, &test.contract.address, &1).is_err());
+
+    advance_ledgers(&test, 9);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity);
+    assert!(test.contract.try_transfer(&test.user, &test.contract.address, &1).is_err());
+
+    advance_ledgers(&test, 1);
+    assert_eq!(test.contract.locked_balance(&test.user), 0);
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity);
+    test.contract.withdraw(&test.user);
+    assert_eq!(test.contract.balance(&test.user), 0);
+}
+
+#[test]
+fn locked_liquidity_can_not_be_transferred() {
+    let test = setup_with_cooldown(10);
+    let user_2 = Address::generate(&test.env);
+    let liquidity = add_liquidity(&test, &50_000_000, &100_000_000);
+
+    // Neither with transfer, transfer_from nor burn
+    assert!(test.contract.try_transfer(&test.user, &user_2, &liquidity).is_err());
+    test.contract.approve(&test.user, &user_2, &liquidity, &1000);
+    assert!(test.contract.try_transfer_from(&user_2, &test.user, &user_2, &liquidity).is_err());
+    assert!(test.contract.try_burn(&test.user, &liquidity).is_err());
+    assert_eq!(test.contract.balance(&test.user), liquidity);
+}
+
+#[test]
+fn transferred_liquidity_is_not_locked() {
+    let test = setup_with_cooldown(10);
+    let user_2 = Address::generate(&test.env);
+    let liquidity = add_liquidity(&test, &50_000_000, &100_000_000);
+    advance_ledgers(&test, 10);
+
+    // LP tokens that were already unlocked stay unlocked for the receiver
+    test.contract.transfer(&test.user, &user_2, &liquidity);
+    assert_eq!(test.contract.locked_balance(&user_2), 0);
+    test.contract.transfer(&user_2, &test.contract.address, &liquidity);
+    test.contract.withdraw(&user_2);
+}
+
+#[test]
+fn only_new_deposits_are_locked() {
+    let test = setup_with_cooldown(10);
+    let liquidity_1 = add_liquidity(&test, &50_000_000, &100_000_000);
+    advance_ledgers(&test, 10);
+
+    let liquidity_2 = add_liquidity(&test, &5_000_000, &10_000_000);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity_2);
+
+    // Only the first deposit can be withdrawn
+    assert!(test.contract.try_transfer(&test.user, &test.contract.address, &(liquidity_1 + 1)).is_err());
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity_1);
+    test.contract.withdraw(&test.user);
+    assert_eq!(test.contract.balance(&test.user), liquidity_2);
+}
+
+#[test]
+fn new_cooldown_does_not_change_open_locks() {
+    let test = setup_with_cooldown(10);
+    let liquidity_1 = add_liquidity(&test, &50_000_000, &100_000_000);
+
+    // A longer cooldown does not delay the liquidity already locked
+    test.contract.set_liquidity_cooldown(&100);
+    advance_ledgers(&test, 5);
+    let liquidity_2 = add_liquidity(&test, &5_000_000, &10_000_000);
+    advance_ledgers(&test, 5);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity_2);
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity_1);
+    test.contract.withdraw(&test.user);
+
+    // Nor does a shorter one unlock it early
+    test.contract.set_liquidity_cooldown(&10);
+    advance_ledgers(&test, 94);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity_2);
+    advance_ledgers(&test, 1);
+    assert_eq!(test.contract.locked_balance(&test.user), 0);
+}
+
+#[test]
+fn deposits_in_the_cooldown_keep_their_own_unlock() {
+    let test = setup_with_cooldown(10);
+    let liquidity_1 = add_liquidity(&test, &50_000_000, &100_000_000);
+    advance_ledgers(&test, 5);
+
+    let liquidity_2 = add_liquidity(&test, &5_000_000, &10_000_000);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity_1 + liquidity_2);
+
+    advance_ledgers(&test, 5);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity_2);
+    advance_ledgers(&test, 5);
+    assert_eq!(test.contract.locked_balance(&test.user), 0);
+}
+
+#[test]
+fn deposits_in_the_cooldown_are_added_to_the_last_lock() {
+    let test = setup_with_cooldown(10);
+    let liquidity_1 = add_liquidity(&test, &50_000_000, &100_000_000);
+    advance_ledgers(&test, 4);
+    let liquidity_2 = add_liquidity(&test, &5_000_000, &10_000_000);
+    advance_ledgers(&test, 4);
+    let liquidity_3 = add_liquidity(&test, &5_000_000, &10_000_000);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity_1 + liquidity_2 + liquidity_3);
+
+    // The first deposit unlocks on time, the next ones a full cooldown after the third one
+    advance_ledgers(&test, 2);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity_2 + liquidity_3);
+    advance_ledgers(&test, 7);
+    assert_eq!(test.contract.locked_balance(&test.user), liquidity_2 + liquidity_3);
+    advance_ledgers(&test, 1);
+    assert_eq!(test.contract.locked_balance(&test.user), 0);
+}
+
+#[test]
+fn deposit_to_someone_else_does_not_extend_their_lock() {
+    let test = setup_with_cooldown(10);
+    let attacker = Address::generate(&test.env);
+    test.token_0.transfer(&test.user, &attacker, &50_000);
+    test.token_1.transfer(&test.user, &attacker, &100_000);
+    let liquidity = add_liquidity(&test, &50_000_000, &100_000_000);
+
+    // Someone keeps depositing dust with the user as receiver right before the cooldown passes
+    let mut dust = 0;
+    for _ in 0..5 {
+        advance_ledgers(&test, 9);
+        test.token_0.transfer(&attacker, &test.contract.address, &5_000);
+        test.token_1.transfer(&attacker, &test.contract.address, &10_000);
+        dust += test.contract.deposit(&test.user);
+        advance_ledgers(&test, 1);
+        assert!(test.contract.locked_balance(&test.user) <= dust);
+    }
+
+    // The liquidity of the user unlocked on time and can be withdrawn
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity);
+    test.contract.withdraw(&test.user);
+    assert_eq!(test.contract.balance(&test.user), dust);
+}
+
+#[test]
+fn deposit_to_someone_else_does_not_lock_unlocked_liquidity() {
+    let test = setup_with_cooldown(10);
+    let attacker = Address::generate(&test.env);
+    let liquidity = add_liquidity(&test, &50_000_000, &100_000_000);
+    advance_ledgers(&test, 10);
+
+    // Someone deposits a small amount with the user as receiver
+    test.token_0.transfer(&test.user, &attacker, &5_000);
+    test.token_1.transfer(&test.user, &attacker, &10_000);
+    test.token_0.transfer(&attacker, &test.contract.address, &5_000);
+    test.token_1.transfer(&attacker, &test.contract.address, &10_000);
+    let dust = test.contract.deposit(&test.user);
+    assert_eq!(test.contract.locked_balance(&test.user), dust);
+
+    // The user can still withdraw the liquidity that was already unlocked
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity);
+    test.contract.withdraw(&test.user);
+    assert_eq!(test.contract.balance(&test.user), dust);
+}
+
+#[test]
+fn deposit_to_the_pair_is_locked() {
+    let test = setup_with_cooldown(10);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    advance_ledgers(&test, 10);
+
+    // Depositing to the pair itself and withdrawing in the same ledger does not work either
+    test.token_0.transfer(&test.user, &test.contract.address, &5_000_000);
+    test.token_1.transfer(&test.user, &test.contract.address, &10_000_000);
+    let liquidity = test.contract.deposit(&test.contract.address);
+    assert_eq!(test.contract.locked_balance(&test.contract.address), liquidity);
+
+    let result = test.contract.try_withdraw(&test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::WithdrawLiquidityLocked)));
+
+    advance_ledgers(&test, 10);
+    test.contract.withdraw(&test.user);
+    assert_eq!(test.contract.balance(&test.contract.address), 1_000);
+}
+
+#[test]
+fn set_liquidity_cooldown_through_factory() {
+    let test = SoroswapPairTest::setup();
+    let pair_address = test.factory.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair.liquidity_cooldown(), 0);
+
+    test.factory.set_liquidity_cooldown(&test.token_1.address, &test.token_0.address, &17_280);
+    assert_eq!(pair.liquidity_cooldown(), 17_280);
+}
@@ -0,0 +1,131 @@
+use crate::test::{SoroswapPairTest, SoroswapPairClient};
+use crate::test::deposit::add_liquidity;
+use crate::test::pair::SoroswapPairError;
+use crate::price_guard::price_impact_bps;
+use soroban_sdk::testutils::Ledger;
+
+fn swap_token_0(test: &SoroswapPairTest, amount_in: i128) -> Result<i128, SoroswapPairError> {
+    let (reserve_0, reserve_1) = test.contract.get_reserves();
+    let amount_in_with_fee = amount_in.checked_mul(997).unwrap();
+    let amount_out = amount_in_with_fee.checked_mul(reserve_1).unwrap() / (reserve_0.checked_mul(1000).unwrap() + amount_in_with_fee);
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+    match test.contract.try_swap(&0, &amount_out, &test.user) {
+        Ok(_) => Ok(amount_out),
+        Err(Ok(error)) => Err(error),
+        Err(Err(_)) => panic!("unexpected error"),
+    }
+}
+
+#[test]
+fn price_impact_bps_math() {
+    // 10_000_000 of token 0 in a (50_000_000, 100_000_000) pair gives 16_624_979 of token 1
+    // price_0 goes from 2 to 83_375_021/60_000_000, price_1 from 0.5 to 60_000_000/83_375_021
+    // the largest movement is the one of price_1: 43.92...% --> 4393 bps (rounded up)
+    assert_eq!(price_impact_bps(50_000_000, 100_000_000, 60_000_000, 83_375_021), 4393);
+    // Same movement in the other direction
+    assert_eq!(price_impact_bps(60_000_000, 83_375_021, 50_000_000, 100_000_000), 4393);
+    // No movement
+    assert_eq!(price_impact_bps(50_000_000, 100_000_000, 100_000_000, 200_000_000), 0);
+}
+
+#[test]
+fn price_guard_disabled_by_default() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    assert_eq!(test.contract.price_guard(), (0, 0));
+
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    assert_eq!(swap_token_0(&test, 10_000_000), Ok(16_624_979));
+}
+
+#[test]
+fn set_price_guard_not_yet_initialized() {
+    let test = SoroswapPairTest::setup();
+    let result = test.contract.try_set_price_guard(&100, &200);
+    assert_eq!(result, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn swap_price_impact_exceeded() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+
+    test.contract.set_price_guard(&4392, &0);
+    assert_eq!(test.contract.price_guard(), (4392, 0));
+    assert_eq!(swap_token_0(&test, 10_000_000), Err(SoroswapPairError::SwapPriceImpactExceeded));
+    assert_eq!(test.contract.get_reserves(), (50_000_000, 100_000_000));
+
+    test.contract.set_price_guard(&4393, &0);
+    test.contract.skim(&test.user);
+    assert_eq!(swap_token_0(&test, 10_000_000), Ok(16_624_979));
+}
+
+#[test]
+fn swap_ledger_price_impact_exceeded() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+
+    // First swap moves the price 404 bps, second one 396 bps
+    test.contract.set_price_guard(&0, &800);
+    assert_eq!(swap_token_0(&test, 1_000_000), Ok(1_955_016));
+    assert_eq!(swap_token_0(&test, 1_000_000), Ok(1_879_932));
+
+    // A third swap in the same ledger goes over the limit
+    assert_eq!(swap_token_0(&test, 1_000), Err(SoroswapPairError::SwapLedgerPriceImpactExceeded));
+    test.contract.skim(&test.user);
+
+    // In the next ledger the cumulative price impact starts again from zero
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number = li.sequence_number + 1;
+    });
+    assert_eq!(swap_token_0(&test, 1_000_000).is_ok(), true);
+}
+
+#[test]
+fn both_price_guards() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+
+    test.contract.set_price_guard(&500, &800);
+    assert_eq!(swap_token_0(&test, 10_000_000), Err(SoroswapPairError::SwapPriceImpactExceeded));
+    test.contract.skim(&test.user);
+    assert_eq!(swap_token_0(&test, 1_000_000), Ok(1_955_016));
+    assert_eq!(swap_token_0(&test, 1_000_000), Ok(1_879_932));
+    assert_eq!(swap_token_0(&test, 1_000), Err(SoroswapPairError::SwapLedgerPriceImpactExceeded));
+}
+
+#[test]
+fn set_price_guard_through_factory() {
+    let test = SoroswapPairTest::setup();
+    let pair_address = test.factory.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair.price_guard(), (0, 0));
+
+    test.factory.set_price_guard(&test.token_1.address, &test.token_0.address, &100, &300);
+    assert_eq!(pair.price_guard(), (100, 300));
+
+    // Setting both to zero disables the guards
+    test.factory.set_price_guard(&test.token_0.address, &test.token_1.address, &0, &0);
+    assert_eq!(pair.price_guard(), (0, 0));
+}
+
+#[test]
+fn tripped_price_guard_reverts_the_swap() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    test.contract.set_price_guard(&0, &500);
+
+    // The guard has no event: its error code is the only signal, and the reverted swap leaves no state
+    assert_eq!(swap_token_0(&test, 1_000_000), Ok(1_955_016));
+    let reserves = test.contract.get_reserves();
+    assert_eq!(swap_token_0(&test, 1_000_000), Err(SoroswapPairError::SwapLedgerPriceImpactExceeded));
+    assert_eq!(test.contract.get_reserves(), reserves);
+    test.contract.skim(&test.user);
+
+    // The reverted swap does not count towards the price impact of the ledger: 404 + 40 bps still fit
+    assert_eq!(swap_token_0(&test, 100_000), Ok(191_294));
+}
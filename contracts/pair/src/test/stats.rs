@@ -0,0 +1,101 @@
+use crate::test::{SoroswapPairTest};
+use crate::test::deposit::add_liquidity;
+use crate::test::pair::PairStats;
+use soroban_sdk::{testutils::{Ledger}};
+
+#[test]
+fn stats_start_at_zero() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number = 100;
+        li.timestamp = 1_000;
+    });
+
+    assert_eq!(test.contract.stats(), PairStats {
+        volume_0: 0,
+        volume_1: 0,
+        fees_0: 0,
+        fees_1: 0,
+        swap_count: 0,
+        protocol_fee_liquidity: 0,
+        ledger: 100,
+        timestamp: 1_000,
+    });
+}
+
+#[test]
+fn stats_swaps() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+
+    // Deposits do not count as volume
+    assert_eq!(test.contract.stats().volume_0, 0);
+    assert_eq!(test.contract.stats().volume_1, 0);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    test.contract.swap(&0, &16_624_979, &test.user);
+
+    let stats = test.contract.stats();
+    assert_eq!(stats.volume_0, 10_000_000);
+    assert_eq!(stats.volume_1, 0);
+    assert_eq!(stats.fees_0, 30_000); // 0.3% of 10_000_000
+    assert_eq!(stats.fees_1, 0);
+    assert_eq!(stats.swap_count, 1);
+
+    test.token_1.transfer(&test.user, &test.contract.address, &5_000_000);
+    test.contract.swap(&3_385_015, &0, &test.user);
+
+    let stats = test.contract.stats();
+    assert_eq!(stats.volume_0, 10_000_000);
+    assert_eq!(stats.volume_1, 5_000_000);
+    assert_eq!(stats.fees_0, 30_000);
+    assert_eq!(stats.fees_1, 15_000); // 0.3% of 5_000_000
+    assert_eq!(stats.swap_count, 2);
+    assert_eq!(stats.protocol_fee_liquidity, 0);
+}
+
+#[test]
+fn stats_protocol_fee() {
+    let test = SoroswapPairTest::setup();
+    test.factory.set_fees_enabled(&true);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    test.contract.swap(&0, &16_624_979, &test.user);
+    assert_eq!(test.contract.stats().protocol_fee_liquidity, 0);
+
+    // The protocol fee is minted on the next deposit (see fee.rs)
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    assert_eq!(test.contract.stats().protocol_fee_liquidity, 2946);
+    assert_eq!(test.contract.stats().protocol_fee_liquidity, test.contract.balance(&test.admin));
+}
+
+#[test]
+fn stats_windowed_from_two_snapshots() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    test.contract.swap(&0, &16_624_979, &test.user);
+    let snapshot_1 = test.contract.stats();
+
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number = li.sequence_number + 17_280;
+        li.timestamp = li.timestamp + 86_400;
+    });
+    test.token_1.transfer(&test.user, &test.contract.address, &5_000_000);
+    test.contract.swap(&3_385_015, &0, &test.user);
+    let snapshot_2 = test.contract.stats();
+
+    // Activity during the last day
+    assert_eq!(snapshot_2.timestamp - snapshot_1.timestamp, 86_400);
+    assert_eq!(snapshot_2.ledger - snapshot_1.ledger, 17_280);
+    assert_eq!(snapshot_2.volume_0 - snapshot_1.volume_0, 0);
+    assert_eq!(snapshot_2.volume_1 - snapshot_1.volume_1, 5_000_000);
+    assert_eq!(snapshot_2.fees_1 - snapshot_1.fees_1, 15_000);
+    assert_eq!(snapshot_2.swap_count - snapshot_1.swap_count, 1);
+}
@@ -0,0 +1,47 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapPmmPoolError {
+    /// SoroswapPmmPool: already initialized
+    InitializeAlreadyInitialized = 1101,
+    /// SoroswapPmmPool: not yet initialized
+    NotInitialized = 1102,
+    /// SoroswapPmmPool: token_0 must be less than token_1 while initializing
+    InitializeTokenOrderInvalid = 1103,
+    /// SoroswapPmmPool: k must be between 0 and PRICE_SCALE, excluded
+    InvalidK = 1104,
+    /// SoroswapPmmPool: the maximum oracle age must be positive
+    InvalidMaxOracleAge = 1105,
+
+    /// SoroswapPmmPool: the oracle price is older than the maximum oracle age
+    OracleStale = 1106,
+    /// SoroswapPmmPool: the oracle price is not positive
+    InvalidOraclePrice = 1107,
+
+    /// SoroswapPmmPool: insufficient amount of token 0 or token 1 sent while doing deposit
+    DepositInsufficientAmount = 1108,
+    /// SoroswapPmmPool: insufficient liquidity minted while doing deposit
+    DepositInsufficientLiquidityMinted = 1109,
+
+    /// SoroswapPmmPool: insufficient output amount while doing swap
+    SwapInsufficientOutputAmount = 1110,
+    /// SoroswapPmmPool: negatives amounts out dont supported while doing swap
+    SwapNegativesOutNotSupported = 1111,
+    /// SoroswapPmmPool: only one token can be sent out while doing swap
+    SwapBothAmountsOut = 1112,
+    /// SoroswapPmmPool: insufficient liquidity to do the swap
+    SwapInsufficientLiquidity = 1113,
+    /// SoroswapPmmPool: invalid to to do the swap
+    SwapInvalidTo = 1114,
+    /// SoroswapPmmPool: insufficient input amount while doing swap
+    SwapInsufficientInputAmount = 1115,
+    /// SoroswapPmmPool: output amount is higher than the curve allows for the input amount
+    SwapCurveNotMet = 1116,
+
+    /// SoroswapPmmPool: shares must be positive and not higher than the balance of the owner while doing withdraw
+    WithdrawInsufficientShares = 1117,
+    /// SoroswapPmmPool: insufficient liquidity burned while doing withdraw
+    WithdrawInsufficientLiquidityBurned = 1118,
+}
@@ -0,0 +1,67 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address};
+
+// DEPOSIT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositEvent {
+    pub to: Address,
+    pub amount_0: i128,
+    pub amount_1: i128,
+    pub shares: i128,
+    pub new_reserve_0: i128,
+    pub new_reserve_1: i128,
+}
+
+pub(crate) fn deposit(e: &Env, event: DepositEvent) {
+    e.events().publish(("SoroswapPmmPool", symbol_short!("deposit")), event);
+}
+
+// SWAP EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapEvent {
+    pub to: Address,
+    pub amount_0_in: i128,
+    pub amount_1_in: i128,
+    pub amount_0_out: i128,
+    pub amount_1_out: i128,
+    pub oracle_price: i128,
+}
+
+pub(crate) fn swap(e: &Env, event: SwapEvent) {
+    e.events().publish(("SoroswapPmmPool", symbol_short!("swap")), event);
+}
+
+// WITHDRAW EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawEvent {
+    pub owner: Address,
+    pub to: Address,
+    pub shares: i128,
+    pub amount_0: i128,
+    pub amount_1: i128,
+    pub new_reserve_0: i128,
+    pub new_reserve_1: i128,
+}
+
+pub(crate) fn withdraw(e: &Env, event: WithdrawEvent) {
+    e.events().publish(("SoroswapPmmPool", symbol_short!("withdraw")), event);
+}
+
+// NEW ORACLE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleEvent {
+    pub oracle: Address,
+    pub max_oracle_age: u64,
+}
+
+pub(crate) fn oracle(e: &Env, oracle: Address, max_oracle_age: u64) {
+    let event = OracleEvent {
+        oracle,
+        max_oracle_age,
+    };
+    e.events().publish(("SoroswapPmmPool", symbol_short!("oracle")), event);
+}
@@ -0,0 +1,432 @@
+#![no_std]
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Env};
+
+mod error;
+mod event;
+mod oracle;
+mod pmm;
+mod storage;
+mod test;
+// The curve and its math are shared with the Soroswap library, so its quotes match the pool
+#[path = "../../library/src/math.rs"]
+mod math;
+#[path = "../../library/src/pmm_curve.rs"]
+mod pmm_curve;
+
+use storage::*;
+pub use error::SoroswapPmmPoolError;
+pub use oracle::{PmmOracleTrait, PriceData};
+pub use pmm::{PmmState, PRICE_SCALE};
+
+/// Swap fee, in basis points, charged on the amounts in and kept by the liquidity providers (0.3%).
+const FEE_BPS: i128 = 30;
+
+// Metadata that is added on to the WASM custom section
+contractmeta!(
+    key = "Description",
+    val = "Soroswap.Finance Protocol - Oracle-pegged proactive market maker with a .3% swap fee"
+);
+
+fn check_initialized(e: &Env) -> Result<(), SoroswapPmmPoolError> {
+    if has_token_0(e) {
+        Ok(())
+    } else {
+        Err(SoroswapPmmPoolError::NotInitialized)
+    }
+}
+
+fn check_oracle_config(k: i128, max_oracle_age: u64) -> Result<(), SoroswapPmmPoolError> {
+    if k < 0 || k >= PRICE_SCALE {
+        return Err(SoroswapPmmPoolError::InvalidK);
+    }
+    if max_oracle_age == 0 {
+        return Err(SoroswapPmmPoolError::InvalidMaxOracleAge);
+    }
+    Ok(())
+}
+
+/// Returns the state of the curve at the current oracle price, with its targets adjusted to it.
+fn current_state(e: &Env) -> Result<PmmState, SoroswapPmmPoolError> {
+    let price = oracle::get_oracle_price(e)?;
+    let (reserve_0, reserve_1) = get_reserves(e);
+    let (target_0, target_1) = get_targets(e);
+    let mut state = PmmState {
+        price,
+        k: get_k(e),
+        reserve_0,
+        reserve_1,
+        target_0,
+        target_1,
+    };
+    pmm::adjust_targets(&mut state);
+    Ok(state)
+}
+
+fn get_balance(e: &Env, token: &Address) -> i128 {
+    TokenClient::new(e, token).balance(&e.current_contract_address())
+}
+
+fn transfer(e: &Env, token: &Address, to: &Address, amount: i128) {
+    if amount > 0 {
+        TokenClient::new(e, token).transfer(&e.current_contract_address(), to, &amount);
+    }
+}
+
+pub trait SoroswapPmmPoolTrait {
+    /// Sets the tokens, the oracle and the slippage parameter of the pool. Called by the factory when
+    /// it creates the pool.
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, oracle: Address, k: i128, max_oracle_age: u64) -> Result<(), SoroswapPmmPoolError>;
+
+    /// Replaces the oracle of the pool. Only the factory can call it.
+    fn set_oracle(e: Env, oracle: Address, max_oracle_age: u64) -> Result<(), SoroswapPmmPoolError>;
+
+    /// Mints shares to `to` for the tokens sent to the pool since the last update.
+    fn deposit(e: Env, to: Address) -> Result<i128, SoroswapPmmPoolError>;
+
+    /// Sends `amount_0_out` or `amount_1_out` to `to` for the other token sent to the pool since the last update.
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapPmmPoolError>;
+
+    /// Burns `shares` of `owner` and sends its part of the reserves to `to`.
+    fn withdraw(e: Env, owner: Address, shares: i128, to: Address) -> Result<(i128, i128), SoroswapPmmPoolError>;
+
+    /// Sends the balances above the reserves to `to`.
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapPmmPoolError>;
+
+    /*  *** Read only functions: *** */
+
+    fn factory(e: Env) -> Result<Address, SoroswapPmmPoolError>;
+    fn token_0(e: Env) -> Result<Address, SoroswapPmmPoolError>;
+    fn token_1(e: Env) -> Result<Address, SoroswapPmmPoolError>;
+    fn oracle(e: Env) -> Result<Address, SoroswapPmmPoolError>;
+    fn max_oracle_age(e: Env) -> Result<u64, SoroswapPmmPoolError>;
+    fn k(e: Env) -> Result<i128, SoroswapPmmPoolError>;
+    fn get_reserves(e: Env) -> Result<(i128, i128), SoroswapPmmPoolError>;
+    fn get_targets(e: Env) -> Result<(i128, i128), SoroswapPmmPoolError>;
+
+    /// Returns the oracle price, `k`, the reserves and the targets adjusted to the oracle price.
+    fn get_pmm_state(e: Env) -> Result<PmmState, SoroswapPmmPoolError>;
+
+    fn total_shares(e: Env) -> Result<i128, SoroswapPmmPoolError>;
+    fn shares(e: Env, owner: Address) -> Result<i128, SoroswapPmmPoolError>;
+}
+
+#[contract]
+struct SoroswapPmmPool;
+
+#[contractimpl]
+impl SoroswapPmmPoolTrait for SoroswapPmmPool {
+
+    /// Sets the tokens, the oracle and the slippage parameter of the pool. Called by the factory when
+    /// it creates the pool.
+    ///
+    /// # Arguments
+    /// * `factory` - The factory creating the pool, the only address allowed to replace the oracle.
+    /// * `token_0` - The base token, priced by the oracle. Must be lower than `token_1`.
+    /// * `token_1` - The quote token.
+    /// * `oracle` - A contract implementing `PmmOracleTrait`.
+    /// * `k` - The slippage parameter, scaled by `PRICE_SCALE`. 0 trades at the oracle price, and values
+    ///   close to `PRICE_SCALE` behave close to a constant product curve.
+    /// * `max_oracle_age` - The number of seconds after which an oracle price is too old to trade.
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, oracle: Address, k: i128, max_oracle_age: u64) -> Result<(), SoroswapPmmPoolError> {
+        if has_token_0(&e) {
+            return Err(SoroswapPmmPoolError::InitializeAlreadyInitialized);
+        }
+        if token_0 >= token_1 {
+            return Err(SoroswapPmmPoolError::InitializeTokenOrderInvalid);
+        }
+        check_oracle_config(k, max_oracle_age)?;
+
+        put_tokens(&e, &factory, &token_0, &token_1);
+        put_oracle(&e, &oracle, max_oracle_age);
+        put_k(&e, k);
+        put_reserves(&e, 0, 0);
+        put_targets(&e, 0, 0);
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Replaces the oracle of the pool. Only the factory can call it.
+    ///
+    /// # Arguments
+    /// * `oracle` - A contract implementing `PmmOracleTrait`.
+    /// * `max_oracle_age` - The number of seconds after which an oracle price is too old to trade.
+    fn set_oracle(e: Env, oracle: Address, max_oracle_age: u64) -> Result<(), SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        get_factory(&e).require_auth();
+        check_oracle_config(get_k(&e), max_oracle_age)?;
+
+        put_oracle(&e, &oracle, max_oracle_age);
+        event::oracle(&e, oracle, max_oracle_age);
+        Ok(())
+    }
+
+    /// Mints shares to `to` for the tokens sent to the pool since the last update.
+    ///
+    /// The first deposit sets the targets to the amounts deposited and mints as many shares as token 0
+    /// deposited, so it should be made at the oracle price. Later deposits mint shares, and raise the
+    /// targets, in proportion to the smaller of the two amounts relative to its reserve. The rest of the
+    /// other token goes to the existing liquidity providers.
+    ///
+    /// # Arguments
+    /// * `to` - The address receiving the shares.
+    ///
+    /// # Returns
+    /// The shares minted.
+    fn deposit(e: Env, to: Address) -> Result<i128, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+
+        let (reserve_0, reserve_1) = get_reserves(&e);
+        let (balance_0, balance_1) = (get_balance(&e, &get_token_0(&e)), get_balance(&e, &get_token_1(&e)));
+        let amount_0 = balance_0.checked_sub(reserve_0).unwrap();
+        let amount_1 = balance_1.checked_sub(reserve_1).unwrap();
+        let total_shares = get_total_shares(&e);
+
+        let shares = if total_shares == 0 {
+            if amount_0 <= 0 || amount_1 <= 0 {
+                return Err(SoroswapPmmPoolError::DepositInsufficientAmount);
+            }
+            put_targets(&e, balance_0, balance_1);
+            amount_0
+        } else {
+            let ratio_0 = if reserve_0 > 0 { Some(amount_0.checked_mul(PRICE_SCALE).unwrap().checked_div(reserve_0).unwrap()) } else { None };
+            let ratio_1 = if reserve_1 > 0 { Some(amount_1.checked_mul(PRICE_SCALE).unwrap().checked_div(reserve_1).unwrap()) } else { None };
+            let mint_ratio = match (ratio_0, ratio_1) {
+                (Some(ratio_0), Some(ratio_1)) => ratio_0.min(ratio_1),
+                (Some(ratio), None) | (None, Some(ratio)) => ratio,
+                (None, None) => return Err(SoroswapPmmPoolError::DepositInsufficientAmount),
+            };
+            let (target_0, target_1) = get_targets(&e);
+            put_targets(
+                &e,
+                target_0.checked_add(target_0.checked_mul(mint_ratio).unwrap() / PRICE_SCALE).unwrap(),
+                target_1.checked_add(target_1.checked_mul(mint_ratio).unwrap() / PRICE_SCALE).unwrap(),
+            );
+            total_shares.checked_mul(mint_ratio).unwrap() / PRICE_SCALE
+        };
+        if shares <= 0 {
+            return Err(SoroswapPmmPoolError::DepositInsufficientLiquidityMinted);
+        }
+
+        put_total_shares(&e, total_shares.checked_add(shares).unwrap());
+        put_shares(&e, &to, get_shares(&e, &to).checked_add(shares).unwrap());
+        put_reserves(&e, balance_0, balance_1);
+
+        event::deposit(&e, event::DepositEvent {
+            to,
+            amount_0,
+            amount_1,
+            shares,
+            new_reserve_0: balance_0,
+            new_reserve_1: balance_1,
+        });
+        Ok(shares)
+    }
+
+    /// Sends `amount_0_out` or `amount_1_out` to `to` for the other token sent to the pool since the
+    /// last update. The output can not be higher than what the curve quotes at the oracle price for the
+    /// input minus the 0.3% fee, see `soroswap_library::get_pmm_amount_out`. Fails if the oracle price
+    /// is stale. The targets adjusted to the oracle price are stored.
+    ///
+    /// # Arguments
+    /// * `amount_0_out` - The amount of token 0 to receive, 0 when selling token 0.
+    /// * `amount_1_out` - The amount of token 1 to receive, 0 when selling token 1.
+    /// * `to` - The address receiving the output.
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+
+        let (reserve_0, reserve_1) = get_reserves(&e);
+        let (token_0, token_1) = (get_token_0(&e), get_token_1(&e));
+        if amount_0_out == 0 && amount_1_out == 0 {
+            return Err(SoroswapPmmPoolError::SwapInsufficientOutputAmount);
+        }
+        if amount_0_out < 0 || amount_1_out < 0 {
+            return Err(SoroswapPmmPoolError::SwapNegativesOutNotSupported);
+        }
+        if amount_0_out > 0 && amount_1_out > 0 {
+            return Err(SoroswapPmmPoolError::SwapBothAmountsOut);
+        }
+        if amount_0_out >= reserve_0 || amount_1_out >= reserve_1 {
+            return Err(SoroswapPmmPoolError::SwapInsufficientLiquidity);
+        }
+        if to == token_0 || to == token_1 {
+            return Err(SoroswapPmmPoolError::SwapInvalidTo);
+        }
+
+        let state = current_state(&e)?;
+
+        transfer(&e, &token_0, &to, amount_0_out);
+        transfer(&e, &token_1, &to, amount_1_out);
+
+        let (balance_0, balance_1) = (get_balance(&e, &token_0), get_balance(&e, &token_1));
+        let amount_0_in = balance_0.checked_sub(reserve_0.checked_sub(amount_0_out).unwrap()).unwrap().max(0);
+        let amount_1_in = balance_1.checked_sub(reserve_1.checked_sub(amount_1_out).unwrap()).unwrap().max(0);
+
+        let (amount_in, amount_out) = if amount_1_out > 0 { (amount_0_in, amount_1_out) } else { (amount_1_in, amount_0_out) };
+        if amount_in == 0 {
+            return Err(SoroswapPmmPoolError::SwapInsufficientInputAmount);
+        }
+        let fee = amount_in.checked_mul(FEE_BPS).unwrap().checked_add(9_999).unwrap() / 10_000;
+        let amount_in_minus_fee = amount_in.checked_sub(fee).unwrap();
+        let max_amount_out = if amount_1_out > 0 {
+            pmm_curve::sell_token_0(&state, amount_in_minus_fee)
+        } else {
+            pmm_curve::sell_token_1(&state, amount_in_minus_fee)
+        };
+        if amount_out > max_amount_out {
+            return Err(SoroswapPmmPoolError::SwapCurveNotMet);
+        }
+
+        put_targets(&e, state.target_0, state.target_1);
+        put_reserves(&e, balance_0, balance_1);
+
+        event::swap(&e, event::SwapEvent {
+            to,
+            amount_0_in,
+            amount_1_in,
+            amount_0_out,
+            amount_1_out,
+            oracle_price: state.price,
+        });
+        Ok(())
+    }
+
+    /// Burns `shares` of `owner` and sends its part of the reserves to `to`. The targets are lowered in
+    /// the same proportion. It does not read the oracle, so liquidity can be withdrawn while it is stale.
+    ///
+    /// # Arguments
+    /// * `owner` - The owner of the shares. Must authorize the call.
+    /// * `shares` - The shares to burn.
+    /// * `to` - The address receiving the tokens.
+    ///
+    /// # Returns
+    /// The amounts of token 0 and token 1 withdrawn.
+    fn withdraw(e: Env, owner: Address, shares: i128, to: Address) -> Result<(i128, i128), SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        owner.require_auth();
+
+        let owner_shares = get_shares(&e, &owner);
+        if shares <= 0 || shares > owner_shares {
+            return Err(SoroswapPmmPoolError::WithdrawInsufficientShares);
+        }
+
+        let total_shares = get_total_shares(&e);
+        let (reserve_0, reserve_1) = get_reserves(&e);
+        let amount_0 = reserve_0.checked_mul(shares).unwrap() / total_shares;
+        let amount_1 = reserve_1.checked_mul(shares).unwrap() / total_shares;
+        if amount_0 <= 0 && amount_1 <= 0 {
+            return Err(SoroswapPmmPoolError::WithdrawInsufficientLiquidityBurned);
+        }
+
+        let (target_0, target_1) = get_targets(&e);
+        put_targets(
+            &e,
+            target_0.checked_sub(target_0.checked_mul(shares).unwrap() / total_shares).unwrap(),
+            target_1.checked_sub(target_1.checked_mul(shares).unwrap() / total_shares).unwrap(),
+        );
+        put_shares(&e, &owner, owner_shares.checked_sub(shares).unwrap());
+        put_total_shares(&e, total_shares.checked_sub(shares).unwrap());
+        let (new_reserve_0, new_reserve_1) = (reserve_0.checked_sub(amount_0).unwrap(), reserve_1.checked_sub(amount_1).unwrap());
+        put_reserves(&e, new_reserve_0, new_reserve_1);
+
+        transfer(&e, &get_token_0(&e), &to, amount_0);
+        transfer(&e, &get_token_1(&e), &to, amount_1);
+
+        event::withdraw(&e, event::WithdrawEvent {
+            owner,
+            to,
+            shares,
+            amount_0,
+            amount_1,
+            new_reserve_0,
+            new_reserve_1,
+        });
+        Ok((amount_0, amount_1))
+    }
+
+    /// Sends the balances above the reserves to `to`.
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let (token_0, token_1) = (get_token_0(&e), get_token_1(&e));
+        let (reserve_0, reserve_1) = get_reserves(&e);
+        transfer(&e, &token_0, &to, get_balance(&e, &token_0).checked_sub(reserve_0).unwrap());
+        transfer(&e, &token_1, &to, get_balance(&e, &token_1).checked_sub(reserve_1).unwrap());
+        Ok(())
+    }
+
+    /*  *** Read only functions: *** */
+
+    fn factory(e: Env) -> Result<Address, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_factory(&e))
+    }
+
+    fn token_0(e: Env) -> Result<Address, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_token_0(&e))
+    }
+
+    fn token_1(e: Env) -> Result<Address, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_token_1(&e))
+    }
+
+    fn oracle(e: Env) -> Result<Address, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_oracle(&e))
+    }
+
+    fn max_oracle_age(e: Env) -> Result<u64, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_max_oracle_age(&e))
+    }
+
+    fn k(e: Env) -> Result<i128, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_k(&e))
+    }
+
+    fn get_reserves(e: Env) -> Result<(i128, i128), SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_reserves(&e))
+    }
+
+    /// Returns the targets stored at the last update, at the oracle price of that update.
+    fn get_targets(e: Env) -> Result<(i128, i128), SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_targets(&e))
+    }
+
+    /// Returns the oracle price, `k`, the reserves and the targets adjusted to the oracle price: what
+    /// `soroswap_library::get_pmm_amount_out` needs to quote a swap. Fails if the oracle price is stale.
+    fn get_pmm_state(e: Env) -> Result<PmmState, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        current_state(&e)
+    }
+
+    fn total_shares(e: Env) -> Result<i128, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_total_shares(&e))
+    }
+
+    fn shares(e: Env, owner: Address) -> Result<i128, SoroswapPmmPoolError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_shares(&e, &owner))
+    }
+}
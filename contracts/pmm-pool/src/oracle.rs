@@ -0,0 +1,39 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+use crate::error::SoroswapPmmPoolError;
+use crate::storage::{get_max_oracle_age, get_oracle, get_token_0, get_token_1};
+
+/// A price published by an oracle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    /// The amount of `quote` per `base`, scaled by `PRICE_SCALE`.
+    pub price: i128,
+    /// The ledger timestamp when the price was published.
+    pub timestamp: u64,
+}
+
+/// Trait defining the interface that the oracle of a PMM pool must implement.
+#[contractclient(name = "PmmOracleClient")]
+pub trait PmmOracleTrait {
+    /// Returns the latest price of `base` in `quote`, and when it was published.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - An instance of the `Env` struct.
+    /// * `base` - The token being priced, token 0 of the pool.
+    /// * `quote` - The token the price is quoted in, token 1 of the pool.
+    fn lastprice(e: Env, base: Address, quote: Address) -> PriceData;
+}
+
+/// Returns the oracle price of token 0 in token 1. Fails if it is not positive, or if it was published
+/// more than the maximum oracle age ago.
+pub fn get_oracle_price(e: &Env) -> Result<i128, SoroswapPmmPoolError> {
+    let data = PmmOracleClient::new(e, &get_oracle(e)).lastprice(&get_token_0(e), &get_token_1(e));
+    if data.price <= 0 {
+        return Err(SoroswapPmmPoolError::InvalidOraclePrice);
+    }
+    if e.ledger().timestamp() > data.timestamp.saturating_add(get_max_oracle_age(e)) {
+        return Err(SoroswapPmmPoolError::OracleStale);
+    }
+    Ok(data.price)
+}
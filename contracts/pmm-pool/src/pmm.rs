@@ -0,0 +1,50 @@
+//! State of the proactive market maker curve, and the adjustment of its targets to the oracle price.
+//!
+//! Trades along the curve are quoted by `pmm_curve`, a file of the Soroswap library compiled into both
+//! crates, so the quotes of the library match the pool.
+use crate::math::checked_mul_div;
+use crate::pmm_curve::{div_scaled_ceil, mul_scaled, reciprocal_price};
+use num_integer::Roots;
+use soroban_sdk::contracttype;
+
+/// Scale of the oracle price and of `k`.
+pub const PRICE_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Everything the curve needs to quote a trade.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PmmState {
+    /// Oracle price of token 0: the amount of token 1 per token 0, scaled by `PRICE_SCALE`.
+    pub price: i128,
+    /// Slippage parameter, scaled by `PRICE_SCALE`. Lower than `PRICE_SCALE`.
+    pub k: i128,
+    pub reserve_0: i128,
+    pub reserve_1: i128,
+    pub target_0: i128,
+    pub target_1: i128,
+}
+
+/// Returns the target of a reserve `v_1` that is short of it, given the value `fair_amount` that the
+/// other token has above its own target: `v_1 * (1 + (sqrt(1 + 4 * k * fair_amount / v_1) - 1) / (2 * k))`.
+fn solve_quadratic_for_target(v_1: i128, k: i128, fair_amount: i128) -> i128 {
+    if k == 0 {
+        return v_1.checked_add(fair_amount).unwrap();
+    }
+    let k_fair = checked_mul_div(k, fair_amount.checked_mul(4).unwrap(), v_1).unwrap();
+    let square_root = PRICE_SCALE.checked_add(k_fair).unwrap().checked_mul(PRICE_SCALE).unwrap().sqrt();
+    let premium = div_scaled_ceil(square_root.checked_sub(PRICE_SCALE).unwrap(), k.checked_mul(2).unwrap());
+    mul_scaled(v_1, PRICE_SCALE.checked_add(premium).unwrap())
+}
+
+/// Updates the targets of `state` to the current oracle price. The token above its target is valued
+/// at the oracle price, and the target of the other token is set to what the pool would hold if that
+/// surplus was sold back along the curve.
+pub fn adjust_targets(state: &mut PmmState) {
+    if state.reserve_0 > state.target_0 {
+        let fair_amount = mul_scaled(state.reserve_0.checked_sub(state.target_0).unwrap(), state.price);
+        state.target_1 = solve_quadratic_for_target(state.reserve_1, state.k, fair_amount);
+    } else if state.reserve_1 > state.target_1 {
+        let fair_amount = mul_scaled(state.reserve_1.checked_sub(state.target_1).unwrap(), reciprocal_price(state.price));
+        state.target_0 = solve_quadratic_for_target(state.reserve_0, state.k, fair_amount);
+    }
+}
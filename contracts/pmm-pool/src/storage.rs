@@ -0,0 +1,131 @@
+use soroban_sdk::{contracttype, Env, Address};
+
+#[derive(Clone)]
+#[contracttype]
+
+enum DataKey {
+    Factory, // Address of the Soroswap Factory that created the pool. Instance Data Type
+    Token0, // Base token. Instance Data Type
+    Token1, // Quote token. Instance Data Type
+    Oracle, // Oracle pricing token 0 in token 1. Instance Data Type
+    MaxOracleAge, // Seconds after which an oracle price is stale. Instance Data Type
+    K, // Slippage parameter, scaled by PRICE_SCALE. Instance Data Type
+    Reserve0, // Instance Data Type
+    Reserve1, // Instance Data Type
+    Target0, // Target of token 0 at the oracle price of the last update. Instance Data Type
+    Target1, // Target of token 1 at the oracle price of the last update. Instance Data Type
+    TotalShares, // Instance Data Type
+    Shares(Address), // Shares of a liquidity provider. Persistent Data Type
+}
+
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+// Helper function in order to know if the contract has been initialized or not
+pub fn has_token_0(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Token0)
+}
+
+pub fn put_tokens(e: &Env, factory: &Address, token_0: &Address, token_1: &Address) {
+    e.storage().instance().set(&DataKey::Factory, factory);
+    e.storage().instance().set(&DataKey::Token0, token_0);
+    e.storage().instance().set(&DataKey::Token1, token_1);
+}
+
+pub fn get_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Factory).unwrap()
+}
+
+pub fn get_token_0(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token0).unwrap()
+}
+
+pub fn get_token_1(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token1).unwrap()
+}
+
+pub fn put_oracle(e: &Env, oracle: &Address, max_oracle_age: u64) {
+    e.storage().instance().set(&DataKey::Oracle, oracle);
+    e.storage().instance().set(&DataKey::MaxOracleAge, &max_oracle_age);
+}
+
+pub fn get_oracle(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Oracle).unwrap()
+}
+
+pub fn get_max_oracle_age(e: &Env) -> u64 {
+    e.storage().instance().get(&DataKey::MaxOracleAge).unwrap()
+}
+
+pub fn put_k(e: &Env, k: i128) {
+    e.storage().instance().set(&DataKey::K, &k);
+}
+
+pub fn get_k(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::K).unwrap()
+}
+
+pub fn put_reserves(e: &Env, reserve_0: i128, reserve_1: i128) {
+    e.storage().instance().set(&DataKey::Reserve0, &reserve_0);
+    e.storage().instance().set(&DataKey::Reserve1, &reserve_1);
+}
+
+pub fn get_reserves(e: &Env) -> (i128, i128) {
+    (
+        e.storage().instance().get(&DataKey::Reserve0).unwrap_or(0),
+        e.storage().instance().get(&DataKey::Reserve1).unwrap_or(0),
+    )
+}
+
+pub fn put_targets(e: &Env, target_0: i128, target_1: i128) {
+    e.storage().instance().set(&DataKey::Target0, &target_0);
+    e.storage().instance().set(&DataKey::Target1, &target_1);
+}
+
+pub fn get_targets(e: &Env) -> (i128, i128) {
+    (
+        e.storage().instance().get(&DataKey::Target0).unwrap_or(0),
+        e.storage().instance().get(&DataKey::Target1).unwrap_or(0),
+    )
+}
+
+pub fn put_total_shares(e: &Env, total_shares: i128) {
+    e.storage().instance().set(&DataKey::TotalShares, &total_shares);
+}
+
+pub fn get_total_shares(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::TotalShares).unwrap_or(0)
+}
+
+pub fn put_shares(e: &Env, owner: &Address, shares: i128) {
+    let key = DataKey::Shares(owner.clone());
+    if shares == 0 {
+        e.storage().persistent().remove(&key);
+        return;
+    }
+    e.storage().persistent().set(&key, &shares);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_shares(e: &Env, owner: &Address) -> i128 {
+    let key = DataKey::Shares(owner.clone());
+    if let Some(shares) = e.storage().persistent().get::<DataKey, i128>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        shares
+    } else {
+        0
+    }
+}
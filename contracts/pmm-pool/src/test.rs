@@ -0,0 +1,139 @@
+#![cfg(test)]
+extern crate std;
+use crate::{SoroswapPmmPool, SoroswapPmmPoolClient, PmmOracleTrait, PriceData, PRICE_SCALE};
+use soroban_sdk::{
+    contract,
+    contractimpl,
+    contracttype,
+    Env,
+    Address,
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(&e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+// MOCK ORACLE: returns the price set by the test.
+
+#[contracttype]
+#[derive(Clone)]
+enum MockOracleDataKey {
+    Price,
+}
+
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(e: Env, price: i128, timestamp: u64) {
+        e.storage().instance().set(&MockOracleDataKey::Price, &PriceData { price, timestamp });
+    }
+}
+
+#[contractimpl]
+impl PmmOracleTrait for MockOracle {
+    fn lastprice(e: Env, _base: Address, _quote: Address) -> PriceData {
+        e.storage().instance().get(&MockOracleDataKey::Price).unwrap()
+    }
+}
+
+pub fn create_mock_oracle<'a>(e: &Env) -> MockOracleClient<'a> {
+    MockOracleClient::new(e, &e.register_contract(None, MockOracle {}))
+}
+
+// SoroswapPmmPool Contract
+fn create_soroswap_pmm_pool<'a>(e: &Env) -> SoroswapPmmPoolClient<'a> {
+    SoroswapPmmPoolClient::new(e, &e.register_contract(None, SoroswapPmmPool {}))
+}
+
+/// 1 token_0 = 2 token_1 at the oracle, with k = 0.1.
+pub const PRICE: i128 = 2 * PRICE_SCALE;
+pub const K: i128 = PRICE_SCALE / 10;
+pub const MAX_ORACLE_AGE: u64 = 600;
+
+// SoroswapPmmPool TEST
+
+pub struct SoroswapPmmPoolTest<'a> {
+    env: Env,
+    contract: SoroswapPmmPoolClient<'a>,
+    oracle: MockOracleClient<'a>,
+    factory: Address,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    user: Address,
+}
+
+impl<'a> SoroswapPmmPoolTest<'a> {
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1000;
+        });
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let factory = Address::generate(&env);
+
+        let mut token_0 = create_token_contract(&env, &admin);
+        let mut token_1 = create_token_contract(&env, &admin);
+        if &token_1.address < &token_0.address {
+            std::mem::swap(&mut token_0, &mut token_1);
+        }
+        token_0.mint(&user, &1_000_000_000_000_000_000);
+        token_1.mint(&user, &1_000_000_000_000_000_000);
+
+        let oracle = create_mock_oracle(&env);
+        oracle.set_price(&PRICE, &1000);
+
+        let contract = create_soroswap_pmm_pool(&env);
+
+        env.budget().reset_unlimited();
+
+        SoroswapPmmPoolTest {
+            env,
+            contract,
+            oracle,
+            factory,
+            token_0,
+            token_1,
+            user,
+        }
+    }
+
+    fn initialize(&self, k: i128) {
+        self.contract.initialize(&self.factory, &self.token_0.address, &self.token_1.address, &self.oracle.address, &k, &MAX_ORACLE_AGE);
+    }
+
+    fn deposit(&self, amount_0: i128, amount_1: i128) -> i128 {
+        self.token_0.transfer(&self.user, &self.contract.address, &amount_0);
+        self.token_1.transfer(&self.user, &self.contract.address, &amount_1);
+        self.contract.deposit(&self.user)
+    }
+
+    /// Initializes the pool and deposits 10_000_000_000 token_0 and 20_000_000_000 token_1 at the oracle price.
+    fn setup_with_liquidity(k: i128) -> Self {
+        let test = Self::setup();
+        test.initialize(k);
+        test.deposit(10_000_000_000, 20_000_000_000);
+        test
+    }
+}
+
+mod initialize;
+mod deposit;
+mod swap;
+mod withdraw;
+mod oracle;
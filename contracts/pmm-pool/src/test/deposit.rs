@@ -0,0 +1,67 @@
+use soroban_sdk::{testutils::Events, symbol_short, vec, IntoVal};
+use crate::test::{SoroswapPmmPoolTest, K};
+use crate::event::DepositEvent;
+use crate::SoroswapPmmPoolError;
+
+#[test]
+fn first_deposit() {
+    let test = SoroswapPmmPoolTest::setup();
+    test.initialize(K);
+    let shares = test.deposit(10_000_000_000, 20_000_000_000);
+
+    // The first deposit sets the targets and mints as many shares as token_0 deposited
+    assert_eq!(shares, 10_000_000_000);
+    assert_eq!(test.contract.shares(&test.user), 10_000_000_000);
+    assert_eq!(test.contract.total_shares(), 10_000_000_000);
+    assert_eq!(test.contract.get_reserves(), (10_000_000_000, 20_000_000_000));
+    assert_eq!(test.contract.get_targets(), (10_000_000_000, 20_000_000_000));
+
+    let expected_event = DepositEvent {
+        to: test.user.clone(),
+        amount_0: 10_000_000_000,
+        amount_1: 20_000_000_000,
+        shares,
+        new_reserve_0: 10_000_000_000,
+        new_reserve_1: 20_000_000_000,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPmmPool", symbol_short!("deposit")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn first_deposit_needs_both_tokens() {
+    let test = SoroswapPmmPoolTest::setup();
+    test.initialize(K);
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000_000);
+    let result = test.contract.try_deposit(&test.user);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::DepositInsufficientAmount)));
+}
+
+#[test]
+fn deposit_in_proportion() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+
+    // 10% of token_0 and 15% of token_1: shares and targets grow by 10%, the rest of token_1 goes to the pool
+    let shares = test.deposit(1_000_000_000, 3_000_000_000);
+    assert_eq!(shares, 1_000_000_000);
+    assert_eq!(test.contract.total_shares(), 11_000_000_000);
+    assert_eq!(test.contract.get_reserves(), (11_000_000_000, 23_000_000_000));
+    assert_eq!(test.contract.get_targets(), (11_000_000_000, 22_000_000_000));
+}
+
+#[test]
+fn deposit_nothing() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    let result = test.contract.try_deposit(&test.user);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::DepositInsufficientLiquidityMinted)));
+}
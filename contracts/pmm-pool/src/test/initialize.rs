@@ -0,0 +1,54 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+use crate::test::{SoroswapPmmPoolTest, K, MAX_ORACLE_AGE};
+use crate::{SoroswapPmmPoolError, PRICE_SCALE};
+
+#[test]
+fn not_yet_initialized() {
+    let test = SoroswapPmmPoolTest::setup();
+    assert_eq!(test.contract.try_get_reserves(), Err(Ok(SoroswapPmmPoolError::NotInitialized)));
+    assert_eq!(test.contract.try_deposit(&test.user), Err(Ok(SoroswapPmmPoolError::NotInitialized)));
+    assert_eq!(test.contract.try_swap(&0, &1, &test.user), Err(Ok(SoroswapPmmPoolError::NotInitialized)));
+}
+
+#[test]
+fn initialize() {
+    let test = SoroswapPmmPoolTest::setup();
+    test.initialize(K);
+    assert_eq!(test.contract.factory(), test.factory);
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.oracle(), test.oracle.address);
+    assert_eq!(test.contract.max_oracle_age(), MAX_ORACLE_AGE);
+    assert_eq!(test.contract.k(), K);
+    assert_eq!(test.contract.get_reserves(), (0, 0));
+    assert_eq!(test.contract.get_targets(), (0, 0));
+    assert_eq!(test.contract.total_shares(), 0);
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapPmmPoolTest::setup();
+    test.initialize(K);
+    let result = test.contract.try_initialize(&test.factory, &test.token_0.address, &test.token_1.address, &test.oracle.address, &K, &MAX_ORACLE_AGE);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_token_order_invalid() {
+    let test = SoroswapPmmPoolTest::setup();
+    let result = test.contract.try_initialize(&test.factory, &test.token_1.address, &test.token_0.address, &test.oracle.address, &K, &MAX_ORACLE_AGE);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::InitializeTokenOrderInvalid)));
+}
+
+#[test]
+fn initialize_invalid_parameters() {
+    let test = SoroswapPmmPoolTest::setup();
+    let oracle = Address::generate(&test.env);
+    let result = test.contract.try_initialize(&test.factory, &test.token_0.address, &test.token_1.address, &oracle, &PRICE_SCALE, &MAX_ORACLE_AGE);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::InvalidK)));
+    let result = test.contract.try_initialize(&test.factory, &test.token_0.address, &test.token_1.address, &oracle, &-1, &MAX_ORACLE_AGE);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::InvalidK)));
+    let result = test.contract.try_initialize(&test.factory, &test.token_0.address, &test.token_1.address, &oracle, &K, &0);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::InvalidMaxOracleAge)));
+}
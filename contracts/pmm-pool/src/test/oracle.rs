@@ -0,0 +1,56 @@
+extern crate std;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    symbol_short,
+    vec,
+    Address,
+    IntoVal,
+    Symbol};
+use crate::test::{SoroswapPmmPoolTest, K};
+use crate::event::OracleEvent;
+use crate::SoroswapPmmPoolError;
+
+#[test]
+fn set_oracle() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    let oracle = Address::generate(&test.env);
+    test.contract.set_oracle(&oracle, &60);
+
+    // Only the factory can replace the oracle
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.factory.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "set_oracle"),
+                    (oracle.clone(), 60_u64).into_val(&test.env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(test.contract.oracle(), oracle);
+    assert_eq!(test.contract.max_oracle_age(), 60);
+
+    let expected_event = OracleEvent { oracle, max_oracle_age: 60 };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPmmPool", symbol_short!("oracle")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn set_oracle_invalid_max_oracle_age() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    let result = test.contract.try_set_oracle(&test.oracle.address, &0);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::InvalidMaxOracleAge)));
+}
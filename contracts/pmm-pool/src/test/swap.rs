@@ -0,0 +1,137 @@
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, symbol_short, vec, Address, IntoVal};
+use crate::test::{SoroswapPmmPoolTest, K, PRICE};
+use crate::event::SwapEvent;
+use crate::{PmmState, SoroswapPmmPoolError, PRICE_SCALE};
+
+#[test]
+fn swap_token_0_for_token_1() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    let bob = Address::generate(&test.env);
+
+    // At the targets the pool quotes the oracle price: 997_000_000 after the fee would be worth 1_994_000_000
+    let amount_out = 1_972_419_518;
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.contract.swap(&0, &amount_out, &bob);
+    assert_eq!(test.token_1.balance(&bob), amount_out);
+    assert_eq!(test.contract.get_reserves(), (11_000_000_000, 20_000_000_000 - amount_out));
+    assert_eq!(test.contract.get_targets(), (10_000_000_000, 20_000_000_000));
+
+    let expected_event = SwapEvent {
+        to: bob.clone(),
+        amount_0_in: 1_000_000_000,
+        amount_1_in: 0,
+        amount_0_out: 0,
+        amount_1_out: amount_out,
+        oracle_price: PRICE,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPmmPool", symbol_short!("swap")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The token_0 surplus is valued at the oracle price, so the fee raises the token_1 target
+    assert_eq!(test.contract.get_pmm_state(), PmmState {
+        price: PRICE,
+        k: K,
+        reserve_0: 11_000_000_000,
+        reserve_1: 20_000_000_000 - amount_out,
+        target_0: 10_000_000_000,
+        target_1: 20_005_871_330,
+    });
+}
+
+#[test]
+fn swap_curve_not_met() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    let result = test.contract.try_swap(&0, &1_972_419_519, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::SwapCurveNotMet)));
+}
+
+#[test]
+fn swap_token_1_for_token_0() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    test.token_1.transfer(&test.user, &test.contract.address, &2_000_000_000);
+    test.contract.swap(&986_209_759, &0, &test.user);
+    assert_eq!(test.contract.get_reserves(), (10_000_000_000 - 986_209_759, 22_000_000_000));
+
+    let result = test.contract.try_swap(&1, &0, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::SwapInsufficientInputAmount)));
+}
+
+#[test]
+fn swap_back_past_the_targets() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.contract.swap(&0, &1_972_419_518, &test.user);
+
+    // 1_978_290_848 token_1 bring the pool back to its targets, and the rest is sold on the other side
+    test.token_1.transfer(&test.user, &test.contract.address, &2_000_000_000);
+    test.contract.swap(&1_007_853_958, &0, &test.user);
+    assert_eq!(test.contract.get_reserves(), (9_992_146_042, 20_027_580_482));
+    assert_eq!(test.contract.get_targets(), (10_000_000_000, 20_005_871_330));
+    assert_eq!(test.contract.get_pmm_state().target_0, 10_002_999_439);
+}
+
+#[test]
+fn oracle_price_moves_the_targets() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.contract.swap(&0, &1_972_419_518, &test.user);
+
+    // The token_0 surplus is worth more token_1 at the new price
+    test.oracle.set_price(&(22 * PRICE_SCALE / 10), &1000);
+    let state = test.contract.get_pmm_state();
+    assert_eq!(state.price, 22 * PRICE_SCALE / 10);
+    assert_eq!(state.target_1, 20_201_368_672);
+}
+
+#[test]
+fn swap_at_the_oracle_price_when_k_is_zero() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(0);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.contract.swap(&0, &1_994_000_000, &test.user);
+    assert_eq!(test.contract.get_reserves(), (11_000_000_000, 18_006_000_000));
+}
+
+#[test]
+fn swap_oracle_stale() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 1601;
+    });
+    let result = test.contract.try_swap(&0, &1_000_000, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::OracleStale)));
+    assert_eq!(test.contract.try_get_pmm_state(), Err(Ok(SoroswapPmmPoolError::OracleStale)));
+
+    test.oracle.set_price(&PRICE, &1001);
+    test.contract.swap(&0, &1_000_000, &test.user);
+}
+
+#[test]
+fn swap_invalid_oracle_price() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.oracle.set_price(&0, &1000);
+    let result = test.contract.try_swap(&0, &1_000_000, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::InvalidOraclePrice)));
+}
+
+#[test]
+fn swap_invalid_amounts_out() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    assert_eq!(test.contract.try_swap(&0, &0, &test.user), Err(Ok(SoroswapPmmPoolError::SwapInsufficientOutputAmount)));
+    assert_eq!(test.contract.try_swap(&-1, &0, &test.user), Err(Ok(SoroswapPmmPoolError::SwapNegativesOutNotSupported)));
+    assert_eq!(test.contract.try_swap(&1, &1, &test.user), Err(Ok(SoroswapPmmPoolError::SwapBothAmountsOut)));
+    assert_eq!(test.contract.try_swap(&10_000_000_000, &0, &test.user), Err(Ok(SoroswapPmmPoolError::SwapInsufficientLiquidity)));
+    assert_eq!(test.contract.try_swap(&0, &1, &test.token_0.address), Err(Ok(SoroswapPmmPoolError::SwapInvalidTo)));
+}
@@ -0,0 +1,64 @@
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, symbol_short, vec, Address, IntoVal};
+use crate::test::{SoroswapPmmPoolTest, K};
+use crate::event::WithdrawEvent;
+use crate::SoroswapPmmPoolError;
+
+#[test]
+fn withdraw() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    let bob = Address::generate(&test.env);
+    let amounts = test.contract.withdraw(&test.user, &2_500_000_000, &bob);
+
+    assert_eq!(amounts, (2_500_000_000, 5_000_000_000));
+    assert_eq!(test.token_0.balance(&bob), 2_500_000_000);
+    assert_eq!(test.token_1.balance(&bob), 5_000_000_000);
+    assert_eq!(test.contract.shares(&test.user), 7_500_000_000);
+    assert_eq!(test.contract.total_shares(), 7_500_000_000);
+    assert_eq!(test.contract.get_reserves(), (7_500_000_000, 15_000_000_000));
+    assert_eq!(test.contract.get_targets(), (7_500_000_000, 15_000_000_000));
+
+    let expected_event = WithdrawEvent {
+        owner: test.user.clone(),
+        to: bob,
+        shares: 2_500_000_000,
+        amount_0: 2_500_000_000,
+        amount_1: 5_000_000_000,
+        new_reserve_0: 7_500_000_000,
+        new_reserve_1: 15_000_000_000,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPmmPool", symbol_short!("withdraw")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn withdraw_all_while_oracle_stale() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 10_000;
+    });
+    test.contract.withdraw(&test.user, &10_000_000_000, &test.user);
+    assert_eq!(test.contract.shares(&test.user), 0);
+    assert_eq!(test.contract.get_reserves(), (0, 0));
+    assert_eq!(test.contract.get_targets(), (0, 0));
+}
+
+#[test]
+fn withdraw_insufficient_shares() {
+    let test = SoroswapPmmPoolTest::setup_with_liquidity(K);
+    let result = test.contract.try_withdraw(&test.user, &10_000_000_001, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::WithdrawInsufficientShares)));
+    let result = test.contract.try_withdraw(&test.user, &0, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::WithdrawInsufficientShares)));
+    let bob = Address::generate(&test.env);
+    let result = test.contract.try_withdraw(&bob, &1, &bob);
+    assert_eq!(result, Err(Ok(SoroswapPmmPoolError::WithdrawInsufficientShares)));
+}
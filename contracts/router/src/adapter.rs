@@ -0,0 +1,193 @@
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contractclient, Address, Env, Vec};
+use soroswap_library::SoroswapLibraryError;
+use crate::error::{CombinedRouterError, SoroswapRouterError};
+use crate::pair::SoroswapPairClient;
+use crate::storage::get_adapter;
+
+/// Adapter id of the pairs of the router's own factory. It can not be registered.
+pub const SOROSWAP_ADAPTER_ID: u32 = 0;
+
+/// Trait defining the interface that an adapter to an external AMM, or to another Soroswap factory,
+/// must implement so the router can route through its pools.
+#[contractclient(name = "AmmAdapterClient")]
+pub trait AmmAdapterTrait {
+    /// Returns the amount of `token_out` a swap of `amount_in` of `token_in` would give.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_in` - The token sent to the adapter.
+    /// * `token_out` - The token received from the adapter.
+    /// * `amount_in` - The amount of `token_in`.
+    fn get_amount_out(e: Env, token_in: Address, token_out: Address, amount_in: i128) -> i128;
+
+    /// Returns the amount of `token_in` needed to get `amount_out` of `token_out`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_in` - The token sent to the adapter.
+    /// * `token_out` - The token received from the adapter.
+    /// * `amount_out` - The amount of `token_out`.
+    fn get_amount_in(e: Env, token_in: Address, token_out: Address, amount_out: i128) -> i128;
+
+    /// Swaps `amount_in` of `token_in`, which the router has already transferred to the adapter, and
+    /// sends the output to `to`. Must panic if the output is below `amount_out_min`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_in` - The token sent to the adapter.
+    /// * `token_out` - The token received from the adapter.
+    /// * `amount_in` - The amount of `token_in` transferred to the adapter.
+    /// * `amount_out_min` - The minimum amount of `token_out` to send.
+    /// * `to` - The address that receives the output.
+    ///
+    /// # Returns
+    ///
+    /// The amount of `token_out` sent to `to`.
+    fn swap(e: Env, token_in: Address, token_out: Address, amount_in: i128, amount_out_min: i128, to: Address) -> i128;
+}
+
+/// Checks that there is one adapter id for every hop of `path`.
+pub fn check_adapter_path(path: &Vec<Address>, adapter_ids: &Vec<u32>) -> Result<(), CombinedRouterError> {
+    if path.len() < 2 || adapter_ids.len() != path.len() - 1 {
+        return Err(SoroswapLibraryError::InvalidPath.into());
+    }
+    Ok(())
+}
+
+/// Returns the registered adapter with id `adapter_id`, or `InvalidAdapterId`.
+fn adapter_client<'a>(e: &'a Env, adapter_id: u32) -> Result<AmmAdapterClient<'a>, CombinedRouterError> {
+    match get_adapter(e, adapter_id) {
+        Some(adapter) => Ok(AmmAdapterClient::new(e, &adapter)),
+        None => Err(SoroswapRouterError::InvalidAdapterId.into()),
+    }
+}
+
+/// Returns the address the input of hop `i` has to be sent to: the Soroswap pair or the adapter.
+fn hop_recipient(
+    e: &Env,
+    factory: &Address,
+    path: &Vec<Address>,
+    adapter_ids: &Vec<u32>,
+    i: u32,
+) -> Result<Address, CombinedRouterError> {
+    let adapter_id = adapter_ids.get(i).unwrap();
+    if adapter_id == SOROSWAP_ADAPTER_ID {
+        Ok(soroswap_library::pair_for(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i + 1).unwrap())?)
+    } else {
+        Ok(adapter_client(e, adapter_id)?.address)
+    }
+}
+
+/// Performs chained get_amount_out calculations on a path where every hop is served by the adapter
+/// with the same index in `adapter_ids`.
+pub fn get_amounts_out(
+    e: &Env,
+    factory: &Address,
+    amount_in: i128,
+    path: &Vec<Address>,
+    adapter_ids: &Vec<u32>,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    check_adapter_path(path, adapter_ids)?;
+    let mut amounts: Vec<i128> = Vec::from_array(e, [amount_in]);
+    for i in 0..path.len() - 1 {
+        let (token_in, token_out) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
+        let amount = amounts.get(i).unwrap();
+        let adapter_id = adapter_ids.get(i).unwrap();
+        let amount_out = if adapter_id == SOROSWAP_ADAPTER_ID {
+            let (reserve_in, reserve_out) = soroswap_library::get_reserves(e.clone(), factory.clone(), token_in, token_out)?;
+            soroswap_library::get_amount_out(amount, reserve_in, reserve_out)?
+        } else {
+            adapter_client(e, adapter_id)?.get_amount_out(&token_in, &token_out, &amount)
+        };
+        amounts.push_back(amount_out);
+    }
+    Ok(amounts)
+}
+
+/// Performs chained get_amount_in calculations on a path where every hop is served by the adapter
+/// with the same index in `adapter_ids`.
+pub fn get_amounts_in(
+    e: &Env,
+    factory: &Address,
+    amount_out: i128,
+    path: &Vec<Address>,
+    adapter_ids: &Vec<u32>,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    check_adapter_path(path, adapter_ids)?;
+    let mut amounts: Vec<i128> = Vec::from_array(e, [amount_out]);
+    for i in (0..path.len() - 1).rev() {
+        let (token_in, token_out) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
+        let amount = amounts.get(0).unwrap();
+        let adapter_id = adapter_ids.get(i).unwrap();
+        let amount_in = if adapter_id == SOROSWAP_ADAPTER_ID {
+            let (reserve_in, reserve_out) = soroswap_library::get_reserves(e.clone(), factory.clone(), token_in, token_out)?;
+            soroswap_library::get_amount_in(amount, reserve_in, reserve_out)?
+        } else {
+            adapter_client(e, adapter_id)?.get_amount_in(&token_in, &token_out, &amount)
+        };
+        amounts.push_front(amount_in);
+    }
+    Ok(amounts)
+}
+
+/// Transfers the input amount from `from` to the first hop and executes every hop, sending the
+/// output of each one straight to the pair or adapter of the next hop, and the last one to `to`.
+/// Adapter hops are asked for at least the quoted amount, and what they send is the input of the next
+/// hop, so an adapter giving more than quoted benefits `to`.
+///
+/// Returns the amounts of tokens received at each step of the route.
+pub fn execute_route(
+    e: &Env,
+    factory: &Address,
+    amounts: &Vec<i128>,
+    path: &Vec<Address>,
+    adapter_ids: &Vec<u32>,
+    from: &Address,
+    to: &Address,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    let first_recipient = hop_recipient(e, factory, path, adapter_ids, 0)?;
+    let mut amount_in = amounts.get(0).unwrap();
+    TokenClient::new(e, &path.get(0).unwrap()).transfer(from, &first_recipient, &amount_in);
+
+    let mut executed_amounts: Vec<i128> = Vec::from_array(e, [amount_in]);
+    let mut recipient = first_recipient;
+    for i in 0..path.len() - 1 {
+        let (token_in, token_out) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
+        let quoted_amount_out = amounts.get(i + 1).unwrap();
+        let next_recipient = if i < path.len() - 2 {
+            hop_recipient(e, factory, path, adapter_ids, i + 1)?
+        } else {
+            to.clone()
+        };
+
+        let amount_out = if adapter_ids.get(i).unwrap() == SOROSWAP_ADAPTER_ID {
+            // The pair is quoted again when the previous hop sent it more than quoted
+            let amount_out = if amount_in == amounts.get(i).unwrap() {
+                quoted_amount_out
+            } else {
+                let (reserve_in, reserve_out) = soroswap_library::get_reserves(e.clone(), factory.clone(), token_in.clone(), token_out.clone())?;
+                soroswap_library::get_amount_out(amount_in, reserve_in, reserve_out)?
+            };
+            let (token_0, _) = soroswap_library::sort_tokens(token_in.clone(), token_out)?;
+            let (amount_0_out, amount_1_out) = if token_in == token_0 { (0, amount_out) } else { (amount_out, 0) };
+            SoroswapPairClient::new(e, &recipient).swap(&amount_0_out, &amount_1_out, &next_recipient);
+            amount_out
+        } else {
+            AmmAdapterClient::new(e, &recipient).swap(
+                &token_in,
+                &token_out,
+                &amount_in,
+                &quoted_amount_out,
+                &next_recipient,
+            )
+        };
+        executed_amounts.push_back(amount_out);
+        amount_in = amount_out;
+        recipient = next_recipient;
+    }
+    Ok(executed_amounts)
+}
@@ -33,6 +33,45 @@ pub enum SoroswapRouterError {
     /// SoroswapRouter: pair does not exist
     PairDoesNotExist = 409,
 
+    /// SoroswapRouter: split route needs at least one leg, and all legs must share the input and output tokens
+    InvalidSplitRoute = 410,
+
+    /// SoroswapRouter: max hops must be between 1 and MAX_HOPS
+    InvalidMaxHops = 411,
+
+    /// SoroswapRouter: no route found
+    NoRouteFound = 412,
+
+    /// SoroswapRouter: too many hub tokens
+    TooManyHubTokens = 413,
+
+    /// SoroswapRouter: insufficient liquidity minted
+    InsufficientLiquidity = 414,
+
+    /// SoroswapRouter: referral fee above the governance cap, or set without a referrer
+    InvalidReferralFee = 415,
+
+    /// SoroswapRouter: price limit must be positive
+    InvalidPriceLimit = 416,
+
+    /// SoroswapRouter: spot price deviates from the oracle price
+    OraclePriceDeviation = 417,
+
+    /// SoroswapRouter: adapter id not registered, or reserved for the router's own pairs
+    InvalidAdapterId = 418,
+
+    /// SoroswapRouter: RFQ quote does not match its hop of the path, or has non-positive amounts
+    InvalidRfqQuote = 419,
+
+    /// SoroswapRouter: RFQ quote expired
+    RfqQuoteExpired = 420,
+
+    /// SoroswapRouter: RFQ quote nonce already filled or cancelled
+    RfqNonceUsed = 421,
+
+    /// SoroswapRouter: the maker has not registered an RFQ signer
+    RfqSignerNotSet = 422,
+
 }
 
 
@@ -57,6 +96,21 @@ pub enum CombinedRouterError {
     LibraryInsufficientOutputAmount = 513,
     LibraryInvalidPath = 514,
     LibrarySortIdenticalTokens = 515,
+
+    RouterInvalidSplitRoute = 516,
+    RouterInvalidMaxHops = 517,
+    RouterNoRouteFound = 518,
+    RouterTooManyHubTokens = 519,
+    RouterInsufficientLiquidity = 520,
+    RouterInvalidReferralFee = 521,
+    RouterInvalidPriceLimit = 522,
+    RouterOraclePriceDeviation = 523,
+    RouterInvalidAdapterId = 524,
+    RouterInvalidRfqQuote = 525,
+    RouterRfqQuoteExpired = 526,
+    RouterRfqNonceUsed = 527,
+    RouterRfqSignerNotSet = 528,
+    LibraryOverflow = 529,
 }
 
 impl From<SoroswapLibraryError> for CombinedRouterError {
@@ -68,6 +122,7 @@ impl From<SoroswapLibraryError> for CombinedRouterError {
             SoroswapLibraryError::InsufficientOutputAmount => CombinedRouterError::LibraryInsufficientOutputAmount,
             SoroswapLibraryError::InvalidPath => CombinedRouterError::LibraryInvalidPath,
             SoroswapLibraryError::SortIdenticalTokens => CombinedRouterError::LibrarySortIdenticalTokens,
+            SoroswapLibraryError::Overflow => CombinedRouterError::LibraryOverflow,
         }
     }
 }
@@ -84,6 +139,19 @@ impl From<SoroswapRouterError> for CombinedRouterError {
             SoroswapRouterError::InsufficientOutputAmount => CombinedRouterError::RouterInsufficientOutputAmount,
             SoroswapRouterError::ExcessiveInputAmount => CombinedRouterError::RouterExcessiveInputAmount,
             SoroswapRouterError::PairDoesNotExist => CombinedRouterError::RouterPairDoesNotExist,
+            SoroswapRouterError::InvalidSplitRoute => CombinedRouterError::RouterInvalidSplitRoute,
+            SoroswapRouterError::InvalidMaxHops => CombinedRouterError::RouterInvalidMaxHops,
+            SoroswapRouterError::NoRouteFound => CombinedRouterError::RouterNoRouteFound,
+            SoroswapRouterError::TooManyHubTokens => CombinedRouterError::RouterTooManyHubTokens,
+            SoroswapRouterError::InsufficientLiquidity => CombinedRouterError::RouterInsufficientLiquidity,
+            SoroswapRouterError::InvalidReferralFee => CombinedRouterError::RouterInvalidReferralFee,
+            SoroswapRouterError::InvalidPriceLimit => CombinedRouterError::RouterInvalidPriceLimit,
+            SoroswapRouterError::OraclePriceDeviation => CombinedRouterError::RouterOraclePriceDeviation,
+            SoroswapRouterError::InvalidAdapterId => CombinedRouterError::RouterInvalidAdapterId,
+            SoroswapRouterError::InvalidRfqQuote => CombinedRouterError::RouterInvalidRfqQuote,
+            SoroswapRouterError::RfqQuoteExpired => CombinedRouterError::RouterRfqQuoteExpired,
+            SoroswapRouterError::RfqNonceUsed => CombinedRouterError::RouterRfqNonceUsed,
+            SoroswapRouterError::RfqSignerNotSet => CombinedRouterError::RouterRfqSignerNotSet,
         }
     }
 }
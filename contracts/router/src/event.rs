@@ -1,5 +1,8 @@
 //! Definition of the Events used in the contract
-use soroban_sdk::{contracttype, symbol_short, Env, Address, Vec};
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN, Vec};
+use crate::referral::SwapReferral;
+use crate::oracle::PairOracle;
+use crate::rfq::RfqQuote;
 
 // INITIALIZED
 #[contracttype]
@@ -16,6 +19,208 @@ pub(crate) fn initialized(e: &Env, factory: Address) {
     e.events().publish(("SoroswapRouter", symbol_short!("init")), event);
 }
 
+// HUB TOKENS
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HubTokensEvent {
+    pub hub_tokens: Vec<Address>
+}
+
+/// Publishes a `HubTokensEvent` when governance sets the hub tokens used to search routes.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `hub_tokens` - The new list of hub tokens.
+pub(crate) fn hub_tokens(e: &Env, hub_tokens: Vec<Address>) {
+    let event = HubTokensEvent {
+        hub_tokens,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("hubs")), event);
+}
+
+// REFERRAL FEE CAP EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralFeeCapEvent {
+    pub max_referral_fee_bps: u32
+}
+
+/// Publishes a `ReferralFeeCapEvent` when governance sets the cap on referral fees.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `max_referral_fee_bps` - The new cap, in basis points.
+pub(crate) fn referral_fee_cap(e: &Env, max_referral_fee_bps: u32) {
+    let event = ReferralFeeCapEvent {
+        max_referral_fee_bps,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("ref_cap")), event);
+}
+
+// PAIR ORACLE EVENTS
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairOracleEvent {
+    pub pair: Address,
+    pub oracle: Address,
+    pub max_deviation_bps: u32
+}
+
+/// Publishes a `PairOracleEvent` when governance sets the reference oracle of a pair.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `pair` - The pair the oracle is checked for.
+/// * `pair_oracle` - The oracle and the largest deviation allowed from its price.
+pub(crate) fn pair_oracle(e: &Env, pair: Address, pair_oracle: PairOracle) {
+    let event = PairOracleEvent {
+        pair,
+        oracle: pair_oracle.oracle,
+        max_deviation_bps: pair_oracle.max_deviation_bps,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("oracle")), event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairOracleRemovedEvent {
+    pub pair: Address
+}
+
+/// Publishes a `PairOracleRemovedEvent` when governance removes the reference oracle of a pair.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `pair` - The pair that no longer has an oracle.
+pub(crate) fn pair_oracle_removed(e: &Env, pair: Address) {
+    let event = PairOracleRemovedEvent {
+        pair,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("no_oracle")), event);
+}
+
+// ADAPTER EVENTS
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdapterEvent {
+    pub adapter_id: u32,
+    pub adapter: Address
+}
+
+/// Publishes an `AdapterEvent` when governance registers an AMM adapter.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `adapter_id` - The id paths use to route through the adapter.
+/// * `adapter` - The adapter contract.
+pub(crate) fn adapter(e: &Env, adapter_id: u32, adapter: Address) {
+    let event = AdapterEvent {
+        adapter_id,
+        adapter,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("adapter")), event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdapterRemovedEvent {
+    pub adapter_id: u32
+}
+
+/// Publishes an `AdapterRemovedEvent` when governance removes an AMM adapter.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `adapter_id` - The id of the removed adapter.
+pub(crate) fn adapter_removed(e: &Env, adapter_id: u32) {
+    let event = AdapterRemovedEvent {
+        adapter_id,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("no_adapt")), event);
+}
+
+// RFQ EVENTS
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RfqSignerEvent {
+    pub maker: Address,
+    pub signer: BytesN<32>
+}
+
+/// Publishes an `RfqSignerEvent` when a maker registers the key that signs its RFQ quotes.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `maker` - The market maker.
+/// * `signer` - The ed25519 public key.
+pub(crate) fn rfq_signer(e: &Env, maker: Address, signer: BytesN<32>) {
+    let event = RfqSignerEvent {
+        maker,
+        signer,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("rfq_sign")), event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RfqCancelEvent {
+    pub maker: Address,
+    pub nonce: u64
+}
+
+/// Publishes an `RfqCancelEvent` when a maker cancels an RFQ quote.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `maker` - The market maker.
+/// * `nonce` - The nonce of the cancelled quote.
+pub(crate) fn rfq_cancel(e: &Env, maker: Address, nonce: u64) {
+    let event = RfqCancelEvent {
+        maker,
+        nonce,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("rfq_cncl")), event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RfqFillEvent {
+    pub maker: Address,
+    pub taker: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub nonce: u64
+}
+
+/// Publishes an `RfqFillEvent` when an RFQ quote is filled as a hop of a swap.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `quote` - The filled quote.
+pub(crate) fn rfq_fill(e: &Env, quote: RfqQuote) {
+    let event = RfqFillEvent {
+        maker: quote.maker,
+        taker: quote.taker,
+        token_in: quote.token_in,
+        token_out: quote.token_out,
+        amount_in: quote.amount_in,
+        amount_out: quote.amount_out,
+        nonce: quote.nonce,
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("rfq_fill")), event);
+}
+
 // ADD LIQUIDITY EVENT
 #[contracttype] 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -26,6 +231,7 @@ pub struct AddLiquidityEvent {
     pub amount_a: i128,
     pub amount_b: i128,
     pub liquidity: i128,
+    pub from: Address,
     pub to: Address
 }
 
@@ -40,6 +246,7 @@ pub struct AddLiquidityEvent {
 /// * `amount_a` - The amount of `token_a` to add to the liquidity.
 /// * `amount_b` - The amount of `token_b` to add to the liquidity.
 /// * `liquidity` - The amount of liquidity tokens minted.
+/// * `from` - The address that provided `token_a` and `token_b`.
 /// * `to` - The address to receive the liquidity tokens.
 pub(crate) fn add_liquidity(
     e: &Env,
@@ -49,6 +256,7 @@ pub(crate) fn add_liquidity(
     amount_a: i128,
     amount_b: i128,
     liquidity: i128,
+    from: Address,
     to: Address,
 ) {
     let event = AddLiquidityEvent {
@@ -58,6 +266,7 @@ pub(crate) fn add_liquidity(
         amount_a,
         amount_b,
         liquidity,
+        from,
         to,
     };
 
@@ -76,6 +285,7 @@ pub struct RemoveLiquidityEvent {
     pub amount_a: i128,
     pub amount_b: i128,
     pub liquidity: i128,
+    pub from: Address,
     pub to: Address
 }
 
@@ -91,6 +301,7 @@ pub struct RemoveLiquidityEvent {
 /// * `amount_a` - The amount of `token_a` removed from the pool.
 /// * `amount_b` - The amount of `token_b` removed from the pool.
 /// * `liquidity` - The amount of liquidity tokens burned.
+/// * `from` - The address that provided the liquidity tokens.
 /// * `to` - The address to receive the token_a and token_b.
 pub(crate) fn remove_liquidity(
     e: &Env,
@@ -100,6 +311,7 @@ pub(crate) fn remove_liquidity(
     amount_a: i128,
     amount_b: i128,
     liquidity: i128,
+    from: Address,
     to: Address,
 ) {
     let event = RemoveLiquidityEvent {
@@ -109,6 +321,7 @@ pub(crate) fn remove_liquidity(
         amount_a,
         amount_b,
         liquidity,
+        from,
         to,
     };
 
@@ -123,7 +336,9 @@ pub(crate) fn remove_liquidity(
 pub struct SwapEvent {
     pub path: Vec<Address>,
     pub amounts: Vec<i128>,
-    pub to: Address
+    pub from: Address,
+    pub to: Address,
+    pub referral: SwapReferral
 }
 
 /// Publishes an `SwapEvent` to the event stream.
@@ -134,18 +349,129 @@ pub struct SwapEvent {
 /// * `path` - A vector representing the trading route, where the first element is the input token 
 ///            and the last is the output token. Intermediate elements represent pairs to trade through.
 /// * `amounts` - A vector containing the amounts of tokens traded at each step of the trading route.
+/// * `from` - The address that paid the input tokens.
 /// * `to` - The address where the output tokens will be sent to.
+/// * `referral` - The referral fee charged on the swap, if any.
 pub(crate) fn swap(
     e: &Env,
     path: Vec<Address>,
     amounts: Vec<i128>,
-    to: Address
+    from: Address,
+    to: Address,
+    referral: SwapReferral
 ) {
     let event = SwapEvent {
         path,
         amounts,
+        from,
         to,
+        referral,
     };
 
     e.events().publish(("SoroswapRouter", symbol_short!("swap")), event);
-}
\ No newline at end of file
+}
+
+
+// ZAP IN EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ZapInEvent {
+    pub token_in: Address,
+    pub amount_in: i128,
+    pub pair: Address,
+    pub amount_a: i128,
+    pub amount_b: i128,
+    pub liquidity: i128,
+    pub refund_a: i128,
+    pub refund_b: i128,
+    pub from: Address,
+    pub to: Address
+}
+
+/// Publishes a `ZapInEvent` to the event stream.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `token_in` - The single token provided by `from`.
+/// * `amount_in` - The amount of `token_in` provided.
+/// * `pair` - The address of the liquidity pair.
+/// * `amount_a` - The amount of `token_a` deposited.
+/// * `amount_b` - The amount of `token_b` deposited.
+/// * `liquidity` - The amount of liquidity tokens minted.
+/// * `refund_a` - The amount of `token_a` left over and sent back to `from`.
+/// * `refund_b` - The amount of `token_b` left over and sent back to `from`.
+/// * `from` - The address that provided `token_in`.
+/// * `to` - The address to receive the liquidity tokens.
+pub(crate) fn zap_in(
+    e: &Env,
+    token_in: Address,
+    amount_in: i128,
+    pair: Address,
+    amount_a: i128,
+    amount_b: i128,
+    liquidity: i128,
+    refund_a: i128,
+    refund_b: i128,
+    from: Address,
+    to: Address,
+) {
+    let event = ZapInEvent {
+        token_in,
+        amount_in,
+        pair,
+        amount_a,
+        amount_b,
+        liquidity,
+        refund_a,
+        refund_b,
+        from,
+        to,
+    };
+
+    e.events().publish(("SoroswapRouter", symbol_short!("zap_in")), event);
+}
+
+// ZAP OUT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ZapOutEvent {
+    pub pair: Address,
+    pub liquidity: i128,
+    pub token_out: Address,
+    pub amount_out: i128,
+    pub from: Address,
+    pub to: Address
+}
+
+/// Publishes a `ZapOutEvent` to the event stream.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `pair` - The address of the liquidity pair.
+/// * `liquidity` - The amount of liquidity tokens burned.
+/// * `token_out` - The single token received by `to`.
+/// * `amount_out` - The amount of `token_out` received.
+/// * `from` - The address that provided the liquidity tokens.
+/// * `to` - The address to receive `token_out`.
+pub(crate) fn zap_out(
+    e: &Env,
+    pair: Address,
+    liquidity: i128,
+    token_out: Address,
+    amount_out: i128,
+    from: Address,
+    to: Address,
+) {
+    let event = ZapOutEvent {
+        pair,
+        liquidity,
+        token_out,
+        amount_out,
+        from,
+        to,
+    };
+
+    e.events().publish(("SoroswapRouter", symbol_short!("zap_out")), event);
+}
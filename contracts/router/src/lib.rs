@@ -1,6 +1,6 @@
 #![no_std]
 use soroban_sdk::token::Client as TokenClient;
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
 use soroswap_library::{SoroswapLibraryError};
 
 mod pair;
@@ -9,11 +9,58 @@ mod test;
 mod event;
 mod storage;
 mod error;
+mod split;
+mod route;
+mod multicall;
+mod referral;
+mod liquidity;
+mod trace;
+mod oracle;
+mod adapter;
+mod rfq;
 
 use factory::SoroswapFactoryClient;
 use pair::SoroswapPairClient;
-use storage::{put_factory, has_factory, get_factory, extend_instance_ttl};
+use storage::{
+    put_factory,
+    has_factory,
+    get_factory,
+    extend_instance_ttl,
+    put_hub_tokens,
+    get_hub_tokens,
+    put_max_referral_fee_bps,
+    get_max_referral_fee_bps,
+    add_referral_fees,
+    get_referral_fees,
+    put_pair_oracle,
+    remove_pair_oracle,
+    get_pair_oracle,
+    put_adapter,
+    remove_adapter,
+    get_adapter,
+    put_rfq_signer,
+    get_rfq_signer,
+    set_rfq_nonce_used,
+    is_rfq_nonce_used,
+};
+use referral::{check_referral_fee, referral_fee_amount};
 pub use error::{SoroswapRouterError, CombinedRouterError};
+pub use split::SwapLeg;
+pub use route::{MAX_HOPS, MAX_HUB_TOKENS};
+pub use referral::{ReferralFee, SwapReferral, MAX_REFERRAL_FEE_BPS};
+pub use trace::RouteQuote;
+pub use oracle::{PairOracle, PriceOracleTrait, PriceOracleClient};
+pub use adapter::{AmmAdapterTrait, AmmAdapterClient, SOROSWAP_ADAPTER_ID};
+pub use rfq::RfqQuote;
+pub use multicall::{
+    AddLiquidityCall,
+    RemoveLiquidityCall,
+    SwapExactTokensCall,
+    SwapForExactTokensCall,
+    RouterCall,
+    MulticallStep,
+    RouterCallResult,
+};
 
 pub fn check_nonnegative_amount(amount: i128) -> Result<(), CombinedRouterError> {
     if amount < 0 {
@@ -88,37 +135,14 @@ fn add_liquidity_amounts(
         token_b.clone(),
     )?;
 
-    // When there is no liquidity (first deposit)
-    if reserve_a == 0 && reserve_b == 0 {
-        Ok((amount_a_desired, amount_b_desired))
-    } else {
-        // We try first with the amount a desired:
-        let amount_b_optimal = soroswap_library::quote(
-            amount_a_desired.clone(),
-            reserve_a.clone(),
-            reserve_b.clone(),
-        )?;
-
-        if amount_b_optimal <= amount_b_desired {
-            if amount_b_optimal < amount_b_min {
-                return Err(SoroswapRouterError::InsufficientBAmount.into());
-            }
-            Ok((amount_a_desired, amount_b_optimal))
-        }
-        // If not, we can try with the amount b desired
-        else {
-            let amount_a_optimal = soroswap_library::quote(amount_b_desired, reserve_b, reserve_a).map_err(SoroswapLibraryError::from)?;
-
-            // This should happen anyway. Because if we were not able to fulfill with our amount_b_desired for our amount_a_desired
-            // It is to expect that the amount_a_optimal for that lower amount_b_desired to be lower than the amount_a_desired
-            assert!(amount_a_optimal <= amount_a_desired);
-
-            if amount_a_optimal < amount_a_min {
-                return Err(SoroswapRouterError::InsufficientAAmount.into());
-            }
-            Ok((amount_a_optimal, amount_b_desired))
-        }
-    }
+    liquidity::optimal_amounts(
+        reserve_a,
+        reserve_b,
+        amount_a_desired,
+        amount_b_desired,
+        amount_a_min,
+        amount_b_min,
+    )
 }
 
 /// Executes a series of token swaps along the provided trading route.
@@ -170,6 +194,354 @@ fn swap(e: &Env, factory_address: &Address, amounts: &Vec<i128>, path: &Vec<Addr
 }
 
 
+/// Publishes a swap event with zero amounts for a swap that the price of the route left unfilled.
+fn unfilled_swap(e: &Env, path: &Vec<Address>, from: &Address, to: &Address) {
+    let mut amounts: Vec<i128> = Vec::new(e);
+    for _ in 0..path.len() {
+        amounts.push_back(0);
+    }
+    event::swap(e, path.clone(), amounts, from.clone(), to.clone(), SwapReferral::NoFee);
+}
+
+/// Transfers the input amount from `from` to the first pair of the route, executes the swaps
+/// and publishes the swap event.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `factory_address` - The address of the Soroswap factory contract.
+/// * `amounts` - A vector containing the amounts for each step of the trading route.
+/// * `path` - A vector representing the trading route, where each element is a token address.
+/// * `from` - The address that pays the input tokens.
+/// * `to` - The final destination address for the swapped tokens.
+fn execute_route(
+    e: &Env,
+    factory_address: &Address,
+    amounts: &Vec<i128>,
+    path: &Vec<Address>,
+    from: &Address,
+    to: &Address,
+) -> Result<(), CombinedRouterError> {
+    // Determine the pair contract address for the first step of the trading route
+    let pair = soroswap_library::pair_for(
+        e.clone(),
+        factory_address.clone(),
+        path.get(0).unwrap(),
+        path.get(1).unwrap(),
+    )?;
+
+    // Transfer input tokens to the pair contract
+    // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
+    // If we implement, we will include an additional cross-contract call...
+    TokenClient::new(e, &path.get(0).unwrap()).transfer(from, &pair, &amounts.get(0).unwrap());
+
+    // Execute the tokens swap
+    swap(e, factory_address, amounts, path, to)?;
+
+    event::swap(
+        e,
+        path.clone(),
+        amounts.clone(),
+        from.clone(),
+        to.clone(),
+        SwapReferral::NoFee);
+
+    Ok(())
+}
+
+
+/// Transfers `amount_in` from `payer` to the first pair of the route and executes the swaps,
+/// without publishing a swap event. Used by the zaps, where `payer` can be the router itself.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `factory_address` - The address of the Soroswap factory contract.
+/// * `payer` - The address that pays the input tokens.
+/// * `amount_in` - The amount of input tokens.
+/// * `path` - A vector representing the trading route, where each element is a token address.
+/// * `to` - The final destination address for the swapped tokens.
+///
+/// # Returns
+/// The amount of the last token of `path` sent to `to`.
+fn pay_and_swap(
+    e: &Env,
+    factory_address: &Address,
+    payer: &Address,
+    amount_in: i128,
+    path: &Vec<Address>,
+    to: &Address,
+) -> Result<i128, CombinedRouterError> {
+    let amounts = soroswap_library::get_amounts_out(
+        e.clone(),
+        factory_address.clone(),
+        amount_in,
+        path.clone(),
+    )?;
+    let pair = soroswap_library::pair_for(
+        e.clone(),
+        factory_address.clone(),
+        path.get(0).unwrap(),
+        path.get(1).unwrap(),
+    )?;
+    TokenClient::new(e, &path.get(0).unwrap()).transfer(payer, &pair, &amount_in);
+    swap(e, factory_address, &amounts, path, to)?;
+    Ok(amounts.last().unwrap())
+}
+
+
+/// Adds liquidity on behalf of `from` once the call has been checked and authorized.
+/// Shared by `add_liquidity_from` and `multicall`.
+///
+/// # Returns
+/// The amounts of token A and B added to the pool, and the amount of liquidity tokens minted.
+fn execute_add_liquidity(
+    e: Env,
+    from: Address,
+    token_a: Address,
+    token_b: Address,
+    amount_a_desired: i128,
+    amount_b_desired: i128,
+    amount_a_min: i128,
+    amount_b_min: i128,
+    to: Address,
+) -> Result<(i128, i128, i128), CombinedRouterError> {
+    let factory = get_factory(&e);
+
+    let (amount_a, amount_b) = add_liquidity_amounts(
+        e.clone(),
+        factory.clone(),
+        token_a.clone(),
+        token_b.clone(),
+        amount_a_desired,
+        amount_b_desired,
+        amount_a_min,
+        amount_b_min,
+    )?;
+
+    let pair: Address = soroswap_library::pair_for(
+        e.clone(),
+        factory,
+        token_a.clone(),
+        token_b.clone(),
+    ).map_err(SoroswapLibraryError::from)?;
+
+    TokenClient::new(&e, &token_a).transfer(&from, &pair, &amount_a);
+    TokenClient::new(&e, &token_b).transfer(&from, &pair, &amount_b);
+
+    let liquidity = SoroswapPairClient::new(&e, &pair).deposit(&to);
+
+    event::add_liquidity(
+        &e,
+        token_a,
+        token_b,
+        pair,
+        amount_a,
+        amount_b,
+        liquidity,
+        from,
+        to);
+        
+    Ok((amount_a, amount_b, liquidity))
+}
+
+
+/// Removes liquidity on behalf of `from` once the call has been checked and authorized.
+/// Shared by `remove_liquidity_from` and `multicall`.
+///
+/// # Returns
+/// The amounts of token A and B withdrawn from the pool.
+fn execute_remove_liquidity(
+    e: Env,
+    from: Address,
+    token_a: Address,
+    token_b: Address,
+    liquidity: i128,
+    amount_a_min: i128,
+    amount_b_min: i128,
+    to: Address,
+) -> Result<(i128, i128), CombinedRouterError> {
+    // Ensure that the pair exists in the Soroswap factory
+    let factory_address = get_factory(&e);
+    let factory = SoroswapFactoryClient::new(&e, &factory_address);
+
+    if !factory.pair_exists(&token_a, &token_b) {
+        return Err(SoroswapRouterError::PairDoesNotExist.into());
+    }
+
+    // Retrieve the pair's contract address using the Soroswap library
+    let pair: Address = soroswap_library::pair_for(
+        e.clone(),
+        get_factory(&e),
+        token_a.clone(),
+        token_b.clone(),
+    )?;
+
+    // Transfer LP tokens from the payer to the pair contract
+    TokenClient::new(&e, &pair).transfer(&from, &pair, &liquidity);
+    
+    // Withdraw paired tokens from the pool
+    let (amount_0, amount_1) = SoroswapPairClient::new(&e, &pair).withdraw(&to);
+
+    // Sort tokens to match the expected order
+    let (token_0, _token_1) = soroswap_library::sort_tokens(token_a.clone(), token_b.clone())?;
+    let (amount_a, amount_b) = if token_a == token_0 {
+        (amount_0, amount_1)
+    } else {
+        (amount_1, amount_0)
+    };
+
+    // Check if the received amounts meet the minimum requirements
+    if amount_a < amount_a_min {
+        return Err(SoroswapRouterError::InsufficientAAmount.into());
+    }
+    if amount_b < amount_b_min {
+        return Err(SoroswapRouterError::InsufficientBAmount.into());
+    }
+
+    event::remove_liquidity(
+        &e,
+        token_a,
+        token_b,
+        pair,
+        amount_a,
+        amount_b,
+        liquidity,
+        from,
+        to);
+
+    // Return the amounts of paired tokens withdrawn
+    Ok((amount_a, amount_b))
+}
+
+
+/// Swaps an exact amount of input tokens paid by `from` once the call has been checked and authorized.
+/// Shared by `swap_exact_tokens_from` and `multicall`.
+///
+/// # Returns
+/// A vector containing the amounts of tokens received at each step of the trading route.
+fn execute_swap_exact_tokens(
+    e: Env,
+    from: Address,
+    amount_in: i128,
+    amount_out_min: i128,
+    path: Vec<Address>,
+    to: Address,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    // Get the expected output amounts for each step of the trading route        
+    let factory_address = get_factory(&e);
+    let amounts = soroswap_library::get_amounts_out(
+        e.clone(),
+        factory_address.clone(),
+        amount_in,
+        path.clone(),
+    )?;
+
+    // Ensure that the final output amount meets the minimum requirement        
+    if amounts.get(amounts.len() - 1).unwrap() < amount_out_min {
+        return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+    }
+    
+    // Pay the first pair, execute the tokens swap and emit the event
+    execute_route(&e, &factory_address, &amounts, &path, &from, &to)?;
+
+    // Return the amounts of tokens received at each step of the trading route
+    Ok(amounts)
+}
+
+
+/// Swaps tokens paid by `from` for an exact amount of output tokens once the call has been checked
+/// and authorized. Shared by `swap_for_exact_tokens_from` and `multicall`.
+///
+/// # Returns
+/// A vector containing the amounts of tokens used at each step of the trading route.
+fn execute_swap_for_exact_tokens(
+    e: Env,
+    from: Address,
+    amount_out: i128,
+    amount_in_max: i128,
+    path: Vec<Address>,
+    to: Address,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    // Get the expected input amounts for each step of the trading route
+    let factory_address = get_factory(&e);
+    let amounts = soroswap_library::get_amounts_in(
+        e.clone(),
+        factory_address.clone(),
+        amount_out,
+        path.clone(),
+    )?;
+    
+    // Ensure that the input amount does not exceed the maximum allowed
+    if amounts.get(0).unwrap() > amount_in_max {
+        return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+    }
+
+    // Pay the first pair, execute the token swap and emit the event
+    execute_route(&e, &factory_address, &amounts, &path, &from, &to)?;
+
+    // Return the amounts of tokens used at each step of the trading route
+    Ok(amounts)
+}
+
+
+/// Runs one `multicall` operation on behalf of `from` once the batch has been checked and authorized.
+///
+/// # Returns
+/// The result of the operation.
+fn execute_call(
+    e: &Env,
+    from: &Address,
+    call: RouterCall,
+    to: &Address,
+) -> Result<RouterCallResult, CombinedRouterError> {
+    call.check_amounts()?;
+    match call {
+        RouterCall::AddLiquidity(call) => {
+            let (amount_a, amount_b, liquidity) = execute_add_liquidity(
+                e.clone(),
+                from.clone(),
+                call.token_a,
+                call.token_b,
+                call.amount_a_desired,
+                call.amount_b_desired,
+                call.amount_a_min,
+                call.amount_b_min,
+                to.clone(),
+            )?;
+            Ok(RouterCallResult::AddLiquidity(amount_a, amount_b, liquidity))
+        }
+        RouterCall::RemoveLiquidity(call) => {
+            let (amount_a, amount_b) = execute_remove_liquidity(
+                e.clone(),
+                from.clone(),
+                call.token_a,
+                call.token_b,
+                call.liquidity,
+                call.amount_a_min,
+                call.amount_b_min,
+                to.clone(),
+            )?;
+            Ok(RouterCallResult::RemoveLiquidity(amount_a, amount_b))
+        }
+        RouterCall::SwapExactTokens(call) => Ok(RouterCallResult::Swap(execute_swap_exact_tokens(
+            e.clone(),
+            from.clone(),
+            call.amount_in,
+            call.amount_out_min,
+            call.path,
+            to.clone(),
+        )?)),
+        RouterCall::SwapForExactTokens(call) => Ok(RouterCallResult::Swap(execute_swap_for_exact_tokens(
+            e.clone(),
+            from.clone(),
+            call.amount_out,
+            call.amount_in_max,
+            call.path,
+            to.clone(),
+        )?)),
+    }
+}
+
+
 /*
     SOROSWAP ROUTER SMART CONTRACT INTERFACE:
 */
@@ -284,301 +656,1880 @@ pub trait SoroswapRouterTrait {
         deadline: u64,
     ) -> Result<Vec<i128>, CombinedRouterError>;
 
-    /*  *** Read only functions: *** */
-
-    /// This function retrieves the factory contract's address associated with the provided environment.
-    /// It also checks if the factory has been initialized and raises an assertion error if not.
-    /// If the factory is not initialized, this code will raise an assertion error with the message "SoroswapRouter: not yet initialized".
+    /// Same as `add_liquidity`, but the tokens are paid by `from` while the LP tokens are minted to `to`.
+    /// Only `from` needs to authorize the call.
     ///
     /// # Arguments
-    /// * `e` - The contract environment (`Env`) in which the contract is executing.
-    fn get_factory(e: Env) -> Result<Address, CombinedRouterError>;
-
-    /*
-    LIBRARY FUNCTIONS:
-    */
+    /// * `from` - The address that provides both tokens and authorizes the operation.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * See `add_liquidity` for the remaining arguments.
+    ///
+    /// # Returns
+    /// The amounts of token A and B added to the pool, and the amount of liquidity tokens minted.
+    fn add_liquidity_from(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
 
-    /// Calculates the deterministic address for a pair without making any external calls.
-    /// check <https://github.com/paltalabs/deterministic-address-soroban>
+    /// Same as `remove_liquidity`, but the LP tokens are taken from `from` while the paired tokens are sent to `to`.
+    /// Only `from` needs to authorize the call.
     ///
     /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `token_a` - The address of the first token.
-    /// * `token_b` - The address of the second token.
+    /// * `from` - The address that provides the LP tokens and authorizes the operation.
+    /// * `to` - The address where the paired tokens will be sent to.
+    /// * See `remove_liquidity` for the remaining arguments.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
-    fn router_pair_for(e: Env, token_a: Address, token_b: Address) -> Result<Address, CombinedRouterError>;
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_from(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError>;
 
-    /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
+    /// Same as `swap_exact_tokens_for_tokens`, but the input tokens are paid by `from` while the output
+    /// tokens are sent to `to`. Only `from` needs to authorize the call.
     ///
     /// # Arguments
-    ///
-    /// * `amount_a` - The amount of the first asset.
-    /// * `reserve_a` - Reserves of the first asset in the pair.
-    /// * `reserve_b` - Reserves of the second asset in the pair.
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * See `swap_exact_tokens_for_tokens` for the remaining arguments.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated equivalent amount, and `Err` indicates an error such as insufficient amount or liquidity
-    fn router_quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, CombinedRouterError>;
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_from(
+        e: Env,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
 
-    /// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset.
+    /// Same as `swap_tokens_for_exact_tokens`, but the input tokens are paid by `from` while the output
+    /// tokens are sent to `to`. Only `from` needs to authorize the call.
     ///
     /// # Arguments
-    ///
-    /// * `amount_in` - The input amount of the asset.
-    /// * `reserve_in` - Reserves of the input asset in the pair.
-    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * See `swap_tokens_for_exact_tokens` for the remaining arguments.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
-    fn router_get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_from(
+        e: Env,
+        from: Address,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
 
-    /// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset.
+    /// Swaps exact amounts of one input token through several trading routes at once, so a large trade can
+    /// be spread over different paths. Each leg's `amount` is the input sent through its `path`; all paths
+    /// must share the same input and output tokens. The legs are executed in order and atomically, and the
+    /// sum of their outputs must reach `amount_out_min`. A swap event is published for every leg.
     ///
     /// # Arguments
-    ///
-    /// * `amount_out` - The output amount of the asset.
-    /// * `reserve_in` - Reserves of the input asset in the pair.
-    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `legs` - The trading routes and the input amount for each of them.
+    /// * `amount_out_min` - The minimum required total amount of output tokens to receive.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
-    fn router_get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+    /// For every leg, the amounts of tokens received at each step of its trading route.
+    fn swap_exact_tokens_split(
+        e: Env,
+        from: Address,
+        legs: Vec<SwapLeg>,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<Vec<i128>>, CombinedRouterError>;
 
-    /// Performs chained get_amount_out calculations on any number of pairs.
+    /// Swaps tokens for exact amounts of one output token through several trading routes at once.
+    /// Each leg's `amount` is the output its `path` must deliver; all paths must share the same input
+    /// and output tokens. The legs are executed in order and atomically, and the sum of their inputs
+    /// can not exceed `amount_in_max`. A swap event is published for every leg.
     ///
     /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `amount_in` - The input amount.
-    /// * `path` - Vector of token addresses representing the path.
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `legs` - The trading routes and the output amount for each of them.
+    /// * `amount_in_max` - The maximum allowed total amount of input tokens to be swapped.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
-    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
-    
-    /// Performs chained get_amount_in calculations on any number of pairs.
+    /// For every leg, the amounts of tokens used at each step of its trading route.
+    fn swap_for_exact_tokens_split(
+        e: Env,
+        from: Address,
+        legs: Vec<SwapLeg>,
+        amount_in_max: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<Vec<i128>>, CombinedRouterError>;
+
+    /// Swaps an exact amount of `token_in` for as many `token_out` as possible, using the best route found
+    /// by `router_best_path_out` among the direct path and the paths through the hub tokens.
     ///
     /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `amount_out` - The output amount.
-    /// * `path` - Vector of token addresses representing the path.
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `token_in` - The input token.
+    /// * `token_out` - The output token.
+    /// * `max_hops` - The maximum number of pairs the route can go through, up to `MAX_HOPS`.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
-    fn router_get_amounts_in(e: Env, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
-
-    
+    /// The path used and the amounts of tokens received at each step of it.
+    fn swap_exact_tokens_best(
+        e: Env,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        token_in: Address,
+        token_out: Address,
+        max_hops: u32,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(Vec<Address>, Vec<i128>), CombinedRouterError>;
 
-}
+    /// Sets the hub tokens (for example XLM and USDC) that route searches can go through.
+    /// Only the factory's `fee_to_setter` can call it, and at most `MAX_HUB_TOKENS` can be set.
+    ///
+    /// # Arguments
+    /// * `hub_tokens` - The new list of hub tokens, replacing the previous one.
+    fn set_hub_tokens(e: Env, hub_tokens: Vec<Address>) -> Result<(), CombinedRouterError>;
+
+    /// Adds liquidity to the `token_a`/`token_b` pool from a single token. The input is first swapped along
+    /// `path` into `token_a` (a one token `path` skips this step). Then the closed-form optimal portion
+    /// of `token_a` is swapped for `token_b`, both are deposited, and any leftover is refunded to `from`.
+    ///
+    /// # Arguments
+    /// * `from` - The address that provides the input token and authorizes the operation.
+    /// * `token_a` - The pool token the input is converted to.
+    /// * `token_b` - The other token of the pool.
+    /// * `path` - The trading route from the input token to `token_a`.
+    /// * `amount_in` - The amount of the input token.
+    /// * `liquidity_min` - The minimum required amount of liquidity tokens to mint.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amounts of token A and B deposited, and the amount of liquidity tokens minted.
+    fn zap_in(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        path: Vec<Address>,
+        amount_in: i128,
+        liquidity_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity from the `token_a`/`token_b` pool into a single token. The withdrawn `token_b` is
+    /// swapped for `token_a` in the same pool, and then all the `token_a` is swapped along `path` into the
+    /// output token (a one token `path` keeps `token_a`).
+    ///
+    /// # Arguments
+    /// * `from` - The address that provides the LP tokens and authorizes the operation.
+    /// * `token_a` - The pool token everything is converted to first.
+    /// * `token_b` - The other token of the pool.
+    /// * `liquidity` - The amount of liquidity tokens to burn.
+    /// * `path` - The trading route from `token_a` to the output token.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amount of output tokens sent to `to`.
+    fn zap_out(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        path: Vec<Address>,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<i128, CombinedRouterError>;
+
+    /// Runs a batch of router operations in order, under a single authorization by `from` and a single
+    /// deadline, and returns the result of every step. The batch is atomic: a failed step reverts it
+    /// entirely, unless the step sets `skip_if_rejected` and the router rejected it before it moved any
+    /// funds, in which case its error code is reported as `RouterCallResult::Failed` and the next step
+    /// runs. Steps are not isolated from each other: a `remove_liquidity` step that misses its minimum
+    /// amounts, or any failed token transfer, always reverts the batch.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays for every operation and authorizes the batch.
+    /// * `steps` - The operations to run, in order.
+    /// * `to` - The address that receives the output of every operation.
+    /// * `deadline` - The deadline for executing the batch.
+    ///
+    /// # Returns
+    /// The result of every step, in order.
+    fn multicall(
+        e: Env,
+        from: Address,
+        steps: Vec<MulticallStep>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<RouterCallResult>, CombinedRouterError>;
+
+    /// Sets the cap on the referral fee integrators can charge on referral swaps.
+    /// Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `max_referral_fee_bps` - The new cap in basis points, at most `MAX_REFERRAL_FEE_BPS`. Zero disables referral fees.
+    fn set_max_referral_fee_bps(e: Env, max_referral_fee_bps: u32) -> Result<(), CombinedRouterError>;
+
+    /// Swaps up to `amount_in_max` input tokens along `path`, stopping once the marginal execution price
+    /// reaches `price_limit`. Only the largest input that keeps the price within the limit is swapped,
+    /// and the rest of `amount_in_max` is left unfilled. Nothing is swapped if the current price is
+    /// already beyond the limit, and the swap event then has zero amounts.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in_max` - The maximum amount of input tokens to be swapped.
+    /// * `price_limit` - The minimum amount of output tokens per input token for the last unit swapped,
+    ///   scaled by `soroswap_library::PRICE_SCALE`.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amount of input tokens swapped and the amount left unfilled.
+    fn swap_tokens_with_price_limit(
+        e: Env,
+        from: Address,
+        amount_in_max: i128,
+        price_limit: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_from`, and sends `referral_fee_bps`
+    /// of the output tokens to `referrer`. The fee is capped by governance.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens for `to`, after the referral fee.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `referrer` - The address that receives the referral fee, if any.
+    /// * `referral_fee_bps` - The referral fee in basis points. Must be zero without a referrer.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route,
+    /// before the referral fee.
+    fn swap_exact_tokens_referral(
+        e: Env,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        referrer: Option<Address>,
+        referral_fee_bps: u32,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output tokens like `swap_for_exact_tokens_from`, and charges
+    /// `from` an extra `referral_fee_bps` of the input amount, sent to `referrer`. The fee is capped by governance.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_out` - The exact amount of output tokens to be received.
+    /// * `amount_in_max` - The maximum amount of input tokens to be spent, including the referral fee.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `referrer` - The address that receives the referral fee, if any.
+    /// * `referral_fee_bps` - The referral fee in basis points. Must be zero without a referrer.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route,
+    /// without the referral fee.
+    fn swap_for_exact_tokens_referral(
+        e: Env,
+        from: Address,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        referrer: Option<Address>,
+        referral_fee_bps: u32,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Sets the reference price oracle of the `token_a`/`token_b` pair, checked by the safe swaps.
+    /// Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `oracle` - The oracle contract, implementing `PriceOracleTrait`.
+    /// * `max_deviation_bps` - The largest deviation allowed between the spot price of the pair and the
+    ///   oracle price, in basis points of the oracle price.
+    fn set_pair_oracle(e: Env, token_a: Address, token_b: Address, oracle: Address, max_deviation_bps: u32) -> Result<(), CombinedRouterError>;
+
+    /// Removes the reference price oracle of the `token_a`/`token_b` pair, so the safe swaps stop checking it.
+    /// Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    fn remove_pair_oracle(e: Env, token_a: Address, token_b: Address) -> Result<(), CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_from`, but first checks every hop whose
+    /// pair has a reference oracle, and fails with `OraclePriceDeviation` if the spot price of the pair
+    /// deviates from the oracle's time-weighted price by more than the configured basis points.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_safe(
+        e: Env,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output tokens like `swap_for_exact_tokens_from`, with the same
+    /// oracle checks as `swap_exact_tokens_safe`.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_out` - The exact amount of output tokens to be received.
+    /// * `amount_in_max` - The maximum amount of input tokens to be spent.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_safe(
+        e: Env,
+        from: Address,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Registers `adapter` under `adapter_id`, so paths can route hops through it. Registering an id again
+    /// replaces its adapter. Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `adapter_id` - The id paths use for the adapter. `SOROSWAP_ADAPTER_ID` is reserved.
+    /// * `adapter` - The adapter contract, implementing `AmmAdapterTrait`.
+    fn set_adapter(e: Env, adapter_id: u32, adapter: Address) -> Result<(), CombinedRouterError>;
+
+    /// Removes the adapter registered under `adapter_id`. Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `adapter_id` - The id of the adapter to remove.
+    fn remove_adapter(e: Env, adapter_id: u32) -> Result<(), CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_from`, along a path that can mix pairs of
+    /// the router's factory with pools of registered adapters. Hop `i`, from `path[i]` to `path[i + 1]`, is
+    /// served by the adapter `adapter_ids[i]`, where `SOROSWAP_ADAPTER_ID` means the router's own pair.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route.
+    /// * `adapter_ids` - The adapter serving each hop of `path`.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route. An adapter
+    /// can send more than it quoted, and the surplus goes on to the next hop.
+    fn swap_exact_tokens_adapters(
+        e: Env,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        adapter_ids: Vec<u32>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output tokens like `swap_for_exact_tokens_from`, along a path of
+    /// pairs and adapters as in `swap_exact_tokens_adapters`.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_out` - The exact amount of output tokens to be received.
+    /// * `amount_in_max` - The maximum amount of input tokens to be spent.
+    /// * `path` - A vector representing the trading route.
+    /// * `adapter_ids` - The adapter serving each hop of `path`.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_adapters(
+        e: Env,
+        from: Address,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        adapter_ids: Vec<u32>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Registers the ed25519 public key that signs the RFQ quotes of `maker`, replacing any previous one.
+    /// Quotes signed by a replaced key can no longer be filled.
+    ///
+    /// # Arguments
+    /// * `maker` - The market maker. Must authorize the call.
+    /// * `signer` - The ed25519 public key.
+    fn set_rfq_signer(e: Env, maker: Address, signer: BytesN<32>) -> Result<(), CombinedRouterError>;
+
+    /// Cancels the RFQ quote of `maker` with `nonce`, so it can no longer be filled.
+    ///
+    /// # Arguments
+    /// * `maker` - The market maker. Must authorize the call.
+    /// * `nonce` - The nonce of the quote.
+    fn cancel_rfq_quote(e: Env, maker: Address, nonce: u64) -> Result<(), CombinedRouterError>;
+
+    /// Swaps along a path where hop `rfq_hop` is filled by a firm quote signed by a market maker, and the
+    /// other hops by the router's pairs. The pairs before the RFQ hop deliver exactly `quote.amount_in` to the
+    /// maker, who pays `quote.amount_out` through its allowance on the router. The taker pays the input.
+    ///
+    /// # Arguments
+    /// * `quote` - The RFQ quote. Its taker pays the input and must authorize the call.
+    /// * `signature` - The ed25519 signature of `router_get_rfq_quote_hash(quote)` by the maker's signer.
+    /// * `path` - A vector representing the trading route.
+    /// * `rfq_hop` - The hop of `path` filled by the quote.
+    /// * `amount_in_max` - The maximum amount of input tokens to be spent.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens at each step of the trading route.
+    fn swap_rfq(
+        e: Env,
+        quote: RfqQuote,
+        signature: BytesN<64>,
+        path: Vec<Address>,
+        rfq_hop: u32,
+        amount_in_max: i128,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /*  *** Read only functions: *** */
+
+    /// This function retrieves the factory contract's address associated with the provided environment.
+    /// It also checks if the factory has been initialized and raises an assertion error if not.
+    /// If the factory is not initialized, this code will raise an assertion error with the message "SoroswapRouter: not yet initialized".
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    fn get_factory(e: Env) -> Result<Address, CombinedRouterError>;
+
+    /// Returns the hub tokens that route searches can go through.
+    fn get_hub_tokens(e: Env) -> Result<Vec<Address>, CombinedRouterError>;
+
+    /// Returns the cap on the referral fee, in basis points. Zero while referral fees are disabled.
+    fn get_max_referral_fee_bps(e: Env) -> Result<u32, CombinedRouterError>;
+
+    /// Returns the lifetime referral fees received by `referrer` in `token`.
+    fn get_referral_fees(e: Env, referrer: Address, token: Address) -> Result<i128, CombinedRouterError>;
+
+    /// Returns the reference price oracle of the `token_a`/`token_b` pair, if it has one.
+    fn get_pair_oracle(e: Env, token_a: Address, token_b: Address) -> Result<Option<PairOracle>, CombinedRouterError>;
+
+    /// Returns the adapter registered under `adapter_id`, if any.
+    fn get_adapter(e: Env, adapter_id: u32) -> Result<Option<Address>, CombinedRouterError>;
+
+    /// Returns the ed25519 public key registered for the RFQ quotes of `maker`, if any.
+    fn get_rfq_signer(e: Env, maker: Address) -> Result<Option<BytesN<32>>, CombinedRouterError>;
+
+    /// Returns whether the RFQ quote of `maker` with `nonce` has been filled or cancelled.
+    fn is_rfq_nonce_used(e: Env, maker: Address, nonce: u64) -> Result<bool, CombinedRouterError>;
+
+    /*
+    LIBRARY FUNCTIONS:
+    */
+
+    /// Calculates the deterministic address for a pair without making any external calls.
+    /// check <https://github.com/paltalabs/deterministic-address-soroban>
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+    fn router_pair_for(e: Env, token_a: Address, token_b: Address) -> Result<Address, CombinedRouterError>;
+
+    /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_a` - The amount of the first asset.
+    /// * `reserve_a` - Reserves of the first asset in the pair.
+    /// * `reserve_b` - Reserves of the second asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated equivalent amount, and `Err` indicates an error such as insufficient amount or liquidity
+    fn router_quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn router_get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
+    fn router_get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Performs chained get_amount_out calculations on any number of pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
+    
+    /// Performs chained get_amount_in calculations on any number of pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_in(e: Env, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Finds the path from `token_in` to `token_out` that gives the most output for `amount_in`. It evaluates
+    /// the direct path and every path through distinct hub tokens of at most `max_hops` pairs, where all
+    /// pairs exist, and quotes them with the library. Paths that can not be quoted are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount.
+    /// * `token_in` - The input token.
+    /// * `token_out` - The output token.
+    /// * `max_hops` - The maximum number of pairs the path can go through, up to `MAX_HOPS`.
+    ///
+    /// # Returns
+    ///
+    /// The best path and the amounts at each step of it, or `NoRouteFound`.
+    fn router_best_path_out(e: Env, amount_in: i128, token_in: Address, token_out: Address, max_hops: u32) -> Result<(Vec<Address>, Vec<i128>), CombinedRouterError>;
+
+    /// Finds the path from `token_in` to `token_out` that needs the least input to get `amount_out`. It
+    /// evaluates the same paths as `router_best_path_out`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount.
+    /// * `token_in` - The input token.
+    /// * `token_out` - The output token.
+    /// * `max_hops` - The maximum number of pairs the path can go through, up to `MAX_HOPS`.
+    ///
+    /// # Returns
+    ///
+    /// The best path and the amounts at each step of it, or `NoRouteFound`.
+    fn router_best_path_in(e: Env, amount_out: i128, token_in: Address, token_out: Address, max_hops: u32) -> Result<(Vec<Address>, Vec<i128>), CombinedRouterError>;
+
+    /// Previews `add_liquidity` without creating the pair or moving tokens. Returns the amounts of token A
+    /// and B that would be deposited for the desired amounts, and the LP tokens that would be minted for
+    /// them. On the first deposit `MINIMUM_LIQUIDITY` is locked in the pair and not counted. LP tokens
+    /// pending to be minted as protocol fee are included in the total supply, as the pair does.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the amounts of token A and B to deposit and the LP tokens minted, or
+    /// `InsufficientLiquidity` if no LP tokens would be minted.
+    fn router_quote_add_liquidity(e: Env, token_a: Address, token_b: Address, amount_a_desired: i128, amount_b_desired: i128) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Previews `remove_liquidity`. Returns the amounts of token A and B the pair would send back for
+    /// `liquidity` LP tokens, diluted by the LP tokens pending to be minted as protocol fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `liquidity` - The amount of LP tokens to remove.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the amounts of token A and B that would be withdrawn.
+    fn router_quote_remove_liquidity(e: Env, token_a: Address, token_b: Address, liquidity: i128) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Quotes an exact input swap hop by hop: for every pair of the route, the amounts in and out, the
+    /// reserves before and after, and the spot price, execution price and price impact of the hop, as
+    /// computed by the library. Prices are scaled by `PRICE_SCALE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// A vector with one `RouteQuote` per hop, or an error such as an invalid path or insufficient liquidity.
+    fn router_get_route_quote_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<RouteQuote>, CombinedRouterError>;
+
+    /// Quotes an exact output swap hop by hop, the same way as `router_get_route_quote_out`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// A vector with one `RouteQuote` per hop, or an error such as an invalid path or insufficient liquidity.
+    fn router_get_route_quote_in(e: Env, amount_out: i128, path: Vec<Address>) -> Result<Vec<RouteQuote>, CombinedRouterError>;
+
+    /// Performs chained get_amount_out calculations on a path of pairs and adapters, as swapped by
+    /// `swap_exact_tokens_adapters`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `adapter_ids` - The adapter serving each hop of `path`.
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated amounts, or an error such as an invalid path or an unregistered adapter.
+    fn router_get_adapter_amounts_out(e: Env, amount_in: i128, path: Vec<Address>, adapter_ids: Vec<u32>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Performs chained get_amount_in calculations on a path of pairs and adapters, as swapped by
+    /// `swap_for_exact_tokens_adapters`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `adapter_ids` - The adapter serving each hop of `path`.
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated amounts, or an error such as an invalid path or an unregistered adapter.
+    fn router_get_adapter_amounts_in(e: Env, amount_out: i128, path: Vec<Address>, adapter_ids: Vec<u32>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Returns the payload the signer of the maker of `quote` has to sign.
+    fn router_get_rfq_quote_hash(e: Env, quote: RfqQuote) -> Result<BytesN<32>, CombinedRouterError>;
+
+    /// Returns the amounts at each step of `path` when hop `rfq_hop` is filled by `quote`, as swapped by `swap_rfq`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `rfq_hop` - The hop of `path` filled by the quote.
+    /// * `quote` - The RFQ quote.
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated amounts, or an error such as an invalid path or a quote that does not match its hop.
+    fn router_get_rfq_amounts(e: Env, path: Vec<Address>, rfq_hop: u32, quote: RfqQuote) -> Result<Vec<i128>, CombinedRouterError>;
+
+    
+
+}
 
 #[contract]
 struct SoroswapRouter;
 
-#[contractimpl]
-impl SoroswapRouterTrait for SoroswapRouter {
-    /// Initializes the contract and sets the factory address
-    fn initialize(e: Env, factory: Address) -> Result<(), CombinedRouterError> {
-        if !has_factory(&e) {
-            put_factory(&e, &factory);
-            event::initialized(&e, factory);
-            extend_instance_ttl(&e);
-            Ok(())
+#[contractimpl]
+impl SoroswapRouterTrait for SoroswapRouter {
+    /// Initializes the contract and sets the factory address
+    fn initialize(e: Env, factory: Address) -> Result<(), CombinedRouterError> {
+        if !has_factory(&e) {
+            put_factory(&e, &factory);
+            event::initialized(&e, factory);
+            extend_instance_ttl(&e);
+            Ok(())
+        } else {
+            Err(SoroswapRouterError::InitializeAlreadyInitialized.into())
+        } 
+        
+    }  
+
+    /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
+    /// of both tokens are added, subject to minimum requirements.
+    /// This function is responsible for transferring tokens from the user to the pool and minting liquidity tokens in return.
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum required amount of the first token to add.
+    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A tuple containing: amounts of token A and B added to the pool.
+    /// plus the amount of liquidity tokens minted.
+    fn add_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        Self::add_liquidity_from(
+            e,
+            to.clone(),
+            token_a,
+            token_b,
+            amount_a_desired,
+            amount_b_desired,
+            amount_a_min,
+            amount_b_min,
+            to,
+            deadline,
+        )
+    }
+
+    /// Same as `add_liquidity`, but the tokens are paid by `from` while the LP tokens are minted to `to`.
+    /// Only `from` needs to authorize the call.
+    /// # Arguments
+    /// * `from` - The address that provides both tokens and authorizes the operation.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * See `add_liquidity` for the remaining arguments.
+    /// # Returns
+    /// The amounts of token A and B added to the pool, and the amount of liquidity tokens minted.
+    fn add_liquidity_from(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_a_desired)?;
+        check_nonnegative_amount(amount_b_desired)?;
+        check_nonnegative_amount(amount_a_min)?;
+        check_nonnegative_amount(amount_b_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        execute_add_liquidity(
+            e,
+            from,
+            token_a,
+            token_b,
+            amount_a_desired,
+            amount_b_desired,
+            amount_a_min,
+            amount_b_min,
+            to,
+        )
+    }
+
+    /// Removes liquidity from a token pair's pool.
+    ///
+    /// This function facilitates the removal of liquidity from a Soroswap Liquidity Pool by burning a specified amount
+    /// of Liquidity Pool tokens (`liquidity`) owned by the caller. In return, it transfers back the corresponding
+    /// amounts of the paired tokens (`token_a` and `token_b`) to the caller's specified address (`to`).
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        Self::remove_liquidity_from(
+            e,
+            to.clone(),
+            token_a,
+            token_b,
+            liquidity,
+            amount_a_min,
+            amount_b_min,
+            to,
+            deadline,
+        )
+    }
+
+    /// Same as `remove_liquidity`, but the LP tokens are taken from `from` while the paired tokens are sent to `to`.
+    /// Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `from` - The address that provides the LP tokens and authorizes the operation.
+    /// * `to` - The address where the paired tokens will be sent to.
+    /// * See `remove_liquidity` for the remaining arguments.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_from(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(liquidity)?;
+        check_nonnegative_amount(amount_a_min)?;
+        check_nonnegative_amount(amount_b_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        execute_remove_liquidity(e, from, token_a, token_b, liquidity, amount_a_min, amount_b_min, to)
+    }
+
+    /// Swaps an exact amount of input tokens for as many output tokens as possible
+    /// along the specified trading route. The route is determined by the `path` vector,
+    /// where the first element is the input token, the last is the output token, 
+    /// and any intermediate elements represent pairs to trade through if a direct pair does not exist.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        Self::swap_exact_tokens_from(e, to.clone(), amount_in, amount_out_min, path, to, deadline)
+    }
+
+    /// Same as `swap_exact_tokens_for_tokens`, but the input tokens are paid by `from` while the output
+    /// tokens are sent to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * See `swap_exact_tokens_for_tokens` for the remaining arguments.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_from(
+        e: Env,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        execute_swap_exact_tokens(e, from, amount_in, amount_out_min, path, to)
+    }
+
+    /// Swaps tokens for an exact amount of output token, following the specified trading route.
+    /// The route is determined by the `path` vector, where the first element is the input token,
+    /// the last is the output token, and any intermediate elements represent pairs to trade through.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_tokens_for_exact_tokens(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        Self::swap_for_exact_tokens_from(e, to.clone(), amount_out, amount_in_max, path, to, deadline)
+    }
+
+    /// Same as `swap_tokens_for_exact_tokens`, but the input tokens are paid by `from` while the output
+    /// tokens are sent to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * See `swap_tokens_for_exact_tokens` for the remaining arguments.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_from(
+        e: Env,
+        from: Address,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_out)?;
+        check_nonnegative_amount(amount_in_max)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        execute_swap_for_exact_tokens(e, from, amount_out, amount_in_max, path, to)
+    }
+
+    /// Swaps exact amounts of one input token through several trading routes at once, so a large trade can
+    /// be spread over different paths. Each leg's `amount` is the input sent through its `path`; all paths
+    /// must share the same input and output tokens. The legs are executed in order and atomically, and the
+    /// sum of their outputs must reach `amount_out_min`. A swap event is published for every leg.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `legs` - The trading routes and the input amount for each of them.
+    /// * `amount_out_min` - The minimum required total amount of output tokens to receive.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// For every leg, the amounts of tokens received at each step of its trading route.
+    fn swap_exact_tokens_split(
+        e: Env,
+        from: Address,
+        legs: Vec<SwapLeg>,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<Vec<i128>>, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+        split::check_legs(&legs)?;
+
+        let factory_address = get_factory(&e);
+        let mut legs_amounts: Vec<Vec<i128>> = Vec::new(&e);
+        let mut total_amount_out: i128 = 0;
+
+        // Every leg is quoted after the previous ones have been executed,
+        // so legs sharing a pair see its updated reserves
+        for leg in legs.iter() {
+            let amounts = soroswap_library::get_amounts_out(
+                e.clone(),
+                factory_address.clone(),
+                leg.amount,
+                leg.path.clone(),
+            )?;
+            execute_route(&e, &factory_address, &amounts, &leg.path, &from, &to)?;
+
+            total_amount_out = total_amount_out.checked_add(amounts.last().unwrap()).unwrap();
+            legs_amounts.push_back(amounts);
+        }
+
+        // Ensure that the total output amount meets the minimum requirement
+        if total_amount_out < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
+
+        Ok(legs_amounts)
+    }
+
+    /// Swaps tokens for exact amounts of one output token through several trading routes at once.
+    /// Each leg's `amount` is the output its `path` must deliver; all paths must share the same input
+    /// and output tokens. The legs are executed in order and atomically, and the sum of their inputs
+    /// can not exceed `amount_in_max`. A swap event is published for every leg.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `legs` - The trading routes and the output amount for each of them.
+    /// * `amount_in_max` - The maximum allowed total amount of input tokens to be swapped.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// For every leg, the amounts of tokens used at each step of its trading route.
+    fn swap_for_exact_tokens_split(
+        e: Env,
+        from: Address,
+        legs: Vec<SwapLeg>,
+        amount_in_max: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<Vec<i128>>, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_in_max)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+        split::check_legs(&legs)?;
+
+        let factory_address = get_factory(&e);
+        let mut legs_amounts: Vec<Vec<i128>> = Vec::new(&e);
+        let mut total_amount_in: i128 = 0;
+
+        for leg in legs.iter() {
+            let amounts = soroswap_library::get_amounts_in(
+                e.clone(),
+                factory_address.clone(),
+                leg.amount,
+                leg.path.clone(),
+            )?;
+            execute_route(&e, &factory_address, &amounts, &leg.path, &from, &to)?;
+
+            total_amount_in = total_amount_in.checked_add(amounts.first().unwrap()).unwrap();
+            legs_amounts.push_back(amounts);
+        }
+
+        // Ensure that the total input amount does not exceed the maximum allowed
+        if total_amount_in > amount_in_max {
+            return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+        }
+
+        Ok(legs_amounts)
+    }
+
+    /// Swaps an exact amount of `token_in` for as many `token_out` as possible, using the best route found
+    /// by `router_best_path_out` among the direct path and the paths through the hub tokens.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `token_in` - The input token.
+    /// * `token_out` - The output token.
+    /// * `max_hops` - The maximum number of pairs the route can go through, up to `MAX_HOPS`.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The path used and the amounts of tokens received at each step of it.
+    fn swap_exact_tokens_best(
+        e: Env,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        token_in: Address,
+        token_out: Address,
+        max_hops: u32,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(Vec<Address>, Vec<i128>), CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        // Find the route giving the most output tokens
+        let factory_address = get_factory(&e);
+        let (path, amounts) = route::best_path_out(
+            &e,
+            &factory_address,
+            amount_in,
+            &token_in,
+            &token_out,
+            max_hops,
+        )?;
+
+        // Ensure that the final output amount meets the minimum requirement
+        if amounts.last().unwrap() < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
+
+        // Pay the first pair, execute the tokens swap and emit the event
+        execute_route(&e, &factory_address, &amounts, &path, &from, &to)?;
+
+        Ok((path, amounts))
+    }
+
+    /// Sets the hub tokens (for example XLM and USDC) that route searches can go through.
+    /// Only the factory's `fee_to_setter` can call it, and at most `MAX_HUB_TOKENS` can be set.
+    ///
+    /// # Arguments
+    /// * `hub_tokens` - The new list of hub tokens, replacing the previous one.
+    fn set_hub_tokens(e: Env, hub_tokens: Vec<Address>) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = SoroswapFactoryClient::new(&e, &get_factory(&e));
+        factory.fee_to_setter().require_auth();
+
+        if hub_tokens.len() > MAX_HUB_TOKENS {
+            return Err(SoroswapRouterError::TooManyHubTokens.into());
+        }
+
+        put_hub_tokens(&e, &hub_tokens);
+        event::hub_tokens(&e, hub_tokens);
+        Ok(())
+    }
+
+    /// Adds liquidity to the `token_a`/`token_b` pool from a single token. The input is first swapped along
+    /// `path` into `token_a` (a one token `path` skips this step). Then the closed-form optimal portion
+    /// of `token_a` is swapped for `token_b`, both are deposited, and any leftover is refunded to `from`.
+    ///
+    /// # Arguments
+    /// * `from` - The address that provides the input token and authorizes the operation.
+    /// * `token_a` - The pool token the input is converted to.
+    /// * `token_b` - The other token of the pool.
+    /// * `path` - The trading route from the input token to `token_a`.
+    /// * `amount_in` - The amount of the input token.
+    /// * `liquidity_min` - The minimum required amount of liquidity tokens to mint.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amounts of token A and B deposited, and the amount of liquidity tokens minted.
+    fn zap_in(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        path: Vec<Address>,
+        amount_in: i128,
+        liquidity_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(liquidity_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        if path.last() != Some(token_a.clone()) {
+            return Err(SoroswapLibraryError::InvalidPath.into());
+        }
+
+        let factory_address = get_factory(&e);
+        if !SoroswapFactoryClient::new(&e, &factory_address).pair_exists(&token_a, &token_b) {
+            return Err(SoroswapRouterError::PairDoesNotExist.into());
+        }
+        let pair = soroswap_library::pair_for(e.clone(), factory_address.clone(), token_a.clone(), token_b.clone())?;
+        let router = e.current_contract_address();
+
+        // Convert the input into token_a, held by the router
+        let total_a = if path.len() == 1 {
+            TokenClient::new(&e, &token_a).transfer(&from, &router, &amount_in);
+            amount_in
         } else {
-            Err(SoroswapRouterError::InitializeAlreadyInitialized.into())
-        } 
-        
-    }  
+            pay_and_swap(&e, &factory_address, &from, amount_in, &path, &router)?
+        };
+
+        // Swap the optimal portion of token_a for token_b
+        let (reserve_a, _) = soroswap_library::get_reserves(e.clone(), factory_address.clone(), token_a.clone(), token_b.clone())?;
+        let swap_amount = soroswap_library::get_zap_swap_amount(total_a, reserve_a)?;
+        let swapped_b = pay_and_swap(
+            &e,
+            &factory_address,
+            &router,
+            swap_amount,
+            &Vec::from_array(&e, [token_a.clone(), token_b.clone()]),
+            &router,
+        )?;
+
+        // Deposit both tokens in the new reserves ratio
+        let remaining_a = total_a.checked_sub(swap_amount).unwrap();
+        let (reserve_a, reserve_b) = soroswap_library::get_reserves(e.clone(), factory_address.clone(), token_a.clone(), token_b.clone())?;
+        let amount_b_optimal = soroswap_library::quote(remaining_a, reserve_a, reserve_b)?;
+        let (amount_a, amount_b) = if amount_b_optimal <= swapped_b {
+            (remaining_a, amount_b_optimal)
+        } else {
+            (soroswap_library::quote(swapped_b, reserve_b, reserve_a)?, swapped_b)
+        };
+        TokenClient::new(&e, &token_a).transfer(&router, &pair, &amount_a);
+        TokenClient::new(&e, &token_b).transfer(&router, &pair, &amount_b);
+        let liquidity = SoroswapPairClient::new(&e, &pair).deposit(&to);
+
+        if liquidity < liquidity_min {
+            return Err(SoroswapRouterError::InsufficientLiquidity.into());
+        }
+
+        // Refund the dust left by rounding
+        let refund_a = remaining_a.checked_sub(amount_a).unwrap();
+        let refund_b = swapped_b.checked_sub(amount_b).unwrap();
+        if refund_a > 0 {
+            TokenClient::new(&e, &token_a).transfer(&router, &from, &refund_a);
+        }
+        if refund_b > 0 {
+            TokenClient::new(&e, &token_b).transfer(&router, &from, &refund_b);
+        }
+
+        event::zap_in(
+            &e,
+            path.first().unwrap(),
+            amount_in,
+            pair,
+            amount_a,
+            amount_b,
+            liquidity,
+            refund_a,
+            refund_b,
+            from,
+            to);
+
+        Ok((amount_a, amount_b, liquidity))
+    }
+
+    /// Removes liquidity from the `token_a`/`token_b` pool into a single token. The withdrawn `token_b` is
+    /// swapped for `token_a` in the same pool, and then all the `token_a` is swapped along `path` into the
+    /// output token (a one token `path` keeps `token_a`).
+    ///
+    /// # Arguments
+    /// * `from` - The address that provides the LP tokens and authorizes the operation.
+    /// * `token_a` - The pool token everything is converted to first.
+    /// * `token_b` - The other token of the pool.
+    /// * `liquidity` - The amount of liquidity tokens to burn.
+    /// * `path` - The trading route from `token_a` to the output token.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amount of output tokens sent to `to`.
+    fn zap_out(
+        e: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        path: Vec<Address>,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<i128, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(liquidity)?;
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        if path.first() != Some(token_a.clone()) {
+            return Err(SoroswapLibraryError::InvalidPath.into());
+        }
+
+        let factory_address = get_factory(&e);
+        if !SoroswapFactoryClient::new(&e, &factory_address).pair_exists(&token_a, &token_b) {
+            return Err(SoroswapRouterError::PairDoesNotExist.into());
+        }
+        let pair = soroswap_library::pair_for(e.clone(), factory_address.clone(), token_a.clone(), token_b.clone())?;
+        let router = e.current_contract_address();
+
+        // Withdraw both tokens to the router
+        TokenClient::new(&e, &pair).transfer(&from, &pair, &liquidity);
+        let (amount_0, amount_1) = SoroswapPairClient::new(&e, &pair).withdraw(&router);
+        let (token_0, _token_1) = soroswap_library::sort_tokens(token_a.clone(), token_b.clone())?;
+        let (amount_a, amount_b) = if token_a == token_0 {
+            (amount_0, amount_1)
+        } else {
+            (amount_1, amount_0)
+        };
+
+        // Swap the withdrawn token_b for token_a in the same pool
+        let swapped_a = if amount_b > 0 {
+            pay_and_swap(
+                &e,
+                &factory_address,
+                &router,
+                amount_b,
+                &Vec::from_array(&e, [token_b.clone(), token_a.clone()]),
+                &router,
+            )?
+        } else {
+            0
+        };
+        let total_a = amount_a.checked_add(swapped_a).unwrap();
+
+        // Convert all the token_a into the output token
+        let amount_out = if path.len() == 1 {
+            TokenClient::new(&e, &token_a).transfer(&router, &to, &total_a);
+            total_a
+        } else {
+            pay_and_swap(&e, &factory_address, &router, total_a, &path, &to)?
+        };
+
+        if amount_out < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
+
+        event::zap_out(
+            &e,
+            pair,
+            liquidity,
+            path.last().unwrap(),
+            amount_out,
+            from,
+            to);
+
+        Ok(amount_out)
+    }
+
+    /// Runs a batch of router operations in order, under a single authorization by `from` and a single
+    /// deadline, and returns the result of every step. The batch is atomic: a failed step reverts it
+    /// entirely, unless the step sets `skip_if_rejected` and the router rejected it before it moved any
+    /// funds, in which case its error code is reported as `RouterCallResult::Failed` and the next step
+    /// runs. Steps are not isolated from each other: a `remove_liquidity` step that misses its minimum
+    /// amounts, or any failed token transfer, always reverts the batch.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays for every operation and authorizes the batch.
+    /// * `steps` - The operations to run, in order.
+    /// * `to` - The address that receives the output of every operation.
+    /// * `deadline` - The deadline for executing the batch.
+    ///
+    /// # Returns
+    /// The result of every step, in order.
+    fn multicall(
+        e: Env,
+        from: Address,
+        steps: Vec<MulticallStep>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<RouterCallResult>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let mut results = Vec::new(&e);
+        for step in steps.iter() {
+            match execute_call(&e, &from, step.call.clone(), &to) {
+                Ok(result) => results.push_back(result),
+                Err(error) if step.skip_if_rejected && !step.call.fails_after_transfers(error) => {
+                    results.push_back(RouterCallResult::Failed(error as u32))
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(results)
+    }
 
-    /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
-    /// of both tokens are added, subject to minimum requirements.
-    /// This function is responsible for transferring tokens from the user to the pool and minting liquidity tokens in return.
+    /// Sets the cap on the referral fee integrators can charge on referral swaps.
+    /// Only the fee_to_setter of the factory can call it.
+    ///
     /// # Arguments
-    /// * `token_a` - The address of the first token to add liquidity for.
-    /// * `token_b` - The address of the second token to add liquidity for.
-    /// * `amount_a_desired` - The desired amount of the first token to add.
-    /// * `amount_b_desired` - The desired amount of the second token to add.
-    /// * `amount_a_min` - The minimum required amount of the first token to add.
-    /// * `amount_b_min` - The minimum required amount of the second token to add.
-    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `max_referral_fee_bps` - The new cap in basis points, at most `MAX_REFERRAL_FEE_BPS`. Zero disables referral fees.
+    fn set_max_referral_fee_bps(e: Env, max_referral_fee_bps: u32) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        SoroswapFactoryClient::new(&e, &get_factory(&e)).fee_to_setter().require_auth();
+
+        if max_referral_fee_bps > MAX_REFERRAL_FEE_BPS {
+            return Err(SoroswapRouterError::InvalidReferralFee.into());
+        }
+        put_max_referral_fee_bps(&e, max_referral_fee_bps);
+        event::referral_fee_cap(&e, max_referral_fee_bps);
+        Ok(())
+    }
+
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_from`, and sends `referral_fee_bps`
+    /// of the output tokens to `referrer`. The fee is capped by governance.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens for `to`, after the referral fee.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `referrer` - The address that receives the referral fee, if any.
+    /// * `referral_fee_bps` - The referral fee in basis points. Must be zero without a referrer.
     /// * `deadline` - The deadline for executing the operation.
+    ///
     /// # Returns
-    /// A tuple containing: amounts of token A and B added to the pool.
-    /// plus the amount of liquidity tokens minted.
-    fn add_liquidity(
+    /// A vector containing the amounts of tokens received at each step of the trading route,
+    /// before the referral fee.
+    fn swap_exact_tokens_referral(
         e: Env,
-        token_a: Address,
-        token_b: Address,
-        amount_a_desired: i128,
-        amount_b_desired: i128,
-        amount_a_min: i128,
-        amount_b_min: i128,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
         to: Address,
+        referrer: Option<Address>,
+        referral_fee_bps: u32,
         deadline: u64,
-    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+    ) -> Result<Vec<i128>, CombinedRouterError> {
         check_initialized(&e)?;
-        check_nonnegative_amount(amount_a_desired)?;
-        check_nonnegative_amount(amount_b_desired)?;
-        check_nonnegative_amount(amount_a_min)?;
-        check_nonnegative_amount(amount_b_min)?;
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(amount_out_min)?;
         extend_instance_ttl(&e);
-        to.require_auth();
+        from.require_auth();
         ensure_deadline(&e, deadline)?;
+        check_referral_fee(&e, &referrer, referral_fee_bps)?;
 
-        let factory = get_factory(&e);
+        let factory_address = get_factory(&e);
+        let amounts = soroswap_library::get_amounts_out(
+            e.clone(),
+            factory_address.clone(),
+            amount_in,
+            path.clone(),
+        )?;
+
+        // The referral fee is taken from the output, so the minimum applies to what is left for `to`
+        let amount_out = amounts.last().unwrap();
+        let fee = referral_fee_amount(amount_out, referral_fee_bps);
+        if amount_out.checked_sub(fee).unwrap() < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
 
-        let (amount_a, amount_b) = add_liquidity_amounts(
+        let pair = soroswap_library::pair_for(
             e.clone(),
-            factory.clone(),
-            token_a.clone(),
-            token_b.clone(),
-            amount_a_desired,
-            amount_b_desired,
-            amount_a_min,
-            amount_b_min,
+            factory_address.clone(),
+            path.get(0).unwrap(),
+            path.get(1).unwrap(),
         )?;
+        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&from, &pair, &amount_in);
+
+        let referral = match referrer {
+            Some(referrer) if fee > 0 => {
+                // Receive the output in the router to split it between `to` and the referrer
+                let router = e.current_contract_address();
+                let token_out = path.last().unwrap();
+                swap(&e, &factory_address, &amounts, &path, &router)?;
+                TokenClient::new(&e, &token_out).transfer(&router, &to, &amount_out.checked_sub(fee).unwrap());
+                TokenClient::new(&e, &token_out).transfer(&router, &referrer, &fee);
+                add_referral_fees(&e, referrer.clone(), token_out.clone(), fee);
+                SwapReferral::Fee(ReferralFee { referrer, token: token_out, amount: fee })
+            }
+            _ => {
+                swap(&e, &factory_address, &amounts, &path, &to)?;
+                SwapReferral::NoFee
+            }
+        };
+
+        event::swap(&e, path, amounts.clone(), from, to, referral);
+
+        Ok(amounts)
+    }
+
+    /// Swaps tokens for an exact amount of output tokens like `swap_for_exact_tokens_from`, and charges
+    /// `from` an extra `referral_fee_bps` of the input amount, sent to `referrer`. The fee is capped by governance.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_out` - The exact amount of output tokens to be received.
+    /// * `amount_in_max` - The maximum amount of input tokens to be spent, including the referral fee.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `referrer` - The address that receives the referral fee, if any.
+    /// * `referral_fee_bps` - The referral fee in basis points. Must be zero without a referrer.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route,
+    /// without the referral fee.
+    fn swap_for_exact_tokens_referral(
+        e: Env,
+        from: Address,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        referrer: Option<Address>,
+        referral_fee_bps: u32,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_out)?;
+        check_nonnegative_amount(amount_in_max)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+        check_referral_fee(&e, &referrer, referral_fee_bps)?;
 
-        let pair: Address = soroswap_library::pair_for(
+        let factory_address = get_factory(&e);
+        let amounts = soroswap_library::get_amounts_in(
             e.clone(),
-            factory,
-            token_a.clone(),
-            token_b.clone(),
-        ).map_err(SoroswapLibraryError::from)?;
+            factory_address.clone(),
+            amount_out,
+            path.clone(),
+        )?;
 
-        TokenClient::new(&e, &token_a).transfer(&to, &pair, &amount_a);
-        TokenClient::new(&e, &token_b).transfer(&to, &pair, &amount_b);
+        // The referral fee is paid on top of the input, so the maximum includes it
+        let amount_in = amounts.get(0).unwrap();
+        let fee = referral_fee_amount(amount_in, referral_fee_bps);
+        if amount_in.checked_add(fee).unwrap() > amount_in_max {
+            return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+        }
 
-        let liquidity = SoroswapPairClient::new(&e, &pair).deposit(&to);
+        let pair = soroswap_library::pair_for(
+            e.clone(),
+            factory_address.clone(),
+            path.get(0).unwrap(),
+            path.get(1).unwrap(),
+        )?;
+        let token_in = path.get(0).unwrap();
+        TokenClient::new(&e, &token_in).transfer(&from, &pair, &amount_in);
+        swap(&e, &factory_address, &amounts, &path, &to)?;
 
-        event::add_liquidity(
-            &e,
-            token_a,
-            token_b,
-            pair,
-            amount_a,
-            amount_b,
-            liquidity,
-            to);
-            
-        Ok((amount_a, amount_b, liquidity))
+        let referral = match referrer {
+            Some(referrer) if fee > 0 => {
+                TokenClient::new(&e, &token_in).transfer(&from, &referrer, &fee);
+                add_referral_fees(&e, referrer.clone(), token_in.clone(), fee);
+                SwapReferral::Fee(ReferralFee { referrer, token: token_in, amount: fee })
+            }
+            _ => SwapReferral::NoFee,
+        };
+
+        event::swap(&e, path, amounts.clone(), from, to, referral);
+
+        Ok(amounts)
     }
 
-    /// Removes liquidity from a token pair's pool.
-    ///
-    /// This function facilitates the removal of liquidity from a Soroswap Liquidity Pool by burning a specified amount
-    /// of Liquidity Pool tokens (`liquidity`) owned by the caller. In return, it transfers back the corresponding
-    /// amounts of the paired tokens (`token_a` and `token_b`) to the caller's specified address (`to`).
+    /// Swaps up to `amount_in_max` input tokens along `path`, stopping once the marginal execution price
+    /// reaches `price_limit`. Only the largest input that keeps the price within the limit is swapped,
+    /// and the rest of `amount_in_max` is left unfilled. Nothing is swapped if the current price is
+    /// already beyond the limit, and the swap event then has zero amounts.
     ///
     /// # Arguments
-    /// * `token_a` - The address of the first token in the Liquidity Pool.
-    /// * `token_b` - The address of the second token in the Liquidity Pool.
-    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
-    /// * `amount_a_min` - The minimum required amount of the first token to receive.
-    /// * `amount_b_min` - The minimum required amount of the second token to receive.
-    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in_max` - The maximum amount of input tokens to be swapped.
+    /// * `price_limit` - The minimum amount of output tokens per input token for the last unit swapped,
+    ///   scaled by `soroswap_library::PRICE_SCALE`.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
     /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
-    fn remove_liquidity(
+    /// The amount of input tokens swapped and the amount left unfilled.
+    fn swap_tokens_with_price_limit(
         e: Env,
-        token_a: Address,
-        token_b: Address,
-        liquidity: i128,
-        amount_a_min: i128,
-        amount_b_min: i128,
+        from: Address,
+        amount_in_max: i128,
+        price_limit: i128,
+        path: Vec<Address>,
         to: Address,
         deadline: u64,
     ) -> Result<(i128, i128), CombinedRouterError> {
         check_initialized(&e)?;
-        check_nonnegative_amount(liquidity)?;
-        check_nonnegative_amount(amount_a_min)?;
-        check_nonnegative_amount(amount_b_min)?;
+        check_nonnegative_amount(amount_in_max)?;
         extend_instance_ttl(&e);
-        to.require_auth();
+        from.require_auth();
         ensure_deadline(&e, deadline)?;
+        if price_limit <= 0 {
+            return Err(SoroswapRouterError::InvalidPriceLimit.into());
+        }
 
-        // Ensure that the pair exists in the Soroswap factory
+        // Largest input that keeps the marginal price of the route within the limit
         let factory_address = get_factory(&e);
-        let factory = SoroswapFactoryClient::new(&e, &factory_address);
-
-        if !factory.pair_exists(&token_a, &token_b) {
-            return Err(SoroswapRouterError::PairDoesNotExist.into());
+        let amount_in = soroswap_library::get_amount_in_for_price_limit(
+            e.clone(),
+            factory_address.clone(),
+            price_limit,
+            path.clone(),
+        )?.min(amount_in_max);
+        if amount_in == 0 {
+            unfilled_swap(&e, &path, &from, &to);
+            return Ok((0, amount_in_max));
         }
 
-        // Retrieve the pair's contract address using the Soroswap library
-        let pair: Address = soroswap_library::pair_for(
+        let amounts = soroswap_library::get_amounts_out(
             e.clone(),
-            get_factory(&e),
-            token_a.clone(),
-            token_b.clone(),
+            factory_address.clone(),
+            amount_in,
+            path.clone(),
         )?;
+        if amounts.last().unwrap() == 0 {
+            unfilled_swap(&e, &path, &from, &to);
+            return Ok((0, amount_in_max));
+        }
 
-        // Transfer LP tokens from the caller to the pair contract
-        TokenClient::new(&e, &pair).transfer(&to, &pair, &liquidity);
-        
-        // Withdraw paired tokens from the pool
-        let (amount_0, amount_1) = SoroswapPairClient::new(&e, &pair).withdraw(&to);
+        execute_route(&e, &factory_address, &amounts, &path, &from, &to)?;
 
-        // Sort tokens to match the expected order
-        let (token_0, _token_1) = soroswap_library::sort_tokens(token_a.clone(), token_b.clone())?;
-        let (amount_a, amount_b) = if token_a == token_0 {
-            (amount_0, amount_1)
-        } else {
-            (amount_1, amount_0)
-        };
+        Ok((amount_in, amount_in_max.checked_sub(amount_in).unwrap()))
+    }
+
+    /// Sets the reference price oracle of the `token_a`/`token_b` pair, checked by the safe swaps.
+    /// Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `oracle` - The oracle contract, implementing `PriceOracleTrait`.
+    /// * `max_deviation_bps` - The largest deviation allowed between the spot price of the pair and the
+    ///   oracle price, in basis points of the oracle price.
+    fn set_pair_oracle(e: Env, token_a: Address, token_b: Address, oracle: Address, max_deviation_bps: u32) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = SoroswapFactoryClient::new(&e, &get_factory(&e));
+        factory.fee_to_setter().require_auth();
 
-        // Check if the received amounts meet the minimum requirements
-        if amount_a < amount_a_min {
-            return Err(SoroswapRouterError::InsufficientAAmount.into());
+        if !factory.pair_exists(&token_a, &token_b) {
+            return Err(SoroswapRouterError::PairDoesNotExist.into());
         }
-        if amount_b < amount_b_min {
-            return Err(SoroswapRouterError::InsufficientBAmount.into());
+        let pair = factory.get_pair(&token_a, &token_b);
+        let pair_oracle = PairOracle { oracle, max_deviation_bps };
+        put_pair_oracle(&e, pair.clone(), &pair_oracle);
+        event::pair_oracle(&e, pair, pair_oracle);
+        Ok(())
+    }
+
+    /// Removes the reference price oracle of the `token_a`/`token_b` pair, so the safe swaps stop checking it.
+    /// Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    fn remove_pair_oracle(e: Env, token_a: Address, token_b: Address) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = SoroswapFactoryClient::new(&e, &get_factory(&e));
+        factory.fee_to_setter().require_auth();
+
+        if !factory.pair_exists(&token_a, &token_b) {
+            return Err(SoroswapRouterError::PairDoesNotExist.into());
         }
+        let pair = factory.get_pair(&token_a, &token_b);
+        remove_pair_oracle(&e, pair.clone());
+        event::pair_oracle_removed(&e, pair);
+        Ok(())
+    }
 
-        event::remove_liquidity(
-            &e,
-            token_a,
-            token_b,
-            pair,
-            amount_a,
-            amount_b,
-            liquidity,
-            to);
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_from`, but first checks every hop whose
+    /// pair has a reference oracle, and fails with `OraclePriceDeviation` if the spot price of the pair
+    /// deviates from the oracle's time-weighted price by more than the configured basis points.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_safe(
+        e: Env,
+        from: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+        oracle::check_route_prices(&e, &get_factory(&e), &path)?;
+
+        execute_swap_exact_tokens(e, from, amount_in, amount_out_min, path, to)
+    }
+
+    /// Swaps tokens for an exact amount of output tokens like `swap_for_exact_tokens_from`, with the same
+    /// oracle checks as `swap_exact_tokens_safe`.
+    ///
+    /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_out` - The exact amount of output tokens to be received.
+    /// * `amount_in_max` - The maximum amount of input tokens to be spent.
+    /// * `path` - A vector representing the trading route.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_safe(
+        e: Env,
+        from: Address,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_out)?;
+        check_nonnegative_amount(amount_in_max)?;
+        extend_instance_ttl(&e);
+        from.require_auth();
+        ensure_deadline(&e, deadline)?;
+        oracle::check_route_prices(&e, &get_factory(&e), &path)?;
+
+        execute_swap_for_exact_tokens(e, from, amount_out, amount_in_max, path, to)
+    }
+
+    /// Registers `adapter` under `adapter_id`, so paths can route hops through it. Registering an id again
+    /// replaces its adapter. Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `adapter_id` - The id paths use for the adapter. `SOROSWAP_ADAPTER_ID` is reserved.
+    /// * `adapter` - The adapter contract, implementing `AmmAdapterTrait`.
+    fn set_adapter(e: Env, adapter_id: u32, adapter: Address) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        SoroswapFactoryClient::new(&e, &get_factory(&e)).fee_to_setter().require_auth();
+
+        if adapter_id == SOROSWAP_ADAPTER_ID {
+            return Err(SoroswapRouterError::InvalidAdapterId.into());
+        }
+        put_adapter(&e, adapter_id, &adapter);
+        event::adapter(&e, adapter_id, adapter);
+        Ok(())
+    }
+
+    /// Removes the adapter registered under `adapter_id`. Only the fee_to_setter of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `adapter_id` - The id of the adapter to remove.
+    fn remove_adapter(e: Env, adapter_id: u32) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        SoroswapFactoryClient::new(&e, &get_factory(&e)).fee_to_setter().require_auth();
 
-        // Return the amounts of paired tokens withdrawn
-        Ok((amount_a, amount_b))
+        if get_adapter(&e, adapter_id).is_none() {
+            return Err(SoroswapRouterError::InvalidAdapterId.into());
+        }
+        remove_adapter(&e, adapter_id);
+        event::adapter_removed(&e, adapter_id);
+        Ok(())
     }
 
-    /// Swaps an exact amount of input tokens for as many output tokens as possible
-    /// along the specified trading route. The route is determined by the `path` vector,
-    /// where the first element is the input token, the last is the output token, 
-    /// and any intermediate elements represent pairs to trade through if a direct pair does not exist.
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_from`, along a path that can mix pairs of
+    /// the router's factory with pools of registered adapters. Hop `i`, from `path[i]` to `path[i + 1]`, is
+    /// served by the adapter `adapter_ids[i]`, where `SOROSWAP_ADAPTER_ID` means the router's own pair.
     ///
     /// # Arguments
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
     /// * `amount_in` - The exact amount of input tokens to be swapped.
     /// * `amount_out_min` - The minimum required amount of output tokens to receive.
-    /// * `path` - A vector representing the trading route, where the first element is the input token 
-    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `path` - A vector representing the trading route.
+    /// * `adapter_ids` - The adapter serving each hop of `path`.
     /// * `to` - The address where the output tokens will be sent to.
     /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    /// A vector containing the amounts of tokens received at each step of the trading route.
-    fn swap_exact_tokens_for_tokens(
+    /// A vector containing the amounts of tokens received at each step of the trading route. An adapter
+    /// can send more than it quoted, and the surplus goes on to the next hop.
+    fn swap_exact_tokens_adapters(
         e: Env,
+        from: Address,
         amount_in: i128,
         amount_out_min: i128,
         path: Vec<Address>,
+        adapter_ids: Vec<u32>,
         to: Address,
         deadline: u64,
     ) -> Result<Vec<i128>, CombinedRouterError> {
@@ -586,68 +2537,41 @@ impl SoroswapRouterTrait for SoroswapRouter {
         check_nonnegative_amount(amount_in)?;
         check_nonnegative_amount(amount_out_min)?;
         extend_instance_ttl(&e);
-        to.require_auth();
+        from.require_auth();
         ensure_deadline(&e, deadline)?;
 
-        // Get the expected output amounts for each step of the trading route        
-        let factory_address = get_factory(&e);
-        let amounts = soroswap_library::get_amounts_out(
-            e.clone(),
-            factory_address.clone(),
-            amount_in,
-            path.clone(),
-        )?;
-
-        // Ensure that the final output amount meets the minimum requirement        
-        if amounts.get(amounts.len() - 1).unwrap() < amount_out_min {
+        let factory = get_factory(&e);
+        let amounts = adapter::get_amounts_out(&e, &factory, amount_in, &path, &adapter_ids)?;
+        if amounts.last().unwrap() < amount_out_min {
             return Err(SoroswapRouterError::InsufficientOutputAmount.into());
         }
-        
-        // Determine the pair contract address for the first step of the trading route
-        let pair = soroswap_library::pair_for(
-            e.clone(),
-            factory_address.clone(),
-            path.get(0).unwrap(),
-            path.get(1).unwrap(),
-        )?;
-        
-        // Transfer input tokens to the pair contract
-        // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
-        // If we implement, we will include an additional cross-contract call...
-        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amounts.get(0).unwrap());
-
-        // Execute the tokens swap
-        swap(&e, &factory_address, &amounts, &path, &to)?;
-    
-        event::swap(
-            &e,
-            path,
-            amounts.clone(),
-            to);
 
-        // Return the amounts of tokens received at each step of the trading route
+        let amounts = adapter::execute_route(&e, &factory, &amounts, &path, &adapter_ids, &from, &to)?;
+        event::swap(&e, path, amounts.clone(), from, to, SwapReferral::NoFee);
         Ok(amounts)
     }
 
-    /// Swaps tokens for an exact amount of output token, following the specified trading route.
-    /// The route is determined by the `path` vector, where the first element is the input token,
-    /// the last is the output token, and any intermediate elements represent pairs to trade through.
+    /// Swaps tokens for an exact amount of output tokens like `swap_for_exact_tokens_from`, along a path of
+    /// pairs and adapters as in `swap_exact_tokens_adapters`.
     ///
     /// # Arguments
-    /// * `amount_out` - The exact amount of output token to be received.
-    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
-    /// * `path` - A vector representing the trading route, where the first element is the input token 
-    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `from` - The address that pays the input tokens and authorizes the operation.
+    /// * `amount_out` - The exact amount of output tokens to be received.
+    /// * `amount_in_max` - The maximum amount of input tokens to be spent.
+    /// * `path` - A vector representing the trading route.
+    /// * `adapter_ids` - The adapter serving each hop of `path`.
     /// * `to` - The address where the output tokens will be sent to.
     /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
     /// A vector containing the amounts of tokens used at each step of the trading route.
-    fn swap_tokens_for_exact_tokens(
+    fn swap_for_exact_tokens_adapters(
         e: Env,
+        from: Address,
         amount_out: i128,
         amount_in_max: i128,
         path: Vec<Address>,
+        adapter_ids: Vec<u32>,
         to: Address,
         deadline: u64,
     ) -> Result<Vec<i128>, CombinedRouterError> {
@@ -655,45 +2579,103 @@ impl SoroswapRouterTrait for SoroswapRouter {
         check_nonnegative_amount(amount_out)?;
         check_nonnegative_amount(amount_in_max)?;
         extend_instance_ttl(&e);
-        to.require_auth(); 
+        from.require_auth();
         ensure_deadline(&e, deadline)?;
 
-        // Get the expected input amounts for each step of the trading route
-        let factory_address = get_factory(&e);
-        let amounts = soroswap_library::get_amounts_in(
-            e.clone(),
-            factory_address.clone(),
-            amount_out,
-            path.clone(),
-        )?;
-        
-        // Ensure that the input amount does not exceed the maximum allowed
+        let factory = get_factory(&e);
+        let amounts = adapter::get_amounts_in(&e, &factory, amount_out, &path, &adapter_ids)?;
         if amounts.get(0).unwrap() > amount_in_max {
             return Err(SoroswapRouterError::ExcessiveInputAmount.into());
         }
 
-        // Determine the pair contract address for the first step of the trading route
-        let pair = soroswap_library::pair_for(
-            e.clone(),
-            factory_address.clone(),
-            path.get(0).unwrap(),
-            path.get(1).unwrap(),
-        )?;
-        // Transfer input tokens to the pair contract
-        // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
-        // If we implement, we will include an additional cross-contract call...
-        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amounts.get(0).unwrap());
+        let amounts = adapter::execute_route(&e, &factory, &amounts, &path, &adapter_ids, &from, &to)?;
+        event::swap(&e, path, amounts.clone(), from, to, SwapReferral::NoFee);
+        Ok(amounts)
+    }
 
-        // Execute the token swap
-        swap(&e, &factory_address, &amounts, &path, &to)?;
-    
-        event::swap(
-            &e,
-            path,
-            amounts.clone(),
-            to);
+    /// Registers the ed25519 public key that signs the RFQ quotes of `maker`, replacing any previous one.
+    /// Quotes signed by a replaced key can no longer be filled.
+    ///
+    /// # Arguments
+    /// * `maker` - The market maker. Must authorize the call.
+    /// * `signer` - The ed25519 public key.
+    fn set_rfq_signer(e: Env, maker: Address, signer: BytesN<32>) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        maker.require_auth();
+
+        put_rfq_signer(&e, &maker, &signer);
+        event::rfq_signer(&e, maker, signer);
+        Ok(())
+    }
+
+    /// Cancels the RFQ quote of `maker` with `nonce`, so it can no longer be filled.
+    ///
+    /// # Arguments
+    /// * `maker` - The market maker. Must authorize the call.
+    /// * `nonce` - The nonce of the quote.
+    fn cancel_rfq_quote(e: Env, maker: Address, nonce: u64) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        maker.require_auth();
+
+        if is_rfq_nonce_used(&e, &maker, nonce) {
+            return Err(SoroswapRouterError::RfqNonceUsed.into());
+        }
+        set_rfq_nonce_used(&e, &maker, nonce);
+        event::rfq_cancel(&e, maker, nonce);
+        Ok(())
+    }
+
+    /// Swaps along a path where hop `rfq_hop` is filled by a firm quote signed by a market maker, and the
+    /// other hops by the router's pairs. The pairs before the RFQ hop deliver exactly `quote.amount_in` to the
+    /// maker, who pays `quote.amount_out` through its allowance on the router. The taker pays the input.
+    ///
+    /// # Arguments
+    /// * `quote` - The RFQ quote. Its taker pays the input and must authorize the call.
+    /// * `signature` - The ed25519 signature of `router_get_rfq_quote_hash(quote)` by the maker's signer.
+    /// * `path` - A vector representing the trading route.
+    /// * `rfq_hop` - The hop of `path` filled by the quote.
+    /// * `amount_in_max` - The maximum amount of input tokens to be spent.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens at each step of the trading route.
+    fn swap_rfq(
+        e: Env,
+        quote: RfqQuote,
+        signature: BytesN<64>,
+        path: Vec<Address>,
+        rfq_hop: u32,
+        amount_in_max: i128,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_in_max)?;
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        quote.taker.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let factory = get_factory(&e);
+        let amounts = rfq::get_amounts(&e, &factory, &path, rfq_hop, &quote)?;
+        if amounts.get(0).unwrap() > amount_in_max {
+            return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+        }
+        if amounts.last().unwrap() < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
 
-        // Return the amounts of tokens used at each step of the trading route
+        rfq::verify_rfq_quote(&e, &quote, &signature)?;
+        set_rfq_nonce_used(&e, &quote.maker, quote.nonce);
+        rfq::execute_route(&e, &factory, &amounts, &path, rfq_hop, &quote, &to)?;
+
+        event::rfq_fill(&e, quote.clone());
+        event::swap(&e, path, amounts.clone(), quote.taker, to, SwapReferral::NoFee);
         Ok(amounts)
     }
 
@@ -713,6 +2695,59 @@ impl SoroswapRouterTrait for SoroswapRouter {
         Ok(factory_address)
     }
 
+    /// Returns the hub tokens that route searches can go through.
+    fn get_hub_tokens(e: Env) -> Result<Vec<Address>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_hub_tokens(&e))
+    }
+
+    /// Returns the cap on the referral fee, in basis points. Zero while referral fees are disabled.
+    fn get_max_referral_fee_bps(e: Env) -> Result<u32, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_max_referral_fee_bps(&e))
+    }
+
+    /// Returns the lifetime referral fees received by `referrer` in `token`.
+    fn get_referral_fees(e: Env, referrer: Address, token: Address) -> Result<i128, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_referral_fees(&e, referrer, token))
+    }
+
+    /// Returns the reference price oracle of the `token_a`/`token_b` pair, if it has one.
+    fn get_pair_oracle(e: Env, token_a: Address, token_b: Address) -> Result<Option<PairOracle>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = SoroswapFactoryClient::new(&e, &get_factory(&e));
+        if !factory.pair_exists(&token_a, &token_b) {
+            return Ok(None);
+        }
+        Ok(get_pair_oracle(&e, factory.get_pair(&token_a, &token_b)))
+    }
+
+    /// Returns the adapter registered under `adapter_id`, if any.
+    fn get_adapter(e: Env, adapter_id: u32) -> Result<Option<Address>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_adapter(&e, adapter_id))
+    }
+
+    /// Returns the ed25519 public key registered for the RFQ quotes of `maker`, if any.
+    fn get_rfq_signer(e: Env, maker: Address) -> Result<Option<BytesN<32>>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(get_rfq_signer(&e, &maker))
+    }
+
+    /// Returns whether the RFQ quote of `maker` with `nonce` has been filled or cancelled.
+    fn is_rfq_nonce_used(e: Env, maker: Address, nonce: u64) -> Result<bool, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(is_rfq_nonce_used(&e, &maker, nonce))
+    }
+
 
     /// Calculates the deterministic address for a pair without making any external calls.
     /// check <https://github.com/paltalabs/deterministic-address-soroban>
@@ -819,5 +2854,192 @@ impl SoroswapRouterTrait for SoroswapRouter {
         Ok(soroswap_library::get_amounts_in(e, factory, amount_out, path)?)
     }
 
+    /// Finds the path from `token_in` to `token_out` that gives the most output for `amount_in`. It evaluates
+    /// the direct path and every path through distinct hub tokens of at most `max_hops` pairs, where all
+    /// pairs exist, and quotes them with the library. Paths that can not be quoted are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount.
+    /// * `token_in` - The input token.
+    /// * `token_out` - The output token.
+    /// * `max_hops` - The maximum number of pairs the path can go through, up to `MAX_HOPS`.
+    ///
+    /// # Returns
+    ///
+    /// The best path and the amounts at each step of it, or `NoRouteFound`.
+    fn router_best_path_out(e: Env, amount_in: i128, token_in: Address, token_out: Address, max_hops: u32) -> Result<(Vec<Address>, Vec<i128>), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        route::best_path_out(&e, &factory, amount_in, &token_in, &token_out, max_hops)
+    }
+
+    /// Finds the path from `token_in` to `token_out` that needs the least input to get `amount_out`. It
+    /// evaluates the same paths as `router_best_path_out`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount.
+    /// * `token_in` - The input token.
+    /// * `token_out` - The output token.
+    /// * `max_hops` - The maximum number of pairs the path can go through, up to `MAX_HOPS`.
+    ///
+    /// # Returns
+    ///
+    /// The best path and the amounts at each step of it, or `NoRouteFound`.
+    fn router_best_path_in(e: Env, amount_out: i128, token_in: Address, token_out: Address, max_hops: u32) -> Result<(Vec<Address>, Vec<i128>), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        route::best_path_in(&e, &factory, amount_out, &token_in, &token_out, max_hops)
+    }
+
+    /// Previews `add_liquidity` without creating the pair or moving tokens. Returns the amounts of token A
+    /// and B that would be deposited for the desired amounts, and the LP tokens that would be minted for
+    /// them. On the first deposit `MINIMUM_LIQUIDITY` is locked in the pair and not counted. LP tokens
+    /// pending to be minted as protocol fee are included in the total supply, as the pair does.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the amounts of token A and B to deposit and the LP tokens minted, or
+    /// `InsufficientLiquidity` if no LP tokens would be minted.
+    fn router_quote_add_liquidity(e: Env, token_a: Address, token_b: Address, amount_a_desired: i128, amount_b_desired: i128) -> Result<(i128, i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(amount_a_desired)?;
+        check_nonnegative_amount(amount_b_desired)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        liquidity::quote_add_liquidity(&e, &factory, &token_a, &token_b, amount_a_desired, amount_b_desired)
+    }
+
+    /// Previews `remove_liquidity`. Returns the amounts of token A and B the pair would send back for
+    /// `liquidity` LP tokens, diluted by the LP tokens pending to be minted as protocol fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `liquidity` - The amount of LP tokens to remove.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the amounts of token A and B that would be withdrawn.
+    fn router_quote_remove_liquidity(e: Env, token_a: Address, token_b: Address, liquidity: i128) -> Result<(i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        check_nonnegative_amount(liquidity)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        liquidity::quote_remove_liquidity(&e, &factory, &token_a, &token_b, liquidity)
+    }
+
+    /// Quotes an exact input swap hop by hop: for every pair of the route, the amounts in and out, the
+    /// reserves before and after, and the spot price, execution price and price impact of the hop, as
+    /// computed by the library. Prices are scaled by `PRICE_SCALE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// A vector with one `RouteQuote` per hop, or an error such as an invalid path or insufficient liquidity.
+    fn router_get_route_quote_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<RouteQuote>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        let amounts = soroswap_library::get_amounts_out(e.clone(), factory.clone(), amount_in, path.clone())?;
+        trace::route_quotes(&e, &factory, &path, &amounts)
+    }
+
+    /// Quotes an exact output swap hop by hop, the same way as `router_get_route_quote_out`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// A vector with one `RouteQuote` per hop, or an error such as an invalid path or insufficient liquidity.
+    fn router_get_route_quote_in(e: Env, amount_out: i128, path: Vec<Address>) -> Result<Vec<RouteQuote>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        let amounts = soroswap_library::get_amounts_in(e.clone(), factory.clone(), amount_out, path.clone())?;
+        trace::route_quotes(&e, &factory, &path, &amounts)
+    }
+
+    /// Performs chained get_amount_out calculations on a path of pairs and adapters, as swapped by
+    /// `swap_exact_tokens_adapters`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `adapter_ids` - The adapter serving each hop of `path`.
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated amounts, or an error such as an invalid path or an unregistered adapter.
+    fn router_get_adapter_amounts_out(e: Env, amount_in: i128, path: Vec<Address>, adapter_ids: Vec<u32>) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        adapter::get_amounts_out(&e, &factory, amount_in, &path, &adapter_ids)
+    }
+
+    /// Performs chained get_amount_in calculations on a path of pairs and adapters, as swapped by
+    /// `swap_for_exact_tokens_adapters`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `adapter_ids` - The adapter serving each hop of `path`.
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated amounts, or an error such as an invalid path or an unregistered adapter.
+    fn router_get_adapter_amounts_in(e: Env, amount_out: i128, path: Vec<Address>, adapter_ids: Vec<u32>) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        adapter::get_amounts_in(&e, &factory, amount_out, &path, &adapter_ids)
+    }
+
+    /// Returns the payload the signer of the maker of `quote` has to sign.
+    fn router_get_rfq_quote_hash(e: Env, quote: RfqQuote) -> Result<BytesN<32>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(rfq::rfq_quote_hash(&e, &quote))
+    }
+
+    /// Returns the amounts at each step of `path` when hop `rfq_hop` is filled by `quote`, as swapped by `swap_rfq`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `rfq_hop` - The hop of `path` filled by the quote.
+    /// * `quote` - The RFQ quote.
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated amounts, or an error such as an invalid path or a quote that does not match its hop.
+    fn router_get_rfq_amounts(e: Env, path: Vec<Address>, rfq_hop: u32, quote: RfqQuote) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        rfq::get_amounts(&e, &factory, &path, rfq_hop, &quote)
+    }
+
 
 }
@@ -0,0 +1,157 @@
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{Address, Env};
+use num_integer::Roots;
+use soroswap_library::SoroswapLibraryError;
+use crate::error::{CombinedRouterError, SoroswapRouterError};
+use crate::factory::SoroswapFactoryClient;
+use crate::pair::SoroswapPairClient;
+
+/// LP tokens the pair locks forever on the first deposit.
+const MINIMUM_LIQUIDITY: i128 = 1000;
+
+/// Calculates the amounts of token A and B to deposit given the desired and minimum amounts and the
+/// current reserves of the pair. When the pair has no reserves the desired amounts are used as they are.
+pub fn optimal_amounts(
+    reserve_a: i128,
+    reserve_b: i128,
+    amount_a_desired: i128,
+    amount_b_desired: i128,
+    amount_a_min: i128,
+    amount_b_min: i128,
+) -> Result<(i128, i128), CombinedRouterError> {
+    // When there is no liquidity (first deposit)
+    if reserve_a == 0 && reserve_b == 0 {
+        Ok((amount_a_desired, amount_b_desired))
+    } else {
+        // We try first with the amount a desired:
+        let amount_b_optimal = soroswap_library::quote(
+            amount_a_desired.clone(),
+            reserve_a.clone(),
+            reserve_b.clone(),
+        )?;
+
+        if amount_b_optimal <= amount_b_desired {
+            if amount_b_optimal < amount_b_min {
+                return Err(SoroswapRouterError::InsufficientBAmount.into());
+            }
+            Ok((amount_a_desired, amount_b_optimal))
+        }
+        // If not, we can try with the amount b desired
+        else {
+            let amount_a_optimal = soroswap_library::quote(amount_b_desired, reserve_b, reserve_a).map_err(SoroswapLibraryError::from)?;
+
+            // This should happen anyway. Because if we were not able to fulfill with our amount_b_desired for our amount_a_desired
+            // It is to expect that the amount_a_optimal for that lower amount_b_desired to be lower than the amount_a_desired
+            assert!(amount_a_optimal <= amount_a_desired);
+
+            if amount_a_optimal < amount_a_min {
+                return Err(SoroswapRouterError::InsufficientAAmount.into());
+            }
+            Ok((amount_a_optimal, amount_b_desired))
+        }
+    }
+}
+
+/// Returns the LP tokens the pair will mint to the protocol `fee_to` on its next deposit or withdrawal,
+/// the same way the pair's `mint_fee` does.
+fn pending_protocol_fee_liquidity(
+    factory: &SoroswapFactoryClient,
+    pair: &SoroswapPairClient,
+    reserve_0: i128,
+    reserve_1: i128,
+    total_supply: i128,
+) -> i128 {
+    let k_last = pair.k_last();
+    if !factory.fees_enabled() || k_last == 0 {
+        return 0;
+    }
+    let root_k = reserve_0.checked_mul(reserve_1).unwrap().sqrt();
+    let root_k_last = k_last.sqrt();
+    if root_k <= root_k_last {
+        return 0;
+    }
+    let numerator = total_supply.checked_mul(root_k.checked_sub(root_k_last).unwrap()).unwrap();
+    let denominator = root_k.checked_mul(5).unwrap().checked_add(root_k_last).unwrap();
+    numerator.checked_div(denominator).unwrap()
+}
+
+/// Returns the LP total supply the pair will use on its next deposit or withdrawal, after minting
+/// the pending protocol fee.
+fn diluted_total_supply(factory: &SoroswapFactoryClient, pair: &SoroswapPairClient) -> i128 {
+    let (reserve_0, reserve_1) = pair.get_reserves();
+    let total_supply = pair.total_supply();
+    total_supply
+        .checked_add(pending_protocol_fee_liquidity(factory, pair, reserve_0, reserve_1, total_supply))
+        .unwrap()
+}
+
+/// Quotes a deposit of up to `amount_a_desired` and `amount_b_desired`: the amounts the router would
+/// take and the LP tokens the pair would mint for them. Nothing is written, and the pair does not need
+/// to exist.
+pub fn quote_add_liquidity(
+    e: &Env,
+    factory: &Address,
+    token_a: &Address,
+    token_b: &Address,
+    amount_a_desired: i128,
+    amount_b_desired: i128,
+) -> Result<(i128, i128, i128), CombinedRouterError> {
+    let factory_client = SoroswapFactoryClient::new(e, factory);
+    let pair_exists = factory_client.pair_exists(token_a, token_b);
+
+    let (reserve_a, reserve_b) = if pair_exists {
+        soroswap_library::get_reserves(e.clone(), factory.clone(), token_a.clone(), token_b.clone())?
+    } else {
+        (0, 0)
+    };
+    let (amount_a, amount_b) = optimal_amounts(reserve_a, reserve_b, amount_a_desired, amount_b_desired, 0, 0)?;
+
+    let total_supply = if pair_exists {
+        let pair = SoroswapPairClient::new(e, &factory_client.get_pair(token_a, token_b));
+        diluted_total_supply(&factory_client, &pair)
+    } else {
+        0
+    };
+
+    let liquidity = if total_supply == 0 {
+        // The first deposit locks MINIMUM_LIQUIDITY in the pair
+        amount_a.checked_mul(amount_b).unwrap().sqrt().checked_sub(MINIMUM_LIQUIDITY).unwrap()
+    } else {
+        let shares_a = amount_a.checked_mul(total_supply).unwrap().checked_div(reserve_a).unwrap();
+        let shares_b = amount_b.checked_mul(total_supply).unwrap().checked_div(reserve_b).unwrap();
+        shares_a.min(shares_b)
+    };
+
+    if liquidity <= 0 {
+        return Err(SoroswapRouterError::InsufficientLiquidity.into());
+    }
+    Ok((amount_a, amount_b, liquidity))
+}
+
+/// Quotes a withdrawal of `liquidity` LP tokens: the amounts of token A and B the pair would send back.
+pub fn quote_remove_liquidity(
+    e: &Env,
+    factory: &Address,
+    token_a: &Address,
+    token_b: &Address,
+    liquidity: i128,
+) -> Result<(i128, i128), CombinedRouterError> {
+    let factory_client = SoroswapFactoryClient::new(e, factory);
+    if !factory_client.pair_exists(token_a, token_b) {
+        return Err(SoroswapRouterError::PairDoesNotExist.into());
+    }
+    let pair_address = factory_client.get_pair(token_a, token_b);
+    let pair = SoroswapPairClient::new(e, &pair_address);
+
+    let total_supply = diluted_total_supply(&factory_client, &pair);
+    if total_supply == 0 {
+        return Err(SoroswapRouterError::InsufficientLiquidity.into());
+    }
+
+    // The pair pays out of its token balances, which can be above the reserves
+    let balance_a = TokenClient::new(e, token_a).balance(&pair_address);
+    let balance_b = TokenClient::new(e, token_b).balance(&pair_address);
+    let amount_a = balance_a.checked_mul(liquidity).unwrap().checked_div(total_supply).unwrap();
+    let amount_b = balance_b.checked_mul(liquidity).unwrap().checked_div(total_supply).unwrap();
+    Ok((amount_a, amount_b))
+}
@@ -0,0 +1,117 @@
+use soroban_sdk::{contracttype, Address, Vec};
+use crate::check_nonnegative_amount;
+use crate::error::CombinedRouterError;
+
+/// Arguments of an `add_liquidity` step. See `add_liquidity` for their meaning.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddLiquidityCall {
+    pub token_a: Address,
+    pub token_b: Address,
+    pub amount_a_desired: i128,
+    pub amount_b_desired: i128,
+    pub amount_a_min: i128,
+    pub amount_b_min: i128,
+}
+
+/// Arguments of a `remove_liquidity` step. See `remove_liquidity` for their meaning.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemoveLiquidityCall {
+    pub token_a: Address,
+    pub token_b: Address,
+    pub liquidity: i128,
+    pub amount_a_min: i128,
+    pub amount_b_min: i128,
+}
+
+/// Arguments of a `swap_exact_tokens_for_tokens` step. See `swap_exact_tokens_for_tokens` for their meaning.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapExactTokensCall {
+    pub amount_in: i128,
+    pub amount_out_min: i128,
+    pub path: Vec<Address>,
+}
+
+/// Arguments of a `swap_tokens_for_exact_tokens` step. See `swap_tokens_for_exact_tokens` for their meaning.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapForExactTokensCall {
+    pub amount_out: i128,
+    pub amount_in_max: i128,
+    pub path: Vec<Address>,
+}
+
+/// A router operation that can be batched in a `multicall`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RouterCall {
+    AddLiquidity(AddLiquidityCall),
+    RemoveLiquidity(RemoveLiquidityCall),
+    SwapExactTokens(SwapExactTokensCall),
+    SwapForExactTokens(SwapForExactTokensCall),
+}
+
+/// One step of a `multicall`: the operation to run and whether the batch goes on if the router rejects it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MulticallStep {
+    pub call: RouterCall,
+    /// If true, a step the router rejects before it moves any funds is reported as
+    /// `RouterCallResult::Failed` instead of reverting the batch. A step that fails later, such as a
+    /// `remove_liquidity` below its minimum amounts or a failed token transfer, still reverts it.
+    pub skip_if_rejected: bool,
+}
+
+/// The outcome of a `multicall` step.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RouterCallResult {
+    /// The amounts of token A and B added to the pool, and the amount of liquidity tokens minted.
+    AddLiquidity(i128, i128, i128),
+    /// The amounts of token A and B withdrawn from the pool.
+    RemoveLiquidity(i128, i128),
+    /// The amounts of tokens at each step of the trading route.
+    Swap(Vec<i128>),
+    /// The `CombinedRouterError` code of a rejected step that set `skip_if_rejected`.
+    Failed(u32),
+}
+
+impl RouterCall {
+    /// Checks that none of the amounts of the operation is negative.
+    pub fn check_amounts(&self) -> Result<(), CombinedRouterError> {
+        match self {
+            RouterCall::AddLiquidity(call) => {
+                check_nonnegative_amount(call.amount_a_desired)?;
+                check_nonnegative_amount(call.amount_b_desired)?;
+                check_nonnegative_amount(call.amount_a_min)?;
+                check_nonnegative_amount(call.amount_b_min)
+            }
+            RouterCall::RemoveLiquidity(call) => {
+                check_nonnegative_amount(call.liquidity)?;
+                check_nonnegative_amount(call.amount_a_min)?;
+                check_nonnegative_amount(call.amount_b_min)
+            }
+            RouterCall::SwapExactTokens(call) => {
+                check_nonnegative_amount(call.amount_in)?;
+                check_nonnegative_amount(call.amount_out_min)
+            }
+            RouterCall::SwapForExactTokens(call) => {
+                check_nonnegative_amount(call.amount_out)?;
+                check_nonnegative_amount(call.amount_in_max)
+            }
+        }
+    }
+
+    /// Whether `error` can be raised after the operation moved funds, in which case the step
+    /// can not be skipped and the whole batch must revert. Only the minimum amounts checks of
+    /// `remove_liquidity` run after the withdrawal.
+    pub fn fails_after_transfers(&self, error: CombinedRouterError) -> bool {
+        matches!(self, RouterCall::RemoveLiquidity(_))
+            && matches!(
+                error,
+                CombinedRouterError::RouterInsufficientAAmount | CombinedRouterError::RouterInsufficientBAmount
+            )
+    }
+}
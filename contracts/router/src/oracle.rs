@@ -0,0 +1,60 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+use crate::error::{CombinedRouterError, SoroswapRouterError};
+use crate::storage::get_pair_oracle;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Trait defining the interface that a reference price oracle used by the router must implement.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracleTrait {
+    /// Returns the time-weighted average price of `token_in`: the amount of `token_out` per `token_in`,
+    /// scaled by `soroswap_library::PRICE_SCALE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_in` - The token being priced.
+    /// * `token_out` - The token the price is quoted in.
+    fn twap(e: Env, token_in: Address, token_out: Address) -> i128;
+}
+
+/// The reference oracle of a pair, checked by the safe swaps.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairOracle {
+    /// The oracle contract, implementing `PriceOracleTrait`.
+    pub oracle: Address,
+    /// The largest deviation, in basis points of the oracle price, allowed between the spot price
+    /// of the pair and the oracle price.
+    pub max_deviation_bps: u32,
+}
+
+/// Checks every hop of `path` whose pair has a reference oracle: the spot price of the pair before
+/// the trade must not deviate from the oracle's time-weighted price by more than the configured
+/// basis points. An oracle price that is not positive fails the check too.
+pub fn check_route_prices(e: &Env, factory: &Address, path: &Vec<Address>) -> Result<(), CombinedRouterError> {
+    for i in 1..path.len() {
+        let token_in = path.get(i - 1).unwrap();
+        let token_out = path.get(i).unwrap();
+        let pair = soroswap_library::pair_for(e.clone(), factory.clone(), token_in.clone(), token_out.clone())?;
+        let pair_oracle = match get_pair_oracle(e, pair) {
+            Some(pair_oracle) => pair_oracle,
+            None => continue,
+        };
+
+        let (reserve_in, reserve_out) = soroswap_library::get_reserves(e.clone(), factory.clone(), token_in.clone(), token_out.clone())?;
+        let spot_price = soroswap_library::get_spot_price(reserve_in, reserve_out)?;
+        let oracle_price = PriceOracleClient::new(e, &pair_oracle.oracle).twap(&token_in, &token_out);
+        if oracle_price <= 0 {
+            return Err(SoroswapRouterError::OraclePriceDeviation.into());
+        }
+
+        // |spot_price - oracle_price| / oracle_price > max_deviation_bps / 10000
+        let deviation = spot_price.checked_sub(oracle_price).unwrap().abs();
+        let max_deviation = oracle_price.checked_mul(pair_oracle.max_deviation_bps as i128).unwrap();
+        if deviation.checked_mul(BPS_DENOMINATOR).unwrap() > max_deviation {
+            return Err(SoroswapRouterError::OraclePriceDeviation.into());
+        }
+    }
+    Ok(())
+}
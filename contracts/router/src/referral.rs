@@ -0,0 +1,53 @@
+use soroban_sdk::{contracttype, Address, Env};
+use crate::error::{CombinedRouterError, SoroswapRouterError};
+use crate::storage::get_max_referral_fee_bps;
+
+/// Highest referral fee governance can allow integrators to charge, in basis points.
+pub const MAX_REFERRAL_FEE_BPS: u32 = 1_000;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// A referral fee charged on a swap.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralFee {
+    /// The address that received the fee.
+    pub referrer: Address,
+    /// The token the fee was paid in: the output token for exact input swaps,
+    /// and the input token for exact output swaps.
+    pub token: Address,
+    /// The amount of `token` sent to the referrer.
+    pub amount: i128,
+}
+
+/// The referral fee of a swap event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SwapReferral {
+    /// No referral fee was charged.
+    NoFee,
+    /// The referral fee charged on the swap.
+    Fee(ReferralFee),
+}
+
+/// Checks that the referral fee does not exceed the governance cap, and that no fee is
+/// requested without a referrer.
+pub fn check_referral_fee(
+    e: &Env,
+    referrer: &Option<Address>,
+    referral_fee_bps: u32,
+) -> Result<(), CombinedRouterError> {
+    if referral_fee_bps > get_max_referral_fee_bps(e) || (referrer.is_none() && referral_fee_bps > 0) {
+        return Err(SoroswapRouterError::InvalidReferralFee.into());
+    }
+    Ok(())
+}
+
+/// Returns the referral fee charged on `amount`, rounded down.
+pub fn referral_fee_amount(amount: i128, referral_fee_bps: u32) -> i128 {
+    amount
+        .checked_mul(referral_fee_bps as i128)
+        .unwrap()
+        .checked_div(BPS_DENOMINATOR)
+        .unwrap()
+}
@@ -0,0 +1,155 @@
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec};
+use soroswap_library::SoroswapLibraryError;
+use crate::error::{CombinedRouterError, SoroswapRouterError};
+use crate::pair::SoroswapPairClient;
+use crate::storage::{get_rfq_signer, is_rfq_nonce_used};
+
+/// A firm quote signed off-chain by a market maker: the maker sells `amount_out` of `token_out` to
+/// `taker` for `amount_in` of `token_in`. It can be filled once, as one hop of a router path.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RfqQuote {
+    /// The account that receives `token_in` and pays `token_out`. It must have approved the router on `token_out`.
+    pub maker: Address,
+    /// The token the maker buys.
+    pub token_in: Address,
+    /// The token the maker sells.
+    pub token_out: Address,
+    /// The exact amount of `token_in` the maker receives.
+    pub amount_in: i128,
+    /// The exact amount of `token_out` the maker pays.
+    pub amount_out: i128,
+    /// The quote can not be filled at or after this timestamp.
+    pub expiry: u64,
+    /// A number the maker has not used before, so the quote can only be filled once.
+    pub nonce: u64,
+    /// The only account that can fill the quote.
+    pub taker: Address,
+}
+
+/// Returns the payload the signer of the maker signs: the sha256 hash of the router address
+/// followed by the quote, both XDR encoded. Binding the router address keeps a signature from
+/// being replayed on another deployment.
+pub fn rfq_quote_hash(e: &Env, quote: &RfqQuote) -> BytesN<32> {
+    let mut payload = Bytes::new(e);
+    payload.append(&e.current_contract_address().to_xdr(e));
+    payload.append(&quote.clone().to_xdr(e));
+    e.crypto().sha256(&payload).into()
+}
+
+/// Checks that `quote` can serve hop `rfq_hop` of `path`, from `path[rfq_hop]` to `path[rfq_hop + 1]`.
+fn check_rfq_hop(path: &Vec<Address>, rfq_hop: u32, quote: &RfqQuote) -> Result<(), CombinedRouterError> {
+    if path.len() < 2 || rfq_hop >= path.len() - 1 {
+        return Err(SoroswapLibraryError::InvalidPath.into());
+    }
+    if path.get(rfq_hop).unwrap() != quote.token_in
+        || path.get(rfq_hop + 1).unwrap() != quote.token_out
+        || quote.amount_in <= 0
+        || quote.amount_out <= 0
+    {
+        return Err(SoroswapRouterError::InvalidRfqQuote.into());
+    }
+    Ok(())
+}
+
+/// Checks that `quote` has not expired nor been filled or cancelled, and panics if `signature` is not
+/// a signature of it by the signer registered for the maker.
+pub fn verify_rfq_quote(e: &Env, quote: &RfqQuote, signature: &BytesN<64>) -> Result<(), CombinedRouterError> {
+    if e.ledger().timestamp() >= quote.expiry {
+        return Err(SoroswapRouterError::RfqQuoteExpired.into());
+    }
+    if is_rfq_nonce_used(e, &quote.maker, quote.nonce) {
+        return Err(SoroswapRouterError::RfqNonceUsed.into());
+    }
+    let signer = get_rfq_signer(e, &quote.maker).ok_or(SoroswapRouterError::RfqSignerNotSet)?;
+    e.crypto().ed25519_verify(&signer, &rfq_quote_hash(e, quote).into(), signature);
+    Ok(())
+}
+
+/// Returns the amounts at each step of `path` when hop `rfq_hop` is filled by `quote`. The pairs
+/// before it are quoted to deliver exactly `quote.amount_in`, and the pairs after it swap `quote.amount_out`.
+pub fn get_amounts(
+    e: &Env,
+    factory: &Address,
+    path: &Vec<Address>,
+    rfq_hop: u32,
+    quote: &RfqQuote,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    check_rfq_hop(path, rfq_hop, quote)?;
+    let mut amounts = if rfq_hop > 0 {
+        soroswap_library::get_amounts_in(e.clone(), factory.clone(), quote.amount_in, path.slice(0..rfq_hop + 1))?
+    } else {
+        Vec::from_array(e, [quote.amount_in])
+    };
+    if rfq_hop + 2 < path.len() {
+        amounts.append(&soroswap_library::get_amounts_out(
+            e.clone(),
+            factory.clone(),
+            quote.amount_out,
+            path.slice(rfq_hop + 1..path.len()),
+        )?);
+    } else {
+        amounts.push_back(quote.amount_out);
+    }
+    Ok(amounts)
+}
+
+/// Returns the address the input of hop `i` has to be sent to: the Soroswap pair or the maker.
+fn hop_recipient(
+    e: &Env,
+    factory: &Address,
+    path: &Vec<Address>,
+    rfq_hop: u32,
+    quote: &RfqQuote,
+    i: u32,
+) -> Result<Address, CombinedRouterError> {
+    if i == rfq_hop {
+        Ok(quote.maker.clone())
+    } else {
+        Ok(soroswap_library::pair_for(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i + 1).unwrap())?)
+    }
+}
+
+/// Transfers the input amount from the taker to the first hop and executes every hop, sending the
+/// output of each one straight to the pair or maker of the next hop, and the last one to `to`. The
+/// maker pays its hop through the allowance it gave the router.
+pub fn execute_route(
+    e: &Env,
+    factory: &Address,
+    amounts: &Vec<i128>,
+    path: &Vec<Address>,
+    rfq_hop: u32,
+    quote: &RfqQuote,
+    to: &Address,
+) -> Result<(), CombinedRouterError> {
+    let first_recipient = hop_recipient(e, factory, path, rfq_hop, quote, 0)?;
+    TokenClient::new(e, &path.get(0).unwrap()).transfer(&quote.taker, &first_recipient, &amounts.get(0).unwrap());
+
+    let mut recipient = first_recipient;
+    for i in 0..path.len() - 1 {
+        let (token_in, token_out) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
+        let amount_out = amounts.get(i + 1).unwrap();
+        let next_recipient = if i < path.len() - 2 {
+            hop_recipient(e, factory, path, rfq_hop, quote, i + 1)?
+        } else {
+            to.clone()
+        };
+
+        if i == rfq_hop {
+            TokenClient::new(e, &token_out).transfer_from(
+                &e.current_contract_address(),
+                &quote.maker,
+                &next_recipient,
+                &amount_out,
+            );
+        } else {
+            let (token_0, _) = soroswap_library::sort_tokens(token_in.clone(), token_out)?;
+            let (amount_0_out, amount_1_out) = if token_in == token_0 { (0, amount_out) } else { (amount_out, 0) };
+            SoroswapPairClient::new(e, &recipient).swap(&amount_0_out, &amount_1_out, &next_recipient);
+        }
+        recipient = next_recipient;
+    }
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use soroban_sdk::{Address, Env, Vec};
+use soroswap_library::SoroswapLibraryError;
+use crate::error::{CombinedRouterError, SoroswapRouterError};
+use crate::factory::SoroswapFactoryClient;
+use crate::storage::get_hub_tokens;
+
+/// Maximum number of pairs a searched route can go through.
+pub const MAX_HOPS: u32 = 3;
+/// Maximum number of hub tokens governance can configure, which bounds the routes to evaluate.
+pub const MAX_HUB_TOKENS: u32 = 5;
+
+/// Lists every path from `token_in` to `token_out` of at most `max_hops` pairs, where the intermediate
+/// tokens are distinct hub tokens and every pair along the path exists. Shorter paths come first.
+fn candidate_paths(
+    e: &Env,
+    factory: &SoroswapFactoryClient,
+    token_in: &Address,
+    token_out: &Address,
+    max_hops: u32,
+) -> Vec<Vec<Address>> {
+    let hub_tokens = get_hub_tokens(e);
+    let mut paths: Vec<Vec<Address>> = Vec::new(e);
+    let mut partial_paths: Vec<Vec<Address>> = Vec::from_array(e, [Vec::from_array(e, [token_in.clone()])]);
+
+    for hops in 1..=max_hops {
+        let mut next_partial_paths: Vec<Vec<Address>> = Vec::new(e);
+        for partial_path in partial_paths.iter() {
+            let last_token = partial_path.last().unwrap();
+            if factory.pair_exists(&last_token, token_out) {
+                let mut path = partial_path.clone();
+                path.push_back(token_out.clone());
+                paths.push_back(path);
+            }
+            if hops == max_hops {
+                continue;
+            }
+            for hub_token in hub_tokens.iter() {
+                if &hub_token == token_out || partial_path.contains(&hub_token) {
+                    continue;
+                }
+                if factory.pair_exists(&last_token, &hub_token) {
+                    let mut path = partial_path.clone();
+                    path.push_back(hub_token);
+                    next_partial_paths.push_back(path);
+                }
+            }
+        }
+        partial_paths = next_partial_paths;
+    }
+    paths
+}
+
+fn check_route_request(token_in: &Address, token_out: &Address, max_hops: u32) -> Result<(), CombinedRouterError> {
+    if token_in == token_out {
+        return Err(SoroswapLibraryError::SortIdenticalTokens.into());
+    }
+    if max_hops == 0 || max_hops > MAX_HOPS {
+        return Err(SoroswapRouterError::InvalidMaxHops.into());
+    }
+    Ok(())
+}
+
+/// Quotes the input needed to get `amount_out` along `path`, like `soroswap_library::get_amounts_in`,
+/// but returns `None` when a pair along the path does not hold enough reserves to deliver its output.
+fn quote_amounts_in(e: &Env, factory_address: &Address, amount_out: i128, path: &Vec<Address>) -> Option<Vec<i128>> {
+    let mut amounts: Vec<i128> = Vec::from_array(e, [amount_out]);
+    for i in (1..path.len()).rev() {
+        let (reserve_in, reserve_out) = soroswap_library::get_reserves(
+            e.clone(),
+            factory_address.clone(),
+            path.get(i - 1).unwrap(),
+            path.get(i).unwrap(),
+        ).ok()?;
+        let step_amount_out = amounts.first().unwrap();
+        if step_amount_out >= reserve_out {
+            return None;
+        }
+        let step_amount_in = soroswap_library::get_amount_in(step_amount_out, reserve_in, reserve_out).ok()?;
+        amounts.push_front(step_amount_in);
+    }
+    Some(amounts)
+}
+
+/// Finds the path that gives the most output tokens for `amount_in`, among the direct path and the
+/// paths through the configured hub tokens. Paths that can not be quoted are skipped.
+///
+/// # Returns
+/// The best path and the amounts at each step of it.
+pub fn best_path_out(
+    e: &Env,
+    factory_address: &Address,
+    amount_in: i128,
+    token_in: &Address,
+    token_out: &Address,
+    max_hops: u32,
+) -> Result<(Vec<Address>, Vec<i128>), CombinedRouterError> {
+    check_route_request(token_in, token_out, max_hops)?;
+    let factory = SoroswapFactoryClient::new(e, factory_address);
+
+    let mut best: Option<(Vec<Address>, Vec<i128>)> = None;
+    for path in candidate_paths(e, &factory, token_in, token_out, max_hops).iter() {
+        let amounts = match soroswap_library::get_amounts_out(e.clone(), factory_address.clone(), amount_in, path.clone()) {
+            Ok(amounts) => amounts,
+            Err(_) => continue,
+        };
+        let is_better = match &best {
+            Some((_, best_amounts)) => amounts.last().unwrap() > best_amounts.last().unwrap(),
+            None => true,
+        };
+        if is_better {
+            best = Some((path, amounts));
+        }
+    }
+    best.ok_or(SoroswapRouterError::NoRouteFound.into())
+}
+
+/// Finds the path that needs the fewest input tokens to get `amount_out`, among the direct path and
+/// the paths through the configured hub tokens. Paths that can not be quoted are skipped.
+///
+/// # Returns
+/// The best path and the amounts at each step of it.
+pub fn best_path_in(
+    e: &Env,
+    factory_address: &Address,
+    amount_out: i128,
+    token_in: &Address,
+    token_out: &Address,
+    max_hops: u32,
+) -> Result<(Vec<Address>, Vec<i128>), CombinedRouterError> {
+    check_route_request(token_in, token_out, max_hops)?;
+    let factory = SoroswapFactoryClient::new(e, factory_address);
+
+    let mut best: Option<(Vec<Address>, Vec<i128>)> = None;
+    for path in candidate_paths(e, &factory, token_in, token_out, max_hops).iter() {
+        let amounts = match quote_amounts_in(e, factory_address, amount_out, &path) {
+            Some(amounts) => amounts,
+            None => continue,
+        };
+        let is_better = match &best {
+            Some((_, best_amounts)) => amounts.first().unwrap() < best_amounts.first().unwrap(),
+            None => true,
+        };
+        if is_better {
+            best = Some((path, amounts));
+        }
+    }
+    best.ok_or(SoroswapRouterError::NoRouteFound.into())
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::{contracttype, Address, Vec};
+use crate::check_nonnegative_amount;
+use crate::error::{CombinedRouterError, SoroswapRouterError};
+
+/// One leg of a split-route swap: a trading route and the amount sent through it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapLeg {
+    /// The trading route, where the first element is the input token and the last is the output token.
+    pub path: Vec<Address>,
+    /// For exact input swaps, the amount of input tokens swapped through this leg.
+    /// For exact output swaps, the amount of output tokens this leg must deliver.
+    pub amount: i128,
+}
+
+/// Checks that there is at least one leg, that no leg amount is negative and that every leg
+/// starts with the same input token and ends with the same output token.
+pub fn check_legs(legs: &Vec<SwapLeg>) -> Result<(), CombinedRouterError> {
+    let first_leg = match legs.first() {
+        Some(leg) => leg,
+        None => return Err(SoroswapRouterError::InvalidSplitRoute.into()),
+    };
+    let token_in = first_leg.path.first();
+    let token_out = first_leg.path.last();
+
+    for leg in legs.iter() {
+        check_nonnegative_amount(leg.amount)?;
+        if leg.path.first() != token_in || leg.path.last() != token_out {
+            return Err(SoroswapRouterError::InvalidSplitRoute.into());
+        }
+    }
+    Ok(())
+}
@@ -1,15 +1,25 @@
-use soroban_sdk::{contracttype, Env, Address};
+use soroban_sdk::{contracttype, Env, Address, BytesN, Vec};
+use crate::oracle::PairOracle;
 
 #[derive(Clone)]
 #[contracttype]
 
 enum DataKey {
     Factory, // Address of the Factory Contract. Instance Data Type
+    HubTokens, // Tokens used as intermediate steps when searching routes. Instance Data Type
+    MaxReferralFeeBps, // Cap on the referral fee integrators can charge. Instance Data Type
+    ReferralFees(Address, Address), // Lifetime referral fees of a referrer in a token. Persistent Data Type
+    PairOracle(Address), // Reference price oracle of a pair. Persistent Data Type
+    Adapter(u32), // Address of a registered AMM adapter. Persistent Data Type
+    RfqSigner(Address), // Ed25519 public key that signs the RFQ quotes of a maker. Persistent Data Type
+    RfqNonce(Address, u64), // Set when an RFQ quote of a maker has been filled or cancelled. Persistent Data Type
 }
 
 const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 pub fn extend_instance_ttl(e: &Env) {
     e.storage()
@@ -28,3 +38,117 @@ pub fn has_factory(e: &Env) -> bool {
 pub fn get_factory(e: &Env) -> Address {
     e.storage().instance().get(&DataKey::Factory).unwrap()
 }
+
+pub fn put_hub_tokens(e: &Env, hub_tokens: &Vec<Address>) {
+    e.storage().instance().set(&DataKey::HubTokens, hub_tokens);
+}
+
+pub fn get_hub_tokens(e: &Env) -> Vec<Address> {
+    e.storage().instance().get(&DataKey::HubTokens).unwrap_or(Vec::new(e))
+}
+
+pub fn put_max_referral_fee_bps(e: &Env, max_referral_fee_bps: u32) {
+    e.storage().instance().set(&DataKey::MaxReferralFeeBps, &max_referral_fee_bps);
+}
+
+pub fn get_max_referral_fee_bps(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::MaxReferralFeeBps).unwrap_or(0)
+}
+
+pub fn get_referral_fees(e: &Env, referrer: Address, token: Address) -> i128 {
+    let key = DataKey::ReferralFees(referrer, token);
+    if let Some(fees) = e.storage().persistent().get::<DataKey, i128>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        fees
+    } else {
+        0
+    }
+}
+
+pub fn add_referral_fees(e: &Env, referrer: Address, token: Address, amount: i128) {
+    let fees = get_referral_fees(e, referrer.clone(), token.clone()).checked_add(amount).unwrap();
+    let key = DataKey::ReferralFees(referrer, token);
+    e.storage().persistent().set(&key, &fees);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn put_pair_oracle(e: &Env, pair: Address, pair_oracle: &PairOracle) {
+    let key = DataKey::PairOracle(pair);
+    e.storage().persistent().set(&key, pair_oracle);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn remove_pair_oracle(e: &Env, pair: Address) {
+    e.storage().persistent().remove(&DataKey::PairOracle(pair));
+}
+
+pub fn get_pair_oracle(e: &Env, pair: Address) -> Option<PairOracle> {
+    let key = DataKey::PairOracle(pair);
+    let pair_oracle = e.storage().persistent().get::<DataKey, PairOracle>(&key);
+    if pair_oracle.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    pair_oracle
+}
+
+pub fn put_adapter(e: &Env, adapter_id: u32, adapter: &Address) {
+    let key = DataKey::Adapter(adapter_id);
+    e.storage().persistent().set(&key, adapter);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn remove_adapter(e: &Env, adapter_id: u32) {
+    e.storage().persistent().remove(&DataKey::Adapter(adapter_id));
+}
+
+pub fn get_adapter(e: &Env, adapter_id: u32) -> Option<Address> {
+    let key = DataKey::Adapter(adapter_id);
+    let adapter = e.storage().persistent().get::<DataKey, Address>(&key);
+    if adapter.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    adapter
+}
+
+pub fn put_rfq_signer(e: &Env, maker: &Address, signer: &BytesN<32>) {
+    let key = DataKey::RfqSigner(maker.clone());
+    e.storage().persistent().set(&key, signer);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_rfq_signer(e: &Env, maker: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::RfqSigner(maker.clone());
+    let signer = e.storage().persistent().get::<DataKey, BytesN<32>>(&key);
+    if signer.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    signer
+}
+
+pub fn set_rfq_nonce_used(e: &Env, maker: &Address, nonce: u64) {
+    let key = DataKey::RfqNonce(maker.clone(), nonce);
+    e.storage().persistent().set(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn is_rfq_nonce_used(e: &Env, maker: &Address, nonce: u64) -> bool {
+    e.storage().persistent().has(&DataKey::RfqNonce(maker.clone(), nonce))
+}
@@ -146,6 +146,18 @@ pub mod library_functions;
 pub mod swap_tokens_for_exact_tokens;
 pub mod swap_exact_tokens_for_tokens;
 pub mod events;
+pub mod from_to;
+pub mod split_swap;
+pub mod best_path;
+pub mod zap;
+pub mod multicall;
+pub mod referral;
+pub mod price_limit;
+pub mod quote_liquidity;
+pub mod route_quote;
+pub mod oracle;
+pub mod adapter;
+pub mod rfq;
 
 // BUDGET TEST MOD
 mod budget;
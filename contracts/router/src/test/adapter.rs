@@ -0,0 +1,244 @@
+use soroban_sdk::{
+    contract,
+    contractimpl,
+    contracttype,
+    testutils::{Address as _, Events},
+    token::Client as SorobanTokenClient,
+    vec,
+    symbol_short,
+    Address,
+    Env,
+    IntoVal};
+
+use crate::test::{SoroswapRouterTest, TokenClient, create_token_contract};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::AdapterEvent;
+use crate::{AmmAdapterTrait, SOROSWAP_ADAPTER_ID};
+use soroswap_library::PRICE_SCALE;
+
+// MOCK AMM ADAPTER: swaps at fixed rates set by the test, paying out of its own balance. It can be set to
+// send a bonus above its quotes.
+
+#[contracttype]
+#[derive(Clone)]
+enum MockAdapterDataKey {
+    Rate(Address, Address),
+    Bonus,
+}
+
+#[contract]
+pub struct MockAdapter;
+
+#[contractimpl]
+impl MockAdapter {
+    pub fn set_rate(e: Env, token_in: Address, token_out: Address, rate: i128) {
+        e.storage().instance().set(&MockAdapterDataKey::Rate(token_in, token_out), &rate);
+    }
+
+    pub fn set_bonus(e: Env, bonus: i128) {
+        e.storage().instance().set(&MockAdapterDataKey::Bonus, &bonus);
+    }
+}
+
+fn rate(e: &Env, token_in: Address, token_out: Address) -> i128 {
+    e.storage().instance().get(&MockAdapterDataKey::Rate(token_in, token_out)).unwrap()
+}
+
+#[contractimpl]
+impl AmmAdapterTrait for MockAdapter {
+    fn get_amount_out(e: Env, token_in: Address, token_out: Address, amount_in: i128) -> i128 {
+        amount_in * rate(&e, token_in, token_out) / PRICE_SCALE
+    }
+
+    fn get_amount_in(e: Env, token_in: Address, token_out: Address, amount_out: i128) -> i128 {
+        let rate = rate(&e, token_in, token_out);
+        (amount_out * PRICE_SCALE + rate - 1) / rate
+    }
+
+    fn swap(e: Env, token_in: Address, token_out: Address, amount_in: i128, amount_out_min: i128, to: Address) -> i128 {
+        let bonus: i128 = e.storage().instance().get(&MockAdapterDataKey::Bonus).unwrap_or(0);
+        let amount_out = Self::get_amount_out(e.clone(), token_in, token_out.clone(), amount_in) + bonus;
+        assert!(amount_out >= amount_out_min);
+        SorobanTokenClient::new(&e, &token_out).transfer(&e.current_contract_address(), &to, &amount_out);
+        amount_out
+    }
+}
+
+const ADAPTER_ID: u32 = 1;
+
+// Registers a mock adapter that swaps 1 token_1 for 2 token_2 and back, holding enough of both.
+fn setup_adapter<'a>(test: &SoroswapRouterTest<'a>) -> (MockAdapterClient<'a>, TokenClient<'a>) {
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(test, &1_000_000_000, &4_000_000_000);
+
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+    let adapter = MockAdapterClient::new(&test.env, &test.env.register_contract(None, MockAdapter {}));
+    adapter.set_rate(&test.token_1.address, &token_2.address, &(2 * PRICE_SCALE));
+    adapter.set_rate(&token_2.address, &test.token_1.address, &(PRICE_SCALE / 2));
+    token_2.mint(&adapter.address, &1_000_000_000_000);
+    test.token_1.mint(&adapter.address, &1_000_000_000_000);
+
+    test.contract.set_adapter(&ADAPTER_ID, &adapter.address);
+    (adapter, token_2)
+}
+
+#[test]
+fn set_adapter() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let adapter = Address::generate(&test.env);
+    assert_eq!(test.contract.get_adapter(&ADAPTER_ID), None);
+
+    test.contract.set_adapter(&ADAPTER_ID, &adapter);
+    assert_eq!(test.contract.get_adapter(&ADAPTER_ID), Some(adapter.clone()));
+
+    let expected_event = AdapterEvent { adapter_id: ADAPTER_ID, adapter };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("adapter")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.remove_adapter(&ADAPTER_ID);
+    assert_eq!(test.contract.get_adapter(&ADAPTER_ID), None);
+    let result = test.contract.try_remove_adapter(&ADAPTER_ID);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidAdapterId)));
+}
+
+#[test]
+fn set_adapter_reserved_id() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let result = test.contract.try_set_adapter(&SOROSWAP_ADAPTER_ID, &Address::generate(&test.env));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidAdapterId)));
+}
+
+#[test]
+#[should_panic]
+fn set_adapter_not_fee_to_setter() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    test.env.mock_auths(&[]);
+    test.contract.set_adapter(&ADAPTER_ID, &Address::generate(&test.env));
+}
+
+#[test]
+fn swap_exact_tokens_adapters_mixed_route() {
+    let test = SoroswapRouterTest::setup();
+    let (adapter, token_2) = setup_adapter(&test);
+    let bob = Address::generate(&test.env);
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let adapter_ids = vec![&test.env, SOROSWAP_ADAPTER_ID, ADAPTER_ID];
+
+    // The Soroswap pair quotes the first hop and the adapter doubles its output
+    let pair_amounts = test.contract.router_get_amounts_out(
+        &1_000_000,
+        &vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]);
+    let amount_1 = pair_amounts.get(1).unwrap();
+    let expected_amounts = vec![&test.env, 1_000_000, amount_1, 2 * amount_1];
+    assert_eq!(test.contract.router_get_adapter_amounts_out(&1_000_000, &path, &adapter_ids), expected_amounts);
+
+    let adapter_balance_1 = test.token_1.balance(&adapter.address);
+    let amounts = test.contract.swap_exact_tokens_adapters(&test.user, &1_000_000, &0, &path, &adapter_ids, &bob, &1000);
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(token_2.balance(&bob), 2 * amount_1);
+    assert_eq!(test.token_1.balance(&adapter.address), adapter_balance_1 + amount_1);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn swap_exact_tokens_adapters_adapter_then_pair() {
+    let test = SoroswapRouterTest::setup();
+    let (_, token_2) = setup_adapter(&test);
+    let bob = Address::generate(&test.env);
+    let path = vec![&test.env, token_2.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+    let adapter_ids = vec![&test.env, ADAPTER_ID, SOROSWAP_ADAPTER_ID];
+
+    // The adapter sends its output straight to the pair of the next hop
+    let expected_amount_0 = test.contract.router_get_amounts_out(
+        &1_000_000,
+        &vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()]).get(1).unwrap();
+    let amounts = test.contract.swap_exact_tokens_adapters(&test.user, &2_000_000, &expected_amount_0, &path, &adapter_ids, &bob, &1000);
+    assert_eq!(amounts, vec![&test.env, 2_000_000, 1_000_000, expected_amount_0]);
+    assert_eq!(test.token_0.balance(&bob), expected_amount_0);
+}
+
+#[test]
+fn swap_exact_tokens_adapters_overdelivery() {
+    let test = SoroswapRouterTest::setup();
+    let (adapter, token_2) = setup_adapter(&test);
+    adapter.set_bonus(&1_000);
+    let bob = Address::generate(&test.env);
+
+    // On the last hop, the bonus goes to `to`
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let adapter_ids = vec![&test.env, SOROSWAP_ADAPTER_ID, ADAPTER_ID];
+    let quoted_amounts = test.contract.router_get_adapter_amounts_out(&1_000_000, &path, &adapter_ids);
+    let amount_2 = quoted_amounts.get(2).unwrap() + 1_000;
+    let amounts = test.contract.swap_exact_tokens_adapters(&test.user, &1_000_000, &0, &path, &adapter_ids, &bob, &1000);
+    assert_eq!(amounts, vec![&test.env, 1_000_000, quoted_amounts.get(1).unwrap(), amount_2]);
+    assert_eq!(token_2.balance(&bob), amount_2);
+
+    // Before a pair, the bonus is swapped by the pair too
+    let path = vec![&test.env, token_2.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+    let adapter_ids = vec![&test.env, ADAPTER_ID, SOROSWAP_ADAPTER_ID];
+    let expected_amount_0 = test.contract.router_get_amounts_out(
+        &1_001_000,
+        &vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()]).get(1).unwrap();
+    assert!(expected_amount_0 > test.contract.router_get_adapter_amounts_out(&2_000_000, &path, &adapter_ids).get(2).unwrap());
+    let amounts = test.contract.swap_exact_tokens_adapters(&test.user, &2_000_000, &0, &path, &adapter_ids, &bob, &1000);
+    assert_eq!(amounts, vec![&test.env, 2_000_000, 1_001_000, expected_amount_0]);
+    assert_eq!(test.token_0.balance(&bob), expected_amount_0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn swap_for_exact_tokens_adapters() {
+    let test = SoroswapRouterTest::setup();
+    let (_, token_2) = setup_adapter(&test);
+    let bob = Address::generate(&test.env);
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let adapter_ids = vec![&test.env, SOROSWAP_ADAPTER_ID, ADAPTER_ID];
+
+    let expected_amounts = test.contract.router_get_adapter_amounts_in(&2_000_000, &path, &adapter_ids);
+    assert_eq!(expected_amounts.get(1).unwrap(), 1_000_000);
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    let amounts = test.contract.swap_for_exact_tokens_adapters(&test.user, &2_000_000, &1_000_000, &path, &adapter_ids, &bob, &1000);
+    assert_eq!(amounts, expected_amounts.clone());
+    assert_eq!(token_2.balance(&bob), 2_000_000);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 - expected_amounts.get(0).unwrap());
+
+    let result = test.contract.try_swap_for_exact_tokens_adapters(&test.user, &2_000_000, &1_000, &path, &adapter_ids, &bob, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterExcessiveInputAmount)));
+}
+
+#[test]
+fn swap_tokens_adapters_invalid_route() {
+    let test = SoroswapRouterTest::setup();
+    let (_, token_2) = setup_adapter(&test);
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+
+    // One adapter id per hop
+    let result = test.contract.try_swap_exact_tokens_adapters(&test.user, &1_000_000, &0, &path, &vec![&test.env, ADAPTER_ID], &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+
+    let unregistered_ids = vec![&test.env, SOROSWAP_ADAPTER_ID, ADAPTER_ID + 1];
+    let result = test.contract.try_swap_exact_tokens_adapters(&test.user, &1_000_000, &0, &path, &unregistered_ids, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidAdapterId)));
+
+    let adapter_ids = vec![&test.env, SOROSWAP_ADAPTER_ID, ADAPTER_ID];
+    let result = test.contract.try_swap_exact_tokens_adapters(&test.user, &1_000_000, &100_000_000, &path, &adapter_ids, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+
+    let result = test.contract.try_swap_exact_tokens_adapters(&test.user, &1_000_000, &0, &path, &adapter_ids, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+}
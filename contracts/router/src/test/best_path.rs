@@ -0,0 +1,226 @@
+extern crate std;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec,
+    symbol_short,
+    Address,
+    IntoVal,
+    Symbol,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, TokenClient, create_token_contract};
+use crate::error::CombinedRouterError;
+use crate::event::HubTokensEvent;
+
+fn new_token<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    let token = create_token_contract(&test.env, &test.admin);
+    token.mint(&test.user, &10_000_000_000_000_000_000);
+    token
+}
+
+fn add_pair(test: &SoroswapRouterTest, token_a: &Address, token_b: &Address, amount_a: i128, amount_b: i128) {
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    test.contract.add_liquidity(token_a, token_b, &amount_a, &amount_b, &0, &0, &test.user, &deadline);
+}
+
+// All pairs quote 1 token_0 = 2 hub = 4 token_1, but token_0/token_1 has little liquidity
+// while token_0/hub and hub/token_1 are deep, so large trades get more output going through the hub.
+fn setup_hub<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    test.contract.initialize(&test.factory.address);
+    let hub = new_token(test);
+    add_pair(test, &test.token_0.address, &test.token_1.address, 1_000_000, 4_000_000);
+    add_pair(test, &test.token_0.address, &hub.address, 1_000_000_000, 2_000_000_000);
+    add_pair(test, &hub.address, &test.token_1.address, 2_000_000_000, 4_000_000_000);
+    hub
+}
+
+#[test]
+fn set_hub_tokens() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    assert_eq!(test.contract.get_hub_tokens(), vec![&test.env]);
+
+    let hub_tokens = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    test.contract.set_hub_tokens(&hub_tokens);
+
+    // Authorized by the factory's fee_to_setter
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "set_hub_tokens"),
+                    (hub_tokens.clone(),).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(test.contract.get_hub_tokens(), hub_tokens);
+
+    let expected_event = HubTokensEvent { hub_tokens };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("hubs")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn set_hub_tokens_not_fee_to_setter() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    test.env.mock_auths(&[]);
+    test.contract.set_hub_tokens(&vec![&test.env, test.token_0.address.clone()]);
+}
+
+#[test]
+fn set_hub_tokens_too_many() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let mut hub_tokens: Vec<Address> = Vec::new(&test.env);
+    for _ in 0..crate::MAX_HUB_TOKENS + 1 {
+        hub_tokens.push_back(Address::generate(&test.env));
+    }
+    let result = test.contract.try_set_hub_tokens(&hub_tokens);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterTooManyHubTokens)));
+}
+
+#[test]
+fn best_path_out_without_hubs_is_direct() {
+    let test = SoroswapRouterTest::setup();
+    setup_hub(&test);
+    let direct_path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    let (path, amounts) = test.contract.router_best_path_out(&10_000_000, &test.token_0.address, &test.token_1.address, &2);
+    assert_eq!(path, direct_path.clone());
+    assert_eq!(amounts, test.contract.router_get_amounts_out(&10_000_000, &direct_path));
+}
+
+#[test]
+fn best_path_out_through_hub() {
+    let test = SoroswapRouterTest::setup();
+    let hub = setup_hub(&test);
+    test.contract.set_hub_tokens(&vec![&test.env, hub.address.clone()]);
+    let hub_path = vec![&test.env, test.token_0.address.clone(), hub.address.clone(), test.token_1.address.clone()];
+
+    let (path, amounts) = test.contract.router_best_path_out(&10_000_000, &test.token_0.address, &test.token_1.address, &2);
+    assert_eq!(path, hub_path.clone());
+    assert_eq!(amounts, test.contract.router_get_amounts_out(&10_000_000, &hub_path));
+
+    // A tiny trade is better served by the direct pair, which skips the second fee
+    let (path, _) = test.contract.router_best_path_out(&100, &test.token_0.address, &test.token_1.address, &2);
+    assert_eq!(path, vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]);
+
+    // With one hop only the direct path is evaluated
+    let (path, _) = test.contract.router_best_path_out(&10_000_000, &test.token_0.address, &test.token_1.address, &1);
+    assert_eq!(path, vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]);
+}
+
+#[test]
+fn best_path_in_skips_paths_without_enough_liquidity() {
+    let test = SoroswapRouterTest::setup();
+    let hub = setup_hub(&test);
+    test.contract.set_hub_tokens(&vec![&test.env, hub.address.clone()]);
+    let hub_path = vec![&test.env, test.token_0.address.clone(), hub.address.clone(), test.token_1.address.clone()];
+
+    // The direct pair only holds 4_000_000 token_1, so it can not be quoted
+    let (path, amounts) = test.contract.router_best_path_in(&10_000_000, &test.token_0.address, &test.token_1.address, &2);
+    assert_eq!(path, hub_path.clone());
+    assert_eq!(amounts, test.contract.router_get_amounts_in(&10_000_000, &hub_path));
+}
+
+#[test]
+fn best_path_out_three_hops() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let hub_a = new_token(&test);
+    let hub_b = new_token(&test);
+    add_pair(&test, &test.token_0.address, &hub_a.address, 1_000_000_000, 1_000_000_000);
+    add_pair(&test, &hub_a.address, &hub_b.address, 1_000_000_000, 1_000_000_000);
+    add_pair(&test, &hub_b.address, &test.token_1.address, 1_000_000_000, 1_000_000_000);
+    test.contract.set_hub_tokens(&vec![&test.env, hub_a.address.clone(), hub_b.address.clone()]);
+
+    let result = test.contract.try_router_best_path_out(&1_000_000, &test.token_0.address, &test.token_1.address, &2);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNoRouteFound)));
+
+    let (path, _) = test.contract.router_best_path_out(&1_000_000, &test.token_0.address, &test.token_1.address, &3);
+    assert_eq!(
+        path,
+        vec![&test.env, test.token_0.address.clone(), hub_a.address.clone(), hub_b.address.clone(), test.token_1.address.clone()]
+    );
+}
+
+#[test]
+fn best_path_invalid_requests() {
+    let test = SoroswapRouterTest::setup();
+    setup_hub(&test);
+
+    let result = test.contract.try_router_best_path_out(&1_000_000, &test.token_0.address, &test.token_1.address, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidMaxHops)));
+    let result = test.contract.try_router_best_path_in(&1_000, &test.token_0.address, &test.token_1.address, &(crate::MAX_HOPS + 1));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidMaxHops)));
+    let result = test.contract.try_router_best_path_out(&1_000_000, &test.token_0.address, &test.token_0.address, &2);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibrarySortIdenticalTokens)));
+
+    let lonely_token = Address::generate(&test.env);
+    let result = test.contract.try_router_best_path_out(&1_000_000, &test.token_0.address, &lonely_token, &2);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNoRouteFound)));
+}
+
+#[test]
+fn swap_exact_tokens_best() {
+    let test = SoroswapRouterTest::setup();
+    let hub = setup_hub(&test);
+    test.contract.set_hub_tokens(&vec![&test.env, hub.address.clone()]);
+    let bob = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let (expected_path, expected_amounts) = test.contract.router_best_path_out(&10_000_000, &test.token_0.address, &test.token_1.address, &2);
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    let (path, amounts) = test.contract.swap_exact_tokens_best(
+        &test.user,
+        &10_000_000,
+        &0,
+        &test.token_0.address,
+        &test.token_1.address,
+        &2,
+        &bob,
+        &deadline,
+    );
+    assert_eq!(path, expected_path);
+    assert_eq!(amounts, expected_amounts.clone());
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 - 10_000_000);
+    assert_eq!(test.token_1.balance(&bob), expected_amounts.last().unwrap());
+}
+
+#[test]
+fn swap_exact_tokens_best_insufficient_output_amount() {
+    let test = SoroswapRouterTest::setup();
+    let hub = setup_hub(&test);
+    test.contract.set_hub_tokens(&vec![&test.env, hub.address.clone()]);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let (_, expected_amounts) = test.contract.router_best_path_out(&10_000_000, &test.token_0.address, &test.token_1.address, &2);
+    let result = test.contract.try_swap_exact_tokens_best(
+        &test.user,
+        &10_000_000,
+        &(expected_amounts.last().unwrap() + 1),
+        &test.token_0.address,
+        &test.token_1.address,
+        &2,
+        &test.user,
+        &deadline,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
@@ -13,6 +13,7 @@ use crate::event::{
     RemoveLiquidityEvent,
     SwapEvent
 };
+use crate::SwapReferral;
 
 
 #[test]
@@ -108,6 +109,7 @@ fn add_liquidity_event() {
         amount_a: deposited_amount_0.clone(),
         amount_b: deposited_amount_1.clone(),
         liquidity: received_liquidity,
+        from: test.user.clone(),
         to: test.user.clone(),
     };
 
@@ -130,6 +132,7 @@ fn add_liquidity_event() {
         amount_a: deposited_amount_0.clone(),
         amount_b: deposited_amount_1.clone(),
         liquidity: 0, // False value
+        from: test.user.clone(),
         to: test.user.clone(),
     };
 
@@ -226,6 +229,7 @@ fn remove_liquidity_event() {
         amount_a: expected_to_remove_0.clone(),
         amount_b: expected_to_remove_1.clone(),
         liquidity: received_liquidity,
+        from: test.user.clone(),
         to: test.user.clone(),
     };
 
@@ -248,6 +252,7 @@ fn remove_liquidity_event() {
         amount_a: (expected_to_remove_0.clone()+1),
         amount_b: expected_to_remove_1.clone(),
         liquidity: received_liquidity,
+        from: test.user.clone(),
         to: test.user.clone(),
     };
 
@@ -328,7 +333,9 @@ fn swap_exact_tokens_for_tokens_event() {
     let expected_swap_event: SwapEvent = SwapEvent {
         path: path.clone(),
         amounts: executed_amounts.clone(),
+        from: test.user.clone(),
         to: test.user.clone(),
+        referral: SwapReferral::NoFee,
     };
 
     assert_eq!(
@@ -352,7 +359,9 @@ fn swap_exact_tokens_for_tokens_event() {
     let false_swap_event: SwapEvent = SwapEvent {
         path: false_path.clone(),
         amounts: executed_amounts.clone(),
+        from: test.user.clone(),
         to: test.user.clone(),
+        referral: SwapReferral::NoFee,
     };
 
     assert_ne!(
@@ -428,7 +437,9 @@ fn swap_tokens_for_exact_tokens_event() {
     let expected_swap_event: SwapEvent = SwapEvent {
         path: path.clone(),
         amounts: executed_amounts.clone(),
+        from: test.user.clone(),
         to: test.user.clone(),
+        referral: SwapReferral::NoFee,
     };
 
     assert_eq!(
@@ -452,7 +463,9 @@ fn swap_tokens_for_exact_tokens_event() {
     let false_swap_event: SwapEvent = SwapEvent {
         path: false_path.clone(),
         amounts: executed_amounts.clone(),
+        from: test.user.clone(),
         to: test.user.clone(),
+        referral: SwapReferral::NoFee,
     };
 
     assert_ne!(
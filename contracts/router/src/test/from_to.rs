@@ -0,0 +1,462 @@
+extern crate std;
+use crate::test::{SoroswapRouterTest, TokenClient};
+use crate::test::add_liquidity::add_liquidity;
+use crate::event::{AddLiquidityEvent, RemoveLiquidityEvent, SwapEvent};
+use crate::SwapReferral;
+use crate::SoroswapRouterClient;
+
+use soroban_sdk::{
+    contract,
+    contractimpl,
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    testutils::{
+        Address as _,
+        AuthorizedFunction,
+        AuthorizedInvocation,
+        Events,
+        MockAuth,
+        MockAuthInvoke},
+    vec,
+    symbol_short,
+    Address,
+    Env,
+    IntoVal,
+    Symbol,
+    Vec};
+
+// A contract that swaps through the router on behalf of `from`. When `from` is the contract itself,
+// it pre-authorizes the input token transfer that the router will make.
+#[contract]
+pub struct SwapForwarder;
+
+#[contractimpl]
+impl SwapForwarder {
+    pub fn swap(
+        e: Env,
+        router: Address,
+        from: Address,
+        amount_in: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128> {
+        let router = SoroswapRouterClient::new(&e, &router);
+        if from == e.current_contract_address() {
+            let pair = router.router_pair_for(&path.get(0).unwrap(), &path.get(1).unwrap());
+            e.authorize_as_current_contract(vec![
+                &e,
+                InvokerContractAuthEntry::Contract(SubContractInvocation {
+                    context: ContractContext {
+                        contract: path.get(0).unwrap(),
+                        fn_name: symbol_short!("transfer"),
+                        args: (e.current_contract_address(), pair, amount_in).into_val(&e),
+                    },
+                    sub_invocations: vec![&e],
+                }),
+            ]);
+        } else {
+            from.require_auth();
+        }
+        router.swap_exact_tokens_from(&from, &amount_in, &0, &path, &to, &deadline)
+    }
+}
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+#[test]
+fn add_liquidity_from() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let bob = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let amount_0: i128 = 1_000_000_000;
+    let amount_1: i128 = 4_000_000_000;
+    let (deposited_0, deposited_1, liquidity) = test.contract.add_liquidity_from(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &amount_0,
+        &amount_1,
+        &0,
+        &0,
+        &bob,
+        &deadline,
+    );
+    let pair = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+
+    // Only the payer signs, and only for its own token transfers
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.user.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "add_liquidity_from"),
+                    (
+                        test.user.clone(),
+                        test.token_0.address.clone(),
+                        test.token_1.address.clone(),
+                        amount_0,
+                        amount_1,
+                        0_i128,
+                        0_i128,
+                        bob.clone(),
+                        deadline,
+                    ).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![
+                    AuthorizedInvocation {
+                        function: AuthorizedFunction::Contract((
+                            test.token_0.address.clone(),
+                            symbol_short!("transfer"),
+                            (test.user.clone(), pair.clone(), amount_0).into_val(&test.env),
+                        )),
+                        sub_invocations: std::vec![]
+                    },
+                    AuthorizedInvocation {
+                        function: AuthorizedFunction::Contract((
+                            test.token_1.address.clone(),
+                            symbol_short!("transfer"),
+                            (test.user.clone(), pair.clone(), amount_1).into_val(&test.env),
+                        )),
+                        sub_invocations: std::vec![]
+                    }
+                ]
+            }
+        )]
+    );
+
+    let lp_token = TokenClient::new(&test.env, &pair);
+    assert_eq!(lp_token.balance(&bob), liquidity);
+    assert_eq!(lp_token.balance(&test.user), 0);
+    assert_eq!(test.token_0.balance(&bob), 0);
+
+    let expected_event = AddLiquidityEvent {
+        token_a: test.token_0.address.clone(),
+        token_b: test.token_1.address.clone(),
+        pair,
+        amount_a: deposited_0,
+        amount_b: deposited_1,
+        liquidity,
+        from: test.user.clone(),
+        to: bob,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("add")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn remove_liquidity_from() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let bob = Address::generate(&test.env);
+
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let pair = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    let user_balance_0 = test.token_0.balance(&test.user);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let (amount_0, amount_1) = test.contract.remove_liquidity_from(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &0,
+        &bob,
+        &deadline,
+    );
+
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.user.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "remove_liquidity_from"),
+                    (
+                        test.user.clone(),
+                        test.token_0.address.clone(),
+                        test.token_1.address.clone(),
+                        liquidity,
+                        0_i128,
+                        0_i128,
+                        bob.clone(),
+                        deadline,
+                    ).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        pair.clone(),
+                        symbol_short!("transfer"),
+                        (test.user.clone(), pair.clone(), liquidity).into_val(&test.env),
+                    )),
+                    sub_invocations: std::vec![]
+                }]
+            }
+        )]
+    );
+
+    assert_eq!(TokenClient::new(&test.env, &pair).balance(&test.user), 0);
+    assert_eq!(test.token_0.balance(&bob), amount_0);
+    assert_eq!(test.token_1.balance(&bob), amount_1);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0);
+
+    let expected_event = RemoveLiquidityEvent {
+        token_a: test.token_0.address.clone(),
+        token_b: test.token_1.address.clone(),
+        pair,
+        amount_a: amount_0,
+        amount_b: amount_1,
+        liquidity,
+        from: test.user.clone(),
+        to: bob,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("remove")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_exact_tokens_from() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let bob = Address::generate(&test.env);
+    add_liquidity(&test, &1_000_000_000_000_000_000, &4_000_000_000_000_000_000);
+    let pair = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let user_balance_1 = test.token_1.balance(&test.user);
+
+    let amount_in = 1_000_000;
+    // (1000000×997×4000000000000000000)÷(1000000000000000000×1000+997×1000000) = 3987999,9
+    let expected_amount_out = 3987999;
+
+    let amounts = test.contract.swap_exact_tokens_from(
+        &test.user,
+        &amount_in,
+        &0,
+        &path(&test),
+        &bob,
+        &deadline,
+    );
+    assert_eq!(amounts, vec![&test.env, amount_in, expected_amount_out]);
+
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.user.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "swap_exact_tokens_from"),
+                    (test.user.clone(), amount_in, 0_i128, path(&test), bob.clone(), deadline).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        test.token_0.address.clone(),
+                        symbol_short!("transfer"),
+                        (test.user.clone(), pair, amount_in).into_val(&test.env),
+                    )),
+                    sub_invocations: std::vec![]
+                }]
+            }
+        )]
+    );
+
+    assert_eq!(test.token_1.balance(&bob), expected_amount_out);
+    assert_eq!(test.token_1.balance(&test.user), user_balance_1);
+
+    let expected_event = SwapEvent {
+        path: path(&test),
+        amounts,
+        from: test.user.clone(),
+        to: bob,
+        referral: SwapReferral::NoFee,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_for_exact_tokens_from() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let bob = Address::generate(&test.env);
+    add_liquidity(&test, &1_000_000_000_000_000_000, &4_000_000_000_000_000_000);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let user_balance_0 = test.token_0.balance(&test.user);
+    let user_balance_1 = test.token_1.balance(&test.user);
+
+    let amount_out = 3987999;
+    let amounts = test.contract.swap_for_exact_tokens_from(
+        &test.user,
+        &amount_out,
+        &1_000_000,
+        &path(&test),
+        &bob,
+        &deadline,
+    );
+    let amount_in = amounts.get(0).unwrap();
+
+    assert_eq!(test.env.auths().len(), 1);
+    assert_eq!(test.env.auths()[0].0, test.user);
+    assert_eq!(test.token_1.balance(&bob), amount_out);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 - amount_in);
+    assert_eq!(test.token_1.balance(&test.user), user_balance_1);
+
+    let expected_event = SwapEvent {
+        path: path(&test),
+        amounts,
+        from: test.user.clone(),
+        to: bob,
+        referral: SwapReferral::NoFee,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn swap_exact_tokens_from_not_authorized_by_recipient() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000_000_000_000, &4_000_000_000_000_000_000);
+    let bob = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // bob can not make the user pay for a swap delivered to him
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "swap_exact_tokens_from",
+                args: (test.user.clone(), 1_000_000_i128, 0_i128, path(&test), bob.clone(), deadline).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .swap_exact_tokens_from(&test.user, &1_000_000, &0, &path(&test), &bob, &deadline);
+}
+
+#[test]
+fn contract_caller_auth_tree() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000_000_000_000, &4_000_000_000_000_000_000);
+    let forwarder = test.env.register_contract(None, SwapForwarder);
+    let forwarder_client = SwapForwarderClient::new(&test.env, &forwarder);
+    let pair = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    let bob = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let amount_in = 1_000_000;
+
+    forwarder_client.swap(&test.contract.address, &test.user, &amount_in, &path(&test), &bob, &deadline);
+
+    // The user signs once, for the forwarder call, which covers the router call and the token transfer
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.user.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    forwarder.clone(),
+                    symbol_short!("swap"),
+                    (test.contract.address.clone(), test.user.clone(), amount_in, path(&test), bob.clone(), deadline).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        test.contract.address.clone(),
+                        Symbol::new(&test.env, "swap_exact_tokens_from"),
+                        (test.user.clone(), amount_in, 0_i128, path(&test), bob.clone(), deadline).into_val(&test.env),
+                    )),
+                    sub_invocations: std::vec![AuthorizedInvocation {
+                        function: AuthorizedFunction::Contract((
+                            test.token_0.address.clone(),
+                            symbol_short!("transfer"),
+                            (test.user.clone(), pair, amount_in).into_val(&test.env),
+                        )),
+                        sub_invocations: std::vec![]
+                    }]
+                }]
+            }
+        )]
+    );
+    assert_eq!(test.token_1.balance(&bob), 3987999);
+}
+
+#[test]
+fn contract_caller_pays_from_own_balance() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000_000_000_000, &4_000_000_000_000_000_000);
+    let forwarder = test.env.register_contract(None, SwapForwarder);
+    let forwarder_client = SwapForwarderClient::new(&test.env, &forwarder);
+    let bob = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let amount_in = 1_000_000;
+    test.token_0.mint(&forwarder, &amount_in);
+
+    // No mocked signatures: the forwarder is authorized as the direct caller of the router,
+    // and the token transfer is covered by its own pre-authorization
+    test.env.mock_auths(&[]);
+    forwarder_client.swap(&test.contract.address, &forwarder, &amount_in, &path(&test), &bob, &deadline);
+
+    assert_eq!(test.token_0.balance(&forwarder), 0);
+    assert_eq!(test.token_1.balance(&bob), 3987999);
+    assert_eq!(test.token_1.balance(&forwarder), 0);
+}
+
+#[test]
+fn contract_caller_can_not_spend_others_balance() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000_000_000_000, &4_000_000_000_000_000_000);
+    let forwarder = test.env.register_contract(None, SwapForwarder);
+    let forwarder_client = SwapForwarderClient::new(&test.env, &forwarder);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    test.env.mock_auths(&[]);
+    let result = forwarder_client.try_swap(&test.contract.address, &test.user, &1_000_000, &path(&test), &forwarder, &deadline);
+    assert!(result.is_err());
+    assert_eq!(test.token_1.balance(&forwarder), 0);
+}
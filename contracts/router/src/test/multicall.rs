@@ -0,0 +1,198 @@
+extern crate std;
+use soroban_sdk::{
+    testutils::Address as _,
+    vec,
+    Address,
+    Symbol,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::{
+    AddLiquidityCall,
+    RemoveLiquidityCall,
+    SwapExactTokensCall,
+    SwapForExactTokensCall,
+    RouterCall,
+    MulticallStep,
+    RouterCallResult,
+};
+
+fn step(call: RouterCall, skip_if_rejected: bool) -> MulticallStep {
+    MulticallStep { call, skip_if_rejected }
+}
+
+fn swap_exact_0_for_1(test: &SoroswapRouterTest, amount_in: i128, amount_out_min: i128) -> RouterCall {
+    RouterCall::SwapExactTokens(SwapExactTokensCall {
+        amount_in,
+        amount_out_min,
+        path: vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()],
+    })
+}
+
+fn pair_client<'a>(test: &SoroswapRouterTest<'a>) -> SoroswapPairClient<'a> {
+    SoroswapPairClient::new(&test.env, &test.contract.router_pair_for(&test.token_0.address, &test.token_1.address))
+}
+
+#[test]
+fn multicall_not_yet_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_multicall(&test.user, &vec![&test.env], &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn multicall_deadline_expired() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let steps = vec![&test.env, step(swap_exact_0_for_1(&test, 1_000, 0), false)];
+    let result = test.contract.try_multicall(&test.user, &steps, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+}
+
+#[test]
+fn multicall_rebalance() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let pair = pair_client(&test);
+
+    // Withdraw half the position, swap the token_0 part into token_1 and add it back
+    let remove_liquidity = liquidity / 2;
+    let steps = vec![
+        &test.env,
+        step(RouterCall::RemoveLiquidity(RemoveLiquidityCall {
+            token_a: test.token_0.address.clone(),
+            token_b: test.token_1.address.clone(),
+            liquidity: remove_liquidity,
+            amount_a_min: 0,
+            amount_b_min: 0,
+        }), false),
+        step(swap_exact_0_for_1(&test, 100_000_000, 0), false),
+        step(RouterCall::AddLiquidity(AddLiquidityCall {
+            token_a: test.token_0.address.clone(),
+            token_b: test.token_1.address.clone(),
+            amount_a_desired: 100_000_000,
+            amount_b_desired: 400_000_000,
+            amount_a_min: 0,
+            amount_b_min: 0,
+        }), false),
+    ];
+    let results = test.contract.multicall(&test.user, &steps, &test.user, &1000);
+
+    assert_eq!(results.len(), 3);
+    let (amount_0, amount_1) = match results.get(0).unwrap() {
+        RouterCallResult::RemoveLiquidity(amount_0, amount_1) => (amount_0, amount_1),
+        other => panic!("unexpected result {:?}", other),
+    };
+    // The pool holds 1 token_0 for every 2 LP tokens and 4 token_1 for every 2 LP tokens
+    assert_eq!(amount_0, remove_liquidity / 2);
+    assert_eq!(amount_1, remove_liquidity * 2);
+    match results.get(1).unwrap() {
+        RouterCallResult::Swap(amounts) => assert_eq!(amounts.get(0).unwrap(), 100_000_000),
+        other => panic!("unexpected result {:?}", other),
+    }
+    let added_liquidity = match results.get(2).unwrap() {
+        RouterCallResult::AddLiquidity(_, _, liquidity) => liquidity,
+        other => panic!("unexpected result {:?}", other),
+    };
+    assert_eq!(pair.balance(&test.user), liquidity - remove_liquidity + added_liquidity);
+}
+
+#[test]
+fn multicall_single_authorization() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let steps = vec![
+        &test.env,
+        step(swap_exact_0_for_1(&test, 1_000_000, 0), false),
+        step(RouterCall::SwapForExactTokens(SwapForExactTokensCall {
+            amount_out: 1_000_000,
+            amount_in_max: 1_000_000,
+            path: vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()],
+        }), false),
+    ];
+    test.contract.multicall(&test.user, &steps, &test.user, &1000);
+
+    // The user signs the batch once, and the token transfers are sub invocations of it
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, test.user);
+    match &auths[0].1.function {
+        soroban_sdk::testutils::AuthorizedFunction::Contract((contract, function, _)) => {
+            assert_eq!(contract, &test.contract.address);
+            assert_eq!(function, &Symbol::new(&test.env, "multicall"));
+        }
+        _ => panic!("unexpected authorized function"),
+    }
+    assert_eq!(auths[0].1.sub_invocations.len(), 2);
+}
+
+#[test]
+fn multicall_skip_if_rejected() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let bob = Address::generate(&test.env);
+    let expected_amounts = test.contract.router_get_amounts_out(
+        &1_000_000,
+        &vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]);
+
+    let steps = vec![
+        &test.env,
+        step(swap_exact_0_for_1(&test, 1_000_000, 4_000_000), true),
+        step(swap_exact_0_for_1(&test, -1, 0), true),
+        step(swap_exact_0_for_1(&test, 1_000_000, 0), true),
+    ];
+    let results = test.contract.multicall(&test.user, &steps, &bob, &1000);
+
+    assert_eq!(
+        results,
+        vec![
+            &test.env,
+            RouterCallResult::Failed(CombinedRouterError::RouterInsufficientOutputAmount as u32),
+            RouterCallResult::Failed(CombinedRouterError::RouterNegativeNotAllowed as u32),
+            RouterCallResult::Swap(expected_amounts.clone()),
+        ]
+    );
+    assert_eq!(test.token_1.balance(&bob), expected_amounts.get(1).unwrap());
+}
+
+#[test]
+fn multicall_failure_reverts_batch() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let steps: Vec<MulticallStep> = vec![
+        &test.env,
+        step(swap_exact_0_for_1(&test, 1_000_000, 0), false),
+        step(swap_exact_0_for_1(&test, 1_000_000, 4_000_000), false),
+    ];
+    let result = test.contract.try_multicall(&test.user, &steps, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
+
+#[test]
+fn multicall_remove_liquidity_below_minimum_reverts_batch() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    // The minimum amounts are checked after the withdrawal, so this step can not be skipped
+    let steps = vec![
+        &test.env,
+        step(RouterCall::RemoveLiquidity(RemoveLiquidityCall {
+            token_a: test.token_0.address.clone(),
+            token_b: test.token_1.address.clone(),
+            liquidity: liquidity / 2,
+            amount_a_min: 1_000_000_000,
+            amount_b_min: 0,
+        }), true),
+    ];
+    let result = test.contract.try_multicall(&test.user, &steps, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientAAmount)));
+}
@@ -0,0 +1,220 @@
+extern crate std;
+use soroban_sdk::{
+    contract,
+    contractimpl,
+    contracttype,
+    testutils::{Address as _, Events},
+    vec,
+    symbol_short,
+    Address,
+    Env,
+    IntoVal,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, TokenClient, create_token_contract};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::PairOracleEvent;
+use crate::{PairOracle, PriceOracleTrait};
+use soroswap_library::PRICE_SCALE;
+
+// MOCK ORACLE: returns the time-weighted prices set by the test.
+
+#[contracttype]
+#[derive(Clone)]
+enum MockOracleDataKey {
+    Twap(Address, Address),
+}
+
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_twap(e: Env, token_in: Address, token_out: Address, twap: i128) {
+        e.storage().instance().set(&MockOracleDataKey::Twap(token_in, token_out), &twap);
+    }
+}
+
+#[contractimpl]
+impl PriceOracleTrait for MockOracle {
+    fn twap(e: Env, token_in: Address, token_out: Address) -> i128 {
+        e.storage().instance().get(&MockOracleDataKey::Twap(token_in, token_out)).unwrap()
+    }
+}
+
+fn create_mock_oracle<'a>(e: &Env) -> MockOracleClient<'a> {
+    MockOracleClient::new(e, &e.register_contract(None, MockOracle {}))
+}
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+// The pair quotes 1 token_0 = 4 token_1, and the oracle agrees, allowing a 1% deviation.
+fn setup_oracle<'a>(test: &SoroswapRouterTest<'a>) -> MockOracleClient<'a> {
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(test, &1_000_000_000, &4_000_000_000);
+    let oracle = create_mock_oracle(&test.env);
+    oracle.set_twap(&test.token_0.address, &test.token_1.address, &(4 * PRICE_SCALE));
+    oracle.set_twap(&test.token_1.address, &test.token_0.address, &(PRICE_SCALE / 4));
+    test.contract.set_pair_oracle(&test.token_0.address, &test.token_1.address, &oracle.address, &100);
+    oracle
+}
+
+// Creates the pairs token_0/token_2 and token_2/token_1, quoting 1 token_0 = 2 token_2 = 4 token_1.
+fn setup_hop<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+    test.contract.add_liquidity(&test.token_0.address, &token_2.address, &1_000_000_000, &2_000_000_000, &0, &0, &test.user, &1000);
+    test.contract.add_liquidity(&token_2.address, &test.token_1.address, &2_000_000_000, &4_000_000_000, &0, &0, &test.user, &1000);
+    token_2
+}
+
+#[test]
+fn set_pair_oracle() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    assert_eq!(test.contract.get_pair_oracle(&test.token_0.address, &test.token_1.address), None);
+
+    let oracle = create_mock_oracle(&test.env);
+    test.contract.set_pair_oracle(&test.token_1.address, &test.token_0.address, &oracle.address, &100);
+    let expected_pair_oracle = PairOracle { oracle: oracle.address.clone(), max_deviation_bps: 100 };
+    assert_eq!(
+        test.contract.get_pair_oracle(&test.token_0.address, &test.token_1.address),
+        Some(expected_pair_oracle)
+    );
+
+    let expected_event = PairOracleEvent {
+        pair: test.contract.router_pair_for(&test.token_0.address, &test.token_1.address),
+        oracle: oracle.address.clone(),
+        max_deviation_bps: 100,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("oracle")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.remove_pair_oracle(&test.token_0.address, &test.token_1.address);
+    assert_eq!(test.contract.get_pair_oracle(&test.token_0.address, &test.token_1.address), None);
+}
+
+#[test]
+fn set_pair_oracle_pair_does_not_exist() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let oracle = create_mock_oracle(&test.env);
+    let result = test.contract.try_set_pair_oracle(&test.token_0.address, &test.token_1.address, &oracle.address, &100);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairDoesNotExist)));
+}
+
+#[test]
+#[should_panic]
+fn set_pair_oracle_not_fee_to_setter() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let oracle = create_mock_oracle(&test.env);
+    test.env.mock_auths(&[]);
+    test.contract.set_pair_oracle(&test.token_0.address, &test.token_1.address, &oracle.address, &100);
+}
+
+#[test]
+fn swap_exact_tokens_safe() {
+    let test = SoroswapRouterTest::setup();
+    setup_oracle(&test);
+    let bob = Address::generate(&test.env);
+
+    let expected_amounts = test.contract.router_get_amounts_out(&1_000_000, &path(&test));
+    let amounts = test.contract.swap_exact_tokens_safe(&test.user, &1_000_000, &0, &path(&test), &bob, &1000);
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_1.balance(&bob), expected_amounts.get(1).unwrap());
+}
+
+#[test]
+fn swap_exact_tokens_safe_price_deviation() {
+    let test = SoroswapRouterTest::setup();
+    let oracle = setup_oracle(&test);
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    // The spot price of 4 is more than 1% above an oracle price of 3.96
+    oracle.set_twap(&test.token_0.address, &test.token_1.address, &(396 * PRICE_SCALE / 100));
+    let result = test.contract.try_swap_exact_tokens_safe(&test.user, &1_000_000, &0, &path(&test), &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterOraclePriceDeviation)));
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0);
+
+    // A deviation of exactly the configured basis points is allowed: 4 is 20% below 5
+    test.contract.set_pair_oracle(&test.token_0.address, &test.token_1.address, &oracle.address, &2_000);
+    oracle.set_twap(&test.token_0.address, &test.token_1.address, &(5 * PRICE_SCALE));
+    test.contract.swap_exact_tokens_safe(&test.user, &1_000_000, &0, &path(&test), &test.user, &1000);
+
+    // The unprotected swap still trades at any price
+    oracle.set_twap(&test.token_0.address, &test.token_1.address, &PRICE_SCALE);
+    test.contract.swap_exact_tokens_for_tokens(&1_000_000, &0, &path(&test), &test.user, &1000);
+}
+
+#[test]
+fn swap_exact_tokens_safe_invalid_oracle_price() {
+    let test = SoroswapRouterTest::setup();
+    let oracle = setup_oracle(&test);
+    oracle.set_twap(&test.token_0.address, &test.token_1.address, &0);
+    let result = test.contract.try_swap_exact_tokens_safe(&test.user, &1_000_000, &0, &path(&test), &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterOraclePriceDeviation)));
+}
+
+#[test]
+fn swap_for_exact_tokens_safe() {
+    let test = SoroswapRouterTest::setup();
+    let oracle = setup_oracle(&test);
+    let reverse_path = vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()];
+
+    let expected_amounts = test.contract.router_get_amounts_in(&1_000_000, &reverse_path);
+    let amounts = test.contract.swap_for_exact_tokens_safe(&test.user, &1_000_000, &5_000_000, &reverse_path, &test.user, &1000);
+    assert_eq!(amounts, expected_amounts);
+
+    // The reverse direction is checked against the oracle price of token_1
+    oracle.set_twap(&test.token_1.address, &test.token_0.address, &(PRICE_SCALE / 5));
+    let result = test.contract.try_swap_for_exact_tokens_safe(&test.user, &1_000_000, &5_000_000, &reverse_path, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterOraclePriceDeviation)));
+
+    // Without an oracle the pair is not checked
+    test.contract.remove_pair_oracle(&test.token_0.address, &test.token_1.address);
+    test.contract.swap_for_exact_tokens_safe(&test.user, &1_000_000, &5_000_000, &reverse_path, &test.user, &1000);
+}
+
+#[test]
+fn swap_exact_tokens_safe_multi_hop() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let token_2 = setup_hop(&test);
+    let hop_path = vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()];
+
+    // Only the second hop has an oracle, and it disagrees with the pair
+    let oracle = create_mock_oracle(&test.env);
+    oracle.set_twap(&token_2.address, &test.token_1.address, &(3 * PRICE_SCALE));
+    test.contract.set_pair_oracle(&token_2.address, &test.token_1.address, &oracle.address, &500);
+    let result = test.contract.try_swap_exact_tokens_safe(&test.user, &1_000_000, &0, &hop_path, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterOraclePriceDeviation)));
+
+    oracle.set_twap(&token_2.address, &test.token_1.address, &(2 * PRICE_SCALE));
+    let amounts = test.contract.swap_exact_tokens_safe(&test.user, &1_000_000, &0, &hop_path, &test.user, &1000);
+    assert_eq!(amounts.len(), 3);
+}
+
+#[test]
+fn swap_tokens_safe_deadline_expired() {
+    let test = SoroswapRouterTest::setup();
+    setup_oracle(&test);
+    let result = test.contract.try_swap_exact_tokens_safe(&test.user, &1_000_000, &0, &path(&test), &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+    let result = test.contract.try_swap_for_exact_tokens_safe(&test.user, &1_000_000, &5_000_000, &path(&test), &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+}
@@ -0,0 +1,160 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec,
+    IntoVal,
+    symbol_short,
+    Address,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, TokenClient, create_token_contract};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::SwapEvent;
+use crate::SwapReferral;
+use soroswap_library::PRICE_SCALE;
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+// Creates the pairs token_0/token_2 and token_2/token_1, quoting 1 token_0 = 2 token_2 = 4 token_1.
+fn setup_hop<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+    test.contract.add_liquidity(&test.token_0.address, &token_2.address, &1_000_000_000, &2_000_000_000, &0, &0, &test.user, &1000);
+    test.contract.add_liquidity(&token_2.address, &test.token_1.address, &2_000_000_000, &4_000_000_000, &0, &0, &test.user, &1000);
+    token_2
+}
+
+#[test]
+fn swap_tokens_with_price_limit_partial_fill() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let bob = Address::generate(&test.env);
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    // The spot price is 3.988 after fees, and the swap stops once it reaches 2
+    let expected_amount_in: i128 = 413_330_640;
+    let expected_amounts = test.contract.router_get_amounts_out(&expected_amount_in, &path(&test));
+
+    let (amount_in, amount_unfilled) = test.contract.swap_tokens_with_price_limit(
+        &test.user,
+        &1_000_000_000,
+        &(2 * PRICE_SCALE),
+        &path(&test),
+        &bob,
+        &1000,
+    );
+    assert_eq!(amount_in, expected_amount_in);
+    assert_eq!(amount_unfilled, 1_000_000_000 - expected_amount_in);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 - expected_amount_in);
+    assert_eq!(test.token_1.balance(&bob), expected_amounts.get(1).unwrap());
+
+    // Any further trade gets less than 2 token_1 per token_0
+    let next_amount_out = test.contract.router_get_amounts_out(&1_000_000, &path(&test)).get(1).unwrap();
+    assert!(next_amount_out < 2_000_000);
+    assert!(next_amount_out > 1_990_000);
+}
+
+#[test]
+fn swap_tokens_with_price_limit_full_fill() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    // A small order does not move the price down to the limit
+    let (amount_in, amount_unfilled) = test.contract.swap_tokens_with_price_limit(
+        &test.user,
+        &1_000_000,
+        &(2 * PRICE_SCALE),
+        &path(&test),
+        &test.user,
+        &1000,
+    );
+    assert_eq!(amount_in, 1_000_000);
+    assert_eq!(amount_unfilled, 0);
+}
+
+#[test]
+fn swap_tokens_with_price_limit_no_fill() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    // The spot price is already below the limit
+    let (amount_in, amount_unfilled) = test.contract.swap_tokens_with_price_limit(
+        &test.user,
+        &1_000_000,
+        &(4 * PRICE_SCALE),
+        &path(&test),
+        &test.user,
+        &1000,
+    );
+    assert_eq!(amount_in, 0);
+    assert_eq!(amount_unfilled, 1_000_000);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0);
+
+    // The unfilled swap still publishes a swap event, with zero amounts
+    let expected_swap_event = SwapEvent {
+        path: path(&test),
+        amounts: vec![&test.env, 0, 0],
+        from: test.user.clone(),
+        to: test.user.clone(),
+        referral: SwapReferral::NoFee,
+    };
+    let swap_event = test.env.events().all().last().unwrap();
+    assert_eq!(
+        vec![&test.env, swap_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                expected_swap_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_tokens_with_price_limit_multi_hop() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let token_2 = setup_hop(&test);
+    let hop_path = vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()];
+
+    // The marginal price of the whole route reaches 3 after this input
+    let expected_amount_in: i128 = 75_959_695;
+    let amount_out = test.contract.router_get_amounts_out(&expected_amount_in, &hop_path).get(2).unwrap();
+    let next_amount_out = test.contract.router_get_amounts_out(&(expected_amount_in + 10_000), &hop_path).get(2).unwrap();
+    assert!((next_amount_out - amount_out - 30_000).abs() <= 1);
+
+    let (amount_in, amount_unfilled) = test.contract.swap_tokens_with_price_limit(
+        &test.user,
+        &1_000_000_000,
+        &(3 * PRICE_SCALE),
+        &hop_path,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(amount_in, expected_amount_in);
+    assert_eq!(amount_unfilled, 1_000_000_000 - expected_amount_in);
+}
+
+#[test]
+fn swap_tokens_with_price_limit_invalid_price_limit() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let result = test.contract.try_swap_tokens_with_price_limit(&test.user, &1_000_000, &0, &path(&test), &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidPriceLimit)));
+
+    let result = test.contract.try_swap_tokens_with_price_limit(&test.user, &-1, &PRICE_SCALE, &path(&test), &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNegativeNotAllowed)));
+
+    let result = test.contract.try_swap_tokens_with_price_limit(&test.user, &1_000_000, &PRICE_SCALE, &path(&test), &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+}
@@ -0,0 +1,145 @@
+use soroban_sdk::{vec, Address};
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+
+fn pair_client<'a>(test: &SoroswapRouterTest<'a>) -> SoroswapPairClient<'a> {
+    SoroswapPairClient::new(&test.env, &test.contract.router_pair_for(&test.token_0.address, &test.token_1.address))
+}
+
+fn swap(test: &SoroswapRouterTest, token_in: &Address, token_out: &Address, amount_in: i128) {
+    test.contract.swap_exact_tokens_for_tokens(
+        &amount_in,
+        &0,
+        &vec![&test.env, token_in.clone(), token_out.clone()],
+        &test.user,
+        &1000,
+    );
+}
+
+#[test]
+fn quote_liquidity_not_yet_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_router_quote_add_liquidity(&test.token_0.address, &test.token_1.address, &1_000, &1_000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+
+    let result = test.contract.try_router_quote_remove_liquidity(&test.token_0.address, &test.token_1.address, &1_000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn quote_liquidity_negative_amounts() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let result = test.contract.try_router_quote_add_liquidity(&test.token_0.address, &test.token_1.address, &-1, &1_000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNegativeNotAllowed)));
+
+    let result = test.contract.try_router_quote_remove_liquidity(&test.token_0.address, &test.token_1.address, &-1);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNegativeNotAllowed)));
+}
+
+#[test]
+fn quote_add_liquidity_first_deposit() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+
+    // The quote does not create the pair, and MINIMUM_LIQUIDITY is locked on the first deposit
+    let quote = test.contract.router_quote_add_liquidity(&test.token_0.address, &test.token_1.address, &1_000_000_000, &4_000_000_000);
+    assert_eq!(quote, (1_000_000_000, 4_000_000_000, 2_000_000_000 - 1_000));
+    assert!(!test.factory.pair_exists(&test.token_0.address, &test.token_1.address));
+
+    assert_eq!(add_liquidity(&test, &1_000_000_000, &4_000_000_000), quote);
+}
+
+#[test]
+fn quote_add_liquidity_first_deposit_insufficient_liquidity() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+
+    // sqrt(1000 * 1000) does not exceed MINIMUM_LIQUIDITY
+    let result = test.contract.try_router_quote_add_liquidity(&test.token_0.address, &test.token_1.address, &1_000, &1_000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientLiquidity)));
+}
+
+#[test]
+fn quote_add_liquidity_optimal_amounts() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    // Only the amount of token_1 matching the pool ratio is taken
+    let quote = test.contract.router_quote_add_liquidity(&test.token_0.address, &test.token_1.address, &1_000_000, &1_000_000_000);
+    assert_eq!(quote, (1_000_000, 4_000_000, 2_000_000));
+    assert_eq!(add_liquidity(&test, &1_000_000, &1_000_000_000), quote);
+
+    // Token order follows the arguments
+    let quote = test.contract.router_quote_add_liquidity(&test.token_1.address, &test.token_0.address, &1_000_000_000, &1_000_000);
+    assert_eq!(quote, (4_000_000, 1_000_000, 2_000_000));
+}
+
+#[test]
+fn quote_remove_liquidity() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let quote = test.contract.router_quote_remove_liquidity(&test.token_0.address, &test.token_1.address, &liquidity);
+    assert_eq!(quote, (1_000_000_000 - 500, 4_000_000_000 - 2_000));
+
+    let reversed_quote = test.contract.router_quote_remove_liquidity(&test.token_1.address, &test.token_0.address, &liquidity);
+    assert_eq!(reversed_quote, (quote.1, quote.0));
+
+    let result = test.contract.remove_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, quote);
+}
+
+#[test]
+fn quote_remove_liquidity_pair_does_not_exist() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let result = test.contract.try_router_quote_remove_liquidity(&test.token_0.address, &test.token_1.address, &1_000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairDoesNotExist)));
+}
+
+#[test]
+fn quote_liquidity_with_protocol_fee() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    test.factory.set_fees_enabled(&true);
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let pair = pair_client(&test);
+
+    // Swap fees grow k, so the pair owes LP tokens to fee_to
+    swap(&test, &test.token_0.address, &test.token_1.address, 100_000_000);
+    swap(&test, &test.token_1.address, &test.token_0.address, 400_000_000);
+    assert_eq!(pair.balance(&test.admin), 0);
+
+    let add_quote = test.contract.router_quote_add_liquidity(&test.token_0.address, &test.token_1.address, &10_000_000, &1_000_000_000);
+    assert_eq!(add_liquidity(&test, &10_000_000, &1_000_000_000), add_quote);
+    let protocol_fee = pair.balance(&test.admin);
+    assert!(protocol_fee > 0);
+
+    // Another swap leaves more protocol fee to mint on the withdrawal
+    swap(&test, &test.token_0.address, &test.token_1.address, 100_000_000);
+    let remove_quote = test.contract.router_quote_remove_liquidity(&test.token_0.address, &test.token_1.address, &liquidity);
+    let result = test.contract.remove_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, remove_quote);
+    assert!(pair.balance(&test.admin) > protocol_fee);
+}
@@ -0,0 +1,290 @@
+extern crate std;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec,
+    symbol_short,
+    Address,
+    IntoVal,
+    Symbol,
+    Vec};
+
+use crate::test::SoroswapRouterTest;
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::{ReferralFeeCapEvent, SwapEvent};
+use crate::{ReferralFee, SwapReferral, MAX_REFERRAL_FEE_BPS};
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+fn setup_referral(test: &SoroswapRouterTest) {
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(test, &1_000_000_000, &4_000_000_000);
+    test.contract.set_max_referral_fee_bps(&100);
+}
+
+#[test]
+fn set_max_referral_fee_bps() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    assert_eq!(test.contract.get_max_referral_fee_bps(), 0);
+
+    test.contract.set_max_referral_fee_bps(&100);
+
+    // Authorized by the factory's fee_to_setter
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "set_max_referral_fee_bps"),
+                    (100_u32,).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(test.contract.get_max_referral_fee_bps(), 100);
+
+    let expected_event = ReferralFeeCapEvent { max_referral_fee_bps: 100 };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("ref_cap")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn set_max_referral_fee_bps_above_limit() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let result = test.contract.try_set_max_referral_fee_bps(&(MAX_REFERRAL_FEE_BPS + 1));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidReferralFee)));
+}
+
+#[test]
+#[should_panic]
+fn set_max_referral_fee_bps_not_fee_to_setter() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    test.env.mock_auths(&[]);
+    test.contract.set_max_referral_fee_bps(&100);
+}
+
+#[test]
+fn swap_exact_tokens_referral() {
+    let test = SoroswapRouterTest::setup();
+    setup_referral(&test);
+    let bob = Address::generate(&test.env);
+    let referrer = Address::generate(&test.env);
+
+    let expected_amounts = test.contract.router_get_amounts_out(&1_000_000, &path(&test));
+    let amount_out = expected_amounts.get(1).unwrap();
+    let fee = amount_out * 50 / 10_000;
+
+    let amounts = test.contract.swap_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &(amount_out - fee),
+        &path(&test),
+        &bob,
+        &Some(referrer.clone()),
+        &50,
+        &1000,
+    );
+    assert_eq!(amounts, expected_amounts.clone());
+    assert_eq!(test.token_1.balance(&bob), amount_out - fee);
+    assert_eq!(test.token_1.balance(&referrer), fee);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.get_referral_fees(&referrer, &test.token_1.address), fee);
+
+    let expected_event = SwapEvent {
+        path: path(&test),
+        amounts: expected_amounts,
+        from: test.user.clone(),
+        to: bob,
+        referral: SwapReferral::Fee(ReferralFee { referrer: referrer.clone(), token: test.token_1.address.clone(), amount: fee }),
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+
+    // Lifetime totals add up across swaps
+    let amounts = test.contract.swap_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &0,
+        &path(&test),
+        &test.user,
+        &Some(referrer.clone()),
+        &50,
+        &1000,
+    );
+    let second_fee = amounts.get(1).unwrap() * 50 / 10_000;
+    assert_eq!(test.contract.get_referral_fees(&referrer, &test.token_1.address), fee + second_fee);
+}
+
+#[test]
+fn swap_exact_tokens_referral_without_referrer() {
+    let test = SoroswapRouterTest::setup();
+    setup_referral(&test);
+    let bob = Address::generate(&test.env);
+
+    let amounts = test.contract.swap_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &0,
+        &path(&test),
+        &bob,
+        &None,
+        &0,
+        &1000,
+    );
+    assert_eq!(test.token_1.balance(&bob), amounts.get(1).unwrap());
+
+    let event: SwapEvent = test.env.events().all().last().unwrap().2.into_val(&test.env);
+    assert_eq!(event.referral, SwapReferral::NoFee);
+
+    // A fee needs a referrer
+    let result = test.contract.try_swap_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &0,
+        &path(&test),
+        &bob,
+        &None,
+        &50,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidReferralFee)));
+}
+
+#[test]
+fn swap_exact_tokens_referral_above_cap() {
+    let test = SoroswapRouterTest::setup();
+    setup_referral(&test);
+    let referrer = Address::generate(&test.env);
+
+    let result = test.contract.try_swap_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &0,
+        &path(&test),
+        &test.user,
+        &Some(referrer.clone()),
+        &101,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidReferralFee)));
+
+    // Referral fees are disabled until governance sets a cap
+    test.contract.set_max_referral_fee_bps(&0);
+    let result = test.contract.try_swap_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &0,
+        &path(&test),
+        &test.user,
+        &Some(referrer),
+        &1,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidReferralFee)));
+}
+
+#[test]
+fn swap_exact_tokens_referral_insufficient_output_amount() {
+    let test = SoroswapRouterTest::setup();
+    setup_referral(&test);
+    let referrer = Address::generate(&test.env);
+
+    // The minimum applies to the output left after the fee
+    let amount_out = test.contract.router_get_amounts_out(&1_000_000, &path(&test)).get(1).unwrap();
+    let fee = amount_out * 50 / 10_000;
+    let result = test.contract.try_swap_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &(amount_out - fee + 1),
+        &path(&test),
+        &test.user,
+        &Some(referrer),
+        &50,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
+
+#[test]
+fn swap_for_exact_tokens_referral() {
+    let test = SoroswapRouterTest::setup();
+    setup_referral(&test);
+    let bob = Address::generate(&test.env);
+    let referrer = Address::generate(&test.env);
+
+    let expected_amounts = test.contract.router_get_amounts_in(&1_000_000, &path(&test));
+    let amount_in = expected_amounts.get(0).unwrap();
+    let fee = amount_in * 100 / 10_000;
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    let amounts = test.contract.swap_for_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &(amount_in + fee),
+        &path(&test),
+        &bob,
+        &Some(referrer.clone()),
+        &100,
+        &1000,
+    );
+    assert_eq!(amounts, expected_amounts.clone());
+    assert_eq!(test.token_1.balance(&bob), 1_000_000);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 - amount_in - fee);
+    assert_eq!(test.token_0.balance(&referrer), fee);
+    assert_eq!(test.contract.get_referral_fees(&referrer, &test.token_0.address), fee);
+    assert_eq!(test.contract.get_referral_fees(&referrer, &test.token_1.address), 0);
+
+    let event: SwapEvent = test.env.events().all().last().unwrap().2.into_val(&test.env);
+    assert_eq!(
+        event.referral,
+        SwapReferral::Fee(ReferralFee { referrer, token: test.token_0.address.clone(), amount: fee })
+    );
+}
+
+#[test]
+fn swap_for_exact_tokens_referral_excessive_input_amount() {
+    let test = SoroswapRouterTest::setup();
+    setup_referral(&test);
+    let referrer = Address::generate(&test.env);
+
+    // The maximum includes the fee paid on top of the input
+    let amount_in = test.contract.router_get_amounts_in(&1_000_000, &path(&test)).get(0).unwrap();
+    let fee = amount_in * 100 / 10_000;
+    let result = test.contract.try_swap_for_exact_tokens_referral(
+        &test.user,
+        &1_000_000,
+        &(amount_in + fee - 1),
+        &path(&test),
+        &test.user,
+        &Some(referrer),
+        &100,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterExcessiveInputAmount)));
+}
@@ -760,3 +760,68 @@ fn test_remove_liquidity_inequal_amount_exact_minimum_out_other_way() {
    assert_eq!(test.token_1.balance(&pair_address), locked_1);
     
 }
+
+#[test]
+#[should_panic]
+fn test_remove_liquidity_locked_by_liquidity_cooldown() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+
+    // The pair gets created with the first deposit, then the factory sets a cooldown of 10 ledgers
+    add_liquidity(&test, &10_000_000_000, &20_000_000_000);
+    test.factory.set_liquidity_cooldown(&test.token_0.address, &test.token_1.address, &10);
+
+    // Liquidity deposited after that is locked, and can't be sent back to the pair
+    let (_, _, locked_liquidity) = add_liquidity(&test, &10_000_000_000, &20_000_000_000);
+    test.contract.remove_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &locked_liquidity,
+        &0,
+        &0,
+        &test.user,
+        &1000
+    );
+}
+
+#[test]
+fn test_remove_liquidity_after_liquidity_cooldown() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+
+    let (_, _, unlocked_liquidity) = add_liquidity(&test, &10_000_000_000, &20_000_000_000);
+    test.factory.set_liquidity_cooldown(&test.token_0.address, &test.token_1.address, &10);
+    let (_, _, locked_liquidity) = add_liquidity(&test, &10_000_000_000, &20_000_000_000);
+
+    let pair_address = test.factory.get_pair(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.locked_balance(&test.user), locked_liquidity);
+
+    // Liquidity deposited before the cooldown was set can be removed right away
+    test.contract.remove_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &unlocked_liquidity,
+        &0,
+        &0,
+        &test.user,
+        &1000
+    );
+    assert_eq!(pair_client.balance(&test.user), locked_liquidity);
+
+    // The rest, once the cooldown has passed
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number = li.sequence_number + 10;
+    });
+    assert_eq!(pair_client.locked_balance(&test.user), 0);
+    test.contract.remove_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &locked_liquidity,
+        &0,
+        &0,
+        &test.user,
+        &1000
+    );
+    assert_eq!(pair_client.balance(&test.user), 0);
+}
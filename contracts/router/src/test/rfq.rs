@@ -0,0 +1,326 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    vec,
+    symbol_short,
+    Address,
+    BytesN,
+    IntoVal};
+
+use crate::test::{SoroswapRouterTest, TokenClient, create_token_contract};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::{RfqSignerEvent, RfqCancelEvent, RfqFillEvent};
+use crate::RfqQuote;
+
+// The maker holds token_1 and token_2, and quotes 1 token_1 for 2 token_2 and back.
+struct RfqTest<'a> {
+    test: SoroswapRouterTest<'a>,
+    token_2: TokenClient<'a>,
+    maker: Address,
+    signing_key: SigningKey,
+}
+
+fn setup_rfq<'a>() -> RfqTest<'a> {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &1_000_000_000);
+    let maker = Address::generate(&test.env);
+    token_2.mint(&maker, &1_000_000_000);
+    test.token_1.mint(&maker, &1_000_000_000);
+    token_2.approve(&maker, &test.contract.address, &1_000_000_000, &1000);
+    test.token_1.approve(&maker, &test.contract.address, &1_000_000_000, &1000);
+
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    let signer = BytesN::from_array(&test.env, &signing_key.verifying_key().to_bytes());
+    test.contract.set_rfq_signer(&maker, &signer);
+    RfqTest { test, token_2, maker, signing_key }
+}
+
+impl<'a> RfqTest<'a> {
+    /// The maker buys 4_000_000 token_1 for 8_000_000 token_2.
+    fn quote(&self, nonce: u64) -> RfqQuote {
+        RfqQuote {
+            maker: self.maker.clone(),
+            token_in: self.test.token_1.address.clone(),
+            token_out: self.token_2.address.clone(),
+            amount_in: 4_000_000,
+            amount_out: 8_000_000,
+            expiry: 1000,
+            nonce,
+            taker: self.test.user.clone(),
+        }
+    }
+
+    fn sign(&self, signing_key: &SigningKey, quote: &RfqQuote) -> BytesN<64> {
+        let payload = self.test.contract.router_get_rfq_quote_hash(quote);
+        BytesN::from_array(&self.test.env, &signing_key.sign(&payload.to_array()).to_bytes())
+    }
+}
+
+#[test]
+fn set_rfq_signer() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let signer = BytesN::from_array(&test.env, &rfq.signing_key.verifying_key().to_bytes());
+    assert_eq!(test.contract.get_rfq_signer(&rfq.maker), Some(signer.clone()));
+    assert_eq!(test.contract.get_rfq_signer(&test.user), None);
+
+    let expected_event = RfqSignerEvent { maker: rfq.maker.clone(), signer };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("rfq_sign")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_rfq_single_hop() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let bob = Address::generate(&test.env);
+    let quote = rfq.quote(0);
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+
+    let user_balance_1 = test.token_1.balance(&test.user);
+    let amounts = test.contract.swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &bob, &1000);
+    assert_eq!(amounts, vec![&test.env, 4_000_000, 8_000_000]);
+    assert_eq!(test.token_1.balance(&test.user), user_balance_1 - 4_000_000);
+    assert_eq!(test.token_1.balance(&rfq.maker), 1_000_000_000 + 4_000_000);
+    assert_eq!(rfq.token_2.balance(&rfq.maker), 1_000_000_000 - 8_000_000);
+    assert_eq!(rfq.token_2.balance(&bob), 8_000_000);
+    assert!(test.contract.is_rfq_nonce_used(&rfq.maker, &0));
+
+    // The fill event is followed by the swap event of the whole route
+    let events = test.env.events().all();
+    let expected_event = RfqFillEvent {
+        maker: rfq.maker.clone(),
+        taker: test.user.clone(),
+        token_in: test.token_1.address.clone(),
+        token_out: rfq.token_2.address.clone(),
+        amount_in: 4_000_000,
+        amount_out: 8_000_000,
+        nonce: 0,
+    };
+    assert_eq!(
+        vec![&test.env, events.get(events.len() - 2).unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("rfq_fill")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_rfq_after_pair_hop() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let bob = Address::generate(&test.env);
+    let quote = rfq.quote(0);
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), rfq.token_2.address.clone()];
+
+    // The pair delivers exactly the input of the quote to the maker
+    let amount_0 = test.contract.router_get_amounts_in(
+        &4_000_000,
+        &vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]).get(0).unwrap();
+    let expected_amounts = vec![&test.env, amount_0, 4_000_000, 8_000_000];
+    assert_eq!(test.contract.router_get_rfq_amounts(&path, &1, &quote), expected_amounts);
+
+    let user_balance_0 = test.token_0.balance(&test.user);
+    let amounts = test.contract.swap_rfq(&quote, &signature, &path, &1, &amount_0, &8_000_000, &bob, &1000);
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 - amount_0);
+    assert_eq!(test.token_1.balance(&rfq.maker), 1_000_000_000 + 4_000_000);
+    assert_eq!(rfq.token_2.balance(&bob), 8_000_000);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn swap_rfq_before_pair_hop() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let bob = Address::generate(&test.env);
+    let quote = RfqQuote {
+        token_in: rfq.token_2.address.clone(),
+        token_out: test.token_1.address.clone(),
+        amount_in: 8_000_000,
+        amount_out: 4_000_000,
+        ..rfq.quote(0)
+    };
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, rfq.token_2.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+
+    // The maker pays the pair of the next hop, which swaps the output of the quote
+    let amount_0 = test.contract.router_get_amounts_out(
+        &4_000_000,
+        &vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()]).get(1).unwrap();
+    let amounts = test.contract.swap_rfq(&quote, &signature, &path, &0, &8_000_000, &amount_0, &bob, &1000);
+    assert_eq!(amounts, vec![&test.env, 8_000_000, 4_000_000, amount_0]);
+    assert_eq!(test.token_0.balance(&bob), amount_0);
+    assert_eq!(rfq.token_2.balance(&rfq.maker), 1_000_000_000 + 8_000_000);
+    assert_eq!(test.token_1.balance(&rfq.maker), 1_000_000_000 - 4_000_000);
+}
+
+#[test]
+fn swap_rfq_only_needs_taker_auth() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let quote = rfq.quote(0);
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+
+    // The maker does not authorize anything in the swap transaction
+    test.env.mock_auths(&[MockAuth {
+        address: &test.user,
+        invoke: &MockAuthInvoke {
+            contract: &test.contract.address,
+            fn_name: "swap_rfq",
+            args: (quote.clone(), signature.clone(), path.clone(), 0_u32, 4_000_000_i128, 8_000_000_i128, test.user.clone(), 1000_u64).into_val(&test.env),
+            sub_invokes: &[MockAuthInvoke {
+                contract: &test.token_1.address,
+                fn_name: "transfer",
+                args: (test.user.clone(), rfq.maker.clone(), 4_000_000_i128).into_val(&test.env),
+                sub_invokes: &[],
+            }],
+        },
+    }]);
+    test.contract.swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &test.user, &1000);
+    assert_eq!(rfq.token_2.balance(&test.user), 1_000_000_000 + 8_000_000);
+}
+
+#[test]
+fn swap_rfq_nonce_replay() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let quote = rfq.quote(0);
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+
+    test.contract.swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &test.user, &1000);
+    let result = test.contract.try_swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterRfqNonceUsed)));
+}
+
+#[test]
+fn cancel_rfq_quote() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let quote = rfq.quote(3);
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+
+    test.contract.cancel_rfq_quote(&rfq.maker, &3);
+    assert!(test.contract.is_rfq_nonce_used(&rfq.maker, &3));
+    let expected_event = RfqCancelEvent { maker: rfq.maker.clone(), nonce: 3 };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("rfq_cncl")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+
+    let result = test.contract.try_swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterRfqNonceUsed)));
+    let result = test.contract.try_cancel_rfq_quote(&rfq.maker, &3);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterRfqNonceUsed)));
+}
+
+#[test]
+fn swap_rfq_expired() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let quote = rfq.quote(0);
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 1000;
+    });
+    let result = test.contract.try_swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &test.user, &2000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterRfqQuoteExpired)));
+}
+
+#[test]
+fn swap_rfq_signer_not_set() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let quote = RfqQuote { maker: Address::generate(&test.env), ..rfq.quote(0) };
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+
+    let result = test.contract.try_swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterRfqSignerNotSet)));
+}
+
+#[test]
+#[should_panic]
+fn swap_rfq_wrong_signer() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let quote = rfq.quote(0);
+    let signature = rfq.sign(&SigningKey::from_bytes(&[8; 32]), &quote);
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+    test.contract.swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &test.user, &1000);
+}
+
+#[test]
+#[should_panic]
+fn swap_rfq_tampered_quote() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let signature = rfq.sign(&rfq.signing_key, &rfq.quote(0));
+    let quote = RfqQuote { amount_out: 9_000_000, ..rfq.quote(0) };
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+    test.contract.swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_000, &test.user, &1000);
+}
+
+#[test]
+fn swap_rfq_invalid_hop() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let quote = rfq.quote(0);
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+
+    // The quote sells token_2 for token_1, not token_1 for token_0
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), rfq.token_2.address.clone()];
+    let result = test.contract.try_swap_rfq(&quote, &signature, &path, &0, &4_000_000, &0, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidRfqQuote)));
+
+    let result = test.contract.try_swap_rfq(&quote, &signature, &path, &2, &4_000_000, &0, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
+
+#[test]
+fn swap_rfq_amount_limits() {
+    let rfq = setup_rfq();
+    let test = &rfq.test;
+    let quote = rfq.quote(0);
+    let signature = rfq.sign(&rfq.signing_key, &quote);
+    let path = vec![&test.env, test.token_1.address.clone(), rfq.token_2.address.clone()];
+
+    let result = test.contract.try_swap_rfq(&quote, &signature, &path, &0, &3_999_999, &0, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterExcessiveInputAmount)));
+    let result = test.contract.try_swap_rfq(&quote, &signature, &path, &0, &4_000_000, &8_000_001, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+    assert!(!test.contract.is_rfq_nonce_used(&rfq.maker, &0));
+}
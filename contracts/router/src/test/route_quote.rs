@@ -0,0 +1,129 @@
+use soroban_sdk::{vec, Address, Vec};
+
+use crate::test::{SoroswapRouterTest, TokenClient, create_token_contract};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::RouteQuote;
+use soroswap_library::PRICE_SCALE;
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+// Creates the pairs token_0/token_2 and token_2/token_1, quoting 1 token_0 = 2 token_2 = 4 token_1.
+fn setup_hop<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+    test.contract.add_liquidity(&test.token_0.address, &token_2.address, &1_000_000_000, &2_000_000_000, &0, &0, &test.user, &1000);
+    test.contract.add_liquidity(&token_2.address, &test.token_1.address, &2_000_000_000, &4_000_000_000, &0, &0, &test.user, &1000);
+    token_2
+}
+
+fn amounts(quotes: &Vec<RouteQuote>) -> Vec<i128> {
+    let mut amounts = vec![quotes.env(), quotes.get(0).unwrap().amount_in];
+    for quote in quotes.iter() {
+        amounts.push_back(quote.amount_out);
+    }
+    amounts
+}
+
+#[test]
+fn route_quote_not_yet_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_router_get_route_quote_out(&1_000_000, &path(&test));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+
+    let result = test.contract.try_router_get_route_quote_in(&1_000_000, &path(&test));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn route_quote_invalid_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let short_path = vec![&test.env, test.token_0.address.clone()];
+
+    let result = test.contract.try_router_get_route_quote_out(&1_000_000, &short_path);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+
+    let result = test.contract.try_router_get_route_quote_in(&1_000_000, &short_path);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
+
+#[test]
+fn route_quote_out() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let quotes = test.contract.router_get_route_quote_out(&1_000_000, &path(&test));
+    assert_eq!(
+        quotes,
+        vec![
+            &test.env,
+            RouteQuote {
+                pair: test.contract.router_pair_for(&test.token_0.address, &test.token_1.address),
+                token_in: test.token_0.address.clone(),
+                token_out: test.token_1.address.clone(),
+                amount_in: 1_000_000,
+                amount_out: 3_984_027,
+                reserve_in_before: 1_000_000_000,
+                reserve_out_before: 4_000_000_000,
+                reserve_in_after: 1_001_000_000,
+                reserve_out_after: 4_000_000_000 - 3_984_027,
+                spot_price: 4 * PRICE_SCALE,
+                execution_price: 3_984_027_000_000_000_000,
+                // 30 basis points of fee and 9.9 of price move, rounded up
+                price_impact_bps: 40,
+            },
+        ]
+    );
+
+    // The quote matches the executed swap
+    let swap_amounts = test.contract.swap_exact_tokens_for_tokens(&1_000_000, &0, &path(&test), &test.user, &1000);
+    assert_eq!(amounts(&quotes), swap_amounts);
+}
+
+#[test]
+fn route_quote_in() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let quotes = test.contract.router_get_route_quote_in(&3_984_027, &path(&test));
+    assert_eq!(quotes.len(), 1);
+    let quote = quotes.get(0).unwrap();
+    assert_eq!(amounts(&quotes), test.contract.router_get_amounts_in(&3_984_027, &path(&test)));
+    assert_eq!(quote.amount_out, 3_984_027);
+    assert_eq!(quote.reserve_in_after, quote.reserve_in_before + quote.amount_in);
+    assert_eq!(quote.reserve_out_after, quote.reserve_out_before - 3_984_027);
+    assert_eq!(quote.spot_price, 4 * PRICE_SCALE);
+    assert_eq!(quote.price_impact_bps, 40);
+}
+
+#[test]
+fn route_quote_multi_hop() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let token_2 = setup_hop(&test);
+    let hop_path = vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()];
+
+    let quotes = test.contract.router_get_route_quote_out(&100_000_000, &hop_path);
+    assert_eq!(amounts(&quotes), test.contract.router_get_amounts_out(&100_000_000, &hop_path));
+    assert_eq!(quotes.len(), 2);
+
+    let first_hop = quotes.get(0).unwrap();
+    let second_hop = quotes.get(1).unwrap();
+    assert_eq!(first_hop.pair, test.contract.router_pair_for(&test.token_0.address, &token_2.address));
+    assert_eq!(second_hop.pair, test.contract.router_pair_for(&token_2.address, &test.token_1.address));
+    assert_eq!(first_hop.token_out, second_hop.token_in);
+    assert_eq!(first_hop.amount_out, second_hop.amount_in);
+    assert_eq!(first_hop.spot_price, 2 * PRICE_SCALE);
+    assert_eq!(second_hop.spot_price, 2 * PRICE_SCALE);
+
+    // A larger trade moves the price further
+    let larger_quotes = test.contract.router_get_route_quote_in(&400_000_000, &hop_path);
+    assert_eq!(amounts(&larger_quotes), test.contract.router_get_amounts_in(&400_000_000, &hop_path));
+    assert!(larger_quotes.get(1).unwrap().price_impact_bps > second_hop.price_impact_bps);
+}
@@ -0,0 +1,238 @@
+extern crate std;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec,
+    symbol_short,
+    Address,
+    IntoVal,
+    Val,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, TokenClient, create_token_contract};
+use crate::error::CombinedRouterError;
+use crate::event::SwapEvent;
+use crate::SwapReferral;
+use crate::SwapLeg;
+
+// Creates the pairs token_0/token_1, token_0/token_2 and token_2/token_1,
+// so token_0 can be swapped for token_1 directly or through token_2.
+fn setup_routes<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    test.contract.initialize(&test.factory.address);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+
+    for (token_a, token_b, amount_a, amount_b) in [
+        (&test.token_0.address, &test.token_1.address, 1_000_000_000_i128, 4_000_000_000_i128),
+        (&test.token_0.address, &token_2.address, 1_000_000_000, 2_000_000_000),
+        (&token_2.address, &test.token_1.address, 2_000_000_000, 8_000_000_000),
+    ] {
+        test.contract.add_liquidity(token_a, token_b, &amount_a, &amount_b, &0, &0, &test.user, &deadline);
+    }
+    token_2
+}
+
+fn direct_path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+fn hop_path(test: &SoroswapRouterTest, token_2: &TokenClient) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()]
+}
+
+fn router_swap_events(test: &SoroswapRouterTest) -> std::vec::Vec<SwapEvent> {
+    let swap_topics: Vec<Val> = ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env);
+    let mut swap_events = std::vec::Vec::new();
+    for (contract, topics, data) in test.env.events().all().iter() {
+        if contract == test.contract.address && topics == swap_topics {
+            swap_events.push(data.into_val(&test.env));
+        }
+    }
+    swap_events
+}
+
+#[test]
+fn swap_exact_tokens_split() {
+    let test = SoroswapRouterTest::setup();
+    let token_2 = setup_routes(&test);
+    let bob = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let direct_amounts = test.contract.router_get_amounts_out(&10_000_000, &direct_path(&test));
+    let hop_amounts = test.contract.router_get_amounts_out(&5_000_000, &hop_path(&test, &token_2));
+    let expected_amount_out = direct_amounts.last().unwrap() + hop_amounts.last().unwrap();
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    let legs = vec![
+        &test.env,
+        SwapLeg { path: direct_path(&test), amount: 10_000_000 },
+        SwapLeg { path: hop_path(&test, &token_2), amount: 5_000_000 },
+    ];
+    let legs_amounts = test.contract.swap_exact_tokens_split(
+        &test.user,
+        &legs,
+        &expected_amount_out,
+        &bob,
+        &deadline,
+    );
+
+    assert_eq!(legs_amounts, vec![&test.env, direct_amounts.clone(), hop_amounts.clone()]);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 - 15_000_000);
+    assert_eq!(test.token_1.balance(&bob), expected_amount_out);
+
+    // One swap event per leg
+    let swap_events = router_swap_events(&test);
+    assert_eq!(
+        swap_events[swap_events.len() - 2..],
+        [
+            SwapEvent { path: direct_path(&test), amounts: direct_amounts, from: test.user.clone(), to: bob.clone(), referral: SwapReferral::NoFee },
+            SwapEvent { path: hop_path(&test, &token_2), amounts: hop_amounts, from: test.user.clone(), to: bob, referral: SwapReferral::NoFee },
+        ]
+    );
+}
+
+#[test]
+fn swap_exact_tokens_split_same_path_sees_updated_reserves() {
+    let test = SoroswapRouterTest::setup();
+    setup_routes(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let first_amounts = test.contract.router_get_amounts_out(&10_000_000, &direct_path(&test));
+    let legs = vec![
+        &test.env,
+        SwapLeg { path: direct_path(&test), amount: 10_000_000 },
+        SwapLeg { path: direct_path(&test), amount: 10_000_000 },
+    ];
+    let legs_amounts = test.contract.swap_exact_tokens_split(&test.user, &legs, &0, &test.user, &deadline);
+
+    // The second leg trades against the reserves left by the first one
+    assert_eq!(legs_amounts.get(0).unwrap(), first_amounts);
+    assert!(legs_amounts.get(1).unwrap().get(1).unwrap() < first_amounts.get(1).unwrap());
+}
+
+#[test]
+fn swap_exact_tokens_split_insufficient_output_amount() {
+    let test = SoroswapRouterTest::setup();
+    let token_2 = setup_routes(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let direct_out = test.contract.router_get_amounts_out(&10_000_000, &direct_path(&test)).last().unwrap();
+    let hop_out = test.contract.router_get_amounts_out(&5_000_000, &hop_path(&test, &token_2)).last().unwrap();
+
+    let legs = vec![
+        &test.env,
+        SwapLeg { path: direct_path(&test), amount: 10_000_000 },
+        SwapLeg { path: hop_path(&test, &token_2), amount: 5_000_000 },
+    ];
+    let result = test.contract.try_swap_exact_tokens_split(
+        &test.user,
+        &legs,
+        &(direct_out + hop_out + 1),
+        &test.user,
+        &deadline,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
+
+#[test]
+fn swap_exact_tokens_split_invalid_route() {
+    let test = SoroswapRouterTest::setup();
+    let token_2 = setup_routes(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // No legs
+    let result = test.contract.try_swap_exact_tokens_split(&test.user, &vec![&test.env], &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidSplitRoute)));
+
+    // Legs ending in different tokens
+    let legs = vec![
+        &test.env,
+        SwapLeg { path: direct_path(&test), amount: 10_000_000 },
+        SwapLeg { path: vec![&test.env, test.token_0.address.clone(), token_2.address.clone()], amount: 10_000_000 },
+    ];
+    let result = test.contract.try_swap_exact_tokens_split(&test.user, &legs, &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidSplitRoute)));
+
+    // Negative leg amount
+    let legs = vec![
+        &test.env,
+        SwapLeg { path: direct_path(&test), amount: -1 },
+    ];
+    let result = test.contract.try_swap_exact_tokens_split(&test.user, &legs, &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNegativeNotAllowed)));
+}
+
+#[test]
+fn swap_exact_tokens_split_deadline_expired() {
+    let test = SoroswapRouterTest::setup();
+    setup_routes(&test);
+    let legs = vec![
+        &test.env,
+        SwapLeg { path: direct_path(&test), amount: 10_000_000 },
+    ];
+    let result = test.contract.try_swap_exact_tokens_split(&test.user, &legs, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+}
+
+#[test]
+fn swap_for_exact_tokens_split() {
+    let test = SoroswapRouterTest::setup();
+    let token_2 = setup_routes(&test);
+    let bob = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let direct_amounts = test.contract.router_get_amounts_in(&20_000_000, &direct_path(&test));
+    let hop_amounts = test.contract.router_get_amounts_in(&10_000_000, &hop_path(&test, &token_2));
+    let expected_amount_in = direct_amounts.first().unwrap() + hop_amounts.first().unwrap();
+    let user_balance_0 = test.token_0.balance(&test.user);
+
+    let legs = vec![
+        &test.env,
+        SwapLeg { path: direct_path(&test), amount: 20_000_000 },
+        SwapLeg { path: hop_path(&test, &token_2), amount: 10_000_000 },
+    ];
+    let legs_amounts = test.contract.swap_for_exact_tokens_split(
+        &test.user,
+        &legs,
+        &expected_amount_in,
+        &bob,
+        &deadline,
+    );
+
+    assert_eq!(legs_amounts, vec![&test.env, direct_amounts.clone(), hop_amounts.clone()]);
+    assert_eq!(test.token_0.balance(&test.user), user_balance_0 - expected_amount_in);
+    assert_eq!(test.token_1.balance(&bob), 30_000_000);
+
+    let swap_events = router_swap_events(&test);
+    assert_eq!(
+        swap_events[swap_events.len() - 2..],
+        [
+            SwapEvent { path: direct_path(&test), amounts: direct_amounts, from: test.user.clone(), to: bob.clone(), referral: SwapReferral::NoFee },
+            SwapEvent { path: hop_path(&test, &token_2), amounts: hop_amounts, from: test.user.clone(), to: bob, referral: SwapReferral::NoFee },
+        ]
+    );
+}
+
+#[test]
+fn swap_for_exact_tokens_split_excessive_input_amount() {
+    let test = SoroswapRouterTest::setup();
+    let token_2 = setup_routes(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let direct_in = test.contract.router_get_amounts_in(&20_000_000, &direct_path(&test)).first().unwrap();
+    let hop_in = test.contract.router_get_amounts_in(&10_000_000, &hop_path(&test, &token_2)).first().unwrap();
+
+    let legs = vec![
+        &test.env,
+        SwapLeg { path: direct_path(&test), amount: 20_000_000 },
+        SwapLeg { path: hop_path(&test, &token_2), amount: 10_000_000 },
+    ];
+    let result = test.contract.try_swap_for_exact_tokens_split(
+        &test.user,
+        &legs,
+        &(direct_in + hop_in - 1),
+        &test.user,
+        &deadline,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterExcessiveInputAmount)));
+}
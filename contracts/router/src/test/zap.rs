@@ -0,0 +1,305 @@
+extern crate std;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec,
+    symbol_short,
+    Address,
+    IntoVal};
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient, TokenClient, create_token_contract};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::{ZapInEvent, ZapOutEvent};
+
+// Creates token_2 and the token_2/token_0 pair, so token_2 can be zapped through token_0.
+fn setup_token_2<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+    test.contract.add_liquidity(
+        &token_2.address,
+        &test.token_0.address,
+        &1_000_000_000,
+        &1_000_000_000,
+        &0,
+        &0,
+        &test.user,
+        &1000);
+    token_2
+}
+
+fn assert_router_holds_nothing(test: &SoroswapRouterTest) {
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn zap_in() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let pair_client = SoroswapPairClient::new(&test.env, &test.contract.router_pair_for(&test.token_0.address, &test.token_1.address));
+    let bob = Address::generate(&test.env);
+    let user_balance_0 = test.token_0.balance(&test.user);
+    let user_balance_1 = test.token_1.balance(&test.user);
+
+    let (amount_a, amount_b, liquidity) = test.contract.zap_in(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &vec![&test.env, test.token_0.address.clone()],
+        &10_000_000,
+        &0,
+        &bob,
+        &1000,
+    );
+
+    assert!(liquidity > 0);
+    assert_eq!(pair_client.balance(&bob), liquidity);
+    assert_router_holds_nothing(&test);
+
+    // Nearly all the input ends up in the pool, the rest is refunded to the user
+    let spent_0 = user_balance_0 - test.token_0.balance(&test.user);
+    let refund_1 = test.token_1.balance(&test.user) - user_balance_1;
+    assert!(spent_0 <= 10_000_000 && spent_0 >= 10_000_000 - 1);
+    assert!(refund_1 <= 4);
+    assert!(amount_a > 0 && amount_b > 0);
+}
+
+#[test]
+fn zap_in_through_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let token_2 = setup_token_2(&test);
+    let user_balance_2 = token_2.balance(&test.user);
+
+    // token_2 is swapped into token_0 first, and token_a can be either token of the pair
+    let path = vec![&test.env, token_2.address.clone(), test.token_0.address.clone()];
+    let (_, _, liquidity) = test.contract.zap_in(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &path,
+        &10_000_000,
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert!(liquidity > 0);
+    assert_eq!(token_2.balance(&test.user), user_balance_2 - 10_000_000);
+    assert_eq!(token_2.balance(&test.contract.address), 0);
+    assert_router_holds_nothing(&test);
+
+    let (_, _, liquidity) = test.contract.zap_in(
+        &test.user,
+        &test.token_1.address,
+        &test.token_0.address,
+        &vec![&test.env, test.token_1.address.clone()],
+        &10_000_000,
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert!(liquidity > 0);
+    assert_router_holds_nothing(&test);
+}
+
+#[test]
+fn zap_in_insufficient_liquidity() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let result = test.contract.try_zap_in(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &vec![&test.env, test.token_0.address.clone()],
+        &10_000_000,
+        &1_000_000_000,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientLiquidity)));
+}
+
+#[test]
+fn zap_in_invalid_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    // The path must end in token_a
+    let result = test.contract.try_zap_in(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &vec![&test.env, test.token_1.address.clone()],
+        &10_000_000,
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+
+    let result = test.contract.try_zap_in(
+        &test.user,
+        &test.token_0.address,
+        &Address::generate(&test.env),
+        &vec![&test.env, test.token_0.address.clone()],
+        &10_000_000,
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairDoesNotExist)));
+}
+
+#[test]
+fn zap_in_event() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let pair = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    let user_balance_0 = test.token_0.balance(&test.user);
+    let user_balance_1 = test.token_1.balance(&test.user);
+
+    let (amount_a, amount_b, liquidity) = test.contract.zap_in(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &vec![&test.env, test.token_0.address.clone()],
+        &10_000_000,
+        &0,
+        &test.user,
+        &1000,
+    );
+
+    let refund_a = 10_000_000 - (user_balance_0 - test.token_0.balance(&test.user));
+    let refund_b = test.token_1.balance(&test.user) - user_balance_1;
+    let event: ZapInEvent = test.env.events().all().last().unwrap().2.into_val(&test.env);
+    assert_eq!(
+        event,
+        ZapInEvent {
+            token_in: test.token_0.address.clone(),
+            amount_in: 10_000_000,
+            pair: pair.clone(),
+            amount_a,
+            amount_b,
+            liquidity,
+            refund_a,
+            refund_b,
+            from: test.user.clone(),
+            to: test.user.clone(),
+        }
+    );
+    assert_eq!(
+        test.env.events().all().last().unwrap().1,
+        ("SoroswapRouter", symbol_short!("zap_in")).into_val(&test.env)
+    );
+}
+
+#[test]
+fn zap_out() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let pair_client = SoroswapPairClient::new(&test.env, &test.contract.router_pair_for(&test.token_0.address, &test.token_1.address));
+    let bob = Address::generate(&test.env);
+
+    let amount_out = test.contract.zap_out(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &(liquidity / 2),
+        &vec![&test.env, test.token_0.address.clone()],
+        &0,
+        &bob,
+        &1000,
+    );
+
+    // Half the pool is withdrawn and the token_1 half is sold back into the pool
+    assert!(amount_out > 500_000_000 && amount_out < 1_000_000_000);
+    assert_eq!(test.token_0.balance(&bob), amount_out);
+    assert_eq!(test.token_1.balance(&bob), 0);
+    assert_eq!(pair_client.balance(&test.user), liquidity - liquidity / 2);
+    assert_router_holds_nothing(&test);
+}
+
+#[test]
+fn zap_out_through_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let token_2 = setup_token_2(&test);
+    let bob = Address::generate(&test.env);
+
+    let amount_out = test.contract.zap_out(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &(liquidity / 10),
+        &vec![&test.env, test.token_0.address.clone(), token_2.address.clone()],
+        &0,
+        &bob,
+        &1000,
+    );
+
+    assert!(amount_out > 0);
+    assert_eq!(token_2.balance(&bob), amount_out);
+    assert_eq!(test.token_0.balance(&bob), 0);
+    assert_eq!(token_2.balance(&test.contract.address), 0);
+    assert_router_holds_nothing(&test);
+
+    let expected_event = ZapOutEvent {
+        pair: test.contract.router_pair_for(&test.token_0.address, &test.token_1.address),
+        liquidity: liquidity / 10,
+        token_out: token_2.address.clone(),
+        amount_out,
+        from: test.user.clone(),
+        to: bob,
+    };
+    assert_eq!(
+        vec![&test.env, test.env.events().all().last().unwrap()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("zap_out")).into_val(&test.env),
+                expected_event.into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn zap_out_insufficient_output_amount() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+
+    let result = test.contract.try_zap_out(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &(liquidity / 2),
+        &vec![&test.env, test.token_0.address.clone()],
+        &1_000_000_000,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+
+    // The path must start in token_a
+    let result = test.contract.try_zap_out(
+        &test.user,
+        &test.token_0.address,
+        &test.token_1.address,
+        &(liquidity / 2),
+        &vec![&test.env, test.token_1.address.clone()],
+        &0,
+        &test.user,
+        &1000,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
@@ -0,0 +1,70 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use crate::error::CombinedRouterError;
+
+/// The quote of one hop of a trading route, as the pair would execute it at its current reserves.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RouteQuote {
+    /// The pair the hop trades through.
+    pub pair: Address,
+    /// The token sent to the pair.
+    pub token_in: Address,
+    /// The token received from the pair.
+    pub token_out: Address,
+    /// The amount of `token_in` sent to the pair.
+    pub amount_in: i128,
+    /// The amount of `token_out` received from the pair.
+    pub amount_out: i128,
+    /// Reserves of `token_in` in the pair before the hop.
+    pub reserve_in_before: i128,
+    /// Reserves of `token_out` in the pair before the hop.
+    pub reserve_out_before: i128,
+    /// Reserves of `token_in` in the pair after the hop, including the fee.
+    pub reserve_in_after: i128,
+    /// Reserves of `token_out` in the pair after the hop.
+    pub reserve_out_after: i128,
+    /// Amount of `token_out` per `token_in` at the reserves before the hop, scaled by `PRICE_SCALE`.
+    pub spot_price: i128,
+    /// Amount of `token_out` received per `token_in` paid, scaled by `PRICE_SCALE`.
+    pub execution_price: i128,
+    /// How much worse the execution price is than the spot price, in basis points.
+    pub price_impact_bps: i128,
+}
+
+/// Builds the quote of every hop of `path`, given the amounts at each step of it as returned by
+/// `get_amounts_out` or `get_amounts_in`. Every hop is quoted at the current reserves of its pair.
+pub fn route_quotes(
+    e: &Env,
+    factory: &Address,
+    path: &Vec<Address>,
+    amounts: &Vec<i128>,
+) -> Result<Vec<RouteQuote>, CombinedRouterError> {
+    let mut quotes: Vec<RouteQuote> = Vec::new(e);
+    for i in 0..path.len() - 1 {
+        let token_in = path.get(i).unwrap();
+        let token_out = path.get(i + 1).unwrap();
+        let amount_in = amounts.get(i).unwrap();
+        let amount_out = amounts.get(i + 1).unwrap();
+
+        let pair = soroswap_library::pair_for(e.clone(), factory.clone(), token_in.clone(), token_out.clone())?;
+        let (reserve_in, reserve_out) = soroswap_library::get_reserves(e.clone(), factory.clone(), token_in.clone(), token_out.clone())?;
+        let spot_price = soroswap_library::get_spot_price(reserve_in, reserve_out)?;
+        let execution_price = soroswap_library::get_execution_price(amount_in, amount_out)?;
+
+        quotes.push_back(RouteQuote {
+            pair,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            reserve_in_before: reserve_in,
+            reserve_out_before: reserve_out,
+            reserve_in_after: reserve_in.checked_add(amount_in).unwrap(),
+            reserve_out_after: reserve_out.checked_sub(amount_out).unwrap(),
+            spot_price,
+            execution_price,
+            price_impact_bps: soroswap_library::get_price_impact_bps(spot_price, execution_price)?,
+        });
+    }
+    Ok(quotes)
+}